@@ -0,0 +1,295 @@
+//! ORCA tuning panel: live edits to `AvoidanceConfig` from the keyboard.
+//! Starts OFF; press F8 to toggle. While open, `,`/`.` select a parameter and
+//! `[`/`]` decrease/increase it.
+
+use bevy::prelude::*;
+
+use crate::gameplay::units::avoidance::AvoidanceConfig;
+use crate::screens::GameState;
+use crate::theme::palette;
+
+/// Gap (pixels) between the panel and the top-right corner of the window.
+const PANEL_MARGIN: f32 = 12.0;
+
+/// Marker resource: when present, the tuning panel is shown and keys apply.
+#[derive(Resource)]
+struct ShowAvoidanceTuning;
+
+/// Marks the panel's text node.
+#[derive(Component)]
+struct AvoidanceTuningPanel;
+
+/// A tunable `AvoidanceConfig` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum AvoidanceParam {
+    #[default]
+    TimeHorizon,
+    MaxNeighbors,
+    NeighborDistance,
+    VelocitySmoothing,
+}
+
+impl AvoidanceParam {
+    /// Panel order. `,`/`.` cycle through it.
+    const ALL: [Self; 4] = [
+        Self::TimeHorizon,
+        Self::MaxNeighbors,
+        Self::NeighborDistance,
+        Self::VelocitySmoothing,
+    ];
+
+    const fn label(self) -> &'static str {
+        match self {
+            Self::TimeHorizon => "time_horizon",
+            Self::MaxNeighbors => "max_neighbors",
+            Self::NeighborDistance => "neighbor_distance",
+            Self::VelocitySmoothing => "velocity_smoothing",
+        }
+    }
+
+    fn value(self, config: &AvoidanceConfig) -> String {
+        match self {
+            Self::TimeHorizon => format!("{:.2}s", config.time_horizon),
+            Self::MaxNeighbors => config.max_neighbors.to_string(),
+            Self::NeighborDistance => format!("{:.0}px", config.neighbor_distance),
+            Self::VelocitySmoothing => format!("{:.2}", config.velocity_smoothing),
+        }
+    }
+
+    /// Move the field one step up (`increase`) or down, within sane bounds.
+    fn step(self, config: &mut AvoidanceConfig, increase: bool) {
+        let sign = if increase { 1.0 } else { -1.0 };
+        match self {
+            Self::TimeHorizon => {
+                config.time_horizon = sign.mul_add(0.25, config.time_horizon).max(0.25);
+            }
+            Self::MaxNeighbors => {
+                config.max_neighbors = if increase {
+                    config.max_neighbors + 1
+                } else {
+                    config.max_neighbors.saturating_sub(1).max(1)
+                };
+            }
+            Self::NeighborDistance => {
+                config.neighbor_distance = sign.mul_add(10.0, config.neighbor_distance).max(10.0);
+            }
+            Self::VelocitySmoothing => {
+                config.velocity_smoothing = sign
+                    .mul_add(0.05, config.velocity_smoothing)
+                    .clamp(0.0, 1.0);
+            }
+        }
+    }
+
+    fn cycle(self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let index = Self::ALL.iter().position(|&p| p == self).unwrap_or(0);
+        let next = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        Self::ALL[next]
+    }
+}
+
+/// Parameter the `[`/`]` keys currently adjust.
+#[derive(Resource, Debug, Default)]
+struct SelectedAvoidanceParam(AvoidanceParam);
+
+/// Toggle the tuning panel with F8.
+fn toggle_avoidance_tuning(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    existing: Option<Res<ShowAvoidanceTuning>>,
+) {
+    if input.just_pressed(KeyCode::F8) {
+        if existing.is_some() {
+            commands.remove_resource::<ShowAvoidanceTuning>();
+        } else {
+            commands.insert_resource(ShowAvoidanceTuning);
+        }
+    }
+}
+
+/// Apply selection and adjustment keys to the live `AvoidanceConfig`.
+/// The spatial hashes' cell size is independent of `neighbor_distance`, so
+/// changing the query radius needs no rebuild.
+fn adjust_avoidance_config(
+    input: Res<ButtonInput<KeyCode>>,
+    mut selected: ResMut<SelectedAvoidanceParam>,
+    mut config: ResMut<AvoidanceConfig>,
+) {
+    if input.just_pressed(KeyCode::Period) {
+        selected.0 = selected.0.cycle(true);
+    }
+    if input.just_pressed(KeyCode::Comma) {
+        selected.0 = selected.0.cycle(false);
+    }
+
+    let increase = input.just_pressed(KeyCode::BracketRight);
+    if !increase && !input.just_pressed(KeyCode::BracketLeft) {
+        return;
+    }
+
+    selected.0.step(&mut config, increase);
+}
+
+/// Panel text: one line per parameter, the selected one marked with `>`.
+fn panel_text(config: &AvoidanceConfig, selected: AvoidanceParam) -> String {
+    let mut text = String::from("ORCA tuning  [,/.] select  [[/]] adjust");
+    for param in AvoidanceParam::ALL {
+        let marker = if param == selected { ">" } else { " " };
+        text.push_str(&format!(
+            "\n{marker} {}: {}",
+            param.label(),
+            param.value(config)
+        ));
+    }
+    text
+}
+
+/// Spawn the panel while the overlay is on, keep its text current, and
+/// despawn it when the overlay goes off.
+fn sync_tuning_panel(
+    mut commands: Commands,
+    overlay: Option<Res<ShowAvoidanceTuning>>,
+    config: Res<AvoidanceConfig>,
+    selected: Res<SelectedAvoidanceParam>,
+    mut panels: Query<(Entity, &mut Text), With<AvoidanceTuningPanel>>,
+) {
+    let text = panel_text(&config, selected.0);
+    match (overlay.is_some(), panels.is_empty()) {
+        (true, true) => {
+            commands.spawn((
+                Name::new("Avoidance Tuning Panel"),
+                AvoidanceTuningPanel,
+                Text::new(text),
+                TextFont::from_font_size(palette::FONT_SIZE_SMALL),
+                TextColor(palette::BODY_TEXT),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(PANEL_MARGIN),
+                    right: Val::Px(PANEL_MARGIN),
+                    ..default()
+                },
+                DespawnOnExit(GameState::InGame),
+            ));
+        }
+        (true, false) => {
+            for (_, mut current) in &mut panels {
+                if **current != text {
+                    **current = text.clone();
+                }
+            }
+        }
+        (false, false) => {
+            for (panel, _) in &panels {
+                commands.entity(panel).despawn();
+            }
+        }
+        (false, true) => {}
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<SelectedAvoidanceParam>();
+    app.add_systems(Update, toggle_avoidance_tuning);
+    app.add_systems(
+        Update,
+        (
+            adjust_avoidance_config.run_if(resource_exists::<ShowAvoidanceTuning>),
+            sync_tuning_panel,
+        )
+            .chain()
+            .run_if(in_state(GameState::InGame)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::spatial_hash::SpatialHash;
+    use crate::gameplay::units::avoidance::{AvoidanceSpatialHash, ObstacleSpatialHash};
+    use pretty_assertions::assert_eq;
+
+    fn create_tuning_test_app(selected: AvoidanceParam) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        crate::testing::init_input_resources(&mut app);
+        let config = AvoidanceConfig::default();
+        app.insert_resource(AvoidanceSpatialHash(SpatialHash::new(
+            config.hash_cell_size,
+        )));
+        app.insert_resource(ObstacleSpatialHash(SpatialHash::new(config.hash_cell_size)));
+        app.insert_resource(config);
+        app.insert_resource(SelectedAvoidanceParam(selected));
+        app.add_systems(Update, adjust_avoidance_config);
+        app
+    }
+
+    fn press(app: &mut App, key: KeyCode) {
+        let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        input.release_all();
+        input.clear();
+        input.press(key);
+        app.update();
+    }
+
+    #[test]
+    fn neighbor_distance_change_keeps_hash_cell_size() {
+        let mut app = create_tuning_test_app(AvoidanceParam::NeighborDistance);
+        let before = app.world().resource::<AvoidanceConfig>().neighbor_distance;
+
+        press(&mut app, KeyCode::BracketRight);
+
+        let config = app.world().resource::<AvoidanceConfig>();
+        assert_eq!(config.neighbor_distance, before + 10.0);
+        let cell_size = config.hash_cell_size;
+        assert_eq!(
+            app.world().resource::<AvoidanceSpatialHash>().cell_size(),
+            cell_size
+        );
+        assert_eq!(
+            app.world().resource::<ObstacleSpatialHash>().cell_size(),
+            cell_size
+        );
+    }
+
+    #[test]
+    fn decrease_key_steps_selected_param_down() {
+        let mut app = create_tuning_test_app(AvoidanceParam::TimeHorizon);
+
+        press(&mut app, KeyCode::BracketLeft);
+
+        let config = app.world().resource::<AvoidanceConfig>();
+        assert_eq!(config.time_horizon, 2.75);
+    }
+
+    #[test]
+    fn selection_cycles_and_wraps() {
+        let mut app = create_tuning_test_app(AvoidanceParam::VelocitySmoothing);
+
+        press(&mut app, KeyCode::Period);
+        assert_eq!(
+            app.world().resource::<SelectedAvoidanceParam>().0,
+            AvoidanceParam::TimeHorizon
+        );
+
+        press(&mut app, KeyCode::Comma);
+        assert_eq!(
+            app.world().resource::<SelectedAvoidanceParam>().0,
+            AvoidanceParam::VelocitySmoothing
+        );
+    }
+
+    #[test]
+    fn velocity_smoothing_stays_within_unit_range() {
+        let mut config = AvoidanceConfig {
+            velocity_smoothing: 0.98,
+            ..default()
+        };
+        AvoidanceParam::VelocitySmoothing.step(&mut config, true);
+        assert_eq!(config.velocity_smoothing, 1.0);
+    }
+}
@@ -0,0 +1,258 @@
+//! Per-`GameSet` frame budget guard. Records each set's execution time as a
+//! diagnostic (`game_set/<set>`, in ms) and warns when one runs over its
+//! millisecond budget. Starts OFF; press F5 to toggle.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bevy::diagnostic::{
+    Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore, RegisterDiagnostic,
+};
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+
+use crate::GameSet;
+
+/// Sets in execution order with their diagnostic paths. Must match the chain in
+/// `crate::plugin`.
+const GAME_SETS: [(GameSet, DiagnosticPath); 7] = [
+    (GameSet::Input, DiagnosticPath::const_new("game_set/input")),
+    (
+        GameSet::Production,
+        DiagnosticPath::const_new("game_set/production"),
+    ),
+    (GameSet::Ai, DiagnosticPath::const_new("game_set/ai")),
+    (
+        GameSet::Movement,
+        DiagnosticPath::const_new("game_set/movement"),
+    ),
+    (
+        GameSet::Combat,
+        DiagnosticPath::const_new("game_set/combat"),
+    ),
+    (GameSet::Death, DiagnosticPath::const_new("game_set/death")),
+    (GameSet::Ui, DiagnosticPath::const_new("game_set/ui")),
+];
+
+/// Budget applied to every set without an override.
+const DEFAULT_BUDGET_MS: f32 = 4.0;
+
+/// Per-set millisecond budgets. When present, the guard is active.
+#[derive(Resource, Debug, Clone)]
+struct SetBudgets {
+    default_ms: f32,
+    overrides: HashMap<GameSet, f32>,
+}
+
+impl Default for SetBudgets {
+    fn default() -> Self {
+        Self {
+            default_ms: DEFAULT_BUDGET_MS,
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+impl SetBudgets {
+    /// Budget for `set`.
+    fn budget(&self, set: GameSet) -> Duration {
+        let ms = self.overrides.get(&set).copied().unwrap_or(self.default_ms);
+        Duration::from_secs_f32(ms / 1000.0)
+    }
+}
+
+/// When each set started this frame. The measured span goes to the diagnostics store.
+#[derive(Resource, Debug, Default)]
+struct SetStarts(HashMap<GameSet, Instant>);
+
+/// Sets whose latest measurement exceeded their budget.
+#[derive(Resource, Debug, Default)]
+struct OverBudget(Vec<GameSet>);
+
+fn start_set_timer(set: GameSet) -> impl FnMut(ResMut<SetStarts>) {
+    move |mut starts: ResMut<SetStarts>| {
+        starts.0.insert(set, Instant::now());
+    }
+}
+
+fn finish_set_timer(
+    set: GameSet,
+    path: DiagnosticPath,
+) -> impl FnMut(ResMut<SetStarts>, Diagnostics) {
+    move |mut starts: ResMut<SetStarts>, mut diagnostics: Diagnostics| {
+        let Some(started) = starts.0.remove(&set) else {
+            return;
+        };
+        diagnostics.add_measurement(&path, || started.elapsed().as_secs_f64() * 1000.0);
+    }
+}
+
+/// Compares each set's latest measurement against its budget. Runs in `Last`,
+/// once this frame's measurements have landed in the store.
+fn check_set_budgets(
+    store: Res<DiagnosticsStore>,
+    budgets: Res<SetBudgets>,
+    mut over_budget: ResMut<OverBudget>,
+) {
+    over_budget.0.clear();
+    for (set, path) in &GAME_SETS {
+        let Some(ms) = store
+            .get_measurement(path)
+            .map(|measurement| measurement.value)
+        else {
+            continue;
+        };
+        let elapsed = Duration::from_secs_f64(ms / 1000.0);
+        let budget = budgets.budget(*set);
+        if elapsed > budget {
+            warn!(
+                "{set:?} took {ms:.2}ms (budget {:.2}ms)",
+                budget.as_secs_f32() * 1000.0
+            );
+            over_budget.0.push(*set);
+        }
+    }
+}
+
+/// Toggle the frame budget guard with F5.
+fn toggle_frame_budget(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    existing: Option<Res<SetBudgets>>,
+) {
+    if input.just_pressed(KeyCode::F5) {
+        if existing.is_some() {
+            commands.remove_resource::<SetBudgets>();
+        } else {
+            commands.insert_resource(SetBudgets::default());
+        }
+    }
+}
+
+/// Registers a diagnostic per `GameSet` and the timing systems around each set.
+/// Split from `plugin` so tests can run without input.
+fn add_set_timers(app: &mut App) {
+    app.init_resource::<SetStarts>()
+        .init_resource::<OverBudget>();
+
+    for (index, (set, path)) in GAME_SETS.iter().enumerate() {
+        app.register_diagnostic(Diagnostic::new(path.clone()).with_suffix("ms"));
+
+        let mut start = start_set_timer(*set).before(*set);
+        if let Some((previous, _)) = index.checked_sub(1).and_then(|i| GAME_SETS.get(i)) {
+            start = start.after(*previous);
+        }
+        let mut finish = finish_set_timer(*set, path.clone()).after(*set);
+        if let Some((next, _)) = GAME_SETS.get(index + 1) {
+            finish = finish.before(*next);
+        }
+        app.add_systems(
+            Update,
+            (start, finish).run_if(resource_exists::<SetBudgets>),
+        );
+    }
+    app.add_systems(
+        Last,
+        check_set_budgets.run_if(resource_exists::<SetBudgets>),
+    );
+}
+
+pub(super) fn plugin(app: &mut App) {
+    add_set_timers(app);
+    app.add_systems(Update, toggle_frame_budget);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_budget_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::diagnostic::DiagnosticsPlugin));
+        app.configure_sets(
+            Update,
+            (
+                GameSet::Input,
+                GameSet::Production,
+                GameSet::Ai,
+                GameSet::Movement,
+                GameSet::Combat,
+                GameSet::Death,
+                GameSet::Ui,
+            )
+                .chain(),
+        );
+        add_set_timers(&mut app);
+        app.insert_resource(SetBudgets::default());
+        app
+    }
+
+    /// Latest measurement for `set`, in milliseconds.
+    fn measured_ms(app: &App, set: GameSet) -> Option<f64> {
+        let (_, path) = GAME_SETS.iter().find(|(s, _)| *s == set).unwrap();
+        app.world()
+            .resource::<DiagnosticsStore>()
+            .get_measurement(path)
+            .map(|measurement| measurement.value)
+    }
+
+    /// Injected span for the stalled set; well over `DEFAULT_BUDGET_MS`.
+    const STALL: Duration = Duration::from_millis(20);
+
+    /// Makes `set` measure `by` longer than it ran by moving its recorded start back.
+    fn stall(set: GameSet, by: Duration) -> impl FnMut(ResMut<SetStarts>) {
+        move |mut starts: ResMut<SetStarts>| {
+            if let Some(started) = starts.0.get_mut(&set) {
+                *started -= by;
+            }
+        }
+    }
+
+    #[test]
+    fn slow_set_is_flagged_over_budget() {
+        let mut app = create_budget_test_app();
+        app.add_systems(
+            Update,
+            stall(GameSet::Movement, STALL).in_set(GameSet::Movement),
+        );
+        app.update();
+
+        let over_budget = &app.world().resource::<OverBudget>().0;
+        assert!(
+            over_budget.contains(&GameSet::Movement),
+            "Expected Movement over budget, got {over_budget:?}"
+        );
+        assert!(!over_budget.contains(&GameSet::Input));
+        assert!(measured_ms(&app, GameSet::Movement).unwrap() >= 20.0);
+    }
+
+    #[test]
+    fn override_raises_budget_for_one_set() {
+        let mut app = create_budget_test_app();
+        app.world_mut()
+            .resource_mut::<SetBudgets>()
+            .overrides
+            .insert(GameSet::Movement, 1000.0);
+        app.add_systems(
+            Update,
+            stall(GameSet::Movement, STALL).in_set(GameSet::Movement),
+        );
+        app.update();
+
+        assert!(app.world().resource::<OverBudget>().0.is_empty());
+    }
+
+    #[test]
+    fn guard_is_inactive_without_budgets() {
+        let mut app = create_budget_test_app();
+        app.world_mut().remove_resource::<SetBudgets>();
+        app.add_systems(
+            Update,
+            stall(GameSet::Movement, STALL).in_set(GameSet::Movement),
+        );
+        app.update();
+
+        assert_eq!(measured_ms(&app, GameSet::Movement), None);
+        assert!(app.world().resource::<OverBudget>().0.is_empty());
+    }
+}
@@ -3,11 +3,16 @@
 //! Debug overlays, inspector setup, and diagnostic tools go here.
 //! This module is stripped from release builds.
 
+mod avoidance_tuning;
+mod frame_budget;
+mod zone_overlay;
+
 use bevy::prelude::*;
 use vleue_navigator::prelude::NavMeshesDebug;
 
 use avian2d::prelude::LinearVelocity;
 
+use crate::gameplay::battlefield::PlayerFortress;
 use crate::gameplay::units::Unit;
 use crate::gameplay::units::avoidance::PreferredVelocity;
 use crate::gameplay::units::pathfinding::NavPath;
@@ -16,6 +21,12 @@ use crate::gameplay::units::pathfinding::NavPath;
 #[derive(Resource)]
 struct ShowWorldInspector;
 
+/// Marker: combat skips damage to this entity. Toggled on the player fortress with F6
+/// for testing long runs without losing.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct Invincible;
+
 pub fn plugin(app: &mut App) {
     // Inspector requires EguiPlugin which needs the render backend.
     // Skip in headless test apps that use MinimalPlugins.
@@ -35,6 +46,19 @@ pub fn plugin(app: &mut App) {
         (debug_draw_unit_paths, debug_draw_avoidance)
             .run_if(crate::gameplay_running.and(resource_exists::<NavMeshesDebug>)),
     );
+
+    // Per-GameSet frame budget warnings start OFF. Press F5 to toggle.
+    app.add_plugins(frame_budget::plugin);
+
+    // Zone boundary overlay starts OFF. Press F7 to toggle.
+    app.add_plugins(zone_overlay::plugin);
+
+    // ORCA tuning panel starts OFF. Press F8 to toggle.
+    app.add_plugins(avoidance_tuning::plugin);
+
+    // Invincible player fortress starts OFF. Press F6 to toggle.
+    app.register_type::<Invincible>();
+    app.add_systems(Update, toggle_invincible_fortress);
 }
 
 /// Toggle world inspector with F4.
@@ -67,6 +91,24 @@ fn toggle_navmesh_debug(
     }
 }
 
+/// Toggle `Invincible` on the player fortress with F6.
+fn toggle_invincible_fortress(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    fortress: Query<(Entity, Has<Invincible>), With<PlayerFortress>>,
+) {
+    if !input.just_pressed(KeyCode::F6) {
+        return;
+    }
+    for (entity, invincible) in &fortress {
+        if invincible {
+            commands.entity(entity).remove::<Invincible>();
+        } else {
+            commands.entity(entity).insert(Invincible);
+        }
+    }
+}
+
 /// Draw ORCA debug visualization: green = preferred velocity, cyan = actual (ORCA-adjusted).
 fn debug_draw_avoidance(
     units: Query<(&GlobalTransform, &LinearVelocity, &PreferredVelocity), With<Unit>>,
@@ -0,0 +1,134 @@
+//! Zone boundary overlay: labeled vertical lines where the battlefield zones
+//! begin, so its layout is legible at a glance. Starts OFF; press F7 to toggle.
+
+use bevy::prelude::*;
+
+use crate::Z_POPUP;
+use crate::gameplay::battlefield::{
+    BATTLEFIELD_HEIGHT, BUILD_ZONE_START_X, CELL_SIZE, COMBAT_ZONE_START_COL, ENEMY_FORT_START_COL,
+    PLAYER_FORT_START_COL,
+};
+use crate::screens::GameState;
+use crate::theme::palette;
+
+/// Gap (pixels) between the top of the battlefield and the labels.
+const LABEL_MARGIN: f32 = 16.0;
+
+/// Line and label color.
+const OVERLAY_COLOR: Color = Color::srgb(1.0, 0.6, 0.0);
+
+/// Marker resource: when present, zone boundaries are drawn.
+#[derive(Resource)]
+struct ShowZoneOverlay;
+
+/// Marks a boundary label spawned by the overlay.
+#[derive(Component)]
+struct ZoneLabel;
+
+/// Left edge of each zone as `(label, world x)`, west to east.
+/// The build zone ends where the combat zone starts.
+fn zone_boundaries() -> [(&'static str, f32); 4] {
+    let col_x = |col: u16| f32::from(col) * CELL_SIZE;
+    [
+        ("Player fortress", col_x(PLAYER_FORT_START_COL)),
+        ("Build zone", BUILD_ZONE_START_X),
+        ("Combat zone", col_x(COMBAT_ZONE_START_COL)),
+        ("Enemy fortress", col_x(ENEMY_FORT_START_COL)),
+    ]
+}
+
+/// Toggle the zone overlay with F7.
+fn toggle_zone_overlay(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    existing: Option<Res<ShowZoneOverlay>>,
+) {
+    if input.just_pressed(KeyCode::F7) {
+        if existing.is_some() {
+            commands.remove_resource::<ShowZoneOverlay>();
+        } else {
+            commands.insert_resource(ShowZoneOverlay);
+        }
+    }
+}
+
+/// Draw a vertical line across the battlefield at each zone boundary.
+fn draw_zone_boundaries(mut gizmos: Gizmos) {
+    for (_, x) in zone_boundaries() {
+        gizmos.line_2d(
+            Vec2::new(x, 0.0),
+            Vec2::new(x, BATTLEFIELD_HEIGHT),
+            OVERLAY_COLOR,
+        );
+    }
+}
+
+/// Spawn the labels (centered above each line) while the overlay is on and
+/// despawn them when it goes off.
+fn sync_zone_labels(
+    mut commands: Commands,
+    overlay: Option<Res<ShowZoneOverlay>>,
+    labels: Query<Entity, With<ZoneLabel>>,
+) {
+    match (overlay.is_some(), labels.is_empty()) {
+        (true, true) => {
+            for (name, x) in zone_boundaries() {
+                commands.spawn((
+                    Name::new("Zone Label"),
+                    ZoneLabel,
+                    Text2d::new(name),
+                    TextFont::from_font_size(palette::FONT_SIZE_SMALL),
+                    TextColor(OVERLAY_COLOR),
+                    Transform::from_xyz(x, BATTLEFIELD_HEIGHT + LABEL_MARGIN, Z_POPUP),
+                    DespawnOnExit(GameState::InGame),
+                ));
+            }
+        }
+        (false, false) => {
+            for label in &labels {
+                commands.entity(label).despawn();
+            }
+        }
+        _ => {}
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Update, toggle_zone_overlay);
+    app.add_systems(
+        Update,
+        (
+            draw_zone_boundaries.run_if(resource_exists::<ShowZoneOverlay>),
+            sync_zone_labels,
+        )
+            .run_if(in_state(GameState::InGame)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::battlefield::{BATTLEFIELD_WIDTH, BUILD_ZONE_END_X, FORTRESS_COLS};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn boundaries_follow_battlefield_constants() {
+        let xs: Vec<f32> = zone_boundaries().iter().map(|(_, x)| *x).collect();
+
+        assert_eq!(xs, vec![0.0, 128.0, 512.0, 5120.0]);
+        assert_eq!(xs[1], BUILD_ZONE_START_X);
+        assert_eq!(xs[2], BUILD_ZONE_END_X);
+        assert_eq!(
+            BATTLEFIELD_WIDTH - xs[3],
+            f32::from(FORTRESS_COLS) * CELL_SIZE
+        );
+    }
+
+    #[test]
+    fn boundaries_run_west_to_east() {
+        let boundaries = zone_boundaries();
+        for pair in boundaries.windows(2) {
+            assert!(pair[0].1 < pair[1].1, "{pair:?}");
+        }
+    }
+}
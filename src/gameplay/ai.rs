@@ -2,9 +2,18 @@
 
 use bevy::prelude::*;
 
-use super::battlefield::CELL_SIZE;
+use super::battlefield::{
+    BATTLEFIELD_ROWS, CELL_SIZE, COMBAT_ZONE_START_COL, ENEMY_FORT_START_COL, EnemyFortress,
+    FortressSegment, PlayerFortress, col_to_world_x, row_to_world_y,
+};
+use super::building::Building;
 use super::spatial_hash::SpatialHash;
-use super::{EntityExtent, Movement, Target, TargetingState, Team, extent_distance};
+use super::units::{EnemyUnit, PlayerUnit};
+use super::{
+    AggroRadius, EntityExtent, FiringArc, Health, Movement, Recall, Target, TargetPreference,
+    TargetPriority, TargetingState, Taunt, Team, extent_distance,
+};
+use crate::menus::PauseInputGuard;
 use crate::screens::GameState;
 use crate::{GameSet, gameplay_running};
 
@@ -34,6 +43,13 @@ const RETARGET_SLOTS: u32 = 10;
 /// Entities without a target (or with a despawned target) always evaluate immediately.
 const RETARGET_SLOT_INTERVAL_SECS: f32 = 0.015;
 
+/// A unit this close (pixels, center to point) to its rally point or
+/// attack-move destination has arrived.
+const ARRIVAL_DISTANCE: f32 = CELL_SIZE / 2.0;
+
+/// Default [`AttackMove::engage_radius`] (pixels, surface distance).
+pub const ATTACK_MOVE_ENGAGE_RADIUS: f32 = 150.0;
+
 /// Timer and slot state for staggered retargeting.
 /// Entities re-evaluate targets in round-robin fashion: slot 0 first, then slot 1, etc.
 /// The timer fires every `RETARGET_INTERVAL_SECS / RETARGET_SLOTS` seconds.
@@ -54,11 +70,90 @@ impl Default for RetargetTimer {
     }
 }
 
+/// Per-team target override: while set, every mobile unit of that team targets
+/// this entity regardless of nearer enemies. Static seekers (fortresses) ignore it.
+/// An override whose entity no longer exists is ignored.
+///
+/// A fortress segment stands for the whole fortress: each unit engages the
+/// segment nearest to it, and the override moves to a surviving segment when
+/// its own is destroyed (see [`carry_forced_fortress_target`]).
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct ForcedTarget {
+    pub player: Option<Entity>,
+    pub enemy: Option<Entity>,
+}
+
+impl ForcedTarget {
+    /// The override for `team`, if any.
+    #[must_use]
+    pub const fn for_team(&self, team: Team) -> Option<Entity> {
+        match team {
+            Team::Player => self.player,
+            Team::Enemy => self.enemy,
+        }
+    }
+}
+
+/// Where newly spawned player units gather before seeking enemies. Set by
+/// shift-clicking a combat-zone cell; shift-clicking the same cell clears it.
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct RallyPoint(pub Option<Vec2>);
+
+/// Marks a unit walking to the rally point it was spawned under. `find_target`
+/// leaves it alone until `arrive_at_rally` removes the marker on arrival.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct MovingToRally(pub Vec2);
+
+/// An attack-move order: walk to `destination`, engaging enemies that come
+/// within `engage_radius` along the way and resuming the walk once they die.
+/// Removed on arrival, after which the unit targets normally.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct AttackMove {
+    pub destination: Vec2,
+    pub engage_radius: f32,
+}
+
+impl AttackMove {
+    /// An order to `destination` with the default engage radius.
+    #[must_use]
+    pub const fn new(destination: Vec2) -> Self {
+        Self {
+            destination,
+            engage_radius: ATTACK_MOVE_ENGAGE_RADIUS,
+        }
+    }
+}
+
+/// Marks a player unit picked with Alt-click. Attack-move orders go only to
+/// selected units while any are selected, and to the whole army otherwise.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Selected;
+
+/// Marks a mobile unit locked onto the entity whose [`Taunt`] caught it.
+/// `find_target` leaves taunted units alone; the lock lifts when the taunt
+/// ends or the taunter dies.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Taunted {
+    pub by: Entity,
+}
+
 /// Spatial hash for target lookups. Populated with all `With<Target>` entities
 /// each frame. Queried by `find_target` to find nearby candidates.
 #[derive(Resource, Debug)]
 pub struct TargetSpatialHash(SpatialHash);
 
+impl Default for TargetSpatialHash {
+    fn default() -> Self {
+        Self(SpatialHash::new(CELL_SIZE))
+    }
+}
+
 impl std::ops::Deref for TargetSpatialHash {
     type Target = SpatialHash;
     fn deref(&self) -> &Self::Target {
@@ -84,39 +179,162 @@ fn rebuild_target_grid(
     }
 }
 
+/// Ticks active [`Taunt`]s and applies them: a freshly inserted taunt locks every
+/// opposing mobile unit within its radius onto the taunter. Units whose taunter
+/// stopped taunting (or died) are released back to `Seeking` so `find_target`
+/// picks a new target this frame. Runs in `GameSet::Ai`, before `find_target`.
+fn update_taunts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut taunters: Query<(Entity, &Team, &GlobalTransform, &EntityExtent, &mut Taunt)>,
+    taunted: Query<(Entity, &Taunted)>,
+    mut mobiles: Query<
+        (
+            Entity,
+            &Team,
+            &GlobalTransform,
+            &EntityExtent,
+            &mut TargetingState,
+        ),
+        With<Movement>,
+    >,
+) {
+    for (taunter, team, transform, extent, mut taunt) in &mut taunters {
+        if taunt.is_added() {
+            let taunter_pos = transform.translation().xy();
+            for (unit, unit_team, unit_transform, unit_extent, mut state) in &mut mobiles {
+                let unit_pos = unit_transform.translation().xy();
+                if *unit_team == *team
+                    || extent_distance(extent, taunter_pos, unit_extent, unit_pos) > taunt.radius
+                {
+                    continue;
+                }
+                *state = TargetingState::Engaging(taunter);
+                commands.entity(unit).insert(Taunted { by: taunter });
+            }
+        }
+        taunt.timer.tick(time.delta());
+        if taunt.timer.is_finished() {
+            commands.entity(taunter).remove::<Taunt>();
+        }
+    }
+
+    for (unit, lock) in &taunted {
+        let still_taunting = taunters
+            .get(lock.by)
+            .is_ok_and(|(.., taunt)| !taunt.timer.is_finished());
+        if still_taunting {
+            continue;
+        }
+        commands.entity(unit).remove::<Taunted>();
+        if let Ok((.., mut state)) = mobiles.get_mut(unit) {
+            *state = TargetingState::Seeking;
+        }
+    }
+}
+
 /// Finds the nearest valid target for each entity with `TargetingState`. Runs in `GameSet::Ai`.
 ///
-/// Works for both units (with `Movement`) and static entities like fortresses (no `Movement`).
+/// Works for both units (`PlayerUnit`/`EnemyUnit`) and static entities like fortresses.
 /// - Entities without a target evaluate every frame (so newly spawned units react instantly).
 /// - Entities with a valid target re-evaluate on their stagger slot (once per
 ///   [`RETARGET_INTERVAL_SECS`] cycle, spread across [`RETARGET_SLOTS`] time intervals).
-/// - Backtrack limit only applies to mobile entities (those with `Movement`).
+/// - Backtrack limit only applies to units; their team marker gives the direction
+///   they advance in (see [`advance_direction`]).
+/// - Entities with [`AggroRadius`] and no current target ignore enemies beyond the radius.
+/// - Units follow their team's [`ForcedTarget`] while it is set. Any change
+///   to it makes every entity re-evaluate this frame.
+/// - Entities with [`Recall`] whose target died only look within the recall radius;
+///   with nothing there they go back to Seeking and return home until an enemy shows up.
+/// - Entities with [`TargetPreference`] treat structures as that much nearer (or farther).
+/// - [`Taunted`] entities are skipped; [`update_taunts`] owns their target.
+/// - [`MovingToRally`] entities are skipped until they reach their rally point.
+/// - Entities with an [`AttackMove`] order only look within its engage radius;
+///   with nothing there they stay Seeking and keep walking to the destination.
+/// - Entities with a non-default [`TargetPriority`] rank candidates by it first
+///   and only fall back to the nearest target when none qualifies.
+/// - Entities with a [`FiringArc`] only pick targets inside it, so a directional
+///   building never locks onto an enemy it can't fire at.
 pub fn find_target(
     time: Res<Time>,
     mut retarget_timer: ResMut<RetargetTimer>,
     grid: Res<TargetSpatialHash>,
-    mut seekers: Query<(
-        Entity,
-        &Team,
-        &GlobalTransform,
-        &EntityExtent,
-        &mut TargetingState,
-        Option<&Movement>,
-    )>,
+    forced: Res<ForcedTarget>,
+    mut seekers: Query<
+        (
+            Entity,
+            &Team,
+            &GlobalTransform,
+            &EntityExtent,
+            &mut TargetingState,
+            Has<PlayerUnit>,
+            Has<EnemyUnit>,
+            Option<&AggroRadius>,
+            Option<&mut Recall>,
+            Option<&TargetPreference>,
+            Option<&TargetPriority>,
+            Option<&AttackMove>,
+            Option<&FiringArc>,
+        ),
+        (Without<Taunted>, Without<MovingToRally>),
+    >,
     all_targets: Query<(Entity, &Team, &GlobalTransform, &EntityExtent), With<Target>>,
+    segments: Query<(Entity, &Team, &GlobalTransform, &EntityExtent), With<FortressSegment>>,
+    structures: Query<(), Or<(With<Building>, With<PlayerFortress>, With<EnemyFortress>)>>,
+    healths: Query<&Health, With<Target>>,
+    buildings: Query<(), With<Building>>,
+    fortresses: Query<(), Or<(With<PlayerFortress>, With<EnemyFortress>)>>,
 ) {
     retarget_timer.timer.tick(time.delta());
     let slot_advanced = retarget_timer.timer.just_finished();
     if slot_advanced {
         retarget_timer.current_slot = (retarget_timer.current_slot + 1) % RETARGET_SLOTS;
     }
+    let forced_changed = forced.is_changed();
+
+    for (
+        entity,
+        team,
+        transform,
+        seeker_extent,
+        mut targeting_state,
+        is_player_unit,
+        is_enemy_unit,
+        aggro,
+        mut recall,
+        preference,
+        priority,
+        attack_move,
+        arc,
+    ) in &mut seekers
+    {
+        let advance = advance_direction(is_player_unit, is_enemy_unit);
+        let forced_target = forced
+            .for_team(*team)
+            .filter(|_| advance.is_some())
+            .filter(|&e| all_targets.get(e).is_ok_and(|(_, t, _, _)| *t != *team))
+            .map(|forced| {
+                if !segments.contains(forced) {
+                    return forced;
+                }
+                nearest_segment(
+                    transform.translation().xy(),
+                    seeker_extent,
+                    team.opposing(),
+                    &segments,
+                )
+                .unwrap_or(forced)
+            });
+        if let Some(forced_target) = forced_target {
+            *targeting_state = TargetingState::Engaging(forced_target);
+            continue;
+        }
 
-    for (entity, team, transform, seeker_extent, mut targeting_state, movement) in &mut seekers {
         let has_valid_target = targeting_state
             .target_entity()
             .is_some_and(|e| all_targets.get(e).is_ok());
 
-        if has_valid_target {
+        if has_valid_target && !forced_changed {
             if !slot_advanced {
                 continue;
             }
@@ -129,18 +347,56 @@ pub fn find_target(
         let my_pos = transform.translation().xy();
         let opposing_team = team.opposing();
 
-        // Two-pass spatial search: nearby first, full battlefield fallback
-        let nearest = find_nearest_target(
+        // A dead target starts the trip home for recalling units
+        let target_lost = targeting_state.target_entity().is_some() && !has_valid_target;
+        if let Some(recall) = recall.as_deref_mut() {
+            recall.returning |= target_lost;
+        }
+
+        // Idle entities with an aggro radius (or returning home, or attack-moving)
+        // only look within it
+        let recall_radius = recall.as_deref().filter(|r| r.returning).map(|r| r.radius);
+        let aggro_radius = recall_radius
+            .or_else(|| attack_move.map(|order| order.engage_radius))
+            .or_else(|| aggro.filter(|_| !has_valid_target).map(|a| a.0));
+
+        let priority = priority.copied().unwrap_or_default();
+        let preferred = find_priority_target(
             &grid,
             entity,
             my_pos,
             seeker_extent,
             opposing_team,
-            movement.is_some(),
-            *team,
+            advance,
+            arc,
+            aggro_radius,
+            priority,
+            |candidate| priority_rank(priority, candidate, &healths, &buildings, &fortresses),
             &all_targets,
         );
 
+        // Otherwise a two-pass spatial search: nearby first, full battlefield fallback
+        let nearest = preferred.or_else(|| {
+            find_nearest_target(
+                &grid,
+                entity,
+                my_pos,
+                seeker_extent,
+                opposing_team,
+                advance,
+                arc,
+                aggro_radius,
+                StructureBias {
+                    pixels: preference.map_or(0.0, |p| p.0),
+                    structures: &structures,
+                },
+                &all_targets,
+            )
+        });
+
+        if let Some(recall) = recall.as_deref_mut() {
+            recall.returning &= nearest.is_none();
+        }
         *targeting_state = nearest.map_or(TargetingState::Seeking, TargetingState::Engaging);
     }
 }
@@ -153,6 +409,9 @@ pub fn find_target(
 ///
 /// Within each pass, uses center-distance as a cheap pre-filter before
 /// calling `extent_distance` on close candidates.
+///
+/// With an `aggro_radius`, only a single pass within that radius is made and
+/// the nearest target's surface must lie inside it.
 #[allow(clippy::too_many_arguments)]
 fn find_nearest_target(
     grid: &TargetSpatialHash,
@@ -160,10 +419,29 @@ fn find_nearest_target(
     seeker_pos: Vec2,
     seeker_extent: &EntityExtent,
     opposing_team: Team,
-    is_mobile: bool,
-    seeker_team: Team,
+    advance: Option<f32>,
+    arc: Option<&FiringArc>,
+    aggro_radius: Option<f32>,
+    bias: StructureBias,
     all_targets: &Query<(Entity, &Team, &GlobalTransform, &EntityExtent), With<Target>>,
 ) -> Option<Entity> {
+    if let Some(aggro_radius) = aggro_radius {
+        return search_radius(
+            grid,
+            aggro_radius + MAX_ENTITY_HALF_EXTENT,
+            seeker_entity,
+            seeker_pos,
+            seeker_extent,
+            opposing_team,
+            advance,
+            arc,
+            bias,
+            all_targets,
+        )
+        .filter(|(_, surf_dist)| *surf_dist <= aggro_radius)
+        .map(|(e, _)| e);
+    }
+
     // First pass: nearby targets
     let result = search_radius(
         grid,
@@ -172,13 +450,14 @@ fn find_nearest_target(
         seeker_pos,
         seeker_extent,
         opposing_team,
-        is_mobile,
-        seeker_team,
+        advance,
+        arc,
+        bias,
         all_targets,
     );
 
-    if result.is_some() {
-        return result;
+    if let Some((entity, _)) = result {
+        return Some(entity);
     }
 
     // Fallback: full battlefield
@@ -189,12 +468,36 @@ fn find_nearest_target(
         seeker_pos,
         seeker_extent,
         opposing_team,
-        is_mobile,
-        seeker_team,
+        advance,
+        arc,
+        bias,
         all_targets,
     )
+    .map(|(e, _)| e)
+}
+
+/// Distance bias applied to structure candidates during target search.
+#[derive(Clone, Copy)]
+struct StructureBias<'a, 'w, 's> {
+    /// Pixels subtracted from a structure's surface distance.
+    pixels: f32,
+    structures:
+        &'a Query<'w, 's, (), Or<(With<Building>, With<PlayerFortress>, With<EnemyFortress>)>>,
+}
+
+impl StructureBias<'_, '_, '_> {
+    /// Surface distance used for ranking `candidate`.
+    fn effective_distance(&self, candidate: Entity, surf_dist: f32) -> f32 {
+        if self.pixels != 0.0 && self.structures.contains(candidate) {
+            surf_dist - self.pixels
+        } else {
+            surf_dist
+        }
+    }
 }
 
+/// Returns the valid target within `radius` with the smallest biased distance,
+/// along with its true surface distance.
 #[allow(clippy::too_many_arguments)]
 fn search_radius(
     grid: &TargetSpatialHash,
@@ -203,10 +506,11 @@ fn search_radius(
     seeker_pos: Vec2,
     seeker_extent: &EntityExtent,
     opposing_team: Team,
-    is_mobile: bool,
-    seeker_team: Team,
+    advance: Option<f32>,
+    arc: Option<&FiringArc>,
+    bias: StructureBias,
     all_targets: &Query<(Entity, &Team, &GlobalTransform, &EntityExtent), With<Target>>,
-) -> Option<Entity> {
+) -> Option<(Entity, f32)> {
     let candidates = grid.query_neighbors(seeker_pos, radius);
 
     // Phase 1: Filter and compute center distances (cheap)
@@ -224,15 +528,14 @@ fn search_radius(
 
         let cand_pos = cand_transform.translation().xy();
 
-        // Backtrack filter (mobile entities only)
-        if is_mobile {
-            let behind = match seeker_team {
-                Team::Player => seeker_pos.x - cand_pos.x,
-                Team::Enemy => cand_pos.x - seeker_pos.x,
-            };
-            if behind > BACKTRACK_DISTANCE {
-                continue;
-            }
+        // Backtrack filter (units only)
+        if advance.is_some_and(|dir| too_far_behind(dir, seeker_pos, cand_pos)) {
+            continue;
+        }
+
+        // Directional seekers only pick targets they can fire at
+        if arc.is_some_and(|arc| !arc.contains(seeker_pos, cand_pos)) {
+            continue;
         }
 
         let center_dist = seeker_pos.distance(cand_pos);
@@ -253,15 +556,19 @@ fn search_radius(
     // Only compute surface_distance for candidates whose center is close
     // enough that they could beat the current best surface distance.
     // Cutoff: min_center_dist + 2 * MAX_ENTITY_HALF_EXTENT covers the
-    // worst case where both entities have maximum collider extent.
-    let center_cutoff = 2.0f32.mul_add(MAX_ENTITY_HALF_EXTENT, min_center_dist);
-
-    let mut nearest: Option<(Entity, f32)> = None;
+    // worst case where both entities have maximum collider extent. The
+    // structure bias can shift either side of the comparison, so it widens
+    // the first cutoff by twice its magnitude and the tightened one by once.
+    let slack = 2.0f32.mul_add(MAX_ENTITY_HALF_EXTENT, bias.pixels.abs());
+    let center_cutoff = min_center_dist + slack + bias.pixels.abs();
+
+    // (entity, surface distance, biased distance)
+    let mut nearest: Option<(Entity, f32, f32)> = None;
     for (cand_entity, cand_pos, cand_extent, center_dist) in &valid_candidates {
         if *center_dist > center_cutoff {
-            if let Some((_, best_surf)) = nearest {
+            if let Some((_, _, best_effective)) = nearest {
                 // Tighten cutoff as we find better candidates
-                if *center_dist > 2.0f32.mul_add(MAX_ENTITY_HALF_EXTENT, best_surf) {
+                if *center_dist > best_effective + slack {
                     continue;
                 }
             } else {
@@ -270,12 +577,351 @@ fn search_radius(
         }
 
         let surf_dist = extent_distance(seeker_extent, seeker_pos, cand_extent, *cand_pos);
-        if nearest.is_none_or(|(_, d)| surf_dist < d) {
-            nearest = Some((*cand_entity, surf_dist));
+        let effective = bias.effective_distance(*cand_entity, surf_dist);
+        if nearest.is_none_or(|(_, _, d)| effective < d) {
+            nearest = Some((*cand_entity, surf_dist, effective));
+        }
+    }
+
+    nearest.map(|(entity, surf_dist, _)| (entity, surf_dist))
+}
+
+/// X direction a unit seeker advances in, read from its team marker: +1 for
+/// `PlayerUnit` (east), -1 for `EnemyUnit` (west). `None` for structures,
+/// which have no backtrack limit and ignore [`ForcedTarget`].
+const fn advance_direction(is_player_unit: bool, is_enemy_unit: bool) -> Option<f32> {
+    if is_player_unit {
+        Some(1.0)
+    } else if is_enemy_unit {
+        Some(-1.0)
+    } else {
+        None
+    }
+}
+
+/// The `team` fortress segment whose surface is nearest to a seeker at `seeker_pos`.
+fn nearest_segment(
+    seeker_pos: Vec2,
+    seeker_extent: &EntityExtent,
+    team: Team,
+    segments: &Query<(Entity, &Team, &GlobalTransform, &EntityExtent), With<FortressSegment>>,
+) -> Option<Entity> {
+    segments
+        .iter()
+        .filter(|(_, segment_team, ..)| **segment_team == team)
+        .map(|(segment, _, transform, extent)| {
+            let distance = extent_distance(
+                seeker_extent,
+                seeker_pos,
+                extent,
+                transform.translation().xy(),
+            );
+            (segment, distance)
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(segment, _)| segment)
+}
+
+/// Whether `cand_pos` lies more than `BACKTRACK_DISTANCE` behind a unit seeker
+/// at `seeker_pos` advancing in x direction `advance`.
+const fn too_far_behind(advance: f32, seeker_pos: Vec2, cand_pos: Vec2) -> bool {
+    (seeker_pos.x - cand_pos.x) * advance > BACKTRACK_DISTANCE
+}
+
+/// Rank of `candidate` under `priority` (lower is better), or `None` when it
+/// does not qualify. Only `LowestHealth` ranks by health; the other modes
+/// just filter.
+fn priority_rank(
+    priority: TargetPriority,
+    candidate: Entity,
+    healths: &Query<&Health, With<Target>>,
+    buildings: &Query<(), With<Building>>,
+    fortresses: &Query<(), Or<(With<PlayerFortress>, With<EnemyFortress>)>>,
+) -> Option<f32> {
+    match priority {
+        TargetPriority::Nearest => Some(0.0),
+        TargetPriority::LowestHealth => healths.get(candidate).ok().map(|h| h.current),
+        TargetPriority::Buildings => buildings.contains(candidate).then_some(0.0),
+        TargetPriority::Fortress => fortresses.contains(candidate).then_some(0.0),
+    }
+}
+
+/// Picks the best-ranked target for a non-`Nearest` [`TargetPriority`]: the
+/// qualifying candidate with the lowest `rank`, ties going to the nearer
+/// surface. `Fortress` searches the whole battlefield, the others
+/// `INITIAL_SEARCH_RADIUS`; an `aggro_radius` overrides both.
+/// Returns `None` for `Nearest` or when nothing qualifies.
+#[allow(clippy::too_many_arguments)]
+fn find_priority_target(
+    grid: &TargetSpatialHash,
+    seeker_entity: Entity,
+    seeker_pos: Vec2,
+    seeker_extent: &EntityExtent,
+    opposing_team: Team,
+    advance: Option<f32>,
+    arc: Option<&FiringArc>,
+    aggro_radius: Option<f32>,
+    priority: TargetPriority,
+    rank: impl Fn(Entity) -> Option<f32>,
+    all_targets: &Query<(Entity, &Team, &GlobalTransform, &EntityExtent), With<Target>>,
+) -> Option<Entity> {
+    let radius = match priority {
+        TargetPriority::Nearest => return None,
+        TargetPriority::Fortress => aggro_radius.unwrap_or(BATTLEFIELD_DIAGONAL),
+        TargetPriority::LowestHealth | TargetPriority::Buildings => {
+            aggro_radius.unwrap_or(INITIAL_SEARCH_RADIUS)
+        }
+    };
+
+    grid.query_neighbors(seeker_pos, radius + MAX_ENTITY_HALF_EXTENT)
+        .into_iter()
+        .filter_map(|candidate| {
+            let (cand_entity, cand_team, cand_transform, cand_extent) =
+                all_targets.get(candidate).ok()?;
+            let cand_pos = cand_transform.translation().xy();
+            if cand_entity == seeker_entity
+                || *cand_team != opposing_team
+                || advance.is_some_and(|dir| too_far_behind(dir, seeker_pos, cand_pos))
+                || arc.is_some_and(|arc| !arc.contains(seeker_pos, cand_pos))
+            {
+                return None;
+            }
+            let cand_rank = rank(cand_entity)?;
+            let surf_dist = extent_distance(seeker_extent, seeker_pos, cand_extent, cand_pos);
+            (surf_dist <= radius).then_some((cand_entity, cand_rank, surf_dist))
+        })
+        .min_by(|(_, a_rank, a_dist), (_, b_rank, b_dist)| {
+            a_rank.total_cmp(b_rank).then(a_dist.total_cmp(b_dist))
+        })
+        .map(|(entity, ..)| entity)
+}
+
+/// Left-clicking the enemy fortress forces all player units onto it; clicking it
+/// again clears the override. Runs in `GameSet::Input`.
+fn handle_fortress_click(
+    mouse: Res<ButtonInput<MouseButton>>,
+    window: Single<&Window>,
+    camera: Single<(&Camera, &GlobalTransform), With<Camera2d>>,
+    fortresses: Query<(Entity, &GlobalTransform, &EntityExtent), With<EnemyFortress>>,
+    mut forced: ResMut<ForcedTarget>,
+    guard: Res<PauseInputGuard>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) || guard.is_armed() {
+        return;
+    }
+    let (camera, camera_global) = *camera;
+    let Some(world_pos) = window
+        .cursor_position()
+        .and_then(|screen_pos| camera.viewport_to_world_2d(camera_global, screen_pos).ok())
+    else {
+        return;
+    };
+    let clicked = fortresses.iter().find(|(_, transform, extent)| {
+        extent.surface_distance_from(transform.translation().xy(), world_pos) <= 0.0
+    });
+    if let Some((fortress, _, _)) = clicked {
+        forced.player = if forced.player == Some(fortress) {
+            None
+        } else {
+            Some(fortress)
+        };
+    }
+}
+
+/// Center of the combat-zone cell containing `world_pos`, or `None` outside the
+/// combat zone.
+fn combat_zone_cell_center(world_pos: Vec2) -> Option<Vec2> {
+    let col = (world_pos.x / CELL_SIZE).floor();
+    let row = (world_pos.y / CELL_SIZE).floor();
+    let in_zone = (f32::from(COMBAT_ZONE_START_COL)..f32::from(ENEMY_FORT_START_COL))
+        .contains(&col)
+        && (0.0..f32::from(BATTLEFIELD_ROWS)).contains(&row);
+    if !in_zone {
+        return None;
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (col, row) = (col as u16, row as u16);
+    Some(Vec2::new(col_to_world_x(col), row_to_world_y(row)))
+}
+
+/// Shift-left-clicking a combat-zone cell moves the rally point there; doing so
+/// on the current rally cell clears it. Runs in `GameSet::Input`.
+fn handle_rally_click(
+    mouse: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    window: Single<&Window>,
+    camera: Single<(&Camera, &GlobalTransform), With<Camera2d>>,
+    mut rally: ResMut<RallyPoint>,
+    guard: Res<PauseInputGuard>,
+) {
+    if !mouse.just_pressed(MouseButton::Left)
+        || !keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+        || guard.is_armed()
+    {
+        return;
+    }
+    let (camera, camera_global) = *camera;
+    let Some(cell) = window
+        .cursor_position()
+        .and_then(|screen_pos| camera.viewport_to_world_2d(camera_global, screen_pos).ok())
+        .and_then(combat_zone_cell_center)
+    else {
+        return;
+    };
+    rally.0 = if rally.0 == Some(cell) {
+        None
+    } else {
+        Some(cell)
+    };
+}
+
+/// Sends each newly spawned player unit to the rally point, if one is set.
+fn send_new_unit_to_rally(
+    add: On<Add, PlayerUnit>,
+    rally: Res<RallyPoint>,
+    mut commands: Commands,
+) {
+    if let Some(point) = rally.0 {
+        commands.entity(add.entity).insert(MovingToRally(point));
+    }
+}
+
+/// Alt-left-clicking a player unit toggles its [`Selected`] marker; Alt-clicking
+/// empty ground clears the selection. Runs in `GameSet::Input`.
+fn handle_select_click(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    window: Single<&Window>,
+    camera: Single<(&Camera, &GlobalTransform), With<Camera2d>>,
+    units: Query<(Entity, &GlobalTransform, &EntityExtent, Has<Selected>), With<PlayerUnit>>,
+    guard: Res<PauseInputGuard>,
+) {
+    if !mouse.just_pressed(MouseButton::Left)
+        || !keyboard.any_pressed([KeyCode::AltLeft, KeyCode::AltRight])
+        || guard.is_armed()
+    {
+        return;
+    }
+    let (camera, camera_global) = *camera;
+    let Some(world_pos) = window
+        .cursor_position()
+        .and_then(|screen_pos| camera.viewport_to_world_2d(camera_global, screen_pos).ok())
+    else {
+        return;
+    };
+    let clicked = units.iter().find(|(_, transform, extent, _)| {
+        extent.surface_distance_from(transform.translation().xy(), world_pos) <= 0.0
+    });
+    match clicked {
+        Some((unit, _, _, true)) => {
+            commands.entity(unit).remove::<Selected>();
+        }
+        Some((unit, _, _, false)) => {
+            commands.entity(unit).insert(Selected);
+        }
+        None => {
+            for (unit, ..) in units.iter().filter(|(.., selected)| *selected) {
+                commands.entity(unit).remove::<Selected>();
+            }
+        }
+    }
+}
+
+/// Units that take an attack-move order: the selected ones, or all of them
+/// when nothing is selected.
+fn attack_move_recipients(units: &[(Entity, bool)]) -> Vec<Entity> {
+    let any_selected = units.iter().any(|&(_, selected)| selected);
+    units
+        .iter()
+        .filter(|&&(_, selected)| selected || !any_selected)
+        .map(|&(unit, _)| unit)
+        .collect()
+}
+
+/// Ctrl-left-clicking a combat-zone cell orders the [`Selected`] player units
+/// to attack-move there, or the whole army when none are selected.
+/// Runs in `GameSet::Input`.
+fn handle_attack_move_click(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    window: Single<&Window>,
+    camera: Single<(&Camera, &GlobalTransform), With<Camera2d>>,
+    units: Query<(Entity, Has<Selected>), (With<PlayerUnit>, With<Movement>)>,
+    guard: Res<PauseInputGuard>,
+) {
+    if !mouse.just_pressed(MouseButton::Left)
+        || !keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+        || guard.is_armed()
+    {
+        return;
+    }
+    let (camera, camera_global) = *camera;
+    let Some(cell) = window
+        .cursor_position()
+        .and_then(|screen_pos| camera.viewport_to_world_2d(camera_global, screen_pos).ok())
+        .and_then(combat_zone_cell_center)
+    else {
+        return;
+    };
+    let units: Vec<(Entity, bool)> = units.iter().collect();
+    for unit in attack_move_recipients(&units) {
+        commands
+            .entity(unit)
+            .remove::<MovingToRally>()
+            .insert(AttackMove::new(cell));
+    }
+}
+
+/// Moves a forced fortress target to a surviving segment of the same fortress
+/// when its segment is destroyed, and clears it once the fortress is gone.
+/// Runs in `GameSet::Ai`, before `find_target`.
+fn carry_forced_fortress_target(
+    mut forced: ResMut<ForcedTarget>,
+    mut destroyed: RemovedComponents<FortressSegment>,
+    segments: Query<(Entity, &Team), With<FortressSegment>>,
+) {
+    for destroyed in destroyed.read() {
+        let forced = &mut *forced;
+        for (team, slot) in [
+            (Team::Player, &mut forced.player),
+            (Team::Enemy, &mut forced.enemy),
+        ] {
+            if *slot == Some(destroyed) {
+                *slot = segments
+                    .iter()
+                    .find(|(_, segment_team)| **segment_team == team.opposing())
+                    .map(|(segment, _)| segment);
+            }
+        }
+    }
+}
+
+/// Releases units that reached their rally point back to normal targeting.
+/// Runs in `GameSet::Ai`, before `find_target`.
+fn arrive_at_rally(
+    mut commands: Commands,
+    rallying: Query<(Entity, &GlobalTransform, &MovingToRally)>,
+) {
+    for (unit, transform, rally) in &rallying {
+        if transform.translation().xy().distance(rally.0) <= ARRIVAL_DISTANCE {
+            commands.entity(unit).remove::<MovingToRally>();
         }
     }
+}
 
-    nearest.map(|(e, _)| e)
+/// Ends attack-move orders whose unit reached the destination.
+/// Runs in `GameSet::Ai`, before `find_target`.
+fn complete_attack_moves(
+    mut commands: Commands,
+    ordered: Query<(Entity, &GlobalTransform, &AttackMove)>,
+) {
+    for (unit, transform, order) in &ordered {
+        if transform.translation().xy().distance(order.destination) <= ARRIVAL_DISTANCE {
+            commands.entity(unit).remove::<AttackMove>();
+        }
+    }
 }
 
 // === Plugin ===
@@ -284,15 +930,52 @@ fn reset_retarget_timer(mut commands: Commands) {
     commands.insert_resource(RetargetTimer::default());
 }
 
+fn reset_forced_target(mut commands: Commands) {
+    commands.insert_resource(ForcedTarget::default());
+}
+
+fn reset_rally_point(mut commands: Commands) {
+    commands.insert_resource(RallyPoint::default());
+}
+
 pub(super) fn plugin(app: &mut App) {
-    app.init_resource::<RetargetTimer>();
-    app.insert_resource(TargetSpatialHash(SpatialHash::new(CELL_SIZE)));
-    app.register_type::<RetargetTimer>();
-    app.add_systems(OnEnter(GameState::InGame), reset_retarget_timer);
+    app.init_resource::<RetargetTimer>()
+        .init_resource::<ForcedTarget>()
+        .init_resource::<RallyPoint>();
+    app.init_resource::<TargetSpatialHash>();
+    app.register_type::<RetargetTimer>()
+        .register_type::<ForcedTarget>()
+        .register_type::<RallyPoint>()
+        .register_type::<MovingToRally>()
+        .register_type::<AttackMove>()
+        .register_type::<Selected>()
+        .register_type::<Taunted>();
+    app.add_observer(send_new_unit_to_rally);
+    app.add_systems(
+        OnEnter(GameState::InGame),
+        (reset_retarget_timer, reset_forced_target, reset_rally_point),
+    );
     app.add_systems(
         Update,
-        (rebuild_target_grid, find_target)
-            .chain_ignore_deferred()
+        (
+            handle_fortress_click,
+            handle_rally_click,
+            handle_select_click,
+            handle_attack_move_click,
+        )
+            .in_set(GameSet::Input)
+            .run_if(gameplay_running),
+    );
+    app.add_systems(
+        Update,
+        (
+            update_taunts,
+            arrive_at_rally,
+            complete_attack_moves,
+            carry_forced_fortress_target,
+            (rebuild_target_grid, find_target).chain_ignore_deferred(),
+        )
+            .chain()
             .in_set(GameSet::Ai)
             .run_if(gameplay_running),
     );
@@ -308,12 +991,20 @@ mod tests {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.init_resource::<RetargetTimer>();
+        app.init_resource::<ForcedTarget>();
         app.insert_resource(TargetSpatialHash(SpatialHash::new(
             crate::gameplay::battlefield::CELL_SIZE,
         )));
         app.add_systems(
             Update,
-            (rebuild_target_grid, find_target).chain_ignore_deferred(),
+            (
+                update_taunts,
+                arrive_at_rally,
+                complete_attack_moves,
+                carry_forced_fortress_target,
+                (rebuild_target_grid, find_target).chain_ignore_deferred(),
+            )
+                .chain(),
         );
         app
     }
@@ -363,6 +1054,123 @@ mod tests {
         assert_eq!(current_target.target_entity(), Some(fortress));
     }
 
+    #[test]
+    fn forced_target_overrides_nearer_enemies() {
+        let mut app = create_ai_test_app();
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        let _near_enemy =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 200.0, 100.0);
+        let fortress =
+            crate::testing::spawn_test_target(app.world_mut(), Team::Enemy, 5000.0, 320.0);
+        app.update();
+
+        app.world_mut().resource_mut::<ForcedTarget>().player = Some(fortress);
+        app.update();
+
+        let current_target = app.world().get::<TargetingState>(player).unwrap();
+        assert_eq!(current_target.target_entity(), Some(fortress));
+    }
+
+    #[test]
+    fn clearing_forced_target_restores_nearest_targeting() {
+        let mut app = create_ai_test_app();
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        let near_enemy =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 200.0, 100.0);
+        let fortress =
+            crate::testing::spawn_test_target(app.world_mut(), Team::Enemy, 5000.0, 320.0);
+        app.world_mut().resource_mut::<ForcedTarget>().player = Some(fortress);
+        app.update();
+
+        app.world_mut().resource_mut::<ForcedTarget>().player = None;
+        app.update();
+
+        let current_target = app.world().get::<TargetingState>(player).unwrap();
+        assert_eq!(current_target.target_entity(), Some(near_enemy));
+    }
+
+    #[test]
+    fn forced_target_only_applies_to_its_team() {
+        let mut app = create_ai_test_app();
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        let enemy = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 200.0, 100.0);
+        let fortress =
+            crate::testing::spawn_test_target(app.world_mut(), Team::Enemy, 5000.0, 320.0);
+        app.world_mut().resource_mut::<ForcedTarget>().player = Some(fortress);
+        app.update();
+
+        let enemy_target = app.world().get::<TargetingState>(enemy).unwrap();
+        assert_eq!(enemy_target.target_entity(), Some(player));
+    }
+
+    #[test]
+    fn despawned_forced_target_is_ignored() {
+        let mut app = create_ai_test_app();
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        let near_enemy =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 200.0, 100.0);
+        let fortress =
+            crate::testing::spawn_test_target(app.world_mut(), Team::Enemy, 5000.0, 320.0);
+        app.world_mut().resource_mut::<ForcedTarget>().player = Some(fortress);
+        app.update();
+
+        app.world_mut().despawn(fortress);
+        app.update();
+
+        let current_target = app.world().get::<TargetingState>(player).unwrap();
+        assert_eq!(current_target.target_entity(), Some(near_enemy));
+    }
+
+    fn spawn_enemy_segment(app: &mut App, row: u16, y: f32) -> Entity {
+        let segment = crate::testing::spawn_test_target(app.world_mut(), Team::Enemy, 5000.0, y);
+        app.world_mut()
+            .entity_mut(segment)
+            .insert((EnemyFortress, FortressSegment { row }));
+        segment
+    }
+
+    #[test]
+    fn forced_fortress_segment_sends_units_to_their_nearest_segment() {
+        let mut app = create_ai_test_app();
+
+        let low = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        let high = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 540.0);
+        let bottom = spawn_enemy_segment(&mut app, 0, 100.0);
+        let top = spawn_enemy_segment(&mut app, 1, 540.0);
+        app.world_mut().resource_mut::<ForcedTarget>().player = Some(top);
+        app.update();
+
+        assert_eq!(target_of(&app, low), Some(bottom));
+        assert_eq!(target_of(&app, high), Some(top));
+    }
+
+    #[test]
+    fn forced_fortress_target_survives_until_the_last_segment_falls() {
+        let mut app = create_ai_test_app();
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        let near_enemy =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 200.0, 100.0);
+        let bottom = spawn_enemy_segment(&mut app, 0, 100.0);
+        let top = spawn_enemy_segment(&mut app, 1, 540.0);
+        app.world_mut().resource_mut::<ForcedTarget>().player = Some(bottom);
+        app.update();
+
+        app.world_mut().despawn(bottom);
+        app.update();
+        assert_eq!(app.world().resource::<ForcedTarget>().player, Some(top));
+        assert_eq!(target_of(&app, player), Some(top));
+
+        app.world_mut().despawn(top);
+        app.update();
+        assert_eq!(app.world().resource::<ForcedTarget>().player, None);
+        assert_eq!(target_of(&app, player), Some(near_enemy));
+    }
+
     #[test]
     fn unit_retargets_when_target_despawned() {
         let mut app = create_ai_test_app();
@@ -427,6 +1235,21 @@ mod tests {
         assert_eq!(ct.target_entity(), None);
     }
 
+    #[test]
+    fn enemy_unit_backtrack_limit_faces_west() {
+        let mut app = create_ai_test_app();
+
+        // Enemy unit advances west: a player 400px east of it is behind
+        let enemy = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 500.0, 100.0);
+        let _behind_player =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 900.0, 100.0);
+
+        app.update();
+
+        let ct = app.world().get::<TargetingState>(enemy).unwrap();
+        assert_eq!(ct.target_entity(), None);
+    }
+
     #[test]
     fn unit_targets_building() {
         let mut app = create_ai_test_app();
@@ -495,21 +1318,51 @@ mod tests {
 
         app.update();
 
-        // Static entity (no Movement) should target regardless of direction
+        // Static entity (no unit marker) should target regardless of direction
         let ct = app.world().get::<TargetingState>(fortress).unwrap();
         assert_eq!(ct.target_entity(), Some(behind_enemy));
     }
 
     #[test]
-    fn targets_enemy_across_large_distance() {
-        // Tests the fallback search (enemy far away, beyond initial radius)
+    fn directional_seeker_ignores_nearer_enemy_outside_its_arc() {
         let mut app = create_ai_test_app();
-        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
-        let far_enemy =
-            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 4000.0, 100.0);
-        app.update();
-        let ct = app.world().get::<TargetingState>(player).unwrap();
-        assert_eq!(ct.target_entity(), Some(far_enemy));
+
+        // East-facing turret at x=500: the enemy behind it is nearer than the one ahead
+        let turret = app
+            .world_mut()
+            .spawn((
+                Team::Player,
+                Target,
+                TargetingState::Seeking,
+                FiringArc {
+                    direction: Vec2::X,
+                    half_angle: std::f32::consts::FRAC_PI_4,
+                },
+                Transform::from_xyz(500.0, 320.0, 0.0),
+                GlobalTransform::from(Transform::from_xyz(500.0, 320.0, 0.0)),
+                crate::gameplay::EntityExtent::Rect(32.0, 32.0),
+            ))
+            .id();
+
+        let _behind = crate::testing::spawn_test_target(app.world_mut(), Team::Enemy, 400.0, 320.0);
+        let ahead = crate::testing::spawn_test_target(app.world_mut(), Team::Enemy, 700.0, 320.0);
+
+        app.update();
+
+        let ct = app.world().get::<TargetingState>(turret).unwrap();
+        assert_eq!(ct.target_entity(), Some(ahead));
+    }
+
+    #[test]
+    fn targets_enemy_across_large_distance() {
+        // Tests the fallback search (enemy far away, beyond initial radius)
+        let mut app = create_ai_test_app();
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        let far_enemy =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 4000.0, 100.0);
+        app.update();
+        let ct = app.world().get::<TargetingState>(player).unwrap();
+        assert_eq!(ct.target_entity(), Some(far_enemy));
     }
 
     #[test]
@@ -536,4 +1389,561 @@ mod tests {
         let ct = app.world().get::<TargetingState>(player).unwrap();
         assert_eq!(ct.target_entity(), None);
     }
+
+    // === Aggro Radius Tests ===
+
+    #[test]
+    fn unit_with_aggro_radius_ignores_far_enemy() {
+        let mut app = create_ai_test_app();
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        app.world_mut().entity_mut(player).insert(AggroRadius(50.0));
+        let _far_enemy =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 500.0, 100.0);
+
+        app.update();
+
+        let state = app.world().get::<TargetingState>(player).unwrap();
+        assert_eq!(*state, TargetingState::Seeking);
+    }
+
+    #[test]
+    fn unit_with_aggro_radius_acquires_enemy_within_radius() {
+        let mut app = create_ai_test_app();
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        app.world_mut().entity_mut(player).insert(AggroRadius(50.0));
+        let enemy = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 500.0, 100.0);
+
+        app.update();
+        assert_eq!(
+            app.world()
+                .get::<TargetingState>(player)
+                .unwrap()
+                .target_entity(),
+            None
+        );
+
+        // Enemy walks into the aggro radius
+        let close = Transform::from_xyz(150.0, 100.0, 0.0);
+        app.world_mut()
+            .entity_mut(enemy)
+            .insert((close, GlobalTransform::from(close)));
+        app.update();
+
+        let state = app.world().get::<TargetingState>(player).unwrap();
+        assert_eq!(state.target_entity(), Some(enemy));
+    }
+
+    #[test]
+    fn unit_without_aggro_radius_still_targets_far_enemy() {
+        let mut app = create_ai_test_app();
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        let far_enemy = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 500.0, 100.0);
+
+        app.update();
+
+        let state = app.world().get::<TargetingState>(player).unwrap();
+        assert_eq!(state.target_entity(), Some(far_enemy));
+    }
+
+    // === Target Preference Tests ===
+
+    /// Player unit at (100, 100) with an enemy unit and an enemy building on the
+    /// same row at the given x positions. Returns `(player, unit, building)`.
+    fn spawn_unit_and_building_targets(
+        app: &mut App,
+        unit_x: f32,
+        building_x: f32,
+    ) -> (Entity, Entity, Entity) {
+        use crate::gameplay::building::{BuildingType, Facing};
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        let unit = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, unit_x, 100.0);
+        let building =
+            crate::testing::spawn_test_target(app.world_mut(), Team::Enemy, building_x, 100.0);
+        app.world_mut().entity_mut(building).insert(Building {
+            building_type: BuildingType::Barracks,
+            grid_col: 0,
+            grid_row: 0,
+            facing: Facing::default(),
+            next_lane: 0,
+        });
+        (player, unit, building)
+    }
+
+    fn target_of(app: &App, entity: Entity) -> Option<Entity> {
+        app.world()
+            .get::<TargetingState>(entity)
+            .unwrap()
+            .target_entity()
+    }
+
+    #[test]
+    fn unit_without_preference_targets_nearer_unit() {
+        let mut app = create_ai_test_app();
+        let (player, unit, _) = spawn_unit_and_building_targets(&mut app, 160.0, 200.0);
+
+        app.update();
+
+        assert_eq!(target_of(&app, player), Some(unit));
+    }
+
+    #[test]
+    fn building_preferring_unit_picks_farther_building_within_bias() {
+        let mut app = create_ai_test_app();
+        let (player, _, building) = spawn_unit_and_building_targets(&mut app, 160.0, 200.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(TargetPreference(64.0));
+
+        app.update();
+
+        assert_eq!(target_of(&app, player), Some(building));
+    }
+
+    #[test]
+    fn building_preference_does_not_reach_beyond_bias() {
+        let mut app = create_ai_test_app();
+        // Building is ~40px farther than the unit; a 20px bias is not enough
+        let (player, unit, _) = spawn_unit_and_building_targets(&mut app, 160.0, 200.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(TargetPreference(20.0));
+
+        app.update();
+
+        assert_eq!(target_of(&app, player), Some(unit));
+    }
+
+    #[test]
+    fn unit_preferring_unit_picks_farther_unit_within_bias() {
+        let mut app = create_ai_test_app();
+        let (player, unit, _) = spawn_unit_and_building_targets(&mut app, 200.0, 160.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(TargetPreference(-64.0));
+
+        app.update();
+
+        assert_eq!(target_of(&app, player), Some(unit));
+    }
+
+    // === Target Priority Tests ===
+
+    /// Sets `entity`'s current health to `current`.
+    fn wound(app: &mut App, entity: Entity, current: f32) {
+        app.world_mut().get_mut::<Health>(entity).unwrap().current = current;
+    }
+
+    #[test]
+    fn lowest_health_seeker_picks_wounded_enemy_over_nearer_one() {
+        let mut app = create_ai_test_app();
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(TargetPriority::LowestHealth);
+        let _near_healthy =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 150.0, 100.0);
+        let far_wounded =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 300.0, 100.0);
+        wound(&mut app, far_wounded, 1.0);
+
+        app.update();
+
+        assert_eq!(target_of(&app, player), Some(far_wounded));
+    }
+
+    #[test]
+    fn nearest_priority_ignores_health() {
+        let mut app = create_ai_test_app();
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(TargetPriority::Nearest);
+        let near_healthy =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 150.0, 100.0);
+        let far_wounded =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 300.0, 100.0);
+        wound(&mut app, far_wounded, 1.0);
+
+        app.update();
+
+        assert_eq!(target_of(&app, player), Some(near_healthy));
+    }
+
+    #[test]
+    fn lowest_health_ignores_wounded_enemy_beyond_search_radius() {
+        let mut app = create_ai_test_app();
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(TargetPriority::LowestHealth);
+        let near_healthy =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 150.0, 100.0);
+        let distant_wounded = crate::testing::spawn_test_unit(
+            app.world_mut(),
+            Team::Enemy,
+            100.0 + 2.0 * INITIAL_SEARCH_RADIUS,
+            100.0,
+        );
+        wound(&mut app, distant_wounded, 1.0);
+
+        app.update();
+
+        assert_eq!(target_of(&app, player), Some(near_healthy));
+    }
+
+    #[test]
+    fn buildings_priority_picks_building_over_nearer_unit() {
+        let mut app = create_ai_test_app();
+        // Building is far beyond what a structure bias would cover
+        let (player, _, building) = spawn_unit_and_building_targets(&mut app, 160.0, 400.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(TargetPriority::Buildings);
+
+        app.update();
+
+        assert_eq!(target_of(&app, player), Some(building));
+    }
+
+    #[test]
+    fn buildings_priority_falls_back_to_nearest_without_buildings() {
+        let mut app = create_ai_test_app();
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(TargetPriority::Buildings);
+        let enemy = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 200.0, 100.0);
+
+        app.update();
+
+        assert_eq!(target_of(&app, player), Some(enemy));
+    }
+
+    #[test]
+    fn fortress_priority_skips_nearer_enemies() {
+        let mut app = create_ai_test_app();
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(TargetPriority::Fortress);
+        let _near_enemy =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 200.0, 100.0);
+        let fortress =
+            crate::testing::spawn_test_target(app.world_mut(), Team::Enemy, 5000.0, 320.0);
+        app.world_mut().entity_mut(fortress).insert(EnemyFortress);
+
+        app.update();
+
+        assert_eq!(target_of(&app, player), Some(fortress));
+    }
+
+    // === Recall Tests ===
+
+    /// Player unit at x = 1000 with a recall home at x = 100, engaged on an
+    /// enemy right next to it, with the enemy fortress far away.
+    fn spawn_recalling_unit_engaged(app: &mut App) -> (Entity, Entity) {
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 1000.0, 100.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(Recall::new(Vec2::new(100.0, 100.0), 100.0));
+        let enemy = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 1030.0, 100.0);
+        let _fortress =
+            crate::testing::spawn_test_target(app.world_mut(), Team::Enemy, 5000.0, 320.0);
+        app.update();
+        assert_eq!(
+            app.world()
+                .get::<TargetingState>(player)
+                .unwrap()
+                .target_entity(),
+            Some(enemy)
+        );
+        (player, enemy)
+    }
+
+    #[test]
+    fn recall_unit_returns_home_when_target_dies_with_no_enemy_nearby() {
+        let mut app = create_ai_test_app();
+        let (player, enemy) = spawn_recalling_unit_engaged(&mut app);
+
+        app.world_mut().despawn(enemy);
+        app.update();
+
+        assert_eq!(
+            *app.world().get::<TargetingState>(player).unwrap(),
+            TargetingState::Seeking
+        );
+        let recall = app.world().get::<Recall>(player).unwrap();
+        assert_eq!(recall.returning_home(), Some(Vec2::new(100.0, 100.0)));
+    }
+
+    #[test]
+    fn recall_unit_engages_enemy_within_radius_after_kill() {
+        let mut app = create_ai_test_app();
+        let (player, enemy) = spawn_recalling_unit_engaged(&mut app);
+        let second = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 1060.0, 100.0);
+
+        app.world_mut().despawn(enemy);
+        app.update();
+
+        let state = app.world().get::<TargetingState>(player).unwrap();
+        assert_eq!(state.target_entity(), Some(second));
+        assert!(!app.world().get::<Recall>(player).unwrap().returning);
+    }
+
+    #[test]
+    fn returning_unit_stops_returning_when_enemy_enters_radius() {
+        let mut app = create_ai_test_app();
+        let (player, enemy) = spawn_recalling_unit_engaged(&mut app);
+        app.world_mut().despawn(enemy);
+        app.update();
+
+        let newcomer = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 1050.0, 100.0);
+        app.update();
+
+        let state = app.world().get::<TargetingState>(player).unwrap();
+        assert_eq!(state.target_entity(), Some(newcomer));
+        assert_eq!(
+            app.world().get::<Recall>(player).unwrap().returning_home(),
+            None
+        );
+    }
+
+    // === Taunt Tests ===
+
+    /// Enemy at x = 1060 engaged on a player unit at x = 1040, with a player
+    /// tank at x = 1000 (farther away, but within taunt range) and an enemy
+    /// straggler at x = 1500 outside it. Returns (tank, decoy, enemy, straggler).
+    fn spawn_taunt_scene(app: &mut App) -> (Entity, Entity, Entity, Entity) {
+        let tank = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 1000.0, 100.0);
+        let decoy = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 1040.0, 100.0);
+        let enemy = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 1060.0, 100.0);
+        let straggler =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 1500.0, 100.0);
+        app.update();
+        assert_eq!(target_of(app, enemy), Some(decoy));
+        (tank, decoy, enemy, straggler)
+    }
+
+    #[test]
+    fn taunt_redirects_nearby_enemies_to_taunter() {
+        let mut app = create_ai_test_app();
+        let (tank, decoy, enemy, straggler) = spawn_taunt_scene(&mut app);
+
+        app.world_mut()
+            .entity_mut(tank)
+            .insert(Taunt::new(100.0, 3.0));
+        app.update();
+
+        assert_eq!(target_of(&app, enemy), Some(tank));
+        assert_eq!(
+            app.world().get::<Taunted>(enemy),
+            Some(&Taunted { by: tank })
+        );
+        assert_ne!(target_of(&app, straggler), Some(tank));
+        assert!(app.world().get::<Taunted>(straggler).is_none());
+
+        // A retarget tick does not pull the taunted enemy back to the nearer decoy.
+        set_retarget_for_entity(&mut app, enemy);
+        app.update();
+        assert_eq!(target_of(&app, enemy), Some(tank));
+        assert_ne!(target_of(&app, enemy), Some(decoy));
+    }
+
+    #[test]
+    fn taunted_enemy_retargets_normally_after_taunt_expires() {
+        let mut app = create_ai_test_app();
+        let (tank, decoy, enemy, _) = spawn_taunt_scene(&mut app);
+        app.world_mut()
+            .entity_mut(tank)
+            .insert(Taunt::new(100.0, 3.0));
+        app.update();
+        assert_eq!(target_of(&app, enemy), Some(tank));
+
+        let mut taunt = app.world_mut().get_mut::<Taunt>(tank).unwrap();
+        crate::testing::nearly_expire_timer(&mut taunt.timer);
+        app.update();
+
+        assert!(app.world().get::<Taunt>(tank).is_none());
+        assert!(app.world().get::<Taunted>(enemy).is_none());
+        assert_eq!(target_of(&app, enemy), Some(decoy));
+    }
+
+    #[test]
+    fn taunted_enemy_is_released_when_taunter_dies() {
+        let mut app = create_ai_test_app();
+        let (tank, decoy, enemy, _) = spawn_taunt_scene(&mut app);
+        app.world_mut()
+            .entity_mut(tank)
+            .insert(Taunt::new(100.0, 3.0));
+        app.update();
+
+        app.world_mut().despawn(tank);
+        app.update();
+
+        assert!(app.world().get::<Taunted>(enemy).is_none());
+        assert_eq!(target_of(&app, enemy), Some(decoy));
+    }
+
+    // === Rally Point ===
+
+    #[test]
+    fn combat_zone_cell_center_snaps_inside_zone_only() {
+        let zone_x = col_to_world_x(COMBAT_ZONE_START_COL);
+        assert_eq!(
+            combat_zone_cell_center(Vec2::new(zone_x + 20.0, 70.0)),
+            Some(Vec2::new(zone_x, row_to_world_y(1)))
+        );
+        assert_eq!(
+            combat_zone_cell_center(Vec2::new(zone_x - CELL_SIZE, 70.0)),
+            None
+        );
+        assert_eq!(
+            combat_zone_cell_center(Vec2::new(col_to_world_x(ENEMY_FORT_START_COL), 70.0)),
+            None
+        );
+        assert_eq!(combat_zone_cell_center(Vec2::new(zone_x, -1.0)), None);
+    }
+
+    #[test]
+    fn new_player_units_are_sent_to_rally_point() {
+        let mut app = create_ai_test_app();
+        app.insert_resource(RallyPoint(Some(Vec2::new(800.0, 160.0))));
+        app.add_observer(send_new_unit_to_rally);
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        let enemy = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 900.0, 100.0);
+        app.world_mut().flush();
+
+        assert_eq!(
+            app.world().get::<MovingToRally>(player),
+            Some(&MovingToRally(Vec2::new(800.0, 160.0)))
+        );
+        assert!(app.world().get::<MovingToRally>(enemy).is_none());
+    }
+
+    #[test]
+    fn no_rally_point_leaves_new_units_untagged() {
+        let mut app = create_ai_test_app();
+        app.init_resource::<RallyPoint>();
+        app.add_observer(send_new_unit_to_rally);
+
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        app.world_mut().flush();
+
+        assert!(app.world().get::<MovingToRally>(player).is_none());
+    }
+
+    #[test]
+    fn rallying_unit_ignores_enemies_until_it_arrives() {
+        let mut app = create_ai_test_app();
+        let rally = Vec2::new(600.0, 100.0);
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(MovingToRally(rally));
+        let enemy = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 200.0, 100.0);
+
+        app.update();
+        assert_eq!(target_of(&app, player), None);
+        assert!(app.world().get::<MovingToRally>(player).is_some());
+
+        // Walk onto the rally point
+        let arrived = GlobalTransform::from(Transform::from_xyz(rally.x, rally.y, 0.0));
+        app.world_mut().entity_mut(player).insert(arrived);
+        app.update();
+
+        assert!(app.world().get::<MovingToRally>(player).is_none());
+        assert_eq!(target_of(&app, player), Some(enemy));
+    }
+
+    // === Attack Move ===
+
+    #[test]
+    fn attack_move_goes_to_whole_army_without_a_selection() {
+        let mut world = World::new();
+        let (a, b) = (world.spawn_empty().id(), world.spawn_empty().id());
+        assert_eq!(
+            attack_move_recipients(&[(a, false), (b, false)]),
+            vec![a, b]
+        );
+    }
+
+    #[test]
+    fn attack_move_goes_only_to_selected_units() {
+        let mut world = World::new();
+        let (a, b, c) = (
+            world.spawn_empty().id(),
+            world.spawn_empty().id(),
+            world.spawn_empty().id(),
+        );
+        assert_eq!(
+            attack_move_recipients(&[(a, false), (b, true), (c, true)]),
+            vec![b, c]
+        );
+    }
+
+    #[test]
+    fn attack_moving_unit_ignores_enemies_beyond_engage_radius() {
+        let mut app = create_ai_test_app();
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(AttackMove::new(Vec2::new(2000.0, 100.0)));
+        let _far_enemy =
+            crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 1000.0, 100.0);
+
+        app.update();
+
+        assert_eq!(target_of(&app, player), None);
+    }
+
+    #[test]
+    fn attack_moving_unit_engages_enemy_in_its_path_then_resumes() {
+        let mut app = create_ai_test_app();
+        let destination = Vec2::new(2000.0, 100.0);
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(AttackMove::new(destination));
+        let enemy = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 200.0, 100.0);
+
+        app.update();
+        assert_eq!(target_of(&app, player), Some(enemy));
+
+        app.world_mut().despawn(enemy);
+        app.update();
+
+        assert_eq!(target_of(&app, player), None);
+        assert_eq!(
+            app.world().get::<AttackMove>(player).map(|o| o.destination),
+            Some(destination)
+        );
+    }
+
+    #[test]
+    fn attack_move_ends_on_arrival() {
+        let mut app = create_ai_test_app();
+        let destination = Vec2::new(600.0, 100.0);
+        let player = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 100.0, 100.0);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(AttackMove::new(destination));
+        app.update();
+        assert!(app.world().get::<AttackMove>(player).is_some());
+
+        let arrived = GlobalTransform::from(Transform::from_xyz(destination.x, 100.0, 0.0));
+        app.world_mut().entity_mut(player).insert(arrived);
+        app.update();
+
+        assert!(app.world().get::<AttackMove>(player).is_none());
+    }
 }
@@ -1,45 +1,154 @@
-//! Camera setup and panning for the battlefield.
+//! Camera setup, panning, zoom, front-line jump, and follow mode for the battlefield.
 
 use bevy::camera::ScalingMode;
+use bevy::input::mouse::AccumulatedMouseScroll;
 use bevy::prelude::*;
 
-use super::{BATTLEFIELD_HEIGHT, BATTLEFIELD_WIDTH, BuildZone};
+use super::{BUILD_ZONE_COLS, BUILD_ZONE_START_COL, BattlefieldBounds, zone_center_x};
 use crate::gameplay::hud::bottom_bar::BOTTOM_BAR_HEIGHT;
+use crate::gameplay::units::{EnemyUnit, PlayerUnit, Unit};
 
 /// Camera panning speed in pixels per second.
 const CAMERA_PAN_SPEED: f32 = 500.0;
 
-/// Computes how many world units the bottom bar covers at the current window size.
-fn bar_world_height(window_height: f32) -> f32 {
-    BOTTOM_BAR_HEIGHT / window_height * BATTLEFIELD_HEIGHT
+/// Orthographic scale change per mouse-wheel notch.
+const CAMERA_ZOOM_STEP: f32 = 0.25;
+
+/// Closest manual zoom (orthographic scale).
+const MIN_CAMERA_ZOOM: f32 = 0.5;
+
+/// Farthest manual zoom (orthographic scale). Past 2.0 health bars hide.
+const MAX_CAMERA_ZOOM: f32 = 3.0;
+
+/// At or below this many living units, follow mode zooms in on them.
+const LAST_STAND_MAX_UNITS: usize = 2;
+
+/// Orthographic scale used to frame the last units (< 1.0 zooms in).
+const LAST_STAND_ZOOM: f32 = 0.5;
+
+/// Follow mode: the camera tracks the centroid of all living units instead of
+/// manual panning, zooming in when only a few remain. Toggled with F.
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct CameraFollow(pub bool);
+
+/// Where follow mode points the camera and at what orthographic scale:
+/// the centroid of `positions`, zoomed to `LAST_STAND_ZOOM` when at most
+/// `LAST_STAND_MAX_UNITS` remain. `None` when no units are left.
+#[must_use]
+pub fn follow_focus(positions: &[Vec2]) -> Option<(Vec2, f32)> {
+    if positions.is_empty() {
+        return None;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let center = positions.iter().sum::<Vec2>() / positions.len() as f32;
+    let scale = if positions.len() <= LAST_STAND_MAX_UNITS {
+        LAST_STAND_ZOOM
+    } else {
+        1.0
+    };
+    Some((center, scale))
+}
+
+/// Where the jump-to-front-line hotkey centers the camera: midway between the
+/// foremost player unit (`player_front`, max x) and foremost enemy unit
+/// (`enemy_front`, min x). Falls back to whichever front exists, then to the
+/// build zone when no units are on the field.
+#[must_use]
+pub fn front_line_x(player_front: Option<f32>, enemy_front: Option<f32>) -> f32 {
+    match (player_front, enemy_front) {
+        (Some(player), Some(enemy)) => f32::midpoint(player, enemy),
+        (Some(x), None) | (None, Some(x)) => x,
+        (None, None) => zone_center_x(BUILD_ZONE_START_COL, BUILD_ZONE_COLS),
+    }
+}
+
+/// Computes how many world units the bottom bar covers at the current window size,
+/// with `battlefield_height` world units filling the window vertically.
+fn bar_world_height(window_height: f32, battlefield_height: f32) -> f32 {
+    BOTTOM_BAR_HEIGHT / window_height * battlefield_height
 }
 
+/// Where the camera starts each battle: centered on the build zone, with the
+/// visible area above the bottom bar centered on the live battlefield height.
+pub(super) fn camera_start_position(window_height: f32, bounds: BattlefieldBounds) -> Vec2 {
+    let bar_world = bar_world_height(window_height, bounds.height);
+    Vec2::new(
+        zone_center_x(BUILD_ZONE_START_COL, BUILD_ZONE_COLS),
+        bounds.height / 2.0 - bar_world / 2.0,
+    )
+}
+
+/// Resets the camera to [`camera_start_position`] on every `OnEnter(InGame)`,
+/// discarding any pan left over from a previous battle.
 pub(super) fn setup_camera_for_battlefield(
     mut camera: Single<(&mut Transform, &mut Projection), With<Camera2d>>,
-    build_zone: Single<&Transform, (With<BuildZone>, Without<Camera2d>)>,
     windows: Single<&Window>,
+    bounds: Res<BattlefieldBounds>,
 ) {
     let (transform, projection) = &mut *camera;
 
-    // Position camera so the visible area above the bar is centered on the battlefield.
-    let bar_world = bar_world_height(windows.height());
-    transform.translation.x = build_zone.translation.x;
-    transform.translation.y = BATTLEFIELD_HEIGHT / 2.0 - bar_world / 2.0;
+    let start = camera_start_position(windows.height(), *bounds);
+    transform.translation.x = start.x;
+    transform.translation.y = start.y;
 
     // Set projection scaling so the full battlefield height fits the window.
     if let Projection::Orthographic(ref mut ortho) = **projection {
         ortho.scaling_mode = ScalingMode::FixedVertical {
-            viewport_height: BATTLEFIELD_HEIGHT,
+            viewport_height: bounds.height,
         };
     }
 }
 
+/// Mouse wheel zooms the camera between `MIN_CAMERA_ZOOM` and `MAX_CAMERA_ZOOM`
+/// (scroll up zooms in). Ignored in follow mode, which owns the zoom.
+/// Runs before `camera_pan` so the pan clamp sees the new visible area.
+pub(super) fn camera_zoom(
+    scroll: Res<AccumulatedMouseScroll>,
+    follow: Res<CameraFollow>,
+    mut projection: Single<&mut Projection, With<Camera2d>>,
+) {
+    if follow.0 || scroll.delta.y.abs() < f32::EPSILON {
+        return;
+    }
+    if let Projection::Orthographic(ortho) = &mut **projection {
+        let step = -scroll.delta.y.signum() * CAMERA_ZOOM_STEP;
+        ortho.scale = (ortho.scale + step).clamp(MIN_CAMERA_ZOOM, MAX_CAMERA_ZOOM);
+    }
+}
+
+/// Whether the player is moving the camera this frame: holding a pan key
+/// (see [`camera_pan`]) or jumping to the front line.
+#[must_use]
+pub fn player_moving_camera(keyboard: &ButtonInput<KeyCode>) -> bool {
+    keyboard.any_pressed([
+        KeyCode::KeyW,
+        KeyCode::KeyA,
+        KeyCode::KeyS,
+        KeyCode::KeyD,
+        KeyCode::ArrowUp,
+        KeyCode::ArrowLeft,
+        KeyCode::ArrowDown,
+        KeyCode::ArrowRight,
+    ]) || keyboard.just_pressed(KeyCode::Space)
+}
+
+/// Pans the camera with WASD/arrow keys, clamped to the live [`BattlefieldBounds`]
+/// at the current zoom.
 pub(super) fn camera_pan(
     keyboard: Res<ButtonInput<KeyCode>>,
     time: Res<Time>,
-    mut camera: Single<&mut Transform, With<Camera2d>>,
+    mut camera: Single<(&mut Transform, &Projection), With<Camera2d>>,
     windows: Single<&Window>,
+    bounds: Res<BattlefieldBounds>,
 ) {
+    let (camera, projection) = &mut *camera;
+    let scale = match *projection {
+        Projection::Orthographic(ortho) => ortho.scale,
+        _ => 1.0,
+    };
+    let visible_height = bounds.height * scale;
+
     // X-axis panning
     let mut x_direction = 0.0;
     if keyboard.pressed(KeyCode::KeyD) || keyboard.pressed(KeyCode::ArrowRight) {
@@ -60,19 +169,87 @@ pub(super) fn camera_pan(
     }
     camera.translation.y += y_direction * CAMERA_PAN_SPEED * time.delta_secs();
 
-    // X clamping: FixedVertical(BATTLEFIELD_HEIGHT) visible width depends on aspect ratio.
+    // X clamping: FixedVertical(bounds.height) visible width depends on aspect ratio.
     let aspect_ratio = windows.width() / windows.height();
-    let visible_width = BATTLEFIELD_HEIGHT * aspect_ratio;
+    let visible_width = visible_height * aspect_ratio;
     let half_visible_x = visible_width / 2.0;
     let min_x = half_visible_x;
-    let max_x = BATTLEFIELD_WIDTH - half_visible_x;
+    let max_x = (bounds.width - half_visible_x).max(min_x);
     camera.translation.x = camera.translation.x.clamp(min_x, max_x);
 
     // Y clamping: allow panning down so the bottom of the battlefield is visible
     // above the opaque bottom bar.
-    let half_visible_y = BATTLEFIELD_HEIGHT / 2.0;
-    let bar_world = bar_world_height(windows.height());
+    let half_visible_y = visible_height / 2.0;
+    let bar_world = bar_world_height(windows.height(), visible_height);
     let min_y = half_visible_y - bar_world; // Pan down: bottom of battlefield above bar
-    let max_y = half_visible_y; // Pan up: top of battlefield at top of window
+    let max_y = (bounds.height - half_visible_y).max(min_y); // Pan up: top of battlefield at top of window
     camera.translation.y = camera.translation.y.clamp(min_y, max_y);
 }
+
+pub(super) fn reset_camera_follow(mut follow: ResMut<CameraFollow>) {
+    follow.0 = false;
+}
+
+pub(super) fn toggle_camera_follow(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut follow: ResMut<CameraFollow>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyF) {
+        follow.0 = !follow.0;
+    }
+}
+
+/// Space jumps the camera to the front line (see [`front_line_x`]).
+/// Runs before `camera_pan` so the result is clamped to the battlefield.
+pub(super) fn jump_to_front_line(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    player_units: Query<&GlobalTransform, With<PlayerUnit>>,
+    enemy_units: Query<&GlobalTransform, With<EnemyUnit>>,
+    mut camera: Single<&mut Transform, With<Camera2d>>,
+) {
+    if !keyboard.just_pressed(KeyCode::Space) {
+        return;
+    }
+    let x = |transform: &GlobalTransform| transform.translation().x;
+    let player_front = player_units.iter().map(x).reduce(f32::max);
+    let enemy_front = enemy_units.iter().map(x).reduce(f32::min);
+    camera.translation.x = front_line_x(player_front, enemy_front);
+}
+
+/// In follow mode, centers the camera on the living units (see [`follow_focus`])
+/// and zooms relative to the scale the player had when follow was turned on.
+/// That scale is restored when follow turns off; outside follow mode the
+/// projection is left alone so manual zoom sticks.
+/// Runs in `GameSet::Ui`, after units have moved this frame.
+pub(super) fn camera_follow(
+    follow: Res<CameraFollow>,
+    units: Query<&Transform, (With<Unit>, Without<Camera2d>)>,
+    mut camera: Single<(&mut Transform, &mut Projection), With<Camera2d>>,
+    mut saved_scale: Local<Option<f32>>,
+) {
+    // Only touch the projection while following (or on the frame follow ends),
+    // so change detection stays quiet and manual zoom isn't clobbered.
+    if !follow.0 && saved_scale.is_none() {
+        return;
+    }
+    let (transform, projection) = &mut *camera;
+    let Projection::Orthographic(ortho) = &mut **projection else {
+        return;
+    };
+
+    if !follow.0 {
+        if let Some(scale) = saved_scale.take() {
+            ortho.scale = scale;
+        }
+        return;
+    }
+
+    let base = *saved_scale.get_or_insert(ortho.scale);
+    let positions: Vec<Vec2> = units.iter().map(|t| t.translation.xy()).collect();
+    let zoom = follow_focus(&positions).map_or(1.0, |(center, zoom)| {
+        transform.translation.x = center.x;
+        transform.translation.y = center.y;
+        zoom
+    });
+    ortho.scale = base * zoom;
+}
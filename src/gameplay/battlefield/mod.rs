@@ -10,6 +10,9 @@ use bevy::prelude::*;
 use crate::screens::GameState;
 use crate::{GameSet, gameplay_running};
 
+pub use camera::{CameraFollow, player_moving_camera};
+pub use renderer::spawn_fortress;
+
 // === Grid Constants ===
 
 /// Size of a single grid cell in pixels.
@@ -21,7 +24,8 @@ pub const BATTLEFIELD_ROWS: u16 = 10;
 /// Number of columns for each fortress.
 pub const FORTRESS_COLS: u16 = 2;
 
-/// Number of rows for each fortress (2×2 building).
+/// Number of rows for each fortress (2×2 building). Each row is its own
+/// `FortressSegment`.
 pub const FORTRESS_ROWS: u16 = 2;
 
 /// Number of columns in the building zone.
@@ -42,16 +46,21 @@ pub const BATTLEFIELD_WIDTH: f32 = TOTAL_COLS as f32 * CELL_SIZE;
 pub const BATTLEFIELD_HEIGHT: f32 = BATTLEFIELD_ROWS as f32 * CELL_SIZE;
 // = 10 * 64 = 640.0
 
-/// Fortress hit points. ~2000 HP — moderate buffer. A few leaked enemies are
-/// survivable, but 20+ breaking through will destroy the fortress.
+/// Fortress hit points, split evenly across its segments. ~2000 HP — moderate
+/// buffer. A few leaked enemies are survivable, but 20+ breaking through will
+/// destroy the fortress.
 pub const FORTRESS_HP: f32 = 2000.0;
 
+/// Fortress armor — shrugs off chip damage from basic units.
+pub const FORTRESS_ARMOR: f32 = 5.0;
+
 // === Fortress Combat Stats ===
 
 /// Fortress damage per projectile — high damage, slow rate.
 pub const FORTRESS_DAMAGE: f32 = 50.0;
 
-/// Fortress attacks per second — slow turret cadence.
+/// Fortress attacks per second — slow turret cadence. Split evenly across its
+/// segments, so each lost segment slows the fortress's fire.
 pub const FORTRESS_ATTACK_SPEED: f32 = 0.5;
 
 /// Fortress attack range in pixels (~5 cells).
@@ -60,8 +69,15 @@ pub const FORTRESS_RANGE: f32 = 300.0;
 /// Fortress health bar dimensions — larger than unit bars for visibility.
 const FORTRESS_HEALTH_BAR_WIDTH: f32 = 100.0;
 const FORTRESS_HEALTH_BAR_HEIGHT: f32 = 6.0;
-/// Y offset: above the 128px fortress. Half height (64) + padding (10) = 74.
-const FORTRESS_HEALTH_BAR_Y_OFFSET: f32 = 74.0;
+/// Gap between a fortress health bar and the top edge of its segment.
+const FORTRESS_HEALTH_BAR_PADDING: f32 = 4.0;
+
+/// Y offset that keeps a fortress health bar inside the top of its
+/// `segment_height`-tall segment. Segments are stacked edge to edge, so a bar
+/// above its own segment would cover the one on the next row.
+const fn fortress_health_bar_y_offset(segment_height: f32) -> f32 {
+    segment_height / 2.0 - FORTRESS_HEALTH_BAR_HEIGHT / 2.0 - FORTRESS_HEALTH_BAR_PADDING
+}
 
 // === Zone Column Ranges (start column, inclusive) ===
 
@@ -92,16 +108,25 @@ pub const BUILD_ZONE_END_X: f32 = (BUILD_ZONE_START_COL + BUILD_ZONE_COLS) as f3
 
 // === Marker Components ===
 
-/// Marks the player's fortress entity. Ticket 8 adds `Health` to this.
+/// Marks each segment of the player's fortress.
 #[derive(Component, Debug, Clone, Copy, Reflect)]
 #[reflect(Component)]
 pub struct PlayerFortress;
 
-/// Marks the enemy's fortress entity. Ticket 8 adds `Health` to this.
+/// Marks each segment of the enemy's fortress.
 #[derive(Component, Debug, Clone, Copy, Reflect)]
 #[reflect(Component)]
 pub struct EnemyFortress;
 
+/// One row of a fortress, with its own `Health` and `Target`. Units attack the
+/// nearest segment; the fortress falls once every segment is destroyed.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct FortressSegment {
+    /// Row within the fortress, counted from the bottom (`0..FORTRESS_ROWS`).
+    pub row: u16,
+}
+
 /// Marks the build zone area entity.
 #[derive(Component, Debug, Clone, Copy, Reflect)]
 #[reflect(Component)]
@@ -151,6 +176,25 @@ impl GridIndex {
     }
 }
 
+/// Live battlefield size in pixels. The camera and minimap read this rather than
+/// `BATTLEFIELD_WIDTH`/`BATTLEFIELD_HEIGHT` so a resized battlefield keeps the
+/// camera and HUD in step with its actual edges.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct BattlefieldBounds {
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Default for BattlefieldBounds {
+    fn default() -> Self {
+        Self {
+            width: BATTLEFIELD_WIDTH,
+            height: BATTLEFIELD_HEIGHT,
+        }
+    }
+}
+
 // === Helper Functions ===
 
 /// Convert a grid column to a world X position (center of the column).
@@ -177,6 +221,18 @@ pub fn battlefield_center_y() -> f32 {
     BATTLEFIELD_HEIGHT / 2.0
 }
 
+/// Mean position of a fortress's remaining segments, or `None` once they are
+/// all gone.
+#[must_use]
+pub fn fortress_center<'a>(segments: impl IntoIterator<Item = &'a Transform>) -> Option<Vec2> {
+    let (sum, count) = segments
+        .into_iter()
+        .fold((Vec2::ZERO, 0.0), |(sum, count), transform| {
+            (sum + transform.translation.xy(), count + 1.0)
+        });
+    (count > 0.0).then(|| sum / count)
+}
+
 // === System Sets ===
 
 /// System set for battlefield setup that runs on `OnEnter(GameState::InGame)`.
@@ -189,26 +245,43 @@ pub struct BattlefieldSetup;
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<PlayerFortress>()
         .register_type::<EnemyFortress>()
+        .register_type::<FortressSegment>()
         .register_type::<BuildZone>()
         .register_type::<CombatZone>()
         .register_type::<BattlefieldBackground>()
         .register_type::<BuildSlot>()
         .register_type::<GridIndex>()
-        .init_resource::<GridIndex>();
+        .register_type::<BattlefieldBounds>()
+        .register_type::<camera::CameraFollow>()
+        .init_resource::<GridIndex>()
+        .init_resource::<BattlefieldBounds>()
+        .init_resource::<camera::CameraFollow>();
 
     app.add_systems(
         OnEnter(GameState::InGame),
         (
-            renderer::spawn_battlefield,
-            camera::setup_camera_for_battlefield,
-        )
-            .chain()
-            .in_set(BattlefieldSetup),
+            (
+                renderer::spawn_battlefield,
+                camera::setup_camera_for_battlefield,
+            )
+                .chain()
+                .in_set(BattlefieldSetup),
+            camera::reset_camera_follow,
+        ),
     )
     .add_systems(
         Update,
-        camera::camera_pan
-            .in_set(GameSet::Input)
+        (
+            (
+                camera::toggle_camera_follow,
+                camera::jump_to_front_line,
+                camera::camera_zoom,
+                camera::camera_pan,
+            )
+                .chain()
+                .in_set(GameSet::Input),
+            camera::camera_follow.in_set(GameSet::Ui),
+        )
             .run_if(gameplay_running),
     );
 }
@@ -292,7 +365,6 @@ mod tests {
     fn fortress_health_bar_constants_valid() {
         assert!(FORTRESS_HEALTH_BAR_WIDTH > 0.0);
         assert!(FORTRESS_HEALTH_BAR_HEIGHT > 0.0);
-        assert!(FORTRESS_HEALTH_BAR_Y_OFFSET >= 0.0);
     }
 
     #[allow(clippy::assertions_on_constants)]
@@ -313,7 +385,7 @@ mod tests {
 #[cfg(test)]
 mod integration_tests {
     use super::*;
-    use crate::gameplay::Health;
+    use crate::gameplay::{Health, Team};
     use crate::testing::assert_entity_count;
     use pretty_assertions::assert_eq;
 
@@ -325,29 +397,298 @@ mod integration_tests {
         app
     }
 
+    fn camera_xy(app: &mut App) -> Vec2 {
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&Transform, With<Camera2d>>();
+        query.single(app.world()).unwrap().translation.xy()
+    }
+
+    #[test]
+    fn camera_resets_to_start_on_reentering_ingame() {
+        let mut app = create_battlefield_test_app();
+        let window_height = {
+            let mut windows = app.world_mut().query::<&Window>();
+            windows.single(app.world()).unwrap().height()
+        };
+        let start = camera::camera_start_position(window_height, BattlefieldBounds::default());
+        assert_eq!(camera_xy(&mut app), start);
+
+        // Pan away, leave, and come back.
+        let mut cameras = app
+            .world_mut()
+            .query_filtered::<&mut Transform, With<Camera2d>>();
+        cameras.single_mut(app.world_mut()).unwrap().translation += Vec3::new(250.0, -40.0, 0.0);
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::MainMenu);
+        app.update();
+        assert_ne!(camera_xy(&mut app), start);
+
+        crate::testing::transition_to_ingame(&mut app);
+        assert_eq!(camera_xy(&mut app), start);
+    }
+
+    #[test]
+    fn camera_start_centers_on_live_battlefield_height() {
+        let window_height = 720.0;
+        let base = camera::camera_start_position(window_height, BattlefieldBounds::default());
+        let taller = camera::camera_start_position(
+            window_height,
+            BattlefieldBounds {
+                height: BATTLEFIELD_HEIGHT * 2.0,
+                ..BattlefieldBounds::default()
+            },
+        );
+
+        assert_eq!(taller.x, base.x);
+        assert!(
+            (taller.y - base.y * 2.0).abs() < 1e-3,
+            "expected the start height to scale with the bounds: {base} -> {taller}"
+        );
+    }
+
+    /// Holds the pan-right key and runs one frame with the camera at `start_x`.
+    fn pan_right_from(app: &mut App, start_x: f32) {
+        let mut cameras = app
+            .world_mut()
+            .query_filtered::<&mut Transform, With<Camera2d>>();
+        cameras.single_mut(app.world_mut()).unwrap().translation.x = start_x;
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyD);
+        app.update();
+    }
+
+    fn half_visible_width(app: &mut App, bounds: BattlefieldBounds) -> f32 {
+        let mut windows = app.world_mut().query::<&Window>();
+        let window = windows.single(app.world()).unwrap();
+        let aspect_ratio = window.width() / window.height();
+        bounds.height * aspect_ratio / 2.0
+    }
+
+    #[test]
+    fn camera_pan_clamps_at_default_right_edge() {
+        let mut app = create_battlefield_test_app();
+        pan_right_from(&mut app, BATTLEFIELD_WIDTH + 1000.0);
+
+        let half = half_visible_width(&mut app, BattlefieldBounds::default());
+        assert_eq!(camera_xy(&mut app).x, BATTLEFIELD_WIDTH - half);
+    }
+
+    #[test]
+    fn camera_pan_clamps_at_reduced_battlefield_width() {
+        let mut app = create_battlefield_test_app();
+        let bounds = BattlefieldBounds {
+            width: 2000.0,
+            ..default()
+        };
+        app.insert_resource(bounds);
+        pan_right_from(&mut app, BATTLEFIELD_WIDTH);
+
+        let half = half_visible_width(&mut app, bounds);
+        assert_eq!(camera_xy(&mut app).x, bounds.width - half);
+        assert!(camera_xy(&mut app).x < BATTLEFIELD_WIDTH - half);
+    }
+
+    /// Like [`create_battlefield_test_app`] but without `InputPlugin`, so a
+    /// `press()` is still `just_pressed` when `Update` runs.
+    fn create_jump_test_app() -> App {
+        let mut app = crate::testing::create_base_test_app_no_input();
+        crate::testing::init_input_resources(&mut app);
+        app.add_plugins(plugin);
+        crate::testing::transition_to_ingame(&mut app);
+        app
+    }
+
+    fn press_space(app: &mut App) {
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Space);
+        app.update();
+    }
+
+    #[test]
+    fn space_centers_camera_between_front_lines() {
+        let mut app = create_jump_test_app();
+        let world = app.world_mut();
+        crate::testing::spawn_test_unit(world, Team::Player, 1200.0, 300.0);
+        crate::testing::spawn_test_unit(world, Team::Player, 1500.0, 300.0);
+        crate::testing::spawn_test_unit(world, Team::Enemy, 2500.0, 300.0);
+        crate::testing::spawn_test_unit(world, Team::Enemy, 2900.0, 300.0);
+
+        press_space(&mut app);
+
+        let x = camera_xy(&mut app).x;
+        assert_eq!(x, 2000.0);
+        assert!(x > 1500.0 && x < 2500.0);
+    }
+
+    #[test]
+    fn space_centers_on_build_zone_without_units() {
+        let mut app = create_jump_test_app();
+        pan_right_from(&mut app, 3000.0);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KeyCode::KeyD);
+
+        press_space(&mut app);
+
+        // The build zone sits near the left edge, so the pan clamp still applies.
+        let half = half_visible_width(&mut app, BattlefieldBounds::default());
+        let expected = zone_center_x(BUILD_ZONE_START_COL, BUILD_ZONE_COLS).max(half);
+        assert_eq!(camera_xy(&mut app).x, expected);
+    }
+
+    #[test]
+    fn front_line_x_uses_single_front_when_other_team_is_absent() {
+        assert_eq!(camera::front_line_x(Some(900.0), None), 900.0);
+        assert_eq!(camera::front_line_x(None, Some(4000.0)), 4000.0);
+    }
+
+    fn camera_scale(app: &mut App) -> f32 {
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&Projection, With<Camera2d>>();
+        match query.single(app.world()).unwrap() {
+            Projection::Orthographic(ortho) => ortho.scale,
+            other => panic!("Expected orthographic camera, got {other:?}"),
+        }
+    }
+
+    fn spawn_unit_marker(app: &mut App, x: f32, y: f32) -> Entity {
+        app.world_mut()
+            .spawn((crate::gameplay::units::Unit, Transform::from_xyz(x, y, 0.0)))
+            .id()
+    }
+
+    #[test]
+    fn follow_camera_centers_on_last_remaining_unit() {
+        let mut app = create_battlefield_test_app();
+        app.world_mut().resource_mut::<camera::CameraFollow>().0 = true;
+        spawn_unit_marker(&mut app, 900.0, 200.0);
+        app.update();
+
+        assert_eq!(camera_xy(&mut app), Vec2::new(900.0, 200.0));
+        assert!(
+            camera_scale(&mut app) < 1.0,
+            "Last unit should be zoomed in on"
+        );
+    }
+
+    #[test]
+    fn follow_camera_zooms_out_while_many_units_remain() {
+        let mut app = create_battlefield_test_app();
+        app.world_mut().resource_mut::<camera::CameraFollow>().0 = true;
+        for x in [800.0, 900.0, 1000.0] {
+            spawn_unit_marker(&mut app, x, 300.0);
+        }
+        app.update();
+
+        assert_eq!(camera_xy(&mut app), Vec2::new(900.0, 300.0));
+        assert_eq!(camera_scale(&mut app), 1.0);
+    }
+
+    fn set_camera_scale(app: &mut App, scale: f32) {
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&mut Projection, With<Camera2d>>();
+        if let Projection::Orthographic(ortho) = &mut *query.single_mut(app.world_mut()).unwrap() {
+            ortho.scale = scale;
+        }
+    }
+
+    #[test]
+    fn camera_ignores_units_when_follow_is_off() {
+        let mut app = create_battlefield_test_app();
+        let before = camera_xy(&mut app);
+        set_camera_scale(&mut app, 1.7);
+        spawn_unit_marker(&mut app, 900.0, 200.0);
+        app.update();
+
+        assert_eq!(camera_xy(&mut app), before);
+        assert_eq!(camera_scale(&mut app), 1.7, "Manual zoom must survive");
+    }
+
+    #[test]
+    fn turning_follow_off_restores_previous_scale() {
+        let mut app = create_battlefield_test_app();
+        set_camera_scale(&mut app, 1.7);
+        app.world_mut().resource_mut::<camera::CameraFollow>().0 = true;
+        spawn_unit_marker(&mut app, 900.0, 200.0);
+        app.update();
+        assert!(
+            camera_scale(&mut app) < 1.7,
+            "Last unit should be zoomed in on"
+        );
+
+        app.world_mut().resource_mut::<camera::CameraFollow>().0 = false;
+        app.update();
+        assert_eq!(camera_scale(&mut app), 1.7);
+    }
+
+    fn scroll(app: &mut App, notches: f32) {
+        app.world_mut()
+            .resource_mut::<bevy::input::mouse::AccumulatedMouseScroll>()
+            .delta = Vec2::new(0.0, notches);
+        app.update();
+    }
+
+    #[test]
+    fn mouse_wheel_zooms_out_far_enough_to_hide_health_bars() {
+        let mut app = create_jump_test_app();
+        for _ in 0..20 {
+            scroll(&mut app, -1.0);
+        }
+        // Clamped to the max, which is past the health-bar hide threshold (2.0).
+        assert_eq!(camera_scale(&mut app), 3.0);
+
+        for _ in 0..20 {
+            scroll(&mut app, 1.0);
+        }
+        assert_eq!(camera_scale(&mut app), 0.5);
+    }
+
+    #[test]
+    fn mouse_wheel_is_ignored_in_follow_mode() {
+        let mut app = create_jump_test_app();
+        app.world_mut().resource_mut::<camera::CameraFollow>().0 = true;
+        scroll(&mut app, -1.0);
+        assert_eq!(camera_scale(&mut app), 1.0);
+    }
+
+    #[test]
+    fn follow_focus_frames_last_two_units() {
+        let (center, scale) =
+            camera::follow_focus(&[Vec2::new(100.0, 0.0), Vec2::new(300.0, 200.0)]).unwrap();
+        assert_eq!(center, Vec2::new(200.0, 100.0));
+        assert!(scale < 1.0);
+        assert_eq!(camera::follow_focus(&[]), None);
+    }
+
     #[test]
     fn spawn_battlefield_creates_expected_sprites() {
         let mut app = create_battlefield_test_app();
-        assert_entity_count::<With<Sprite>>(&mut app, 67); // 5 zones + 2 fortress zone backdrops + 60 grid cells
+        assert_entity_count::<With<Sprite>>(&mut app, 69); // 5 zones + 4 fortress segments + 60 grid cells
     }
 
     #[test]
     fn spawn_battlefield_creates_player_fortress() {
         let mut app = create_battlefield_test_app();
-        assert_entity_count::<With<PlayerFortress>>(&mut app, 1);
+        assert_entity_count::<With<PlayerFortress>>(&mut app, FORTRESS_ROWS as usize);
     }
 
     #[test]
     fn spawn_battlefield_creates_enemy_fortress() {
         let mut app = create_battlefield_test_app();
-        assert_entity_count::<With<EnemyFortress>>(&mut app, 1);
+        assert_entity_count::<With<EnemyFortress>>(&mut app, FORTRESS_ROWS as usize);
     }
 
     #[test]
     fn all_battlefield_entities_have_despawn_on_exit() {
         let mut app = create_battlefield_test_app();
-        // All 5 zones + 60 grid cells have DespawnOnExit
-        assert_entity_count::<(With<Sprite>, With<DespawnOnExit<GameState>>)>(&mut app, 67);
+        // All 5 zones + 4 fortress segments + 60 grid cells have DespawnOnExit
+        assert_entity_count::<(With<Sprite>, With<DespawnOnExit<GameState>>)>(&mut app, 69);
     }
 
     #[test]
@@ -356,8 +697,9 @@ mod integration_tests {
         let mut query = app
             .world_mut()
             .query_filtered::<&Transform, With<PlayerFortress>>();
-        let transform = query.single(app.world()).unwrap();
-        assert!(transform.translation.x < BATTLEFIELD_WIDTH / 4.0);
+        for transform in query.iter(app.world()) {
+            assert!(transform.translation.x < BATTLEFIELD_WIDTH / 4.0);
+        }
     }
 
     #[test]
@@ -366,8 +708,9 @@ mod integration_tests {
         let mut query = app
             .world_mut()
             .query_filtered::<&Transform, With<EnemyFortress>>();
-        let transform = query.single(app.world()).unwrap();
-        assert!(transform.translation.x > BATTLEFIELD_WIDTH * 3.0 / 4.0);
+        for transform in query.iter(app.world()) {
+            assert!(transform.translation.x > BATTLEFIELD_WIDTH * 3.0 / 4.0);
+        }
     }
 
     #[test]
@@ -440,9 +783,11 @@ mod integration_tests {
         let mut query = app
             .world_mut()
             .query_filtered::<&Health, With<PlayerFortress>>();
-        let health = query.single(app.world()).unwrap();
-        assert_eq!(health.current, FORTRESS_HP);
-        assert_eq!(health.max, FORTRESS_HP);
+        let total: f32 = query.iter(app.world()).map(|health| health.max).sum();
+        assert_eq!(total, FORTRESS_HP);
+        for health in query.iter(app.world()) {
+            assert_eq!(health.current, health.max);
+        }
     }
 
     #[test]
@@ -451,46 +796,178 @@ mod integration_tests {
         let mut query = app
             .world_mut()
             .query_filtered::<&Health, With<EnemyFortress>>();
-        let health = query.single(app.world()).unwrap();
-        assert_eq!(health.current, FORTRESS_HP);
-        assert_eq!(health.max, FORTRESS_HP);
+        let total: f32 = query.iter(app.world()).map(|health| health.max).sum();
+        assert_eq!(total, FORTRESS_HP);
+        for health in query.iter(app.world()) {
+            assert_eq!(health.current, health.max);
+        }
     }
 
     #[test]
     fn fortress_has_health_bar_config() {
         use crate::gameplay::combat::HealthBarConfig;
         let mut app = create_battlefield_test_app();
-        assert_entity_count::<(With<PlayerFortress>, With<HealthBarConfig>)>(&mut app, 1);
-        assert_entity_count::<(With<EnemyFortress>, With<HealthBarConfig>)>(&mut app, 1);
+        let segments = FORTRESS_ROWS as usize;
+        assert_entity_count::<(With<PlayerFortress>, With<HealthBarConfig>)>(&mut app, segments);
+        assert_entity_count::<(With<EnemyFortress>, With<HealthBarConfig>)>(&mut app, segments);
     }
 
     #[test]
-    fn fortress_is_2x2_cells() {
+    fn fortress_health_bars_stay_inside_their_segment() {
         let mut app = create_battlefield_test_app();
-        let expected = Vec2::new(
-            f32::from(FORTRESS_COLS) * CELL_SIZE,
-            f32::from(FORTRESS_ROWS) * CELL_SIZE,
-        );
+        let mut segments = app.world_mut().query::<(
+            &FortressSegment,
+            &Sprite,
+            &crate::gameplay::combat::HealthBarConfig,
+        )>();
+        for (segment, sprite, config) in segments.iter(app.world()) {
+            let half_height = sprite.custom_size.unwrap().y / 2.0;
+            assert!(
+                config.y_offset + config.height / 2.0 <= half_height,
+                "Row {} bar overlaps the segment above",
+                segment.row
+            );
+            assert!(config.y_offset - config.height / 2.0 >= -half_height);
+        }
+    }
+
+    #[test]
+    fn fortress_segments_are_one_row_each() {
+        let mut app = create_battlefield_test_app();
+        let expected = Vec2::new(f32::from(FORTRESS_COLS) * CELL_SIZE, CELL_SIZE);
         let mut query = app
             .world_mut()
             .query_filtered::<&Sprite, With<PlayerFortress>>();
-        let sprite = query.single(app.world()).unwrap();
-        assert_eq!(sprite.custom_size, Some(expected));
+        for sprite in query.iter(app.world()) {
+            assert_eq!(sprite.custom_size, Some(expected));
+        }
+    }
+
+    #[test]
+    fn fortress_segments_cover_the_2x2_footprint() {
+        let mut app = create_battlefield_test_app();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&FortressSegment, &Transform), With<PlayerFortress>>();
+        let mut rows: Vec<(u16, Vec2)> = query
+            .iter(app.world())
+            .map(|(segment, transform)| (segment.row, transform.translation.xy()))
+            .collect();
+        rows.sort_by_key(|&(row, _)| row);
+
+        let x = zone_center_x(PLAYER_FORT_START_COL, FORTRESS_COLS);
+        let bottom = battlefield_center_y() - f32::from(FORTRESS_ROWS) * CELL_SIZE / 2.0;
+        assert_eq!(
+            rows,
+            vec![
+                (0, Vec2::new(x, bottom + CELL_SIZE / 2.0)),
+                (1, Vec2::new(x, bottom + CELL_SIZE * 1.5)),
+            ]
+        );
     }
 
     #[test]
     fn fortress_has_combat_stats() {
         use crate::gameplay::CombatStats;
         let mut app = create_battlefield_test_app();
-        assert_entity_count::<(With<PlayerFortress>, With<CombatStats>)>(&mut app, 1);
-        assert_entity_count::<(With<EnemyFortress>, With<CombatStats>)>(&mut app, 1);
+        let segments = FORTRESS_ROWS as usize;
+        assert_entity_count::<(With<PlayerFortress>, With<CombatStats>)>(&mut app, segments);
+        assert_entity_count::<(With<EnemyFortress>, With<CombatStats>)>(&mut app, segments);
     }
 
     #[test]
     fn fortress_has_targeting_state() {
         use crate::gameplay::TargetingState;
         let mut app = create_battlefield_test_app();
-        assert_entity_count::<(With<PlayerFortress>, With<TargetingState>)>(&mut app, 1);
-        assert_entity_count::<(With<EnemyFortress>, With<TargetingState>)>(&mut app, 1);
+        let segments = FORTRESS_ROWS as usize;
+        assert_entity_count::<(With<PlayerFortress>, With<TargetingState>)>(&mut app, segments);
+        assert_entity_count::<(With<EnemyFortress>, With<TargetingState>)>(&mut app, segments);
+    }
+
+    #[test]
+    fn fortress_center_averages_remaining_segments() {
+        let segments = [
+            Transform::from_xyz(64.0, 288.0, 0.0),
+            Transform::from_xyz(64.0, 352.0, 0.0),
+        ];
+        assert_eq!(fortress_center(&segments), Some(Vec2::new(64.0, 320.0)));
+        assert_eq!(
+            fortress_center(&segments[1..]),
+            Some(Vec2::new(64.0, 352.0))
+        );
+        assert_eq!(fortress_center(&segments[..0]), None);
+    }
+
+    // === spawn_fortress Tests ===
+
+    fn spawn_test_fortress(
+        team: crate::gameplay::Team,
+        position: Vec2,
+        hp: f32,
+    ) -> (App, Vec<Entity>) {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        let segments = app
+            .world_mut()
+            .run_system_once(move |mut commands: Commands| {
+                spawn_fortress(&mut commands, team, position, hp)
+            })
+            .unwrap();
+        (app, segments)
+    }
+
+    #[test]
+    fn spawn_fortress_sets_team_health_and_target() {
+        use crate::gameplay::{Target, Team};
+
+        let (app, segments) = spawn_test_fortress(Team::Enemy, Vec2::new(100.0, 50.0), 500.0);
+        let world = app.world();
+
+        assert_eq!(segments.len(), FORTRESS_ROWS as usize);
+        for (row, &entity) in (0..).zip(&segments) {
+            assert_eq!(
+                world.get::<FortressSegment>(entity),
+                Some(&FortressSegment { row })
+            );
+            assert_eq!(world.get::<Team>(entity), Some(&Team::Enemy));
+            let health = world.get::<Health>(entity).unwrap();
+            assert_eq!(health.current, 250.0);
+            assert_eq!(health.max, 250.0);
+            assert!(world.get::<Target>(entity).is_some());
+            assert!(world.get::<EnemyFortress>(entity).is_some());
+            assert!(world.get::<PlayerFortress>(entity).is_none());
+        }
+    }
+
+    #[test]
+    fn spawn_fortress_has_collider_at_position() {
+        use crate::gameplay::Team;
+        use avian2d::prelude::Collider;
+
+        let (app, segments) = spawn_test_fortress(Team::Player, Vec2::new(100.0, 50.0), 500.0);
+        let world = app.world();
+
+        let mut center = Vec2::ZERO;
+        for &entity in &segments {
+            assert!(world.get::<Collider>(entity).is_some());
+            assert!(world.get::<PlayerFortress>(entity).is_some());
+            let transform = world.get::<Transform>(entity).unwrap();
+            assert_eq!(transform.translation.x, 100.0);
+            center += transform.translation.xy();
+        }
+        assert_eq!(center / 2.0, Vec2::new(100.0, 50.0));
+    }
+
+    #[test]
+    fn spawn_battlefield_produces_one_fortress_per_team() {
+        use crate::gameplay::Team;
+
+        let mut app = create_battlefield_test_app();
+        let segments = FORTRESS_ROWS as usize;
+        assert_entity_count::<(With<PlayerFortress>, With<Team>)>(&mut app, segments);
+        assert_entity_count::<(With<EnemyFortress>, With<Team>)>(&mut app, segments);
+        assert_entity_count::<With<FortressSegment>>(&mut app, 2 * segments);
     }
 }
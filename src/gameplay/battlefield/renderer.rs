@@ -6,15 +6,15 @@ use bevy::prelude::*;
 use super::{
     BATTLEFIELD_HEIGHT, BATTLEFIELD_ROWS, BATTLEFIELD_WIDTH, BUILD_ZONE_COLS, BUILD_ZONE_START_COL,
     BattlefieldBackground, BuildSlot, BuildZone, CELL_SIZE, COMBAT_ZONE_COLS,
-    COMBAT_ZONE_START_COL, CombatZone, ENEMY_FORT_START_COL, EnemyFortress, FORTRESS_ATTACK_SPEED,
-    FORTRESS_COLS, FORTRESS_DAMAGE, FORTRESS_HEALTH_BAR_HEIGHT, FORTRESS_HEALTH_BAR_WIDTH,
-    FORTRESS_HEALTH_BAR_Y_OFFSET, FORTRESS_HP, FORTRESS_RANGE, FORTRESS_ROWS, GridIndex,
-    PLAYER_FORT_START_COL, PlayerFortress, battlefield_center_y, col_to_world_x, row_to_world_y,
-    zone_center_x,
+    COMBAT_ZONE_START_COL, CombatZone, ENEMY_FORT_START_COL, EnemyFortress, FORTRESS_ARMOR,
+    FORTRESS_ATTACK_SPEED, FORTRESS_COLS, FORTRESS_DAMAGE, FORTRESS_HEALTH_BAR_HEIGHT,
+    FORTRESS_HEALTH_BAR_WIDTH, FORTRESS_HP, FORTRESS_RANGE, FORTRESS_ROWS, FortressSegment,
+    GridIndex, PLAYER_FORT_START_COL, PlayerFortress, battlefield_center_y, col_to_world_x,
+    fortress_health_bar_y_offset, row_to_world_y, zone_center_x,
 };
 use crate::gameplay::combat::{AttackTimer, HealthBarConfig};
 use crate::gameplay::units::UNIT_RADIUS;
-use crate::gameplay::{CombatStats, EntityExtent, Health, Target, TargetingState, Team};
+use crate::gameplay::{CombatStats, Defense, EntityExtent, Health, Target, TargetingState, Team};
 use crate::screens::GameState;
 use crate::third_party::{NavObstacle, solid_entity_layers};
 use crate::{Z_BACKGROUND, Z_FORTRESS, Z_GRID, Z_ZONE};
@@ -27,11 +27,6 @@ use crate::theme::palette;
 pub(super) fn spawn_battlefield(mut commands: Commands, mut grid_index: ResMut<GridIndex>) {
     grid_index.clear(); // Reset stale entity refs from previous session
 
-    let fortress_size = Vec2::new(
-        f32::from(FORTRESS_COLS) * CELL_SIZE,
-        f32::from(FORTRESS_ROWS) * CELL_SIZE,
-    );
-
     // Background (slightly larger than battlefield for visual framing)
     commands.spawn((
         Name::new("Battlefield Background"),
@@ -62,44 +57,15 @@ pub(super) fn spawn_battlefield(mut commands: Commands, mut grid_index: ResMut<G
         DespawnOnExit(GameState::InGame),
     ));
 
-    // Player fortress (blue)
-    commands
-        .spawn((
-            Name::new("Player Fortress"),
-            PlayerFortress,
-            Team::Player,
-            Target,
-            Health::new(FORTRESS_HP),
-            HealthBarConfig {
-                width: FORTRESS_HEALTH_BAR_WIDTH,
-                height: FORTRESS_HEALTH_BAR_HEIGHT,
-                y_offset: FORTRESS_HEALTH_BAR_Y_OFFSET,
-            },
-            CombatStats {
-                damage: FORTRESS_DAMAGE,
-                attack_speed: FORTRESS_ATTACK_SPEED,
-                range: FORTRESS_RANGE,
-            },
-            AttackTimer(Timer::from_seconds(
-                1.0 / FORTRESS_ATTACK_SPEED,
-                TimerMode::Repeating,
-            )),
-            TargetingState::Seeking,
-            Sprite::from_color(palette::PLAYER_FORTRESS, fortress_size),
-            Transform::from_xyz(
-                zone_center_x(PLAYER_FORT_START_COL, FORTRESS_COLS),
-                battlefield_center_y(),
-                Z_FORTRESS,
-            ),
-            DespawnOnExit(GameState::InGame),
-        ))
-        .insert((
-            EntityExtent::Rect(fortress_size.x / 2.0, fortress_size.y / 2.0),
-            NavObstacle,
-            RigidBody::Static,
-            Collider::rectangle(fortress_size.x, fortress_size.y),
-            solid_entity_layers(),
-        ));
+    spawn_fortress(
+        &mut commands,
+        Team::Player,
+        Vec2::new(
+            zone_center_x(PLAYER_FORT_START_COL, FORTRESS_COLS),
+            battlefield_center_y(),
+        ),
+        FORTRESS_HP,
+    );
 
     // Building zone (dark blue-gray)
     commands.spawn((
@@ -145,44 +111,15 @@ pub(super) fn spawn_battlefield(mut commands: Commands, mut grid_index: ResMut<G
         DespawnOnExit(GameState::InGame),
     ));
 
-    // Enemy fortress (red)
-    commands
-        .spawn((
-            Name::new("Enemy Fortress"),
-            EnemyFortress,
-            Team::Enemy,
-            Target,
-            Health::new(FORTRESS_HP),
-            HealthBarConfig {
-                width: FORTRESS_HEALTH_BAR_WIDTH,
-                height: FORTRESS_HEALTH_BAR_HEIGHT,
-                y_offset: FORTRESS_HEALTH_BAR_Y_OFFSET,
-            },
-            CombatStats {
-                damage: FORTRESS_DAMAGE,
-                attack_speed: FORTRESS_ATTACK_SPEED,
-                range: FORTRESS_RANGE,
-            },
-            AttackTimer(Timer::from_seconds(
-                1.0 / FORTRESS_ATTACK_SPEED,
-                TimerMode::Repeating,
-            )),
-            TargetingState::Seeking,
-            Sprite::from_color(palette::ENEMY_FORTRESS, fortress_size),
-            Transform::from_xyz(
-                zone_center_x(ENEMY_FORT_START_COL, FORTRESS_COLS),
-                battlefield_center_y(),
-                Z_FORTRESS,
-            ),
-            DespawnOnExit(GameState::InGame),
-        ))
-        .insert((
-            EntityExtent::Rect(fortress_size.x / 2.0, fortress_size.y / 2.0),
-            NavObstacle,
-            RigidBody::Static,
-            Collider::rectangle(fortress_size.x, fortress_size.y),
-            solid_entity_layers(),
-        ));
+    spawn_fortress(
+        &mut commands,
+        Team::Enemy,
+        Vec2::new(
+            zone_center_x(ENEMY_FORT_START_COL, FORTRESS_COLS),
+            battlefield_center_y(),
+        ),
+        FORTRESS_HP,
+    );
 
     // Build slots: 10 rows × 6 cols — visible grid cells, indexed for O(1) lookup
     for row in 0..BATTLEFIELD_ROWS {
@@ -223,3 +160,66 @@ pub(super) fn spawn_battlefield(mut commands: Commands, mut grid_index: ResMut<G
         DespawnOnExit(GameState::InGame),
     ));
 }
+
+/// Spawns a 2×2 fortress for `team` centered at `position` with `hp` total health.
+///
+/// The fortress is one `FortressSegment` per row, each with an even share of `hp`
+/// and of the turret's fire rate. Returns the segments from the bottom row up.
+/// The team picks the marker (`PlayerFortress`/`EnemyFortress`), name, and color;
+/// count and placement are up to the caller.
+pub fn spawn_fortress(commands: &mut Commands, team: Team, position: Vec2, hp: f32) -> Vec<Entity> {
+    let segment_size = Vec2::new(f32::from(FORTRESS_COLS) * CELL_SIZE, CELL_SIZE);
+    let rows = f32::from(FORTRESS_ROWS);
+    let attack_speed = FORTRESS_ATTACK_SPEED / rows;
+    let (name, color) = match team {
+        Team::Player => ("Player Fortress", palette::PLAYER_FORTRESS),
+        Team::Enemy => ("Enemy Fortress", palette::ENEMY_FORTRESS),
+    };
+
+    (0..FORTRESS_ROWS)
+        .map(|row| {
+            // Rows stack upward from the fortress's bottom edge.
+            let y = (f32::from(row) + 0.5).mul_add(CELL_SIZE, position.y - rows * CELL_SIZE / 2.0);
+            let mut segment = commands.spawn((
+                Name::new(format!("{name} (row {row})")),
+                FortressSegment { row },
+                team,
+                Target,
+                Health::new(hp / rows),
+                Defense {
+                    armor: FORTRESS_ARMOR,
+                },
+                HealthBarConfig {
+                    width: FORTRESS_HEALTH_BAR_WIDTH,
+                    height: FORTRESS_HEALTH_BAR_HEIGHT,
+                    y_offset: fortress_health_bar_y_offset(segment_size.y),
+                },
+                CombatStats {
+                    damage: FORTRESS_DAMAGE,
+                    attack_speed,
+                    range: FORTRESS_RANGE,
+                },
+                AttackTimer(Timer::from_seconds(
+                    1.0 / attack_speed,
+                    TimerMode::Repeating,
+                )),
+                TargetingState::Seeking,
+                Sprite::from_color(color, segment_size),
+                Transform::from_xyz(position.x, y, Z_FORTRESS),
+                DespawnOnExit(GameState::InGame),
+            ));
+            segment.insert((
+                EntityExtent::Rect(segment_size.x / 2.0, segment_size.y / 2.0),
+                NavObstacle,
+                RigidBody::Static,
+                Collider::rectangle(segment_size.x, segment_size.y),
+                solid_entity_layers(),
+            ));
+            match team {
+                Team::Player => segment.insert(PlayerFortress),
+                Team::Enemy => segment.insert(EnemyFortress),
+            };
+            segment.id()
+        })
+        .collect()
+}
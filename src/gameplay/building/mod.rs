@@ -3,10 +3,19 @@
 mod placement;
 mod production;
 
+pub use placement::spawn_building;
+
+use avian2d::prelude::Collider;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use crate::gameplay::battlefield::{BATTLEFIELD_HEIGHT, BattlefieldSetup, CELL_SIZE};
+use crate::gameplay::battlefield::{
+    BATTLEFIELD_HEIGHT, BATTLEFIELD_ROWS, BattlefieldSetup, CELL_SIZE,
+};
+use crate::gameplay::combat::HealthRegen;
+use crate::gameplay::economy::shop_ui::ShopInput;
 use crate::gameplay::units::UnitType;
+use crate::gameplay::{EntityExtent, FiringArc};
 use crate::screens::GameState;
 use crate::{GameSet, gameplay_running};
 
@@ -38,18 +47,69 @@ pub struct Building {
     pub grid_col: u16,
     /// Grid row (0–9).
     pub grid_row: u16,
+    /// Direction chosen during placement. Only matters for directional buildings.
+    pub facing: Facing,
+    /// Row the next produced unit is sent down. Starts at the building's own row
+    /// and cycles through every battlefield row.
+    pub next_lane: u16,
+}
+
+impl Building {
+    /// Lane for the next produced unit; advances the cursor round-robin.
+    pub const fn take_lane(&mut self) -> u16 {
+        let lane = self.next_lane % BATTLEFIELD_ROWS;
+        self.next_lane = (lane + 1) % BATTLEFIELD_ROWS;
+        lane
+    }
+}
+
+/// Cardinal direction a building faces, chosen before placement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect, Serialize, Deserialize)]
+pub enum Facing {
+    /// Toward the enemy fortress.
+    #[default]
+    East,
+    South,
+    West,
+    North,
+}
+
+impl Facing {
+    /// Next facing in clockwise order (East → South → West → North → East).
+    #[must_use]
+    pub const fn rotated_clockwise(self) -> Self {
+        match self {
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+            Self::North => Self::East,
+        }
+    }
+
+    /// World-space unit vector for this facing (+Y is up).
+    #[must_use]
+    pub const fn direction(self) -> Vec2 {
+        match self {
+            Self::East => Vec2::X,
+            Self::South => Vec2::NEG_Y,
+            Self::West => Vec2::NEG_X,
+            Self::North => Vec2::Y,
+        }
+    }
 }
 
 /// Types of buildings the player can place.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
 pub enum BuildingType {
     Barracks,
     Farm,
+    SlowTower,
+    Catapult,
 }
 
 impl BuildingType {
     /// All building types, used by shop card pool.
-    pub const ALL: &[Self] = &[Self::Barracks, Self::Farm];
+    pub const ALL: &[Self] = &[Self::Barracks, Self::Farm, Self::SlowTower, Self::Catapult];
 
     /// Human-readable display name.
     #[must_use]
@@ -57,10 +117,94 @@ impl BuildingType {
         match self {
             Self::Barracks => "Barracks",
             Self::Farm => "Farm",
+            Self::SlowTower => "Slow Tower",
+            Self::Catapult => "Catapult",
+        }
+    }
+
+    /// Building type that grants a combo rebate when placed next to this one.
+    #[must_use]
+    pub const fn combo_partner(self) -> Option<Self> {
+        match self {
+            Self::Barracks => Some(Self::Farm),
+            Self::Farm => Some(Self::Barracks),
+            Self::SlowTower | Self::Catapult => None,
+        }
+    }
+}
+
+/// Shop draw weight of a [`Rarity::Common`] building.
+pub const COMMON_SHOP_WEIGHT: u32 = 4;
+
+/// Shop draw weight of a [`Rarity::Rare`] building.
+pub const RARE_SHOP_WEIGHT: u32 = 1;
+
+/// How often a building type turns up as a shop card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum Rarity {
+    Common,
+    Rare,
+}
+
+impl Rarity {
+    /// Relative chance of a building with this rarity being drawn as a shop card.
+    #[must_use]
+    pub const fn shop_weight(self) -> u32 {
+        match self {
+            Self::Common => COMMON_SHOP_WEIGHT,
+            Self::Rare => RARE_SHOP_WEIGHT,
+        }
+    }
+}
+
+/// Physics and pathing shape of a placed building. The navmesh carves around
+/// the collider, so this also decides how units path around the building.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ColliderShape {
+    /// Square matching the sprite.
+    Rect,
+    /// Circle inscribed in the sprite, for round buildings.
+    Circle,
+    /// Square covering the building's whole grid cell.
+    Footprint,
+}
+
+impl ColliderShape {
+    /// Collider for a building with this shape.
+    #[must_use]
+    pub fn collider(self) -> Collider {
+        match self {
+            Self::Rect => Collider::rectangle(BUILDING_SPRITE_SIZE, BUILDING_SPRITE_SIZE),
+            Self::Circle => Collider::circle(BUILDING_SPRITE_SIZE / 2.0),
+            Self::Footprint => Collider::rectangle(CELL_SIZE, CELL_SIZE),
+        }
+    }
+
+    /// Targeting extent matching [`Self::collider`].
+    #[must_use]
+    pub const fn extent(self) -> EntityExtent {
+        match self {
+            Self::Rect => {
+                EntityExtent::Rect(BUILDING_SPRITE_SIZE / 2.0, BUILDING_SPRITE_SIZE / 2.0)
+            }
+            Self::Circle => EntityExtent::Circle(BUILDING_SPRITE_SIZE / 2.0),
+            Self::Footprint => EntityExtent::Rect(CELL_SIZE / 2.0, CELL_SIZE / 2.0),
         }
     }
 }
 
+/// Ranged attack of a defensive building. Fired through the same `attack`
+/// system as units and fortresses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BuildingAttack {
+    pub damage: f32,
+    pub attack_speed: f32,
+    /// Surface-to-surface reach in pixels.
+    pub range: f32,
+    /// Blast radius of each projectile. 0.0 = single-target.
+    pub splash_radius: f32,
+}
+
 /// Stats for a building type. All values are compile-time constants.
 #[derive(Debug, Clone, Copy)]
 pub struct BuildingStats {
@@ -76,6 +220,29 @@ pub struct BuildingStats {
     pub production_interval: Option<f32>,
     /// Income timer interval (seconds), if this building generates income.
     pub income_interval: Option<f32>,
+    /// Firing arc half-angle (radians), if this is a directional building.
+    pub firing_arc: Option<f32>,
+    /// Area that slows enemy units inside it, if this building emits one.
+    pub slow_field: Option<SlowField>,
+    /// Ranged attack, if this building fires at enemies.
+    pub attack: Option<BuildingAttack>,
+    /// Flat damage reduction per hit. Zero means no `Defense` component.
+    pub armor: f32,
+    /// How often this type is drawn as a shop card.
+    pub rarity: Rarity,
+    /// Collider and targeting shape used at placement.
+    pub collider_shape: ColliderShape,
+}
+
+impl BuildingStats {
+    /// Firing arc for a building with these stats placed with the given facing.
+    #[must_use]
+    pub fn firing_arc_for(&self, facing: Facing) -> Option<FiringArc> {
+        self.firing_arc.map(|half_angle| FiringArc {
+            direction: facing.direction(),
+            half_angle,
+        })
+    }
 }
 
 /// Look up stats for a building type.
@@ -89,6 +256,12 @@ pub const fn building_stats(building_type: BuildingType) -> BuildingStats {
             produced_unit: Some(UnitType::Soldier),
             production_interval: Some(3.0),
             income_interval: None,
+            firing_arc: None,
+            slow_field: None,
+            attack: None,
+            armor: 5.0,
+            rarity: Rarity::Common,
+            collider_shape: ColliderShape::Rect,
         },
         BuildingType::Farm => BuildingStats {
             hp: 150.0,
@@ -97,7 +270,108 @@ pub const fn building_stats(building_type: BuildingType) -> BuildingStats {
             produced_unit: None,
             production_interval: None,
             income_interval: Some(1.0),
+            firing_arc: None,
+            slow_field: None,
+            attack: None,
+            armor: 0.0,
+            rarity: Rarity::Common,
+            collider_shape: ColliderShape::Rect,
+        },
+        BuildingType::SlowTower => BuildingStats {
+            hp: 200.0,
+            cost: 75,
+            color: palette::SLOW_TOWER,
+            produced_unit: None,
+            production_interval: None,
+            income_interval: None,
+            firing_arc: None,
+            slow_field: Some(SlowField {
+                radius: 96.0,
+                speed_multiplier: 0.5,
+            }),
+            attack: None,
+            armor: 0.0,
+            rarity: Rarity::Rare,
+            collider_shape: ColliderShape::Circle,
+        },
+        BuildingType::Catapult => BuildingStats {
+            hp: 180.0,
+            cost: 125,
+            color: palette::CATAPULT,
+            produced_unit: None,
+            production_interval: None,
+            income_interval: None,
+            firing_arc: Some(std::f32::consts::FRAC_PI_4),
+            slow_field: None,
+            attack: Some(BuildingAttack {
+                damage: 20.0,
+                attack_speed: 0.4,
+                range: 160.0,
+                splash_radius: 40.0,
+            }),
+            armor: 0.0,
+            rarity: Rarity::Rare,
+            collider_shape: ColliderShape::Rect,
+        },
+    }
+}
+
+/// Highest level a building can be upgraded to.
+pub const MAX_BUILDING_LEVEL: u8 = 3;
+
+/// Slow self-repair on every player building, so chip damage from stray
+/// projectiles doesn't permanently erode it. Starts 8s after the last damage.
+pub const BUILDING_REGEN: HealthRegen = HealthRegen {
+    per_sec: 2.0,
+    delay: 8.0,
+};
+
+/// Max HP gained per level above 1, as a fraction of base HP.
+const HP_GAIN_PER_LEVEL: f32 = 0.5;
+
+/// Production/income speedup per level above 1 (0.25 = 25% faster per level).
+const RATE_GAIN_PER_LEVEL: f32 = 0.25;
+
+/// Stats for a building type at `level` (1-based, capped at `MAX_BUILDING_LEVEL`).
+/// Each level above 1 adds `HP_GAIN_PER_LEVEL` of base HP and shortens production
+/// and income intervals by `RATE_GAIN_PER_LEVEL`. Level 1 matches `building_stats`.
+#[must_use]
+pub const fn building_stats_at_level(building_type: BuildingType, level: u8) -> BuildingStats {
+    let base = building_stats(building_type);
+    let level = if level > MAX_BUILDING_LEVEL {
+        MAX_BUILDING_LEVEL
+    } else {
+        level
+    };
+    let steps = level.saturating_sub(1) as f32;
+    let rate = 1.0 + RATE_GAIN_PER_LEVEL * steps;
+    BuildingStats {
+        hp: base.hp * (1.0 + HP_GAIN_PER_LEVEL * steps),
+        production_interval: match base.production_interval {
+            Some(interval) => Some(interval / rate),
+            None => None,
+        },
+        income_interval: match base.income_interval {
+            Some(interval) => Some(interval / rate),
+            None => None,
         },
+        ..base
+    }
+}
+
+/// Squad size of a producer upgraded to `MAX_BUILDING_LEVEL`.
+pub const PHALANX_SQUAD_SIZE: u32 = 3;
+
+/// `Phalanx` for a building at `level`: producers reaching `MAX_BUILDING_LEVEL`
+/// field `PHALANX_SQUAD_SIZE` squads. `None` for everything else.
+#[must_use]
+pub const fn phalanx_at_level(building_type: BuildingType, level: u8) -> Option<Phalanx> {
+    if level >= MAX_BUILDING_LEVEL && building_stats(building_type).produced_unit.is_some() {
+        Some(Phalanx {
+            squad_size: PHALANX_SQUAD_SIZE,
+        })
+    } else {
+        None
     }
 }
 
@@ -111,16 +385,73 @@ pub struct Occupied;
 #[reflect(Component)]
 pub struct GridCursor;
 
+/// Facing applied to the next placed building. Rotated with Q.
+#[derive(Resource, Default, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct PlacementFacing(pub Facing);
+
 /// Tracks which build-zone cell the mouse is currently over.
 #[derive(Resource, Default, Debug, Reflect)]
 #[reflect(Resource)]
 pub struct HoveredCell(pub Option<(u16, u16)>);
 
+/// Last build-zone cell the mouse hovered. Unlike `HoveredCell`, kept while the
+/// mouse is over the bottom bar, so quick-buy can target it.
+#[derive(Resource, Default, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct LastHoveredCell(pub Option<(u16, u16)>);
+
+/// Building whose production is concentrated: it produces `PRODUCTION_FOCUS_BOOST`
+/// times faster while every other producer holds. `None` = all produce normally.
+/// Toggled with P over a producer (see `placement::toggle_production_focus`).
+/// A focus on a building that no longer exists is ignored.
+#[derive(Resource, Default, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct ProductionFocus(pub Option<Entity>);
+
+/// Upgrade tier of a placed building, from 1 up to `MAX_BUILDING_LEVEL`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct BuildingLevel(pub u8);
+
+impl Default for BuildingLevel {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
 /// Production timer for buildings that spawn units (e.g., Barracks).
 #[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component)]
+#[require(UnitsProduced)]
 pub struct ProductionTimer(pub Timer);
 
+/// Units a producing building has spawned so far. Selling it refunds more the
+/// higher this is (see `sell_refund`).
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct UnitsProduced(pub u32);
+
+/// Optional: a producer with this spawns `squad_size` units at once, lined up in a
+/// column so they advance as a wall. Its timer ticks `squad_size` times slower, so
+/// overall unit output matches a regular producer. Granted by `phalanx_at_level`.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct Phalanx {
+    pub squad_size: u32,
+}
+
+/// Area around a building that slows opposing units while they are inside it.
+/// Units regain full speed as soon as they leave; overlapping fields don't stack.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct SlowField {
+    /// Radius (pixels) from the building center.
+    pub radius: f32,
+    /// Multiplier applied to a slowed unit's preferred velocity (0.0–1.0).
+    pub speed_multiplier: f32,
+}
+
 // === Helper Functions ===
 
 /// Convert a world position to build-zone grid coordinates.
@@ -195,30 +526,52 @@ fn strip_buildings_before_despawn(
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<Building>()
         .register_type::<BuildingType>()
+        .register_type::<Facing>()
+        .register_type::<PlacementFacing>()
         .register_type::<Occupied>()
         .register_type::<GridCursor>()
         .register_type::<HoveredCell>()
+        .register_type::<LastHoveredCell>()
+        .register_type::<ProductionFocus>()
         .register_type::<ProductionTimer>()
+        .register_type::<UnitsProduced>()
+        .register_type::<Phalanx>()
+        .register_type::<SlowField>()
         .register_type::<production::ProductionBarBackground>()
         .register_type::<production::ProductionBarFill>()
         .register_type::<production::ProductionBarConfig>()
-        .init_resource::<HoveredCell>();
+        .register_type::<production::ReadyPulseBase>()
+        .init_resource::<HoveredCell>()
+        .init_resource::<LastHoveredCell>()
+        .init_resource::<PlacementFacing>()
+        .init_resource::<ProductionFocus>();
 
     app.add_observer(clear_build_slot_on_building_removed);
     app.add_observer(production::spawn_production_bars);
+    app.add_observer(production::record_ready_pulse_base);
 
     // Strip Building markers before DespawnOnExit to prevent observer warnings.
     app.add_systems(OnExit(GameState::InGame), strip_buildings_before_despawn);
 
     app.add_systems(
         OnEnter(GameState::InGame),
-        placement::spawn_grid_cursor.after(BattlefieldSetup),
+        (
+            placement::spawn_grid_cursor.after(BattlefieldSetup),
+            placement::reset_placement_facing,
+            placement::reset_last_hovered_cell,
+            production::reset_production_focus,
+        ),
     )
     .add_systems(
         Update,
         (
             placement::update_grid_cursor,
+            placement::rotate_placement_facing,
             placement::handle_building_placement,
+            placement::sell_selected_building,
+            placement::upgrade_hovered_building,
+            placement::toggle_production_focus,
+            placement::handle_quick_buy.after(ShopInput),
         )
             .chain_ignore_deferred()
             .in_set(GameSet::Input)
@@ -232,7 +585,10 @@ pub(super) fn plugin(app: &mut App) {
     )
     .add_systems(
         Update,
-        production::update_production_bars
+        (
+            production::update_production_bars,
+            production::update_ready_pulse,
+        )
             .in_set(GameSet::Ui)
             .run_if(gameplay_running),
     );
@@ -327,16 +683,155 @@ mod tests {
         assert!(stats.income_interval.is_some());
     }
 
+    #[test]
+    fn slow_tower_stats() {
+        let stats = building_stats(BuildingType::SlowTower);
+        assert!(stats.hp > 0.0);
+        assert!(stats.cost > 0);
+        assert!(stats.produced_unit.is_none());
+        assert!(stats.income_interval.is_none());
+        let field = stats
+            .slow_field
+            .expect("Slow Tower should emit a slow field");
+        assert!(field.radius > 0.0);
+        assert!(field.speed_multiplier > 0.0 && field.speed_multiplier < 1.0);
+    }
+
+    #[test]
+    fn catapult_fires_splash_projectiles() {
+        let stats = building_stats(BuildingType::Catapult);
+        assert!(stats.produced_unit.is_none());
+        let attack = stats.attack.expect("Catapult should attack");
+        assert!(attack.damage > 0.0 && attack.attack_speed > 0.0 && attack.range > 0.0);
+        assert!(attack.splash_radius > 0.0);
+    }
+
     #[test]
     fn building_type_display_name() {
         assert_eq!(BuildingType::Barracks.display_name(), "Barracks");
         assert_eq!(BuildingType::Farm.display_name(), "Farm");
+        assert_eq!(BuildingType::SlowTower.display_name(), "Slow Tower");
+        assert_eq!(BuildingType::Catapult.display_name(), "Catapult");
+    }
+
+    #[test]
+    fn combo_partners_are_symmetric() {
+        for &building_type in BuildingType::ALL {
+            if let Some(partner) = building_type.combo_partner() {
+                assert_eq!(partner.combo_partner(), Some(building_type));
+            }
+        }
+        assert_eq!(BuildingType::SlowTower.combo_partner(), None);
+    }
+
+    #[test]
+    fn every_building_type_can_be_drawn_in_the_shop() {
+        for &building_type in BuildingType::ALL {
+            assert!(
+                building_stats(building_type).rarity.shop_weight() > 0,
+                "{building_type:?}"
+            );
+        }
     }
 
     #[test]
     fn building_type_all_contains_all_variants() {
         assert!(BuildingType::ALL.contains(&BuildingType::Barracks));
         assert!(BuildingType::ALL.contains(&BuildingType::Farm));
+        assert!(BuildingType::ALL.contains(&BuildingType::SlowTower));
+        assert!(BuildingType::ALL.contains(&BuildingType::Catapult));
+    }
+
+    // --- Facing / firing arc tests ---
+
+    #[test]
+    fn facing_default_points_toward_enemy() {
+        assert_eq!(Facing::default(), Facing::East);
+        assert_eq!(Facing::default().direction(), Vec2::X);
+    }
+
+    #[test]
+    fn facing_rotates_clockwise_full_cycle() {
+        let mut facing = Facing::East;
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            facing = facing.rotated_clockwise();
+            seen.push(facing);
+        }
+        assert_eq!(
+            seen,
+            vec![Facing::South, Facing::West, Facing::North, Facing::East]
+        );
+    }
+
+    #[test]
+    fn only_catapult_has_a_firing_arc() {
+        for &building_type in BuildingType::ALL {
+            assert_eq!(
+                building_stats(building_type)
+                    .firing_arc_for(Facing::North)
+                    .is_some(),
+                building_type == BuildingType::Catapult,
+                "{building_type:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn catapult_arc_follows_rotation() {
+        let stats = building_stats(BuildingType::Catapult);
+        for facing in [Facing::East, Facing::South, Facing::West, Facing::North] {
+            let arc = stats.firing_arc_for(facing).unwrap();
+            assert_eq!(arc.direction, facing.direction());
+            assert!(arc.contains(Vec2::ZERO, facing.direction() * 100.0));
+            assert!(!arc.contains(Vec2::ZERO, -facing.direction() * 100.0));
+        }
+    }
+
+    #[test]
+    fn firing_arc_aligns_with_facing() {
+        let stats = BuildingStats {
+            firing_arc: Some(std::f32::consts::FRAC_PI_4),
+            ..building_stats(BuildingType::Barracks)
+        };
+        let arc = stats.firing_arc_for(Facing::North).unwrap();
+        assert_eq!(arc.direction, Facing::North.direction());
+        assert!(arc.contains(Vec2::ZERO, Vec2::new(0.0, 100.0)));
+        assert!(!arc.contains(Vec2::ZERO, Vec2::new(100.0, 0.0)));
+    }
+
+    #[test]
+    fn level_one_stats_match_base_stats() {
+        for &building_type in BuildingType::ALL {
+            let base = building_stats(building_type);
+            let level_one = building_stats_at_level(building_type, 1);
+            assert_eq!(level_one.hp, base.hp);
+            assert_eq!(level_one.production_interval, base.production_interval);
+            assert_eq!(level_one.income_interval, base.income_interval);
+        }
+    }
+
+    #[test]
+    fn higher_levels_scale_hp_and_intervals() {
+        let base = building_stats(BuildingType::Barracks);
+        let level_two = building_stats_at_level(BuildingType::Barracks, 2);
+        assert!(level_two.hp > base.hp);
+        assert!(level_two.production_interval.unwrap() < base.production_interval.unwrap());
+        assert_eq!(level_two.cost, base.cost);
+    }
+
+    #[test]
+    fn levels_above_max_are_capped() {
+        let max = building_stats_at_level(BuildingType::Farm, MAX_BUILDING_LEVEL);
+        let beyond = building_stats_at_level(BuildingType::Farm, MAX_BUILDING_LEVEL + 5);
+        assert_eq!(beyond.hp, max.hp);
+        assert_eq!(beyond.income_interval, max.income_interval);
+    }
+
+    #[test]
+    fn barracks_is_armored_and_farm_is_not() {
+        assert!(building_stats(BuildingType::Barracks).armor > 0.0);
+        assert!(building_stats(BuildingType::Farm).armor.abs() < f32::EPSILON);
     }
 
     // --- building_color / building_hp delegate to building_stats ---
@@ -395,17 +890,11 @@ mod observer_tests {
             .insert(2, 3, slot);
 
         // Spawn a building at that grid position
-        let building = app
-            .world_mut()
-            .spawn((
-                Building {
-                    building_type: BuildingType::Barracks,
-                    grid_col: 2,
-                    grid_row: 3,
-                },
-                Health::new(building_stats(BuildingType::Barracks).hp),
-            ))
-            .id();
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 2, 3);
+        app.world_mut()
+            .entity_mut(building)
+            .insert(Health::new(building_stats(BuildingType::Barracks).hp));
 
         app.update();
 
@@ -424,17 +913,11 @@ mod observer_tests {
         let mut app = create_observer_test_app();
 
         // Spawn a building without a matching grid index entry
-        let building = app
-            .world_mut()
-            .spawn((
-                Building {
-                    building_type: BuildingType::Farm,
-                    grid_col: 0,
-                    grid_row: 0,
-                },
-                Health::new(building_stats(BuildingType::Farm).hp),
-            ))
-            .id();
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Farm, 0, 0);
+        app.world_mut()
+            .entity_mut(building)
+            .insert(Health::new(building_stats(BuildingType::Farm).hp));
 
         app.update();
         app.world_mut().despawn(building);
@@ -1,19 +1,33 @@
-//! Building placement systems: grid cursor spawning, hover tracking, click-to-place.
+//! Building placement systems: grid cursor spawning, hover tracking, click-to-place,
+//! selling, upgrades, and production focus.
+
+use std::time::Duration;
 
 use avian2d::prelude::*;
 use bevy::prelude::*;
 
 use super::{
     BUILDING_HEALTH_BAR_HEIGHT, BUILDING_HEALTH_BAR_WIDTH, BUILDING_HEALTH_BAR_Y_OFFSET,
-    BUILDING_SPRITE_SIZE, Building, CELL_SIZE, GridCursor, HoveredCell, Occupied, ProductionTimer,
-    building_color, building_hp, building_stats, world_to_build_grid,
+    BUILDING_REGEN, BUILDING_SPRITE_SIZE, Building, BuildingLevel, BuildingType, CELL_SIZE, Facing,
+    GridCursor, HoveredCell, LastHoveredCell, MAX_BUILDING_LEVEL, Occupied, PlacementFacing,
+    ProductionFocus, ProductionTimer, UnitsProduced, building_color, building_hp, building_stats,
+    building_stats_at_level, phalanx_at_level, world_to_build_grid,
 };
 use crate::gameplay::battlefield::{
-    BUILD_ZONE_START_COL, GridIndex, col_to_world_x, row_to_world_y,
+    BATTLEFIELD_ROWS, BUILD_ZONE_COLS, BUILD_ZONE_START_COL, GridIndex, col_to_world_x,
+    row_to_world_y,
 };
-use crate::gameplay::combat::HealthBarConfig;
-use crate::gameplay::{EntityExtent, Health, Target, Team};
-
+use crate::gameplay::combat::{AttackTimer, HealthBarConfig};
+use crate::gameplay::economy::income::IncomeTimer;
+use crate::gameplay::economy::shop::Shop;
+use crate::gameplay::economy::shop_ui::QuickBuyRequest;
+use crate::gameplay::economy::{
+    COMBO_REBATE, Gold, RunStats, scale_refund_by_health, sell_refund, upgrade_cost,
+};
+use crate::gameplay::{
+    CombatStats, Defense, Health, HealthChanged, Splash, Target, TargetingState, Team,
+};
+use crate::menus::PauseInputGuard;
 use crate::screens::GameState;
 use crate::third_party::{NavObstacle, solid_entity_layers};
 use crate::{Z_BUILDING, Z_GRID_CURSOR};
@@ -39,6 +53,7 @@ pub(super) fn update_grid_cursor(
     camera: Single<(&Camera, &GlobalTransform), With<Camera2d>>,
     mut cursor: Single<(&mut Transform, &mut Visibility), With<GridCursor>>,
     mut hovered: ResMut<HoveredCell>,
+    mut last_hovered: ResMut<LastHoveredCell>,
 ) {
     let (cursor_transform, cursor_visibility) = &mut *cursor;
     let (camera, camera_global) = *camera;
@@ -53,6 +68,9 @@ pub(super) fn update_grid_cursor(
         .and_then(world_to_build_grid);
 
     hovered.0 = grid_cell;
+    if grid_cell.is_some() {
+        last_hovered.0 = grid_cell;
+    }
 
     if let Some((col, row)) = grid_cell {
         // Position cursor sprite at the hovered cell
@@ -66,21 +84,50 @@ pub(super) fn update_grid_cursor(
     }
 }
 
+/// Resets the placement facing to its default on entering `InGame`.
+pub(super) fn reset_placement_facing(mut facing: ResMut<PlacementFacing>) {
+    *facing = PlacementFacing::default();
+}
+
+/// Forgets the last hovered cell on entering `InGame`.
+pub(super) fn reset_last_hovered_cell(mut last_hovered: ResMut<LastHoveredCell>) {
+    *last_hovered = LastHoveredCell::default();
+}
+
+/// Rotates the facing of the next building clockwise when Q is pressed.
+pub(super) fn rotate_placement_facing(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut facing: ResMut<PlacementFacing>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyQ) {
+        facing.0 = facing.0.rotated_clockwise();
+    }
+}
+
 /// Places a building when the player left-clicks an empty grid cell.
 pub(super) fn handle_building_placement(
     mut commands: Commands,
     mouse: Res<ButtonInput<MouseButton>>,
     hovered: Res<HoveredCell>,
+    facing: Res<PlacementFacing>,
     grid_index: Res<GridIndex>,
     occupied: Query<(), With<Occupied>>,
-    mut gold: ResMut<crate::gameplay::economy::Gold>,
-    mut shop: ResMut<crate::gameplay::economy::shop::Shop>,
+    buildings: Query<&Building>,
+    mut gold: ResMut<Gold>,
+    mut run_stats: ResMut<RunStats>,
+    mut shop: ResMut<Shop>,
     ui_buttons: Query<&Interaction, With<Button>>,
+    guard: Res<PauseInputGuard>,
 ) {
     if !mouse.just_pressed(MouseButton::Left) {
         return;
     }
 
+    // Skip clicks buffered while the pause menu was open
+    if guard.is_armed() {
+        return;
+    }
+
     // Skip if mouse is over any UI button (prevents click-through from shop panel)
     if ui_buttons.iter().any(|i| *i != Interaction::None) {
         return;
@@ -90,6 +137,225 @@ pub(super) fn handle_building_placement(
         return;
     };
 
+    place_selected_building(
+        &mut commands,
+        col,
+        row,
+        facing.0,
+        &grid_index,
+        &occupied,
+        &buildings,
+        &mut gold,
+        &mut run_stats,
+        &mut shop,
+    );
+}
+
+/// Sells the building under the cursor when the player right-clicks an occupied cell,
+/// refunding `sell_refund` gold for its `UnitsProduced`, scaled by its remaining
+/// HP fraction (rounded down). The `Occupied` marker is cleared by `clear_build_slot_on_building_removed` once
+/// the building despawns.
+pub(super) fn sell_selected_building(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    hovered: Res<HoveredCell>,
+    buildings: Query<(Entity, &Building, &Health, Option<&UnitsProduced>)>,
+    mut gold: ResMut<Gold>,
+    ui_buttons: Query<&Interaction, With<Button>>,
+    guard: Res<PauseInputGuard>,
+) {
+    if !mouse.just_pressed(MouseButton::Right) {
+        return;
+    }
+
+    // Skip clicks buffered while the pause menu was open
+    if guard.is_armed() {
+        return;
+    }
+
+    // Skip if mouse is over any UI button (prevents click-through from shop panel)
+    if ui_buttons.iter().any(|i| *i != Interaction::None) {
+        return;
+    }
+
+    let Some((col, row)) = hovered.0 else {
+        return;
+    };
+    let Some((entity, building, health, produced)) = buildings
+        .iter()
+        .find(|(_, building, ..)| building.grid_col == col && building.grid_row == row)
+    else {
+        return;
+    };
+
+    let produced = produced.map_or(0, |produced| produced.0);
+    gold.0 += scale_refund_by_health(sell_refund(building.building_type, produced), health);
+    commands.entity(entity).despawn();
+}
+
+/// Upgrades the building under the cursor one level when U is pressed, if gold
+/// allows and it is below `MAX_BUILDING_LEVEL`. Max HP, current HP, and timer
+/// intervals follow `building_stats_at_level`; producers reaching the top level
+/// become a `Phalanx` (see `phalanx_at_level`).
+pub(super) fn upgrade_hovered_building(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    hovered: Res<HoveredCell>,
+    mut buildings: Query<(
+        Entity,
+        &Building,
+        &mut BuildingLevel,
+        &mut Health,
+        Option<&mut ProductionTimer>,
+        Option<&mut IncomeTimer>,
+    )>,
+    mut gold: ResMut<Gold>,
+    mut run_stats: ResMut<RunStats>,
+    mut health_changes: MessageWriter<HealthChanged>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyU) {
+        return;
+    }
+    let Some((col, row)) = hovered.0 else {
+        return;
+    };
+    let Some((entity, building, mut level, mut health, production, income)) = buildings
+        .iter_mut()
+        .find(|(_, building, ..)| building.grid_col == col && building.grid_row == row)
+    else {
+        return;
+    };
+    if level.0 >= MAX_BUILDING_LEVEL {
+        return;
+    }
+    let cost = upgrade_cost(building.building_type, level.0);
+    if gold.0 < cost {
+        return;
+    }
+
+    gold.0 -= cost;
+    run_stats.record_spend(cost);
+    level.0 += 1;
+
+    let stats = building_stats_at_level(building.building_type, level.0);
+    if stats.produced_unit.is_some() {
+        run_stats.record_production_spend(cost);
+    }
+    // Keep damage taken so far; the upgrade only raises the ceiling
+    let gained = stats.hp - health.max;
+    health.max = stats.hp;
+    let new = health.current + gained;
+    health.set_current(entity, new, &mut health_changes);
+    if let (Some(mut timer), Some(interval)) = (production, stats.production_interval) {
+        timer.0.set_duration(Duration::from_secs_f32(interval));
+    }
+    if let (Some(mut timer), Some(interval)) = (income, stats.income_interval) {
+        timer.0.set_duration(Duration::from_secs_f32(interval));
+    }
+    if let Some(phalanx) = phalanx_at_level(building.building_type, level.0) {
+        commands.entity(entity).insert(phalanx);
+    }
+}
+
+/// Toggles `ProductionFocus` on the producer under the cursor when P is pressed:
+/// focuses it, or clears the focus if it is already the focused building.
+pub(super) fn toggle_production_focus(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    hovered: Res<HoveredCell>,
+    producers: Query<(Entity, &Building), With<ProductionTimer>>,
+    mut focus: ResMut<ProductionFocus>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyP) {
+        return;
+    }
+    let Some((col, row)) = hovered.0 else {
+        return;
+    };
+    let Some((entity, _)) = producers
+        .iter()
+        .find(|(_, building)| building.grid_col == col && building.grid_row == row)
+    else {
+        return;
+    };
+    focus.0 = if focus.0 == Some(entity) {
+        None
+    } else {
+        Some(entity)
+    };
+}
+
+/// Places a double-clicked card right away: at the last hovered cell if it is
+/// free, otherwise at the first free cell (column by column from the fortress).
+pub(super) fn handle_quick_buy(
+    mut commands: Commands,
+    mut request: ResMut<QuickBuyRequest>,
+    last_hovered: Res<LastHoveredCell>,
+    facing: Res<PlacementFacing>,
+    grid_index: Res<GridIndex>,
+    occupied: Query<(), With<Occupied>>,
+    buildings: Query<&Building>,
+    mut gold: ResMut<Gold>,
+    mut run_stats: ResMut<RunStats>,
+    mut shop: ResMut<Shop>,
+) {
+    let Some(slot) = request.0.take() else {
+        return;
+    };
+    if shop.cards.get(slot).is_none_or(Option::is_none) {
+        return;
+    }
+    shop.selected = Some(slot);
+
+    let is_free = |(col, row): (u16, u16)| {
+        grid_index
+            .get(col, row)
+            .is_some_and(|slot_entity| !occupied.contains(slot_entity))
+            && !building_at(buildings.iter(), col, row)
+    };
+    let Some((col, row)) = last_hovered.0.filter(|&cell| is_free(cell)).or_else(|| {
+        (0..BUILD_ZONE_COLS)
+            .flat_map(|col| (0..BATTLEFIELD_ROWS).map(move |row| (col, row)))
+            .find(|&cell| is_free(cell))
+    }) else {
+        return;
+    };
+
+    place_selected_building(
+        &mut commands,
+        col,
+        row,
+        facing.0,
+        &grid_index,
+        &occupied,
+        &buildings,
+        &mut gold,
+        &mut run_stats,
+        &mut shop,
+    );
+}
+
+/// Whether any of `buildings` sits on grid cell (`col`, `row`).
+fn building_at<'a>(buildings: impl IntoIterator<Item = &'a Building>, col: u16, row: u16) -> bool {
+    buildings
+        .into_iter()
+        .any(|building| building.grid_col == col && building.grid_row == row)
+}
+
+/// Buys the selected card and spawns its building at (`col`, `row`).
+/// Does nothing if the cell is occupied, no card is selected, or gold is short.
+/// Rebates `COMBO_REBATE` gold if the building lands next to its combo partner.
+fn place_selected_building(
+    commands: &mut Commands,
+    col: u16,
+    row: u16,
+    facing: Facing,
+    grid_index: &GridIndex,
+    occupied: &Query<(), With<Occupied>>,
+    buildings: &Query<&Building>,
+    gold: &mut Gold,
+    run_stats: &mut RunStats,
+    shop: &mut Shop,
+) {
     // O(1) lookup via GridIndex
     let Some(slot_entity) = grid_index.get(col, row) else {
         return;
@@ -100,6 +366,13 @@ pub(super) fn handle_building_placement(
         return;
     }
 
+    // Defensive: a building on the cell without an `Occupied` marker means the
+    // markers desynced. Restore the marker and refuse the placement.
+    if building_at(buildings.iter(), col, row) {
+        commands.entity(slot_entity).insert(Occupied);
+        return;
+    }
+
     // Get selected building from shop
     let Some(building_type) = shop.selected_building() else {
         return; // No card selected
@@ -113,12 +386,32 @@ pub(super) fn handle_building_placement(
 
     // Deduct gold and remove card from shop
     gold.0 -= stats.cost;
+    run_stats.record_spend(stats.cost);
+    if stats.produced_unit.is_some() {
+        run_stats.record_production_spend(stats.cost);
+    }
     shop.remove_selected();
 
+    if has_combo_neighbor(building_type, col, row, grid_index, occupied, buildings) {
+        gold.0 += COMBO_REBATE;
+    }
+
     // Mark slot as occupied
     commands.entity(slot_entity).insert(Occupied);
 
-    // Spawn the building entity
+    spawn_building(commands, building_type, col, row, facing);
+}
+
+/// Spawns a player building of `building_type` at build-grid (`col`, `row`) with
+/// its full component set. Does not touch gold, the shop, or `Occupied` markers.
+pub fn spawn_building(
+    commands: &mut Commands,
+    building_type: BuildingType,
+    col: u16,
+    row: u16,
+    facing: Facing,
+) -> Entity {
+    let stats = building_stats(building_type);
     let world_x = col_to_world_x(BUILD_ZONE_START_COL + col);
     let world_y = row_to_world_y(row);
 
@@ -128,10 +421,13 @@ pub(super) fn handle_building_placement(
             building_type,
             grid_col: col,
             grid_row: row,
+            facing,
+            next_lane: row,
         },
+        BuildingLevel::default(),
         Team::Player,
         Target,
-        Health::new(building_hp(building_type)),
+        (Health::new(building_hp(building_type)), BUILDING_REGEN),
         HealthBarConfig {
             width: BUILDING_HEALTH_BAR_WIDTH,
             height: BUILDING_HEALTH_BAR_HEIGHT,
@@ -143,11 +439,11 @@ pub(super) fn handle_building_placement(
         ),
         Transform::from_xyz(world_x, world_y, Z_BUILDING),
         DespawnOnExit(GameState::InGame),
-        EntityExtent::Rect(BUILDING_SPRITE_SIZE / 2.0, BUILDING_SPRITE_SIZE / 2.0),
+        stats.collider_shape.extent(),
         NavObstacle,
         // Physics
         RigidBody::Static,
-        Collider::rectangle(BUILDING_SPRITE_SIZE, BUILDING_SPRITE_SIZE),
+        stats.collider_shape.collider(),
         solid_entity_layers(),
     ));
 
@@ -163,15 +459,74 @@ pub(super) fn handle_building_placement(
         ));
     }
     if let Some(interval) = stats.income_interval {
-        entity_commands.insert(crate::gameplay::economy::income::IncomeTimer(
-            Timer::from_seconds(interval, TimerMode::Repeating),
+        entity_commands.insert(IncomeTimer(Timer::from_seconds(
+            interval,
+            TimerMode::Repeating,
+        )));
+    }
+    if let Some(arc) = stats.firing_arc_for(facing) {
+        entity_commands.insert(arc);
+    }
+    if let Some(slow_field) = stats.slow_field {
+        entity_commands.insert(slow_field);
+    }
+    if let Some(attack) = stats.attack {
+        entity_commands.insert((
+            CombatStats {
+                damage: attack.damage,
+                attack_speed: attack.attack_speed,
+                range: attack.range,
+            },
+            AttackTimer(Timer::from_seconds(
+                1.0 / attack.attack_speed,
+                TimerMode::Repeating,
+            )),
+            TargetingState::Seeking,
         ));
+        if attack.splash_radius > 0.0 {
+            entity_commands.insert(Splash(attack.splash_radius));
+        }
     }
+    if stats.armor > 0.0 {
+        entity_commands.insert(Defense { armor: stats.armor });
+    }
+    entity_commands.id()
+}
+
+/// Whether an orthogonal neighbor of (`col`, `row`) holds `building_type`'s combo partner.
+fn has_combo_neighbor(
+    building_type: BuildingType,
+    col: u16,
+    row: u16,
+    grid_index: &GridIndex,
+    occupied: &Query<(), With<Occupied>>,
+    buildings: &Query<&Building>,
+) -> bool {
+    let Some(partner) = building_type.combo_partner() else {
+        return false;
+    };
+    let neighbors = [
+        col.checked_sub(1).map(|c| (c, row)),
+        Some((col + 1, row)),
+        row.checked_sub(1).map(|r| (col, r)),
+        Some((col, row + 1)),
+    ];
+    neighbors.into_iter().flatten().any(|(n_col, n_row)| {
+        grid_index
+            .get(n_col, n_row)
+            .is_some_and(|slot_entity| occupied.contains(slot_entity))
+            && buildings.iter().any(|building| {
+                building.building_type == partner
+                    && building.grid_col == n_col
+                    && building.grid_row == n_row
+            })
+    })
 }
 
 #[cfg(test)]
 mod integration_tests {
     use super::*;
+    use crate::gameplay::EntityExtent;
     use crate::gameplay::battlefield::BuildSlot;
     use crate::gameplay::building::BuildingType;
     use crate::menus::Menu;
@@ -243,11 +598,14 @@ mod integration_tests {
         app.add_plugins(crate::gameplay::battlefield::plugin);
         app.register_type::<Building>()
             .register_type::<Occupied>()
-            .init_resource::<HoveredCell>();
+            .init_resource::<HoveredCell>()
+            .init_resource::<PlacementFacing>();
         crate::testing::init_economy_resources(&mut app);
         app.add_systems(
             Update,
-            handle_building_placement.run_if(in_state(GameState::InGame).and(in_state(Menu::None))),
+            (rotate_placement_facing, handle_building_placement)
+                .chain()
+                .run_if(in_state(GameState::InGame).and(in_state(Menu::None))),
         );
         crate::testing::transition_to_ingame(&mut app);
 
@@ -259,6 +617,61 @@ mod integration_tests {
         app
     }
 
+    /// Helper: app with only `handle_quick_buy`, a Barracks in card slot 0, nothing selected.
+    fn create_quick_buy_test_app() -> App {
+        use crate::gameplay::economy::shop::Shop;
+
+        let mut app = crate::testing::create_base_test_app_no_input();
+        app.add_plugins(crate::gameplay::battlefield::plugin);
+        app.init_resource::<LastHoveredCell>()
+            .init_resource::<PlacementFacing>();
+        crate::testing::init_economy_resources(&mut app);
+        app.add_systems(Update, handle_quick_buy.run_if(in_state(GameState::InGame)));
+        crate::testing::transition_to_ingame(&mut app);
+
+        app.world_mut().resource_mut::<Shop>().cards[0] = Some(BuildingType::Barracks);
+        app
+    }
+
+    fn placed_cells(app: &mut App) -> Vec<(u16, u16)> {
+        let mut query = app.world_mut().query::<&Building>();
+        query
+            .iter(app.world())
+            .map(|building| (building.grid_col, building.grid_row))
+            .collect()
+    }
+
+    #[test]
+    fn quick_buy_places_at_last_hovered_cell() {
+        let mut app = create_quick_buy_test_app();
+        app.world_mut().resource_mut::<LastHoveredCell>().0 = Some((4, 7));
+        app.world_mut().resource_mut::<QuickBuyRequest>().0 = Some(0);
+        app.update();
+
+        assert_eq!(placed_cells(&mut app), vec![(4, 7)]);
+        assert_eq!(app.world().resource::<QuickBuyRequest>().0, None);
+    }
+
+    #[test]
+    fn quick_buy_falls_back_to_first_free_cell() {
+        let mut app = create_quick_buy_test_app();
+        let occupied_slot = app.world().resource::<GridIndex>().get(0, 0).unwrap();
+        app.world_mut().entity_mut(occupied_slot).insert(Occupied);
+        app.world_mut().resource_mut::<QuickBuyRequest>().0 = Some(0);
+        app.update();
+
+        assert_eq!(placed_cells(&mut app), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn quick_buy_of_empty_slot_does_nothing() {
+        let mut app = create_quick_buy_test_app();
+        app.world_mut().resource_mut::<QuickBuyRequest>().0 = Some(2);
+        app.update();
+
+        assert_entity_count::<With<Building>>(&mut app, 0);
+    }
+
     #[test]
     fn clicking_empty_cell_places_building() {
         let mut app = create_placement_test_app();
@@ -318,6 +731,42 @@ mod integration_tests {
         assert_entity_count::<With<Building>>(&mut app, 1); // Still just one
     }
 
+    /// Spawn a bare `Building` at (`col`, `row`) without marking its slot `Occupied`,
+    /// as if the marker had desynced.
+    fn spawn_unmarked_building(app: &mut App, col: u16, row: u16) {
+        crate::testing::spawn_test_building(app.world_mut(), BuildingType::Farm, col, row);
+    }
+
+    #[test]
+    fn building_without_occupied_marker_still_blocks_placement() {
+        let mut app = create_placement_test_app();
+        spawn_unmarked_building(&mut app, 3, 5);
+        let gold_before = app.world().resource::<Gold>().0;
+
+        app.world_mut().resource_mut::<HoveredCell>().0 = Some((3, 5));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+
+        assert_eq!(placed_cells(&mut app), vec![(3, 5)]);
+        assert_eq!(app.world().resource::<Gold>().0, gold_before);
+        // The missing marker is restored
+        assert_entity_count::<(With<BuildSlot>, With<Occupied>)>(&mut app, 1);
+    }
+
+    #[test]
+    fn quick_buy_skips_cell_with_unmarked_building() {
+        let mut app = create_quick_buy_test_app();
+        spawn_unmarked_building(&mut app, 0, 0);
+        app.world_mut().resource_mut::<QuickBuyRequest>().0 = Some(0);
+        app.update();
+
+        let mut cells = placed_cells(&mut app);
+        cells.sort_unstable();
+        assert_eq!(cells, vec![(0, 0), (0, 1)]);
+    }
+
     #[test]
     fn clicking_with_no_hovered_cell_does_nothing() {
         let mut app = create_placement_test_app();
@@ -377,6 +826,19 @@ mod integration_tests {
             crate::gameplay::economy::STARTING_GOLD
                 - crate::gameplay::building::building_stats(BuildingType::Barracks).cost
         );
+        assert_eq!(
+            app.world()
+                .resource::<crate::gameplay::economy::RunStats>()
+                .gold_spent,
+            crate::gameplay::building::building_stats(BuildingType::Barracks).cost
+        );
+        // Barracks produce units, so their cost counts toward the trade ratio
+        assert_eq!(
+            app.world()
+                .resource::<crate::gameplay::economy::RunStats>()
+                .production_spent,
+            crate::gameplay::building::building_stats(BuildingType::Barracks).cost
+        );
         assert_entity_count::<With<Building>>(&mut app, 1);
     }
 
@@ -423,6 +885,325 @@ mod integration_tests {
         assert_entity_count::<With<Building>>(&mut app, 0);
     }
 
+    // === Combo Tests ===
+
+    /// Places a Farm at (`col`, `row`) in a fresh placement app, next to a Barracks
+    /// already standing at (2, 3).
+    fn place_farm_with_barracks_at_2_3(col: u16, row: u16) -> App {
+        use crate::gameplay::economy::shop::Shop;
+
+        let mut app = create_placement_test_app();
+        let slot = app.world().resource::<GridIndex>().get(2, 3).unwrap();
+        app.world_mut().entity_mut(slot).insert(Occupied);
+        crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 2, 3);
+        app.world_mut().resource_mut::<Shop>().cards[0] = Some(BuildingType::Farm);
+
+        app.world_mut().resource_mut::<HoveredCell>().0 = Some((col, row));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+        app
+    }
+
+    #[test]
+    fn farm_next_to_barracks_grants_combo_rebate() {
+        use crate::gameplay::economy::{COMBO_REBATE, Gold, STARTING_GOLD};
+
+        let mut app = place_farm_with_barracks_at_2_3(2, 4);
+
+        assert_entity_count::<With<Building>>(&mut app, 2);
+        assert_eq!(
+            app.world().resource::<Gold>().0,
+            STARTING_GOLD - building_stats(BuildingType::Farm).cost + COMBO_REBATE
+        );
+    }
+
+    #[test]
+    fn isolated_farm_grants_no_combo_rebate() {
+        use crate::gameplay::economy::{Gold, STARTING_GOLD};
+
+        let mut app = place_farm_with_barracks_at_2_3(5, 9);
+
+        assert_entity_count::<With<Building>>(&mut app, 2);
+        assert_eq!(
+            app.world().resource::<Gold>().0,
+            STARTING_GOLD - building_stats(BuildingType::Farm).cost
+        );
+    }
+
+    #[test]
+    fn diagonal_barracks_grants_no_combo_rebate() {
+        use crate::gameplay::economy::{Gold, STARTING_GOLD};
+
+        let mut app = place_farm_with_barracks_at_2_3(3, 4);
+
+        assert_eq!(
+            app.world().resource::<Gold>().0,
+            STARTING_GOLD - building_stats(BuildingType::Farm).cost
+        );
+    }
+
+    // === Upgrade Tests ===
+
+    /// Placement app with `upgrade_hovered_building`, a Barracks placed at (2, 3),
+    /// and `gold` in the bank. Returns the app and the building.
+    fn create_upgrade_test_app(gold: u32) -> (App, Entity) {
+        use crate::gameplay::economy::Gold;
+
+        let mut app = create_placement_test_app();
+        app.add_systems(Update, upgrade_hovered_building);
+        app.world_mut().resource_mut::<HoveredCell>().0 = Some((2, 3));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .clear();
+
+        app.world_mut().resource_mut::<Gold>().0 = gold;
+        let mut query = app.world_mut().query_filtered::<Entity, With<Building>>();
+        let building = query.single(app.world()).unwrap();
+        (app, building)
+    }
+
+    fn press_upgrade(app: &mut App) {
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(KeyCode::KeyU);
+        keyboard.clear();
+        keyboard.press(KeyCode::KeyU);
+        app.update();
+    }
+
+    #[test]
+    fn placed_building_starts_at_level_one() {
+        let (app, building) = create_upgrade_test_app(0);
+        assert_eq!(
+            *app.world().get::<BuildingLevel>(building).unwrap(),
+            BuildingLevel(1)
+        );
+    }
+
+    #[test]
+    fn upgrade_raises_hp_and_shortens_production_interval() {
+        use crate::gameplay::economy::{Gold, upgrade_cost};
+
+        let (mut app, building) = create_upgrade_test_app(500);
+        press_upgrade(&mut app);
+
+        let upgraded = building_stats_at_level(BuildingType::Barracks, 2);
+        let world = app.world();
+        assert_eq!(
+            *world.get::<BuildingLevel>(building).unwrap(),
+            BuildingLevel(2)
+        );
+        let health = world.get::<Health>(building).unwrap();
+        assert_eq!(health.max, upgraded.hp);
+        assert_eq!(health.current, upgraded.hp);
+        assert!(upgraded.hp > building_hp(BuildingType::Barracks));
+        let timer = world.get::<ProductionTimer>(building).unwrap();
+        assert_eq!(
+            timer.0.duration(),
+            Duration::from_secs_f32(upgraded.production_interval.unwrap())
+        );
+        assert_eq!(
+            world.resource::<Gold>().0,
+            500 - upgrade_cost(BuildingType::Barracks, 1)
+        );
+    }
+
+    #[test]
+    fn upgrade_keeps_damage_taken() {
+        let (mut app, building) = create_upgrade_test_app(500);
+        app.world_mut().get_mut::<Health>(building).unwrap().current -= 100.0;
+        press_upgrade(&mut app);
+
+        let health = app.world().get::<Health>(building).unwrap();
+        assert_eq!(health.current, health.max - 100.0);
+    }
+
+    #[test]
+    fn upgrade_blocked_with_insufficient_gold() {
+        use crate::gameplay::economy::{Gold, upgrade_cost};
+
+        let short = upgrade_cost(BuildingType::Barracks, 1) - 1;
+        let (mut app, building) = create_upgrade_test_app(short);
+        press_upgrade(&mut app);
+
+        assert_eq!(
+            *app.world().get::<BuildingLevel>(building).unwrap(),
+            BuildingLevel(1)
+        );
+        assert_eq!(
+            app.world().get::<Health>(building).unwrap().max,
+            building_hp(BuildingType::Barracks)
+        );
+        assert_eq!(app.world().resource::<Gold>().0, short);
+    }
+
+    #[test]
+    fn upgrade_stops_at_max_level() {
+        let (mut app, building) = create_upgrade_test_app(10_000);
+        for _ in 0..MAX_BUILDING_LEVEL + 1 {
+            press_upgrade(&mut app);
+        }
+
+        assert_eq!(
+            *app.world().get::<BuildingLevel>(building).unwrap(),
+            BuildingLevel(MAX_BUILDING_LEVEL)
+        );
+    }
+
+    #[test]
+    fn barracks_becomes_phalanx_at_max_level() {
+        use super::super::{PHALANX_SQUAD_SIZE, Phalanx};
+
+        let (mut app, building) = create_upgrade_test_app(10_000);
+        for _ in 1..MAX_BUILDING_LEVEL - 1 {
+            press_upgrade(&mut app);
+        }
+        assert!(app.world().get::<Phalanx>(building).is_none());
+
+        press_upgrade(&mut app);
+        assert_eq!(
+            app.world().get::<Phalanx>(building).unwrap().squad_size,
+            PHALANX_SQUAD_SIZE
+        );
+    }
+
+    // === Production Focus Tests ===
+
+    fn press_focus(app: &mut App) {
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(KeyCode::KeyP);
+        keyboard.clear();
+        keyboard.press(KeyCode::KeyP);
+        app.update();
+    }
+
+    #[test]
+    fn focus_key_toggles_focus_on_hovered_producer() {
+        let (mut app, building) = create_upgrade_test_app(0);
+        app.init_resource::<ProductionFocus>();
+        app.add_systems(Update, toggle_production_focus);
+
+        press_focus(&mut app);
+        assert_eq!(app.world().resource::<ProductionFocus>().0, Some(building));
+
+        press_focus(&mut app);
+        assert_eq!(app.world().resource::<ProductionFocus>().0, None);
+    }
+
+    #[test]
+    fn focus_key_ignores_empty_cell() {
+        let (mut app, _) = create_upgrade_test_app(0);
+        app.init_resource::<ProductionFocus>();
+        app.add_systems(Update, toggle_production_focus);
+        app.world_mut().resource_mut::<HoveredCell>().0 = Some((4, 4));
+
+        press_focus(&mut app);
+        assert_eq!(app.world().resource::<ProductionFocus>().0, None);
+    }
+
+    // === Sell Tests ===
+
+    /// Placement app with `sell_selected_building` and the slot-clearing observer,
+    /// plus a full-HP Barracks standing on (2, 3).
+    fn create_sell_test_app() -> App {
+        let mut app = create_placement_test_app();
+        app.add_systems(Update, sell_selected_building);
+        app.add_observer(super::super::clear_build_slot_on_building_removed);
+
+        let slot = app.world().resource::<GridIndex>().get(2, 3).unwrap();
+        app.world_mut().entity_mut(slot).insert(Occupied);
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 2, 3);
+        app.world_mut()
+            .entity_mut(building)
+            .insert(Health::new(building_hp(BuildingType::Barracks)));
+        app
+    }
+
+    fn right_click_cell(app: &mut App, col: u16, row: u16) {
+        app.world_mut().resource_mut::<HoveredCell>().0 = Some((col, row));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Right);
+        app.update();
+    }
+
+    #[test]
+    fn selling_barracks_refunds_half_cost() {
+        use crate::gameplay::economy::{Gold, STARTING_GOLD};
+
+        let mut app = create_sell_test_app();
+        right_click_cell(&mut app, 2, 3);
+
+        assert_eq!(app.world().resource::<Gold>().0, STARTING_GOLD + 50);
+        assert_entity_count::<With<Building>>(&mut app, 0);
+    }
+
+    #[test]
+    fn selling_half_health_barracks_refunds_half_of_base() {
+        use crate::gameplay::economy::{Gold, STARTING_GOLD};
+
+        let mut app = create_sell_test_app();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&mut Health, With<Building>>();
+        let mut health = query.single_mut(app.world_mut()).unwrap();
+        health.current = health.max / 2.0;
+        right_click_cell(&mut app, 2, 3);
+
+        assert_eq!(app.world().resource::<Gold>().0, STARTING_GOLD + 25);
+    }
+
+    #[test]
+    fn selling_veteran_barracks_refunds_more_than_fresh() {
+        use crate::gameplay::economy::{Gold, STARTING_GOLD};
+
+        let mut fresh = create_sell_test_app();
+        right_click_cell(&mut fresh, 2, 3);
+        let fresh_refund = fresh.world().resource::<Gold>().0 - STARTING_GOLD;
+
+        let mut veteran = create_sell_test_app();
+        let mut query = veteran
+            .world_mut()
+            .query_filtered::<Entity, With<Building>>();
+        let barracks = query.single(veteran.world()).unwrap();
+        veteran
+            .world_mut()
+            .entity_mut(barracks)
+            .insert(UnitsProduced(4));
+        right_click_cell(&mut veteran, 2, 3);
+        let veteran_refund = veteran.world().resource::<Gold>().0 - STARTING_GOLD;
+
+        assert!(
+            veteran_refund > fresh_refund,
+            "veteran {veteran_refund} should beat fresh {fresh_refund}"
+        );
+    }
+
+    #[test]
+    fn selling_building_frees_its_slot() {
+        let mut app = create_sell_test_app();
+        right_click_cell(&mut app, 2, 3);
+
+        assert_entity_count::<With<Occupied>>(&mut app, 0);
+    }
+
+    #[test]
+    fn right_clicking_empty_cell_refunds_nothing() {
+        use crate::gameplay::economy::{Gold, STARTING_GOLD};
+
+        let mut app = create_sell_test_app();
+        right_click_cell(&mut app, 5, 5);
+
+        assert_eq!(app.world().resource::<Gold>().0, STARTING_GOLD);
+        assert_entity_count::<With<Building>>(&mut app, 1);
+    }
+
     // === Building Health Tests (GAM-21) ===
 
     #[test]
@@ -458,4 +1239,171 @@ mod integration_tests {
 
         assert_entity_count::<(With<Building>, With<HealthBarConfig>)>(&mut app, 1);
     }
+
+    #[test]
+    fn placed_building_regenerates_health() {
+        use crate::gameplay::combat::{HealthRegen, LastDamagedAt};
+
+        let mut app = create_placement_test_app();
+
+        app.world_mut().resource_mut::<HoveredCell>().0 = Some((2, 3));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+
+        assert_entity_count::<(With<Building>, With<HealthRegen>, With<LastDamagedAt>)>(
+            &mut app, 1,
+        );
+    }
+
+    // === Facing Tests ===
+
+    #[test]
+    fn placed_building_defaults_to_east_facing() {
+        use crate::gameplay::building::Facing;
+
+        let mut app = create_placement_test_app();
+
+        app.world_mut().resource_mut::<HoveredCell>().0 = Some((2, 3));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+
+        let mut query = app.world_mut().query::<&Building>();
+        let building = query.single(app.world()).unwrap();
+        assert_eq!(building.facing, Facing::East);
+    }
+
+    #[test]
+    fn rotating_before_placement_stores_chosen_facing() {
+        use crate::gameplay::building::Facing;
+
+        let mut app = create_placement_test_app();
+
+        // Rotate once: East → South
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyQ);
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KeyCode::KeyQ);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+        assert_eq!(app.world().resource::<PlacementFacing>().0, Facing::South);
+
+        app.world_mut().resource_mut::<HoveredCell>().0 = Some((2, 3));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+
+        let mut query = app.world_mut().query::<&Building>();
+        let building = query.single(app.world()).unwrap();
+        assert_eq!(building.facing, Facing::South);
+    }
+
+    #[test]
+    fn non_directional_building_has_no_firing_arc() {
+        use crate::gameplay::FiringArc;
+
+        let mut app = create_placement_test_app();
+
+        app.world_mut().resource_mut::<HoveredCell>().0 = Some((2, 3));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+
+        assert_entity_count::<(With<Building>, With<FiringArc>)>(&mut app, 0);
+    }
+
+    #[test]
+    fn rotated_catapult_gets_firing_arc_for_its_facing() {
+        use crate::gameplay::FiringArc;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        let world = app.world_mut();
+        let catapult = spawn_building(
+            &mut world.commands(),
+            BuildingType::Catapult,
+            0,
+            0,
+            Facing::North,
+        );
+        world.flush();
+
+        let arc = world.get::<FiringArc>(catapult).unwrap();
+        assert_eq!(arc.direction, Facing::North.direction());
+    }
+
+    // === Pause Input Guard Tests ===
+
+    #[test]
+    fn click_ignored_while_pause_guard_armed() {
+        let mut app = create_placement_test_app();
+        app.insert_resource(PauseInputGuard::armed());
+
+        // A click buffered during the pause lands on the unpause frame
+        app.world_mut().resource_mut::<HoveredCell>().0 = Some((2, 3));
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        app.update();
+        assert_entity_count::<With<Building>>(&mut app, 0);
+
+        // Once the guard is released the same click places the building
+        app.insert_resource(PauseInputGuard::default());
+        app.update();
+
+        assert_entity_count::<With<Building>>(&mut app, 1);
+    }
+
+    // === Collider shape Tests ===
+
+    /// Spawns `building_type` at (0, 0) and returns its collider and extent.
+    fn spawned_shape(building_type: BuildingType) -> (Collider, EntityExtent) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        let world = app.world_mut();
+        let building = spawn_building(&mut world.commands(), building_type, 0, 0, Facing::East);
+        world.flush();
+        let entity = world.entity(building);
+        (
+            entity.get::<Collider>().unwrap().clone(),
+            *entity.get::<EntityExtent>().unwrap(),
+        )
+    }
+
+    #[test]
+    fn circular_building_spawns_with_circle_collider() {
+        use crate::gameplay::building::{ColliderShape, building_stats};
+
+        assert_eq!(
+            building_stats(BuildingType::SlowTower).collider_shape,
+            ColliderShape::Circle
+        );
+        let (collider, extent) = spawned_shape(BuildingType::SlowTower);
+
+        let ball = collider.shape().as_ball().expect("circle collider");
+        assert_eq!(ball.radius, BUILDING_SPRITE_SIZE / 2.0);
+        assert_eq!(extent, EntityExtent::Circle(BUILDING_SPRITE_SIZE / 2.0));
+    }
+
+    #[test]
+    fn rect_building_spawns_with_sprite_sized_collider() {
+        let (collider, extent) = spawned_shape(BuildingType::Barracks);
+
+        let cuboid = collider.shape().as_cuboid().expect("rectangle collider");
+        assert_eq!(cuboid.half_extents.x, BUILDING_SPRITE_SIZE / 2.0);
+        assert_eq!(cuboid.half_extents.y, BUILDING_SPRITE_SIZE / 2.0);
+        assert_eq!(
+            extent,
+            EntityExtent::Rect(BUILDING_SPRITE_SIZE / 2.0, BUILDING_SPRITE_SIZE / 2.0)
+        );
+    }
 }
@@ -1,17 +1,35 @@
-//! Building production: timer ticking, unit spawning, and production progress bars.
+//! Building production: timer ticking, unit spawning, production progress bars,
+//! and the "ready" pulse before a spawn.
 
 use bevy::prelude::*;
 use vleue_navigator::prelude::*;
 
-use super::ProductionTimer;
+use super::{Phalanx, ProductionFocus, ProductionTimer, UnitsProduced};
 use crate::gameplay::building::building_stats;
-use crate::gameplay::units::{UnitAssets, random_navigable_spawn, spawn_unit};
+use crate::gameplay::economy::RunStats;
+use crate::gameplay::units::{
+    AssignedLane, SpawnIdCounter, UnitAssets, spawn_unit, try_navigable_spawn,
+};
+use crate::gameplay::{GameRng, RECALL_RADIUS, Recall};
+use crate::settings::Settings;
 use crate::theme::palette;
 
 /// Radius from building center where spawned units appear.
 /// Clears the 40px building sprite + 6px unit radius with margin.
 const BUILDING_SPAWN_RADIUS: f32 = 40.0;
 
+/// Production speed multiplier for the `ProductionFocus` building.
+const PRODUCTION_FOCUS_BOOST: u32 = 2;
+
+/// Vertical gap between phalanx rows. Two unit diameters plus margin.
+const PHALANX_ROW_SPACING: f32 = 16.0;
+
+/// Production timer fraction past which a building pulses to signal an imminent spawn.
+const READY_PULSE_THRESHOLD: f32 = 0.9;
+
+/// Extra scale a pulsing building's sprite reaches the instant its timer fires.
+const READY_PULSE_SCALE: f32 = 0.15;
+
 // === Production Bar Components ===
 
 /// Marker: dark background bar (full width, shows "remaining" time).
@@ -33,6 +51,12 @@ pub struct ProductionBarConfig {
     pub y_offset: f32,
 }
 
+/// Sprite size a building's ready pulse scales from, captured when its
+/// `ProductionTimer` is added so the pulse settles back to that size.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ReadyPulseBase(pub Vec2);
+
 // === Production Bar Systems ===
 
 /// Spawns production bar child entities when `ProductionTimer` is added to an entity
@@ -86,11 +110,73 @@ pub(super) fn update_production_bars(
     }
 }
 
+/// Building sprite scale for a production timer at `fraction`: 1.0 up to
+/// `READY_PULSE_THRESHOLD`, then growing linearly to `1.0 + READY_PULSE_SCALE`.
+#[must_use]
+pub fn ready_pulse_scale(fraction: f32) -> f32 {
+    let progress = ((fraction - READY_PULSE_THRESHOLD) / (1.0 - READY_PULSE_THRESHOLD)).max(0.0);
+    READY_PULSE_SCALE.mul_add(progress.min(1.0), 1.0)
+}
+
+/// Records the sprite size a producing building pulses from (`ReadyPulseBase`).
+pub(super) fn record_ready_pulse_base(
+    add: On<Add, ProductionTimer>,
+    sprites: Query<&Sprite>,
+    mut commands: Commands,
+) {
+    let Some(size) = sprites
+        .get(add.entity)
+        .ok()
+        .and_then(|sprite| sprite.custom_size)
+    else {
+        return;
+    };
+    commands.entity(add.entity).insert(ReadyPulseBase(size));
+}
+
+/// Swells the sprites of buildings whose production is about to fire; the timer
+/// wrapping on spawn brings them back to their `ReadyPulseBase` size. Only the
+/// sprite grows — scaling the root `Transform` would also grow the collider and
+/// the navmesh obstacle. Runs in `GameSet::Ui`.
+pub(super) fn update_ready_pulse(
+    mut buildings: Query<(&ProductionTimer, &ReadyPulseBase, &mut Sprite), With<super::Building>>,
+) {
+    for (timer, base, mut sprite) in &mut buildings {
+        let size = base.0 * ready_pulse_scale(timer.0.fraction());
+        if sprite.custom_size != Some(size) {
+            sprite.custom_size = Some(size);
+        }
+    }
+}
+
+/// Resets the production focus on entering `InGame`.
+pub(super) fn reset_production_focus(mut focus: ResMut<ProductionFocus>) {
+    *focus = ProductionFocus::default();
+}
+
 /// Ticks production timers on all buildings and spawns units when timers fire.
+/// Boxed-in buildings hold production until a spawn point frees up.
+/// With a `ProductionFocus`, only the focused building produces (boosted).
+/// Each spawn takes the building's next lane (`AssignedLane`); a phalanx squad
+/// shares one lane so it keeps formation, and squad rows that fall off the navmesh
+/// are dropped. Every spawned unit counts toward the building's `UnitsProduced`.
+/// With `Settings::recall_units_after_kill` on, units get a `Recall` home to the building.
 pub(super) fn tick_production_and_spawn_units(
     time: Res<Time>,
-    mut buildings: Query<(&super::Building, &mut ProductionTimer, &Transform)>,
+    focus: Res<ProductionFocus>,
+    settings: Res<Settings>,
+    mut buildings: Query<(
+        Entity,
+        &mut super::Building,
+        &mut ProductionTimer,
+        &mut UnitsProduced,
+        &Transform,
+        Option<&Phalanx>,
+    )>,
     unit_assets: Res<UnitAssets>,
+    mut spawn_ids: ResMut<SpawnIdCounter>,
+    mut run_stats: ResMut<RunStats>,
+    mut rng: ResMut<GameRng>,
     navmeshes: Option<Res<Assets<NavMesh>>>,
     navmesh_query: Option<Single<(&ManagedNavMesh, &NavMeshStatus)>>,
     mut commands: Commands,
@@ -102,31 +188,112 @@ pub(super) fn tick_production_and_spawn_units(
         (*status == NavMeshStatus::Built).then(|| meshes.get(managed))?
     });
 
-    for (building, mut timer, transform) in &mut buildings {
-        timer.0.tick(time.delta());
-
-        if timer.0.just_finished() {
-            let stats = building_stats(building.building_type);
-            if let Some(unit_type) = stats.produced_unit {
-                let center = transform.translation.xy();
-                let spawn_xy = random_navigable_spawn(center, BUILDING_SPAWN_RADIUS, navmesh);
-
-                spawn_unit(
-                    &mut commands,
-                    unit_type,
-                    crate::gameplay::Team::Player,
-                    spawn_xy,
-                    &unit_assets,
-                );
+    let focus = focus.0.filter(|&focused| buildings.contains(focused));
+
+    for (entity, mut building, mut timer, mut produced, transform, phalanx) in &mut buildings {
+        let stats = building_stats(building.building_type);
+        let Some(unit_type) = stats.produced_unit else {
+            timer.0.tick(time.delta());
+            continue;
+        };
+
+        let boost = match focus {
+            Some(focused) if focused == entity => PRODUCTION_FOCUS_BOOST,
+            Some(_) => continue, // Held while another building has focus
+            None => 1,
+        };
+        let squad_size = phalanx.map_or(1, |phalanx| phalanx.squad_size.max(1));
+        let delta = time.delta() * boost / squad_size;
+        let center = transform.translation.xy();
+        let Some(spawn_xy) = advance_production(&mut timer.0, delta, center, navmesh, &mut rng.0)
+        else {
+            continue;
+        };
+
+        let lane = AssignedLane(building.take_lane());
+        for position in navigable_phalanx_column(spawn_xy, squad_size, navmesh) {
+            let unit = spawn_unit(
+                &mut commands,
+                unit_type,
+                crate::gameplay::Team::Player,
+                position,
+                &unit_assets,
+                &mut spawn_ids,
+            );
+            commands.entity(unit).insert(lane);
+            if settings.recall_units_after_kill {
+                commands
+                    .entity(unit)
+                    .insert(Recall::new(center, RECALL_RADIUS));
             }
+            produced.0 += 1;
+            run_stats.record_production();
         }
     }
 }
 
+/// Positions for a squad of `size` units in a vertical column centered on `anchor`,
+/// one unit per row. A squad of one is just `anchor`.
+fn phalanx_column(anchor: Vec2, size: u32) -> impl Iterator<Item = Vec2> {
+    #[allow(clippy::cast_precision_loss)]
+    let half_height = size.saturating_sub(1) as f32 * PHALANX_ROW_SPACING / 2.0;
+    (0..size).map(move |row| {
+        #[allow(clippy::cast_precision_loss)]
+        let y = (row as f32).mul_add(PHALANX_ROW_SPACING, anchor.y - half_height);
+        Vec2::new(anchor.x, y)
+    })
+}
+
+/// [`phalanx_column`] minus the rows that fall off `navmesh`. The anchor itself
+/// comes from `try_navigable_spawn`, but outer rows can land inside an obstacle.
+/// Without a built navmesh every row is kept.
+fn navigable_phalanx_column(
+    anchor: Vec2,
+    size: u32,
+    navmesh: Option<&NavMesh>,
+) -> impl Iterator<Item = Vec2> + '_ {
+    phalanx_column(anchor, size)
+        .filter(move |position| navmesh.is_none_or(|mesh| mesh.is_in_mesh(*position)))
+}
+
+/// Advances one building's production timer and returns where to spawn a unit,
+/// if one is due this frame.
+///
+/// When the timer fires but no navigable spawn point exists (e.g. the building is
+/// boxed in), production is held: the timer is paused at full and the spawn is
+/// retried every frame until space frees up.
+fn advance_production(
+    timer: &mut Timer,
+    delta: std::time::Duration,
+    center: Vec2,
+    navmesh: Option<&NavMesh>,
+    rng: &mut impl rand::Rng,
+) -> Option<Vec2> {
+    if timer.is_paused() {
+        let spawn_xy = try_navigable_spawn(center, BUILDING_SPAWN_RADIUS, navmesh, rng)?;
+        timer.reset();
+        timer.unpause();
+        return Some(spawn_xy);
+    }
+
+    timer.tick(delta);
+    if !timer.just_finished() {
+        return None;
+    }
+
+    let spawn_xy = try_navigable_spawn(center, BUILDING_SPAWN_RADIUS, navmesh, rng);
+    if spawn_xy.is_none() {
+        let duration = timer.duration();
+        timer.set_elapsed(duration);
+        timer.pause();
+    }
+    spawn_xy
+}
+
 #[cfg(test)]
 mod integration_tests {
     use super::*;
-    use crate::gameplay::building::{Building, BuildingType, HoveredCell, ProductionTimer};
+    use crate::gameplay::building::{Building, BuildingType, Facing, HoveredCell, ProductionTimer};
     use crate::gameplay::units::Unit;
     use crate::gameplay::{CombatStats, Health, Movement, Team};
     use crate::menus::Menu;
@@ -144,7 +311,12 @@ mod integration_tests {
 
         app.configure_sets(
             Update,
-            (crate::GameSet::Input, crate::GameSet::Production).chain(),
+            (
+                crate::GameSet::Input,
+                crate::GameSet::Production,
+                crate::GameSet::Ui,
+            )
+                .chain(),
         );
 
         app.add_plugins(crate::gameplay::battlefield::plugin);
@@ -173,7 +345,8 @@ mod integration_tests {
         app.register_type::<Building>()
             .register_type::<super::super::Occupied>()
             .register_type::<ProductionTimer>()
-            .init_resource::<HoveredCell>();
+            .init_resource::<HoveredCell>()
+            .init_resource::<super::super::PlacementFacing>();
         crate::testing::init_economy_resources(&mut app);
         app.add_systems(
             Update,
@@ -202,12 +375,9 @@ mod integration_tests {
     fn production_timer_spawns_unit() {
         let mut app = create_production_test_app();
 
-        app.world_mut().spawn((
-            Building {
-                building_type: BuildingType::Barracks,
-                grid_col: 2,
-                grid_row: 3,
-            },
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 2, 3);
+        app.world_mut().entity_mut(building).insert((
             ProductionTimer(nearly_elapsed_timer()),
             Transform::from_xyz(320.0, 160.0, crate::Z_BUILDING),
             DespawnOnExit(GameState::InGame),
@@ -217,16 +387,119 @@ mod integration_tests {
         assert_entity_count::<With<Unit>>(&mut app, 1);
     }
 
+    #[test]
+    fn consecutive_units_take_lanes_round_robin() {
+        use crate::gameplay::battlefield::BATTLEFIELD_ROWS;
+        use crate::gameplay::units::SpawnId;
+
+        let mut app = create_production_test_app();
+        let barracks = app
+            .world_mut()
+            .spawn((
+                Building {
+                    building_type: BuildingType::Barracks,
+                    grid_col: 2,
+                    grid_row: 3,
+                    facing: Facing::default(),
+                    next_lane: BATTLEFIELD_ROWS - 2,
+                },
+                ProductionTimer(nearly_elapsed_timer()),
+                Transform::from_xyz(320.0, 160.0, crate::Z_BUILDING),
+                DespawnOnExit(GameState::InGame),
+            ))
+            .id();
+        for _ in 0..3 {
+            app.update();
+            app.world_mut()
+                .entity_mut(barracks)
+                .insert(ProductionTimer(nearly_elapsed_timer()));
+        }
+
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&SpawnId, &AssignedLane), With<Unit>>();
+        let mut lanes: Vec<_> = query
+            .iter(app.world())
+            .map(|(id, lane)| (*id, lane.0))
+            .collect();
+        lanes.sort_unstable();
+        let rows: Vec<u16> = lanes.into_iter().map(|(_, row)| row).collect();
+        assert_eq!(rows, vec![BATTLEFIELD_ROWS - 2, BATTLEFIELD_ROWS - 1, 0]);
+    }
+
+    #[test]
+    fn produced_unit_is_counted_in_run_stats() {
+        let mut app = create_production_test_app();
+
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 2, 3);
+        app.world_mut().entity_mut(building).insert((
+            ProductionTimer(nearly_elapsed_timer()),
+            Transform::from_xyz(320.0, 160.0, crate::Z_BUILDING),
+            DespawnOnExit(GameState::InGame),
+        ));
+        app.update();
+
+        let stats = app.world().resource::<crate::gameplay::economy::RunStats>();
+        assert_eq!(stats.units_produced, 1);
+    }
+
+    #[test]
+    fn each_spawn_counts_toward_units_produced() {
+        let mut app = create_production_test_app();
+
+        let barracks = spawn_barracks(&mut app, 2, Vec2::new(320.0, 160.0));
+        for _ in 0..3 {
+            crate::testing::nearly_expire_timer(
+                &mut app
+                    .world_mut()
+                    .get_mut::<ProductionTimer>(barracks)
+                    .unwrap()
+                    .0,
+            );
+            app.update();
+        }
+
+        assert_eq!(
+            app.world().get::<UnitsProduced>(barracks),
+            Some(&UnitsProduced(3))
+        );
+    }
+
+    #[test]
+    fn recall_setting_sends_produced_units_home_to_their_building() {
+        let mut app = create_production_test_app();
+        app.world_mut()
+            .resource_mut::<Settings>()
+            .recall_units_after_kill = true;
+
+        let barracks_xy = Vec2::new(320.0, 160.0);
+        spawn_barracks(&mut app, 2, barracks_xy);
+        app.update();
+
+        let mut recalls = app.world_mut().query_filtered::<&Recall, With<Unit>>();
+        let recall = recalls.single(app.world()).unwrap();
+        assert_eq!(recall.home, barracks_xy);
+        assert_eq!(recall.radius, RECALL_RADIUS);
+    }
+
+    #[test]
+    fn produced_units_have_no_recall_by_default() {
+        let mut app = create_production_test_app();
+        spawn_barracks(&mut app, 2, Vec2::new(320.0, 160.0));
+        app.update();
+
+        assert_entity_count::<With<Unit>>(&mut app, 1);
+        assert_entity_count::<(With<Unit>, With<Recall>)>(&mut app, 0);
+    }
+
     #[test]
     fn spawned_unit_has_correct_components() {
         let mut app = create_production_test_app();
 
-        app.world_mut().spawn((
-            Building {
-                building_type: BuildingType::Barracks,
-                grid_col: 2,
-                grid_row: 3,
-            },
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 2, 3);
+        app.world_mut().entity_mut(building).insert((
             ProductionTimer(nearly_elapsed_timer()),
             Transform::from_xyz(320.0, 160.0, crate::Z_BUILDING),
             DespawnOnExit(GameState::InGame),
@@ -247,12 +520,9 @@ mod integration_tests {
     fn spawned_unit_is_player_team() {
         let mut app = create_production_test_app();
 
-        app.world_mut().spawn((
-            Building {
-                building_type: BuildingType::Barracks,
-                grid_col: 0,
-                grid_row: 0,
-            },
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 0, 0);
+        app.world_mut().entity_mut(building).insert((
             ProductionTimer(nearly_elapsed_timer()),
             Transform::from_xyz(200.0, 100.0, crate::Z_BUILDING),
             DespawnOnExit(GameState::InGame),
@@ -271,12 +541,9 @@ mod integration_tests {
         let building_x = 320.0;
         let building_y = 160.0;
 
-        app.world_mut().spawn((
-            Building {
-                building_type: BuildingType::Barracks,
-                grid_col: 2,
-                grid_row: 3,
-            },
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 2, 3);
+        app.world_mut().entity_mut(building).insert((
             ProductionTimer(nearly_elapsed_timer()),
             Transform::from_xyz(building_x, building_y, crate::Z_BUILDING),
             DespawnOnExit(GameState::InGame),
@@ -301,6 +568,344 @@ mod integration_tests {
         assert_entity_count::<With<Unit>>(&mut app, 0);
     }
 
+    // === Ready Pulse Tests ===
+
+    #[test]
+    fn ready_pulse_scale_ramps_after_threshold() {
+        assert_eq!(ready_pulse_scale(0.0), 1.0);
+        assert_eq!(ready_pulse_scale(READY_PULSE_THRESHOLD), 1.0);
+        assert!(ready_pulse_scale(0.95) > 1.0);
+        assert_eq!(ready_pulse_scale(1.0), 1.0 + READY_PULSE_SCALE);
+    }
+
+    fn spawn_pulsing_barracks(app: &mut App, size: Vec2) -> Entity {
+        let mut timer = Timer::from_seconds(10.0, TimerMode::Repeating);
+        timer.set_elapsed(std::time::Duration::from_secs_f32(9.5));
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 2, 3);
+        app.world_mut().entity_mut(building).insert((
+            ProductionTimer(timer),
+            Sprite::from_color(Color::WHITE, size),
+            Transform::from_xyz(320.0, 160.0, crate::Z_BUILDING),
+            DespawnOnExit(GameState::InGame),
+        ));
+        building
+    }
+
+    fn sprite_size(app: &App, entity: Entity) -> Vec2 {
+        app.world()
+            .get::<Sprite>(entity)
+            .unwrap()
+            .custom_size
+            .unwrap()
+    }
+
+    #[test]
+    fn building_pulses_before_spawn_and_settles_after() {
+        let mut app = create_production_test_app();
+        let barracks =
+            spawn_pulsing_barracks(&mut app, Vec2::splat(super::super::BUILDING_SPRITE_SIZE));
+        app.update();
+
+        assert_entity_count::<With<Unit>>(&mut app, 0);
+        let size = sprite_size(&app, barracks);
+        assert!(
+            size.x > super::super::BUILDING_SPRITE_SIZE
+                && size.y > super::super::BUILDING_SPRITE_SIZE,
+            "expected pulse, got {size}"
+        );
+
+        crate::testing::nearly_expire_timer(
+            &mut app
+                .world_mut()
+                .get_mut::<ProductionTimer>(barracks)
+                .unwrap()
+                .0,
+        );
+        app.update();
+
+        assert_entity_count::<With<Unit>>(&mut app, 1);
+        assert_eq!(
+            sprite_size(&app, barracks),
+            Vec2::splat(super::super::BUILDING_SPRITE_SIZE)
+        );
+    }
+
+    #[test]
+    fn pulse_settles_back_to_each_buildings_own_size() {
+        let mut app = create_production_test_app();
+        let base = Vec2::new(24.0, 32.0);
+        let barracks = spawn_pulsing_barracks(&mut app, base);
+        app.update();
+
+        assert_eq!(
+            app.world().get::<ReadyPulseBase>(barracks),
+            Some(&ReadyPulseBase(base))
+        );
+        assert!(sprite_size(&app, barracks).y > base.y);
+
+        crate::testing::nearly_expire_timer(
+            &mut app
+                .world_mut()
+                .get_mut::<ProductionTimer>(barracks)
+                .unwrap()
+                .0,
+        );
+        app.update();
+
+        assert_eq!(sprite_size(&app, barracks), base);
+    }
+
+    #[test]
+    fn pulse_leaves_collider_and_obstacle_footprint_unchanged() {
+        use avian2d::prelude::Collider;
+
+        let mut app = create_production_test_app();
+        let barracks =
+            spawn_pulsing_barracks(&mut app, Vec2::splat(super::super::BUILDING_SPRITE_SIZE));
+        let shape = building_stats(BuildingType::Barracks).collider_shape;
+        app.world_mut().entity_mut(barracks).insert((
+            shape.collider(),
+            shape.extent(),
+            crate::third_party::NavObstacle,
+        ));
+        app.update();
+
+        assert!(sprite_size(&app, barracks).x > super::super::BUILDING_SPRITE_SIZE);
+        let world = app.world();
+        assert_eq!(world.get::<Transform>(barracks).unwrap().scale, Vec3::ONE);
+        assert_eq!(
+            *world
+                .get::<crate::gameplay::EntityExtent>(barracks)
+                .unwrap(),
+            shape.extent()
+        );
+        let cuboid = world.get::<Collider>(barracks).unwrap().shape().as_cuboid();
+        assert_eq!(
+            cuboid.expect("rectangle collider").half_extents.x,
+            super::super::BUILDING_SPRITE_SIZE / 2.0
+        );
+    }
+
+    // === Production Focus Tests ===
+
+    fn spawn_barracks(app: &mut App, grid_col: u16, position: Vec2) -> Entity {
+        let building = crate::testing::spawn_test_building(
+            app.world_mut(),
+            BuildingType::Barracks,
+            grid_col,
+            3,
+        );
+        app.world_mut().entity_mut(building).insert((
+            ProductionTimer(nearly_elapsed_timer()),
+            Transform::from_translation(position.extend(crate::Z_BUILDING)),
+            DespawnOnExit(GameState::InGame),
+        ));
+        building
+    }
+
+    #[test]
+    fn focused_building_spawns_while_others_hold() {
+        let mut app = create_production_test_app();
+
+        let focused_xy = Vec2::new(200.0, 160.0);
+        let focused = spawn_barracks(&mut app, 0, focused_xy);
+        let held = spawn_barracks(&mut app, 4, Vec2::new(400.0, 160.0));
+        app.world_mut()
+            .resource_mut::<super::super::ProductionFocus>()
+            .0 = Some(focused);
+        app.update();
+
+        assert_entity_count::<With<Unit>>(&mut app, 1);
+        let mut query = app.world_mut().query_filtered::<&Transform, With<Unit>>();
+        let unit_xy = query.single(app.world()).unwrap().translation.xy();
+        assert!(
+            (unit_xy.distance(focused_xy) - BUILDING_SPAWN_RADIUS).abs() < 0.01,
+            "Expected the unit from the focused building, got {unit_xy}"
+        );
+
+        // The held building's timer did not advance.
+        let timer = &app.world().get::<ProductionTimer>(held).unwrap().0;
+        assert!(!timer.is_finished());
+        assert!(timer.fraction() < 1.0);
+    }
+
+    #[test]
+    fn focus_on_missing_building_is_ignored() {
+        let mut app = create_production_test_app();
+
+        spawn_barracks(&mut app, 0, Vec2::new(200.0, 160.0));
+        spawn_barracks(&mut app, 4, Vec2::new(400.0, 160.0));
+        let gone = app.world_mut().spawn_empty().id();
+        app.world_mut().despawn(gone);
+        app.world_mut()
+            .resource_mut::<super::super::ProductionFocus>()
+            .0 = Some(gone);
+        app.update();
+
+        assert_entity_count::<With<Unit>>(&mut app, 2);
+    }
+
+    // === Phalanx Tests ===
+
+    #[test]
+    fn phalanx_spawns_whole_squad_in_one_tick() {
+        let mut app = create_production_test_app();
+
+        let building_xy = Vec2::new(320.0, 160.0);
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 2, 3);
+        app.world_mut().entity_mut(building).insert((
+            ProductionTimer(nearly_elapsed_timer()),
+            super::super::Phalanx { squad_size: 3 },
+            Transform::from_translation(building_xy.extend(crate::Z_BUILDING)),
+            DespawnOnExit(GameState::InGame),
+        ));
+        app.update();
+
+        assert_entity_count::<With<Unit>>(&mut app, 3);
+
+        let mut query = app.world_mut().query_filtered::<&Transform, With<Unit>>();
+        let mut positions: Vec<Vec2> = query
+            .iter(app.world())
+            .map(|transform| transform.translation.xy())
+            .collect();
+        positions.sort_by(|a, b| a.y.total_cmp(&b.y));
+
+        for pair in positions.windows(2) {
+            assert!(
+                (pair[1].y - pair[0].y - PHALANX_ROW_SPACING).abs() < 0.01,
+                "Expected rows {PHALANX_ROW_SPACING} apart, got {positions:?}"
+            );
+            assert!((pair[1].x - pair[0].x).abs() < 0.01, "Expected one column");
+        }
+        // The middle row sits on the spawn ring around the building.
+        let dist = positions[1].distance(building_xy);
+        assert!(
+            (dist - BUILDING_SPAWN_RADIUS).abs() < 0.01,
+            "Expected squad centered {BUILDING_SPAWN_RADIUS} from building, got {dist}"
+        );
+    }
+
+    #[test]
+    fn phalanx_column_is_centered_on_anchor() {
+        let anchor = Vec2::new(10.0, 20.0);
+        let rows: Vec<Vec2> = super::phalanx_column(anchor, 2).collect();
+        assert_eq!(
+            rows,
+            vec![
+                Vec2::new(10.0, 20.0 - PHALANX_ROW_SPACING / 2.0),
+                Vec2::new(10.0, 20.0 + PHALANX_ROW_SPACING / 2.0),
+            ]
+        );
+        assert_eq!(
+            super::phalanx_column(anchor, 1).collect::<Vec<_>>(),
+            vec![anchor]
+        );
+    }
+
+    #[test]
+    fn phalanx_rows_off_the_navmesh_are_dropped() {
+        // A 100x20 strip: a three-row column centered on its midline sticks out
+        // above and below.
+        let mesh: polyanya::Mesh = polyanya::Trimesh {
+            vertices: vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(100.0, 0.0),
+                Vec2::new(100.0, 20.0),
+                Vec2::new(0.0, 20.0),
+            ],
+            triangles: vec![[0, 1, 2], [0, 2, 3]],
+        }
+        .try_into()
+        .expect("valid trimesh");
+        let navmesh = NavMesh::from_polyanya_mesh(mesh);
+        let anchor = Vec2::new(50.0, 10.0);
+
+        let rows: Vec<Vec2> = super::navigable_phalanx_column(anchor, 3, Some(&navmesh)).collect();
+        assert_eq!(rows, vec![anchor]);
+        assert_eq!(super::navigable_phalanx_column(anchor, 3, None).count(), 3);
+    }
+
+    // === Production Hold Tests ===
+
+    /// A 10x10 navmesh at the origin: every point `BUILDING_SPAWN_RADIUS` from
+    /// its center lies outside it, so a building there is boxed in.
+    fn build_boxed_in_navmesh() -> NavMesh {
+        let mesh: polyanya::Mesh = polyanya::Trimesh {
+            vertices: vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(10.0, 0.0),
+                Vec2::new(10.0, 10.0),
+                Vec2::new(0.0, 10.0),
+            ],
+            triangles: vec![[0, 1, 2], [0, 2, 3]],
+        }
+        .try_into()
+        .expect("valid trimesh");
+        NavMesh::from_polyanya_mesh(mesh)
+    }
+
+    const BOXED_IN_CENTER: Vec2 = Vec2::new(5.0, 5.0);
+
+    #[test]
+    fn production_held_without_navigable_spawn_point() {
+        let navmesh = build_boxed_in_navmesh();
+        let mut rng = crate::gameplay::GameRng::seeded(0);
+        let mut timer = nearly_elapsed_timer();
+
+        let spawn = super::advance_production(
+            &mut timer,
+            std::time::Duration::from_millis(1),
+            BOXED_IN_CENTER,
+            Some(&navmesh),
+            &mut rng.0,
+        );
+
+        assert_eq!(spawn, None);
+        assert!(timer.is_paused());
+        assert!((timer.fraction() - 1.0).abs() < f32::EPSILON);
+
+        // Still blocked on later frames: keeps holding.
+        let spawn = super::advance_production(
+            &mut timer,
+            std::time::Duration::from_millis(1),
+            BOXED_IN_CENTER,
+            Some(&navmesh),
+            &mut rng.0,
+        );
+        assert_eq!(spawn, None);
+        assert!(timer.is_paused());
+    }
+
+    #[test]
+    fn held_production_resumes_once_space_frees() {
+        let navmesh = build_boxed_in_navmesh();
+        let mut rng = crate::gameplay::GameRng::seeded(0);
+        let mut timer = nearly_elapsed_timer();
+        let _ = super::advance_production(
+            &mut timer,
+            std::time::Duration::from_millis(1),
+            BOXED_IN_CENTER,
+            Some(&navmesh),
+            &mut rng.0,
+        );
+        assert!(timer.is_paused());
+
+        // Space frees up (no blocking mesh): the held unit spawns immediately.
+        let spawn = super::advance_production(
+            &mut timer,
+            std::time::Duration::ZERO,
+            BOXED_IN_CENTER,
+            None,
+            &mut rng.0,
+        );
+
+        assert!(spawn.is_some());
+        assert!(!timer.is_paused());
+        assert!(timer.fraction() < f32::EPSILON);
+    }
+
     // === Production Bar Tests ===
 
     #[test]
@@ -3,7 +3,16 @@
 use avian2d::prelude::*;
 use bevy::prelude::*;
 
-use crate::gameplay::{CombatStats, EntityExtent, Health, TargetingState, Team, extent_distance};
+use crate::gameplay::ai::TargetSpatialHash;
+use crate::gameplay::battlefield::{EnemyFortress, PlayerFortress};
+use crate::gameplay::building::Building;
+use crate::gameplay::combat::status::{StatusEffect, StatusEffects};
+use crate::gameplay::units::Unit;
+use crate::gameplay::units::combined_arms::CombinedArms;
+use crate::gameplay::{
+    CombatStats, Defense, EntityExtent, FiringArc, Health, HealthChanged, Incendiary, Knockback,
+    SiegeBonus, Splash, TargetingState, Team, extent_distance,
+};
 use crate::screens::GameState;
 use crate::third_party::CollisionLayer;
 use crate::{GameSet, Z_PROJECTILE, gameplay_running};
@@ -34,8 +43,54 @@ pub struct Projectile {
     pub target: Entity,
     pub damage: f32,
     pub speed: f32,
+    /// Area-of-effect radius around the impact point. 0.0 = single-target.
+    pub splash_radius: f32,
+    /// Entity that fired this projectile, for kill attribution.
+    pub attacker: Option<Entity>,
+    /// Where the projectile was fired from. Sets the knockback direction.
+    pub origin: Vec2,
+    /// Speed (px/s) added to a dynamic hit target, away from `origin`. 0.0 = none.
+    pub knockback: f32,
 }
 
+impl Projectile {
+    /// Splash damage at `distance` from the impact point: full at the center,
+    /// falling off linearly to zero at `splash_radius`.
+    #[must_use]
+    pub const fn splash_damage_at(&self, distance: f32) -> f32 {
+        if self.splash_radius <= 0.0 {
+            return 0.0;
+        }
+        self.damage * (1.0 - distance / self.splash_radius).max(0.0)
+    }
+}
+
+/// The entity whose projectile last damaged this one. Lets death-time
+/// systems credit a kill to the unit that landed it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct LastHitBy(pub Entity);
+
+/// Damage dealt to a target, scaled by `SiegeBonus` when the target is a building
+/// or fortress.
+fn damage_against(damage: f32, siege: Option<&SiegeBonus>, is_structure: bool) -> f32 {
+    match siege {
+        Some(bonus) if is_structure => damage * bonus.0,
+        _ => damage,
+    }
+}
+
+/// Damage left after the target's `Defense`. Targets without it take the full amount.
+fn mitigated(damage: f32, defense: Option<&Defense>) -> f32 {
+    defense.map_or(damage, |defense| defense.mitigate(damage))
+}
+
+/// Marker: this entity strikes its target directly when its `AttackTimer` fires
+/// (see `apply_melee`) instead of firing projectiles. Given to soldiers.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct MeleeAttacker;
+
 /// Marker for hitbox sensor entities (attack colliders that damage hurtbox targets).
 /// Lives on projectiles; future: melee swing entities.
 #[derive(Component, Debug, Clone, Copy, Reflect)]
@@ -46,26 +101,62 @@ pub struct Hitbox;
 
 /// Ticks attack timers and spawns projectiles toward targets in range.
 /// Uses surface-to-surface distance so entities can attack large targets (buildings, fortresses).
+/// Entities with a `FiringArc` only fire at targets inside the arc.
+/// Zero-range attackers never fire projectiles — they deal contact damage instead.
+/// Melee attackers never fire projectiles either — `apply_melee` handles them.
+/// Unit projectiles carry their team's `CombinedArms` damage multiplier.
+/// Attackers with `Splash` fire projectiles with that blast radius.
 /// Runs in `GameSet::Combat`.
 fn attack(
     time: Res<Time>,
+    combined_arms: Res<CombinedArms>,
     mut attackers: Query<(
+        Entity,
         &TargetingState,
         &CombatStats,
         &mut AttackTimer,
         &GlobalTransform,
         &EntityExtent,
         &Team,
+        Option<&FiringArc>,
+        Option<&SiegeBonus>,
+        Option<&Knockback>,
+        Option<&Incendiary>,
+        Option<&Splash>,
+        Has<Unit>,
+        Has<MeleeAttacker>,
     )>,
     targets: Query<(&GlobalTransform, &EntityExtent)>,
     mut commands: Commands,
 ) {
-    for (targeting_state, stats, mut timer, attacker_pos, attacker_extent, team) in &mut attackers {
+    for (
+        attacker,
+        targeting_state,
+        stats,
+        mut timer,
+        attacker_pos,
+        attacker_extent,
+        team,
+        arc,
+        siege,
+        knockback,
+        incendiary,
+        splash,
+        is_unit,
+        is_melee,
+    ) in &mut attackers
+    {
         // Always tick the timer so it stays warm — entities fire on a cadence
         // regardless of whether a target is currently in range.
         timer.0.tick(time.delta());
         let ready = timer.0.just_finished();
 
+        // Zero-range attackers are handled by `apply_fortress_contact_damage`,
+        // melee attackers by `apply_melee`
+        if stats.range <= 0.0 || is_melee {
+            continue;
+        }
+
         let Some(target_entity) = targeting_state.target_entity() else {
             continue;
         };
@@ -84,13 +175,32 @@ fn attack(
             continue;
         }
 
+        // Directional attackers only fire inside their arc
+        if arc.is_some_and(|arc| {
+            !arc.contains(
+                attacker_pos.translation().xy(),
+                target_pos.translation().xy(),
+            )
+        }) {
+            continue;
+        }
+
         if ready {
-            commands.spawn((
+            let damage = if is_unit {
+                stats.damage * combined_arms.damage_multiplier(*team)
+            } else {
+                stats.damage
+            };
+            let mut projectile = commands.spawn((
                 Name::new("Projectile"),
                 Projectile {
                     target: target_entity,
-                    damage: stats.damage,
+                    damage,
                     speed: PROJECTILE_SPEED,
+                    splash_radius: splash.map_or(0.0, |splash| splash.0),
+                    attacker: Some(attacker),
+                    origin: attacker_pos.translation().xy(),
+                    knockback: knockback.map_or(0.0, |knockback| knockback.0),
                 },
                 *team,
                 Hitbox,
@@ -109,7 +219,146 @@ fn attack(
                 CollisionEventsEnabled,
                 CollidingEntities::default(),
             ));
+            if let Some(siege) = siege {
+                projectile.insert(*siege);
+            }
+            if let Some(incendiary) = incendiary {
+                projectile.insert(*incendiary);
+            }
+        }
+    }
+}
+
+/// Melee strikes: when a `MeleeAttacker`'s timer (ticked by `attack` earlier in
+/// the chain) fires with its target in range, the damage lands immediately with
+/// no projectile. Applies the same `CombinedArms`, `SiegeBonus`, and `Defense`
+/// scaling as projectile hits and credits the kill via `LastHitBy`.
+/// Runs in `GameSet::Combat`.
+fn apply_melee(
+    mut commands: Commands,
+    combined_arms: Res<CombinedArms>,
+    attackers: Query<
+        (
+            Entity,
+            &TargetingState,
+            &CombatStats,
+            &AttackTimer,
+            &GlobalTransform,
+            &EntityExtent,
+            &Team,
+            Option<&SiegeBonus>,
+            Has<Unit>,
+        ),
+        With<MeleeAttacker>,
+    >,
+    mut targets: Query<(
+        &GlobalTransform,
+        &EntityExtent,
+        &mut Health,
+        Option<&Defense>,
+    )>,
+    structures: Query<(), Or<(With<Building>, With<PlayerFortress>, With<EnemyFortress>)>>,
+    mut health_changes: MessageWriter<HealthChanged>,
+    #[cfg(feature = "dev")] invincible: Query<(), With<crate::dev_tools::Invincible>>,
+) {
+    for (
+        attacker,
+        targeting_state,
+        stats,
+        timer,
+        attacker_pos,
+        attacker_extent,
+        team,
+        siege,
+        is_unit,
+    ) in &attackers
+    {
+        if stats.range <= 0.0 || !timer.0.just_finished() {
+            continue;
+        }
+        let Some(target_entity) = targeting_state.target_entity() else {
+            continue;
+        };
+        let Ok((target_pos, target_extent, mut health, defense)) = targets.get_mut(target_entity)
+        else {
+            continue;
+        };
+        let distance = extent_distance(
+            attacker_extent,
+            attacker_pos.translation().xy(),
+            target_extent,
+            target_pos.translation().xy(),
+        );
+        if distance > stats.range {
+            continue;
+        }
+        #[cfg(feature = "dev")]
+        if invincible.contains(target_entity) {
+            continue;
+        }
+
+        let damage = if is_unit {
+            stats.damage * combined_arms.damage_multiplier(*team)
+        } else {
+            stats.damage
+        };
+        let damage = damage_against(damage, siege, structures.contains(target_entity));
+        let damage = mitigated(damage, defense);
+        let new = (health.current - damage).max(0.0);
+        health.set_current(target_entity, new, &mut health_changes);
+        commands
+            .entity(target_entity)
+            .try_insert(LastHitBy(attacker));
+    }
+}
+
+/// Contact-damage fallback: zero-range enemies touching a player fortress segment
+/// damage it on their attack cadence. An enemy touching two segments hits the one
+/// it is targeting, if any. Reads the timer ticked by `attack` earlier in the chain.
+/// Runs in `GameSet::Combat`.
+fn apply_fortress_contact_damage(
+    combined_arms: Res<CombinedArms>,
+    attackers: Query<(
+        &CombatStats,
+        &AttackTimer,
+        &Team,
+        &CollidingEntities,
+        Option<&TargetingState>,
+        Option<&SiegeBonus>,
+        Has<Unit>,
+    )>,
+    mut fortress: Query<(&mut Health, Option<&Defense>), With<PlayerFortress>>,
+    mut health_changes: MessageWriter<HealthChanged>,
+    #[cfg(feature = "dev")] invincible: Query<(), With<crate::dev_tools::Invincible>>,
+) {
+    for (stats, timer, team, colliding, targeting, siege, is_unit) in &attackers {
+        if *team != Team::Enemy || stats.range > 0.0 || !timer.0.just_finished() {
+            continue;
+        }
+        let touching =
+            |segment: &Entity| colliding.0.contains(segment) && fortress.contains(*segment);
+        let Some(segment) = targeting
+            .and_then(|state| state.target_entity())
+            .filter(touching)
+            .or_else(|| colliding.0.iter().copied().find(touching))
+        else {
+            continue;
+        };
+        #[cfg(feature = "dev")]
+        if invincible.contains(segment) {
+            continue;
         }
+        let Ok((mut health, defense)) = fortress.get_mut(segment) else {
+            continue;
+        };
+        let damage = if is_unit {
+            stats.damage * combined_arms.damage_multiplier(*team)
+        } else {
+            stats.damage
+        };
+        let damage = mitigated(damage_against(damage, siege, true), defense);
+        let new = (health.current - damage).max(0.0);
+        health.set_current(segment, new, &mut health_changes);
     }
 }
 
@@ -154,41 +403,163 @@ fn move_projectiles(
 
 /// Checks projectile hitbox overlaps with hurtboxes via `CollidingEntities`.
 /// Damages the first opposing-team entity hit and despawns the projectile.
+/// Splash projectiles also deal falloff damage to every other opposing entity
+/// within `splash_radius` of the impact point (the projectile's position),
+/// looked up through the `TargetSpatialHash`.
+/// Projectiles carrying `SiegeBonus` deal multiplied damage to buildings and fortresses.
+/// Projectiles with `knockback` push a dynamic direct hit away from their origin.
+/// Projectiles carrying `Incendiary` set the direct hit burning (`StatusEffects`).
 /// Runs after `move_projectiles` in the combat chain.
 fn handle_projectile_hits(
     mut commands: Commands,
-    projectiles: Query<(Entity, &Projectile, &Team, &CollidingEntities), With<Hitbox>>,
-    mut targets: Query<(&Team, &mut Health)>,
+    grid: Res<TargetSpatialHash>,
+    projectiles: Query<
+        (
+            Entity,
+            &Projectile,
+            &Team,
+            &Transform,
+            &CollidingEntities,
+            Option<&SiegeBonus>,
+            Option<&Incendiary>,
+        ),
+        With<Hitbox>,
+    >,
+    mut targets: Query<(
+        Entity,
+        &Team,
+        &mut Health,
+        Option<&Defense>,
+        Option<&GlobalTransform>,
+    )>,
+    mut bodies: Query<(&RigidBody, &mut LinearVelocity)>,
+    mut statuses: Query<&mut StatusEffects>,
+    structures: Query<(), Or<(With<Building>, With<PlayerFortress>, With<EnemyFortress>)>>,
+    mut health_changes: MessageWriter<HealthChanged>,
+    #[cfg(feature = "dev")] invincible: Query<(), With<crate::dev_tools::Invincible>>,
 ) {
-    for (entity, projectile, proj_team, colliding) in &projectiles {
-        for &hit in &colliding.0 {
-            let Ok((hit_team, mut health)) = targets.get_mut(hit) else {
+    for (entity, projectile, proj_team, transform, colliding, siege, incendiary) in &projectiles {
+        // No friendly fire
+        let Some(hit) = colliding.0.iter().copied().find(|&hit| {
+            targets
+                .get(hit)
+                .is_ok_and(|(_, team, ..)| team != proj_team)
+        }) else {
+            continue;
+        };
+        // One hit per projectile. `try_despawn` because `move_projectiles` may
+        // already have queued this despawn if the projectile's target is gone.
+        commands.entity(entity).try_despawn();
+
+        let mut victims = vec![(hit, projectile.damage)];
+        if projectile.splash_radius > 0.0 {
+            let impact = transform.translation.xy();
+            let candidates = grid.query_neighbors(impact, projectile.splash_radius);
+            victims.extend(candidates.into_iter().filter_map(|victim| {
+                let (_, team, _, _, position) = targets.get(victim).ok()?;
+                if victim == hit || team == proj_team {
+                    return None;
+                }
+                let distance = position?.translation().xy().distance(impact);
+                (distance <= projectile.splash_radius)
+                    .then(|| (victim, projectile.splash_damage_at(distance)))
+            }));
+        }
+
+        for (victim, damage) in victims {
+            let Ok((_, _, mut health, defense, _)) = targets.get_mut(victim) else {
                 continue;
             };
-            // No friendly fire
-            if hit_team == proj_team {
-                continue;
+            let damage = damage_against(damage, siege, structures.contains(victim));
+            let damage = mitigated(damage, defense);
+            #[cfg(feature = "dev")]
+            let damage = if invincible.contains(victim) {
+                0.0
+            } else {
+                damage
+            };
+            let new = (health.current - damage).max(0.0);
+            health.set_current(victim, new, &mut health_changes);
+            if let Some(attacker) = projectile.attacker {
+                commands.entity(victim).try_insert(LastHitBy(attacker));
             }
-            health.current = (health.current - projectile.damage).max(0.0);
-            commands.entity(entity).despawn();
-            break; // One hit per projectile
         }
+
+        // Incendiary projectiles set the direct hit burning
+        if let Some(incendiary) = incendiary {
+            #[cfg(feature = "dev")]
+            let immune = invincible.contains(hit);
+            #[cfg(not(feature = "dev"))]
+            let immune = false;
+            if !immune {
+                ignite(&mut commands, &mut statuses, hit, incendiary);
+            }
+        }
+
+        // Knockback shoves only the direct hit, and only if it is a dynamic body
+        if projectile.knockback > 0.0 {
+            let hit_pos = targets
+                .get(hit)
+                .ok()
+                .and_then(|(.., position)| position)
+                .map(|position| position.translation().xy());
+            if let (Some(hit_pos), Ok((RigidBody::Dynamic, mut velocity))) =
+                (hit_pos, bodies.get_mut(hit))
+            {
+                velocity.0 += knockback_impulse(projectile, hit_pos);
+            }
+        }
+    }
+}
+
+/// Start (or refresh) a burn from `incendiary` on `target`.
+fn ignite(
+    commands: &mut Commands,
+    statuses: &mut Query<&mut StatusEffects>,
+    target: Entity,
+    incendiary: &Incendiary,
+) {
+    let burning = StatusEffect::Burning {
+        dps: incendiary.dps,
+        remaining: incendiary.duration,
+    };
+    if let Ok(mut effects) = statuses.get_mut(target) {
+        effects.apply(burning);
+    } else {
+        commands
+            .entity(target)
+            .try_insert(StatusEffects(vec![burning]));
     }
 }
 
+/// Velocity `projectile` adds to a target at `target_pos`: `knockback` along the
+/// line from where it was fired to the target (zero if they coincide).
+fn knockback_impulse(projectile: &Projectile, target_pos: Vec2) -> Vec2 {
+    (target_pos - projectile.origin).normalize_or_zero() * projectile.knockback
+}
+
 // === Plugin ===
 
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<AttackTimer>()
         .register_type::<Projectile>()
-        .register_type::<Hitbox>();
+        .register_type::<LastHitBy>()
+        .register_type::<MeleeAttacker>()
+        .register_type::<Hitbox>()
+        .init_resource::<TargetSpatialHash>();
 
-    // Combat: spawn → move → check hits.
+    // Combat: spawn (or strike in melee) → move → check hits.
     // chain_ignore_deferred so newly spawned projectiles don't move until next frame
     // (prevents instant-hit invisible projectiles).
     app.add_systems(
         Update,
-        (attack, move_projectiles, handle_projectile_hits)
+        (
+            attack,
+            apply_melee,
+            apply_fortress_contact_damage,
+            move_projectiles,
+            handle_projectile_hits,
+        )
             .chain_ignore_deferred()
             .in_set(GameSet::Combat)
             .run_if(gameplay_running),
@@ -220,6 +591,7 @@ mod integration_tests {
     fn create_attack_test_app() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
+        app.init_resource::<CombinedArms>();
         app.add_systems(Update, attack);
         app.update(); // Initialize time (first frame delta=0)
         app
@@ -276,6 +648,75 @@ mod integration_tests {
         assert_entity_count::<With<Projectile>>(&mut app, 1);
     }
 
+    #[test]
+    fn projectile_records_its_attacker() {
+        let mut app = create_attack_test_app();
+
+        let target = spawn_target(app.world_mut(), 114.0, 100.0);
+        let attacker = spawn_attacker(app.world_mut(), 100.0, Some(target));
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        let mut projectiles = app.world_mut().query::<&Projectile>();
+        let projectile = projectiles.single(app.world()).unwrap();
+        assert_eq!(projectile.attacker, Some(attacker));
+    }
+
+    /// Damage carried by the projectile a player unit fires with `player_types`
+    /// distinct unit types on its team.
+    fn projectile_damage_with_player_types(player_types: u32) -> f32 {
+        let mut app = create_attack_test_app();
+        app.insert_resource(CombinedArms {
+            player_types,
+            enemy_types: 1,
+        });
+
+        let target = spawn_target(app.world_mut(), 114.0, 100.0);
+        spawn_attacker(app.world_mut(), 100.0, Some(target));
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        let mut projectiles = app.world_mut().query::<&Projectile>();
+        projectiles.single(app.world()).unwrap().damage
+    }
+
+    #[test]
+    fn mixed_army_projectiles_hit_harder_than_single_type_army() {
+        let single = projectile_damage_with_player_types(1);
+        let mixed = projectile_damage_with_player_types(2);
+
+        assert!(mixed > single, "mixed {mixed} should beat single {single}");
+        assert_eq!(
+            mixed,
+            single
+                * CombinedArms {
+                    player_types: 2,
+                    enemy_types: 0,
+                }
+                .damage_multiplier(Team::Player)
+        );
+    }
+
+    #[test]
+    fn archer_fires_projectile_from_long_range() {
+        use crate::gameplay::units::{UnitType, unit_stats};
+
+        let mut app = create_attack_test_app();
+        let stats = unit_stats(UnitType::Archer);
+
+        let target = spawn_target(app.world_mut(), 200.0, 100.0);
+        let archer = spawn_attacker(app.world_mut(), 100.0, Some(target)); // surface distance = 89
+        app.world_mut().entity_mut(archer).insert(CombatStats {
+            damage: stats.damage,
+            attack_speed: stats.attack_speed,
+            range: stats.attack_range,
+        });
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        assert_entity_count::<With<Projectile>>(&mut app, 1);
+    }
+
     #[test]
     fn unit_does_not_attack_out_of_range() {
         let mut app = create_attack_test_app();
@@ -310,6 +751,10 @@ mod integration_tests {
                 target,
                 damage: 10.0,
                 speed: PROJECTILE_SPEED,
+                splash_radius: 0.0,
+                attacker: None,
+                origin: Vec2::ZERO,
+                knockback: 0.0,
             },
             Transform::from_xyz(100.0, 100.0, 0.0),
         ));
@@ -340,11 +785,113 @@ mod integration_tests {
         assert_entity_count::<With<Projectile>>(&mut app, 0);
     }
 
+    // === Melee Tests ===
+
+    fn create_melee_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<CombinedArms>();
+        app.add_message::<HealthChanged>();
+        app.add_systems(Update, (attack, apply_melee).chain_ignore_deferred());
+        app.update(); // Initialize time
+        app
+    }
+
+    fn spawn_melee_attacker(world: &mut World, x: f32, target: Entity) -> Entity {
+        let attacker = spawn_attacker(world, x, Some(target));
+        world.entity_mut(attacker).insert(MeleeAttacker);
+        attacker
+    }
+
+    #[test]
+    fn melee_unit_damages_adjacent_enemy_without_projectile() {
+        let mut app = create_melee_test_app();
+
+        let target = spawn_target(app.world_mut(), 114.0, 100.0);
+        let attacker = spawn_melee_attacker(app.world_mut(), 100.0, target); // surface distance = 3
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        assert_entity_count::<With<Projectile>>(&mut app, 0);
+        let damage = app.world().get::<CombatStats>(attacker).unwrap().damage;
+        assert_eq!(
+            app.world().get::<Health>(target).unwrap().current,
+            100.0 - damage
+        );
+        assert_eq!(
+            app.world().get::<LastHitBy>(target),
+            Some(&LastHitBy(attacker))
+        );
+    }
+
+    #[test]
+    fn melee_unit_out_of_range_deals_no_damage() {
+        let mut app = create_melee_test_app();
+
+        let target = spawn_target(app.world_mut(), 200.0, 100.0);
+        spawn_melee_attacker(app.world_mut(), 100.0, target);
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        assert_entity_count::<With<Projectile>>(&mut app, 0);
+        assert_eq!(app.world().get::<Health>(target).unwrap().current, 100.0);
+    }
+
+    #[test]
+    fn ram_melee_deals_siege_bonus_to_building() {
+        use crate::gameplay::building::{BuildingType, Facing};
+        use crate::gameplay::units::{RAM_SIEGE_BONUS, UnitType, unit_stats};
+
+        let mut app = create_melee_test_app();
+
+        let building = spawn_target(app.world_mut(), 114.0, 300.0);
+        app.world_mut().entity_mut(building).insert(Building {
+            building_type: BuildingType::Barracks,
+            grid_col: 0,
+            grid_row: 0,
+            facing: Facing::default(),
+            next_lane: 0,
+        });
+        let ram = spawn_melee_attacker(app.world_mut(), 100.0, building);
+        let stats = unit_stats(UnitType::Ram);
+        app.world_mut().entity_mut(ram).insert((
+            UnitType::Ram,
+            CombatStats {
+                damage: stats.damage,
+                attack_speed: stats.attack_speed,
+                range: stats.attack_range,
+            },
+            SiegeBonus(RAM_SIEGE_BONUS),
+        ));
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        assert_eq!(
+            app.world().get::<Health>(building).unwrap().current,
+            stats.damage.mul_add(-RAM_SIEGE_BONUS, 300.0)
+        );
+    }
+
+    #[test]
+    fn ranged_unit_still_fires_projectile_alongside_melee() {
+        let mut app = create_melee_test_app();
+
+        let target = spawn_target(app.world_mut(), 114.0, 100.0);
+        spawn_melee_attacker(app.world_mut(), 100.0, target);
+        spawn_attacker(app.world_mut(), 100.0, Some(target));
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        assert_entity_count::<With<Projectile>>(&mut app, 1);
+    }
+
     // === Collision-Based Hit Tests ===
 
     fn create_hit_test_app() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
+        app.add_message::<HealthChanged>();
+        app.init_resource::<TargetSpatialHash>();
         app.add_systems(Update, handle_projectile_hits);
         app.update(); // Initialize
         app
@@ -366,10 +913,15 @@ mod integration_tests {
                     target,
                     damage,
                     speed: 200.0,
+                    splash_radius: 0.0,
+                    attacker: None,
+                    origin: Vec2::ZERO,
+                    knockback: 0.0,
                 },
                 team,
                 Hitbox,
                 colliding,
+                Transform::default(),
             ))
             .id()
     }
@@ -390,6 +942,31 @@ mod integration_tests {
         assert_eq!(health.current, 75.0);
     }
 
+    #[test]
+    fn projectile_hit_reports_health_change() {
+        let mut app = create_hit_test_app();
+
+        let enemy = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0)))
+            .id();
+        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 25.0, &[enemy]);
+
+        app.update();
+
+        let messages = app.world().resource::<Messages<HealthChanged>>();
+        let changes: Vec<HealthChanged> =
+            messages.iter_current_update_messages().copied().collect();
+        assert_eq!(
+            changes,
+            vec![HealthChanged {
+                entity: enemy,
+                old: 100.0,
+                new: 75.0,
+            }]
+        );
+    }
+
     #[test]
     fn projectile_hit_clamps_health_at_zero() {
         let mut app = create_hit_test_app();
@@ -405,94 +982,616 @@ mod integration_tests {
     }
 
     #[test]
-    fn projectile_despawns_on_hit() {
+    fn projectile_hit_tags_victim_with_attacker() {
         let mut app = create_hit_test_app();
 
+        let archer = app.world_mut().spawn(Team::Player).id();
         let enemy = app
             .world_mut()
             .spawn((Team::Enemy, Health::new(100.0)))
             .id();
-        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 10.0, &[enemy]);
+        let projectile =
+            spawn_test_projectile(app.world_mut(), Team::Player, enemy, 25.0, &[enemy]);
+        app.world_mut()
+            .get_mut::<Projectile>(projectile)
+            .unwrap()
+            .attacker = Some(archer);
 
         app.update();
 
-        assert_entity_count::<With<Projectile>>(&mut app, 0);
+        assert_eq!(
+            app.world().get::<LastHitBy>(enemy),
+            Some(&LastHitBy(archer))
+        );
     }
 
+    #[cfg(feature = "dev")]
     #[test]
-    fn projectile_does_not_friendly_fire() {
+    fn invincible_fortress_ignores_projectile_damage() {
+        use crate::gameplay::battlefield::FORTRESS_HP;
+
         let mut app = create_hit_test_app();
 
-        // Player projectile collides with a friendly player unit
-        let friendly = app
-            .world_mut()
-            .spawn((Team::Player, Health::new(100.0)))
-            .id();
-        let dummy_target = app
+        let fortress = app
             .world_mut()
-            .spawn((Team::Enemy, Health::new(100.0)))
+            .spawn((
+                PlayerFortress,
+                Team::Player,
+                Health::new(FORTRESS_HP),
+                crate::dev_tools::Invincible,
+            ))
             .id();
-        spawn_test_projectile(
-            app.world_mut(),
-            Team::Player,
-            dummy_target,
-            25.0,
-            &[friendly],
-        );
+        spawn_test_projectile(app.world_mut(), Team::Enemy, fortress, 500.0, &[fortress]);
 
         app.update();
 
-        // Friendly undamaged, projectile still alive
-        let hp = app.world().get::<Health>(friendly).unwrap();
-        assert_eq!(hp.current, 100.0);
-        assert_entity_count::<With<Projectile>>(&mut app, 1);
+        let health = app.world().get::<Health>(fortress).unwrap();
+        assert_eq!(health.current, FORTRESS_HP);
+        // The projectile is still used up
+        assert_entity_count::<With<Projectile>>(&mut app, 0);
+    }
+
+    // === Splash Tests ===
+
+    /// Gives `projectile` a splash radius and moves it to the impact point.
+    fn make_splash(world: &mut World, projectile: Entity, radius: f32, impact: Vec2) {
+        let mut entity = world.entity_mut(projectile);
+        entity.get_mut::<Projectile>().unwrap().splash_radius = radius;
+        entity.insert(Transform::from_translation(impact.extend(0.0)));
+    }
+
+    /// Targetable entity with 100 HP at `position`, registered in the spatial hash.
+    fn spawn_damageable(world: &mut World, team: Team, position: Vec2) -> Entity {
+        let entity = world
+            .spawn((
+                team,
+                crate::gameplay::Target,
+                Health::new(100.0),
+                GlobalTransform::from_translation(position.extend(0.0)),
+            ))
+            .id();
+        world
+            .resource_mut::<TargetSpatialHash>()
+            .insert(entity, position);
+        entity
     }
 
     #[test]
-    fn projectile_hits_non_target_enemy() {
+    fn splash_on_single_enemy_deals_full_damage() {
         let mut app = create_hit_test_app();
 
-        // Projectile aimed at enemy_far but collides with enemy_near (different entity)
-        let enemy_near = app
-            .world_mut()
-            .spawn((Team::Enemy, Health::new(100.0)))
-            .id();
-        let enemy_far = app
-            .world_mut()
-            .spawn((Team::Enemy, Health::new(100.0)))
-            .id();
-        spawn_test_projectile(
-            app.world_mut(),
-            Team::Player,
-            enemy_far,
-            25.0,
-            &[enemy_near],
-        );
+        let enemy = spawn_damageable(app.world_mut(), Team::Enemy, Vec2::ZERO);
+        let projectile =
+            spawn_test_projectile(app.world_mut(), Team::Player, enemy, 40.0, &[enemy]);
+        make_splash(app.world_mut(), projectile, 50.0, Vec2::ZERO);
 
         app.update();
 
-        // enemy_near takes damage (even though it wasn't the intended target)
-        let near_hp = app.world().get::<Health>(enemy_near).unwrap();
-        assert_eq!(near_hp.current, 75.0);
-        // enemy_far undamaged
-        let far_hp = app.world().get::<Health>(enemy_far).unwrap();
-        assert_eq!(far_hp.current, 100.0);
-        assert_entity_count::<With<Projectile>>(&mut app, 0);
+        assert_eq!(app.world().get::<Health>(enemy).unwrap().current, 60.0);
     }
 
     #[test]
-    fn projectile_no_collision_yet() {
+    fn splash_damages_clustered_enemies_with_falloff() {
         let mut app = create_hit_test_app();
 
-        let enemy = app
-            .world_mut()
-            .spawn((Team::Enemy, Health::new(100.0)))
-            .id();
-        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 25.0, &[]); // empty
+        let hit = spawn_damageable(app.world_mut(), Team::Enemy, Vec2::ZERO);
+        let nearby = spawn_damageable(app.world_mut(), Team::Enemy, Vec2::new(25.0, 0.0));
+        let outside = spawn_damageable(app.world_mut(), Team::Enemy, Vec2::new(80.0, 0.0));
+        let projectile = spawn_test_projectile(app.world_mut(), Team::Player, hit, 40.0, &[hit]);
+        make_splash(app.world_mut(), projectile, 50.0, Vec2::ZERO);
 
         app.update();
 
-        let health = app.world().get::<Health>(enemy).unwrap();
+        let world = app.world();
+        assert_eq!(world.get::<Health>(hit).unwrap().current, 60.0);
+        // Halfway out: 40 * (1 - 25/50) = 20
+        assert_eq!(world.get::<Health>(nearby).unwrap().current, 80.0);
+        assert_eq!(world.get::<Health>(outside).unwrap().current, 100.0);
+        assert_entity_count::<With<Projectile>>(&mut app, 0);
+    }
+
+    #[test]
+    fn splash_leaves_friendly_units_untouched() {
+        let mut app = create_hit_test_app();
+
+        let enemy = spawn_damageable(app.world_mut(), Team::Enemy, Vec2::ZERO);
+        let friend = spawn_damageable(app.world_mut(), Team::Player, Vec2::new(10.0, 0.0));
+        let projectile =
+            spawn_test_projectile(app.world_mut(), Team::Player, enemy, 40.0, &[enemy]);
+        make_splash(app.world_mut(), projectile, 50.0, Vec2::ZERO);
+
+        app.update();
+
+        assert_eq!(app.world().get::<Health>(friend).unwrap().current, 100.0);
+    }
+
+    #[test]
+    fn catapult_fires_splash_projectile_that_damages_cluster() {
+        use crate::gameplay::building::{BuildingType, Facing, building_stats, spawn_building};
+        use bevy::ecs::entity::hash_set::EntityHashSet;
+
+        let mut app = create_attack_test_app();
+        app.add_message::<HealthChanged>();
+        app.init_resource::<TargetSpatialHash>();
+        app.add_systems(Update, handle_projectile_hits.after(attack));
+
+        let world = app.world_mut();
+        let catapult = spawn_building(
+            &mut world.commands(),
+            BuildingType::Catapult,
+            0,
+            0,
+            Facing::East,
+        );
+        world.flush();
+        let origin = world.get::<Transform>(catapult).unwrap().translation.xy();
+        world
+            .entity_mut(catapult)
+            .insert(GlobalTransform::from_translation(origin.extend(0.0)));
+
+        let impact = origin + Vec2::new(100.0, 0.0);
+        let hit = spawn_damageable(world, Team::Enemy, impact);
+        let nearby = spawn_damageable(world, Team::Enemy, impact + Vec2::new(20.0, 0.0));
+        world
+            .entity_mut(hit)
+            .insert(crate::gameplay::EntityExtent::Circle(5.0));
+        world
+            .entity_mut(catapult)
+            .insert(TargetingState::Engaging(hit));
+        crate::testing::nearly_expire_timer(&mut world.get_mut::<AttackTimer>(catapult).unwrap().0);
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        let attack = building_stats(BuildingType::Catapult).attack.unwrap();
+        let mut projectiles = app.world_mut().query::<(Entity, &Projectile)>();
+        let (projectile, fired) = projectiles.single(app.world()).unwrap();
+        assert_eq!(fired.splash_radius, attack.splash_radius);
+        let nearby_damage = fired.splash_damage_at(20.0);
+
+        // Land the shot on `hit`
+        app.world_mut().entity_mut(projectile).insert((
+            Transform::from_translation(impact.extend(0.0)),
+            CollidingEntities(EntityHashSet::from_iter([hit])),
+        ));
+        app.update();
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<Health>(hit).unwrap().current,
+            100.0 - attack.damage
+        );
+        assert_eq!(
+            world.get::<Health>(nearby).unwrap().current,
+            100.0 - nearby_damage
+        );
+    }
+
+    #[test]
+    fn single_target_projectile_ignores_nearby_enemies() {
+        let mut app = create_hit_test_app();
+
+        let enemy = spawn_damageable(app.world_mut(), Team::Enemy, Vec2::ZERO);
+        let nearby = spawn_damageable(app.world_mut(), Team::Enemy, Vec2::new(5.0, 0.0));
+        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 40.0, &[enemy]);
+
+        app.update();
+
+        assert_eq!(app.world().get::<Health>(enemy).unwrap().current, 60.0);
+        assert_eq!(app.world().get::<Health>(nearby).unwrap().current, 100.0);
+    }
+
+    // === Armor Tests ===
+
+    #[test]
+    fn zero_armor_leaves_damage_unchanged() {
+        let mut app = create_hit_test_app();
+
+        let enemy = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0), Defense { armor: 0.0 }))
+            .id();
+        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 25.0, &[enemy]);
+
+        app.update();
+
+        let health = app.world().get::<Health>(enemy).unwrap();
+        assert_eq!(health.current, 75.0);
+    }
+
+    #[test]
+    fn armor_reduces_damage_per_hit() {
+        let mut app = create_hit_test_app();
+
+        let enemy = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0), Defense { armor: 10.0 }))
+            .id();
+        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 25.0, &[enemy]);
+
+        app.update();
+
+        let health = app.world().get::<Health>(enemy).unwrap();
+        assert_eq!(health.current, 85.0);
+    }
+
+    #[test]
+    fn high_armor_floors_damage_at_one() {
+        let mut app = create_hit_test_app();
+
+        let enemy = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0), Defense { armor: 500.0 }))
+            .id();
+        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 25.0, &[enemy]);
+
+        app.update();
+
+        let health = app.world().get::<Health>(enemy).unwrap();
+        assert_eq!(health.current, 99.0);
+    }
+
+    #[test]
+    fn target_without_defense_takes_full_damage_below_one() {
+        let mut app = create_hit_test_app();
+
+        let enemy = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0)))
+            .id();
+        // No `Defense` — no armor and no 1-damage floor either
+        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 0.5, &[enemy]);
+
+        app.update();
+
+        let health = app.world().get::<Health>(enemy).unwrap();
+        assert_eq!(health.current, 99.5);
+    }
+
+    // === Siege Bonus Tests ===
+
+    #[test]
+    fn siege_projectile_deals_bonus_damage_to_building() {
+        use crate::gameplay::building::BuildingType;
+
+        let mut app = create_hit_test_app();
+
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 0, 0);
+        app.world_mut()
+            .entity_mut(building)
+            .insert((Team::Player, Health::new(300.0)));
+        let projectile =
+            spawn_test_projectile(app.world_mut(), Team::Enemy, building, 20.0, &[building]);
+        app.world_mut()
+            .entity_mut(projectile)
+            .insert(SiegeBonus(3.0));
+
+        app.update();
+
+        let health = app.world().get::<Health>(building).unwrap();
+        assert_eq!(health.current, 240.0);
+    }
+
+    #[test]
+    fn siege_projectile_deals_bonus_damage_to_fortress() {
+        let mut app = create_hit_test_app();
+
+        let fortress = app
+            .world_mut()
+            .spawn((EnemyFortress, Team::Enemy, Health::new(1000.0)))
+            .id();
+        let projectile =
+            spawn_test_projectile(app.world_mut(), Team::Player, fortress, 20.0, &[fortress]);
+        app.world_mut()
+            .entity_mut(projectile)
+            .insert(SiegeBonus(3.0));
+
+        app.update();
+
+        let health = app.world().get::<Health>(fortress).unwrap();
+        assert_eq!(health.current, 940.0);
+    }
+
+    #[test]
+    fn siege_projectile_deals_normal_damage_to_unit() {
+        let mut app = create_hit_test_app();
+
+        let unit = app
+            .world_mut()
+            .spawn((Team::Player, Health::new(100.0)))
+            .id();
+        let projectile = spawn_test_projectile(app.world_mut(), Team::Enemy, unit, 20.0, &[unit]);
+        app.world_mut()
+            .entity_mut(projectile)
+            .insert(SiegeBonus(3.0));
+
+        app.update();
+
+        let health = app.world().get::<Health>(unit).unwrap();
+        assert_eq!(health.current, 80.0);
+    }
+
+    #[test]
+    fn siege_attacker_projectiles_carry_bonus() {
+        let mut app = create_attack_test_app();
+
+        let target = spawn_target(app.world_mut(), 114.0, 100.0);
+        let attacker = spawn_attacker(app.world_mut(), 100.0, Some(target));
+        app.world_mut().entity_mut(attacker).insert(SiegeBonus(2.0));
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        assert_entity_count::<(With<Projectile>, With<SiegeBonus>)>(&mut app, 1);
+    }
+
+    #[test]
+    fn projectile_despawns_on_hit() {
+        let mut app = create_hit_test_app();
+
+        let enemy = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0)))
+            .id();
+        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 10.0, &[enemy]);
+
+        app.update();
+
+        assert_entity_count::<With<Projectile>>(&mut app, 0);
+    }
+
+    #[test]
+    fn simultaneous_hits_on_one_target_all_apply() {
+        let mut app = create_hit_test_app();
+
+        let enemy = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0)))
+            .id();
+        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 25.0, &[enemy]);
+        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 30.0, &[enemy]);
+
+        app.update();
+
+        let health = app.world().get::<Health>(enemy).unwrap();
+        assert_eq!(health.current, 45.0);
+        assert_entity_count::<With<Projectile>>(&mut app, 0);
+    }
+
+    #[test]
+    fn simultaneous_overkill_hits_despawn_all_projectiles() {
+        let mut app = create_hit_test_app();
+
+        let enemy = app.world_mut().spawn((Team::Enemy, Health::new(20.0))).id();
+        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 25.0, &[enemy]);
+        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 25.0, &[enemy]);
+
+        app.update();
+
+        let health = app.world().get::<Health>(enemy).unwrap();
+        assert_eq!(health.current, 0.0);
+        assert_entity_count::<With<Projectile>>(&mut app, 0);
+    }
+
+    #[test]
+    fn projectile_with_dead_target_hitting_another_enemy_despawns_once() {
+        // Same ordering as the plugin: `move_projectiles` queues a despawn for the
+        // missing target, then `handle_projectile_hits` sees the live collision.
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<HealthChanged>();
+        app.add_systems(
+            Update,
+            (move_projectiles, handle_projectile_hits).chain_ignore_deferred(),
+        );
+        app.update();
+
+        let dead = app.world_mut().spawn(Team::Enemy).id();
+        let bystander = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0)))
+            .id();
+        spawn_test_projectile(app.world_mut(), Team::Player, dead, 10.0, &[bystander]);
+        app.world_mut().despawn(dead);
+
+        app.update();
+
+        assert_entity_count::<With<Projectile>>(&mut app, 0);
+        let health = app.world().get::<Health>(bystander).unwrap();
+        assert_eq!(health.current, 90.0);
+    }
+
+    /// Fire a knockback projectile from `origin` into an enemy body at (100, 100).
+    /// Returns the enemy's velocity after the hit.
+    fn velocity_after_knockback(body: RigidBody, origin: Vec2) -> Vec2 {
+        let mut app = create_hit_test_app();
+
+        let enemy = app
+            .world_mut()
+            .spawn((
+                Team::Enemy,
+                Health::new(100.0),
+                body,
+                LinearVelocity::ZERO,
+                GlobalTransform::from_xyz(100.0, 100.0, 0.0),
+            ))
+            .id();
+        let projectile =
+            spawn_test_projectile(app.world_mut(), Team::Player, enemy, 10.0, &[enemy]);
+        let mut projectile = app.world_mut().get_mut::<Projectile>(projectile).unwrap();
+        projectile.origin = origin;
+        projectile.knockback = 40.0;
+
+        app.update();
+
+        app.world().get::<LinearVelocity>(enemy).unwrap().0
+    }
+
+    #[test]
+    fn knockback_pushes_dynamic_unit_away_from_shooter() {
+        // Shooter to the west: the target is pushed east
+        let velocity = velocity_after_knockback(RigidBody::Dynamic, Vec2::new(40.0, 100.0));
+
+        assert!(velocity.x > 0.0, "expected eastward push, got {velocity:?}");
+        assert!((velocity.length() - 40.0).abs() < 1e-4, "got {velocity:?}");
+    }
+
+    #[test]
+    fn knockback_ignores_static_building() {
+        let velocity = velocity_after_knockback(RigidBody::Static, Vec2::new(40.0, 100.0));
+
+        assert_eq!(velocity, Vec2::ZERO);
+    }
+
+    #[test]
+    fn knockback_attacker_projectiles_carry_it() {
+        let mut app = create_attack_test_app();
+        let target = spawn_target(app.world_mut(), 114.0, 100.0);
+        let attacker = spawn_attacker(app.world_mut(), 100.0, Some(target));
+        app.world_mut().entity_mut(attacker).insert(Knockback(25.0));
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        let mut projectiles = app.world_mut().query::<&Projectile>();
+        let projectile = projectiles.single(app.world()).unwrap();
+        assert_eq!(projectile.knockback, 25.0);
+        assert_eq!(projectile.origin, Vec2::new(100.0, 100.0));
+    }
+
+    #[test]
+    fn incendiary_hit_sets_target_burning() {
+        let mut app = create_hit_test_app();
+
+        let enemy = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0)))
+            .id();
+        let projectile =
+            spawn_test_projectile(app.world_mut(), Team::Player, enemy, 10.0, &[enemy]);
+        app.world_mut().entity_mut(projectile).insert(Incendiary {
+            dps: 4.0,
+            duration: 3.0,
+        });
+
+        app.update();
+
+        assert_eq!(
+            app.world().get::<StatusEffects>(enemy).unwrap().0,
+            vec![StatusEffect::Burning {
+                dps: 4.0,
+                remaining: 3.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn plain_hit_does_not_set_target_burning() {
+        let mut app = create_hit_test_app();
+
+        let enemy = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0)))
+            .id();
+        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 10.0, &[enemy]);
+
+        app.update();
+
+        assert!(app.world().get::<StatusEffects>(enemy).is_none());
+    }
+
+    #[test]
+    fn incendiary_attacker_projectiles_carry_it() {
+        let mut app = create_attack_test_app();
+        let target = spawn_target(app.world_mut(), 114.0, 100.0);
+        let attacker = spawn_attacker(app.world_mut(), 100.0, Some(target));
+        app.world_mut().entity_mut(attacker).insert(Incendiary {
+            dps: 4.0,
+            duration: 3.0,
+        });
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        let mut projectiles = app
+            .world_mut()
+            .query_filtered::<&Incendiary, With<Projectile>>();
+        let incendiary = projectiles.single(app.world()).unwrap();
+        assert_eq!(incendiary.dps, 4.0);
+        assert_eq!(incendiary.duration, 3.0);
+    }
+
+    #[test]
+    fn projectile_does_not_friendly_fire() {
+        let mut app = create_hit_test_app();
+
+        // Player projectile collides with a friendly player unit
+        let friendly = app
+            .world_mut()
+            .spawn((Team::Player, Health::new(100.0)))
+            .id();
+        let dummy_target = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0)))
+            .id();
+        spawn_test_projectile(
+            app.world_mut(),
+            Team::Player,
+            dummy_target,
+            25.0,
+            &[friendly],
+        );
+
+        app.update();
+
+        // Friendly undamaged, projectile still alive
+        let hp = app.world().get::<Health>(friendly).unwrap();
+        assert_eq!(hp.current, 100.0);
+        assert_entity_count::<With<Projectile>>(&mut app, 1);
+    }
+
+    #[test]
+    fn projectile_hits_non_target_enemy() {
+        let mut app = create_hit_test_app();
+
+        // Projectile aimed at enemy_far but collides with enemy_near (different entity)
+        let enemy_near = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0)))
+            .id();
+        let enemy_far = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0)))
+            .id();
+        spawn_test_projectile(
+            app.world_mut(),
+            Team::Player,
+            enemy_far,
+            25.0,
+            &[enemy_near],
+        );
+
+        app.update();
+
+        // enemy_near takes damage (even though it wasn't the intended target)
+        let near_hp = app.world().get::<Health>(enemy_near).unwrap();
+        assert_eq!(near_hp.current, 75.0);
+        // enemy_far undamaged
+        let far_hp = app.world().get::<Health>(enemy_far).unwrap();
+        assert_eq!(far_hp.current, 100.0);
+        assert_entity_count::<With<Projectile>>(&mut app, 0);
+    }
+
+    #[test]
+    fn projectile_no_collision_yet() {
+        let mut app = create_hit_test_app();
+
+        let enemy = app
+            .world_mut()
+            .spawn((Team::Enemy, Health::new(100.0)))
+            .id();
+        spawn_test_projectile(app.world_mut(), Team::Player, enemy, 25.0, &[]); // empty
+
+        app.update();
+
+        let health = app.world().get::<Health>(enemy).unwrap();
         assert_eq!(health.current, 100.0);
         assert_entity_count::<With<Projectile>>(&mut app, 1);
     }
@@ -532,6 +1631,204 @@ mod integration_tests {
         assert_entity_count::<With<Projectile>>(&mut app, 1);
     }
 
+    #[test]
+    fn attacker_does_not_fire_outside_firing_arc() {
+        let mut app = create_attack_test_app();
+
+        let target = spawn_target(app.world_mut(), 114.0, 100.0);
+        let attacker = spawn_attacker(app.world_mut(), 100.0, Some(target));
+        // Target is east of the attacker; arc faces west
+        app.world_mut().entity_mut(attacker).insert(FiringArc {
+            direction: Vec2::NEG_X,
+            half_angle: std::f32::consts::FRAC_PI_4,
+        });
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        assert_entity_count::<With<Projectile>>(&mut app, 0);
+    }
+
+    #[test]
+    fn attacker_fires_inside_firing_arc() {
+        let mut app = create_attack_test_app();
+
+        let target = spawn_target(app.world_mut(), 114.0, 100.0);
+        let attacker = spawn_attacker(app.world_mut(), 100.0, Some(target));
+        app.world_mut().entity_mut(attacker).insert(FiringArc {
+            direction: Vec2::X,
+            half_angle: std::f32::consts::FRAC_PI_4,
+        });
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        assert_entity_count::<With<Projectile>>(&mut app, 1);
+    }
+
+    // === Contact Damage Tests ===
+
+    fn create_contact_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<HealthChanged>();
+        app.init_resource::<CombinedArms>();
+        app.add_systems(
+            Update,
+            (attack, apply_fortress_contact_damage).chain_ignore_deferred(),
+        );
+        app.update(); // Initialize time
+        app
+    }
+
+    /// Spawn a player fortress and a zero-range enemy touching it.
+    /// Returns `(fortress, enemy)`.
+    fn spawn_fortress_and_touching_enemy(world: &mut World) -> (Entity, Entity) {
+        use crate::gameplay::battlefield::FORTRESS_HP;
+        use bevy::ecs::entity::hash_set::EntityHashSet;
+
+        let fortress = world
+            .spawn((PlayerFortress, Team::Player, Health::new(FORTRESS_HP)))
+            .id();
+        let enemy = crate::testing::spawn_test_unit(world, Team::Enemy, 100.0, 100.0);
+        let mut entity = world.entity_mut(enemy);
+        entity.get_mut::<CombatStats>().unwrap().range = 0.0;
+        entity.insert((
+            TargetingState::Engaging(fortress),
+            CollidingEntities(EntityHashSet::from_iter([fortress])),
+        ));
+        crate::testing::nearly_expire_timer(&mut entity.get_mut::<AttackTimer>().unwrap().0);
+        (fortress, enemy)
+    }
+
+    #[test]
+    fn zero_range_enemy_in_contact_damages_fortress() {
+        use crate::gameplay::battlefield::FORTRESS_HP;
+
+        let mut app = create_contact_test_app();
+        let (fortress, enemy) = spawn_fortress_and_touching_enemy(app.world_mut());
+        let damage = app.world().get::<CombatStats>(enemy).unwrap().damage;
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        let health = app.world().get::<Health>(fortress).unwrap();
+        assert_eq!(health.current, FORTRESS_HP - damage);
+        // Contact damage replaces projectiles for zero-range attackers
+        assert_entity_count::<With<Projectile>>(&mut app, 0);
+    }
+
+    #[test]
+    fn siege_contact_damage_applies_bonus_to_fortress() {
+        use crate::gameplay::battlefield::FORTRESS_HP;
+
+        let mut app = create_contact_test_app();
+        let (fortress, enemy) = spawn_fortress_and_touching_enemy(app.world_mut());
+        app.world_mut().entity_mut(enemy).insert(SiegeBonus(2.0));
+        let damage = app.world().get::<CombatStats>(enemy).unwrap().damage;
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        let health = app.world().get::<Health>(fortress).unwrap();
+        assert_eq!(health.current, 2.0f32.mul_add(-damage, FORTRESS_HP));
+    }
+
+    #[test]
+    fn fortress_armor_reduces_contact_damage() {
+        use crate::gameplay::battlefield::FORTRESS_HP;
+
+        let mut app = create_contact_test_app();
+        let (fortress, enemy) = spawn_fortress_and_touching_enemy(app.world_mut());
+        app.world_mut()
+            .entity_mut(fortress)
+            .insert(Defense { armor: 4.0 });
+        let damage = app.world().get::<CombatStats>(enemy).unwrap().damage;
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        let health = app.world().get::<Health>(fortress).unwrap();
+        assert_eq!(health.current, FORTRESS_HP - (damage - 4.0));
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn invincible_fortress_ignores_contact_damage() {
+        use crate::gameplay::battlefield::FORTRESS_HP;
+
+        let mut app = create_contact_test_app();
+        let (fortress, _enemy) = spawn_fortress_and_touching_enemy(app.world_mut());
+        app.world_mut()
+            .entity_mut(fortress)
+            .insert(crate::dev_tools::Invincible);
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        let health = app.world().get::<Health>(fortress).unwrap();
+        assert_eq!(health.current, FORTRESS_HP);
+    }
+
+    #[test]
+    fn contact_damage_follows_attack_cadence() {
+        use crate::gameplay::battlefield::FORTRESS_HP;
+
+        let mut app = create_contact_test_app();
+        let (fortress, enemy) = spawn_fortress_and_touching_enemy(app.world_mut());
+        let damage = app.world().get::<CombatStats>(enemy).unwrap().damage;
+
+        // First hit fires immediately (timer nearly expired)
+        advance_and_update(&mut app, Duration::from_millis(100));
+        // Timer just restarted — still in contact, but no second hit yet
+        advance_and_update(&mut app, Duration::from_millis(100));
+        let health = app.world().get::<Health>(fortress).unwrap();
+        assert_eq!(health.current, FORTRESS_HP - damage);
+
+        // Timer fires again — second hit
+        crate::testing::nearly_expire_timer(
+            &mut app.world_mut().get_mut::<AttackTimer>(enemy).unwrap().0,
+        );
+        advance_and_update(&mut app, Duration::from_millis(100));
+        let health = app.world().get::<Health>(fortress).unwrap();
+        assert_eq!(health.current, 2.0f32.mul_add(-damage, FORTRESS_HP));
+    }
+
+    #[test]
+    fn contact_damage_hits_only_the_targeted_segment() {
+        use crate::gameplay::battlefield::FORTRESS_HP;
+
+        let mut app = create_contact_test_app();
+        let (fortress, enemy) = spawn_fortress_and_touching_enemy(app.world_mut());
+        let other = app
+            .world_mut()
+            .spawn((PlayerFortress, Team::Player, Health::new(FORTRESS_HP)))
+            .id();
+        app.world_mut()
+            .get_mut::<CollidingEntities>(enemy)
+            .unwrap()
+            .0
+            .insert(other);
+        let damage = app.world().get::<CombatStats>(enemy).unwrap().damage;
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        let health = app.world().get::<Health>(fortress).unwrap();
+        assert_eq!(health.current, FORTRESS_HP - damage);
+        let health = app.world().get::<Health>(other).unwrap();
+        assert_eq!(health.current, FORTRESS_HP);
+    }
+
+    #[test]
+    fn zero_range_enemy_not_in_contact_deals_no_damage() {
+        use crate::gameplay::battlefield::FORTRESS_HP;
+
+        let mut app = create_contact_test_app();
+        let (fortress, enemy) = spawn_fortress_and_touching_enemy(app.world_mut());
+        app.world_mut()
+            .entity_mut(enemy)
+            .insert(CollidingEntities::default());
+
+        advance_and_update(&mut app, Duration::from_millis(100));
+
+        let health = app.world().get::<Health>(fortress).unwrap();
+        assert_eq!(health.current, FORTRESS_HP);
+    }
+
     // NOTE: Tier 2 integration test with PhysicsPlugins was removed because avian2d's
     // FixedUpdate-based collision pipeline is unreliable under MinimalPlugins (wall-clock
     // time accumulation is non-deterministic). Collision layer wiring is verified by
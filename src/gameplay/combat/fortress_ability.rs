@@ -0,0 +1,199 @@
+//! Fortress ability: the player fortress blasts every enemy within a radius.
+//! Press B; then on cooldown.
+
+use bevy::prelude::*;
+
+use crate::gameplay::battlefield::{PlayerFortress, fortress_center};
+use crate::gameplay::{Health, HealthChanged, Team};
+use crate::screens::GameState;
+use crate::{GameSet, gameplay_running};
+
+/// Key that triggers the blast.
+pub const FORTRESS_ABILITY_KEY: KeyCode = KeyCode::KeyB;
+
+/// Damage dealt to each enemy caught in the blast.
+pub const FORTRESS_ABILITY_DAMAGE: f32 = 60.0;
+
+/// Blast radius (pixels) around the fortress center — 4 cells.
+pub const FORTRESS_ABILITY_RADIUS: f32 = 256.0;
+
+/// Seconds between blasts.
+pub const FORTRESS_ABILITY_COOLDOWN: f32 = 45.0;
+
+/// Time until the blast can be used again. Starts (and resets on entering
+/// `InGame`) ready.
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct FortressAbilityCooldown(pub Timer);
+
+impl Default for FortressAbilityCooldown {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(FORTRESS_ABILITY_COOLDOWN, TimerMode::Once);
+        let duration = timer.duration();
+        timer.tick(duration);
+        Self(timer)
+    }
+}
+
+/// Ticks the cooldown and, on a key press while it is ready, damages every
+/// `Team::Enemy` entity within `FORTRESS_ABILITY_RADIUS` of the player fortress's
+/// remaining segments. Does nothing once the fortress is destroyed.
+fn fire_fortress_ability(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut cooldown: ResMut<FortressAbilityCooldown>,
+    fortress: Query<&Transform, With<PlayerFortress>>,
+    mut targets: Query<(Entity, &Team, &Transform, &mut Health)>,
+    mut health_changes: MessageWriter<HealthChanged>,
+) {
+    cooldown.0.tick(time.delta());
+    if !keyboard.just_pressed(FORTRESS_ABILITY_KEY) || !cooldown.0.is_finished() {
+        return;
+    }
+
+    let Some(center) = fortress_center(&fortress) else {
+        return;
+    };
+    for (entity, team, transform, mut health) in &mut targets {
+        if *team == Team::Enemy
+            && transform.translation.xy().distance(center) <= FORTRESS_ABILITY_RADIUS
+        {
+            let new = (health.current - FORTRESS_ABILITY_DAMAGE).max(0.0);
+            health.set_current(entity, new, &mut health_changes);
+        }
+    }
+    cooldown.0.reset();
+}
+
+fn reset_fortress_ability(mut commands: Commands) {
+    commands.insert_resource(FortressAbilityCooldown::default());
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<FortressAbilityCooldown>()
+        .init_resource::<FortressAbilityCooldown>();
+
+    app.add_systems(OnEnter(GameState::InGame), reset_fortress_ability);
+    app.add_systems(
+        Update,
+        fire_fortress_ability
+            .in_set(GameSet::Input)
+            .run_if(gameplay_running),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::transition_to_ingame;
+    use pretty_assertions::assert_eq;
+
+    const FORTRESS_XY: Vec2 = Vec2::new(64.0, 320.0);
+
+    fn create_ability_test_app() -> App {
+        let mut app = crate::testing::create_base_test_app_no_input();
+        crate::testing::init_input_resources(&mut app);
+        app.add_plugins(plugin);
+        transition_to_ingame(&mut app);
+        app.world_mut().spawn((
+            PlayerFortress,
+            Team::Player,
+            Transform::from_translation(FORTRESS_XY.extend(0.0)),
+        ));
+        app
+    }
+
+    fn spawn_damageable(app: &mut App, team: Team, offset_x: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                team,
+                Health::new(100.0),
+                Transform::from_xyz(FORTRESS_XY.x + offset_x, FORTRESS_XY.y, 0.0),
+            ))
+            .id()
+    }
+
+    fn press_ability_key(app: &mut App) {
+        let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        input.release_all();
+        input.clear();
+        input.press(FORTRESS_ABILITY_KEY);
+        app.update();
+    }
+
+    fn health(app: &App, entity: Entity) -> f32 {
+        app.world().get::<Health>(entity).unwrap().current
+    }
+
+    #[test]
+    fn blast_damages_enemies_in_radius_only() {
+        let mut app = create_ability_test_app();
+        let near = spawn_damageable(&mut app, Team::Enemy, 100.0);
+        let edge = spawn_damageable(&mut app, Team::Enemy, FORTRESS_ABILITY_RADIUS);
+        let far = spawn_damageable(&mut app, Team::Enemy, FORTRESS_ABILITY_RADIUS + 50.0);
+
+        press_ability_key(&mut app);
+
+        let hit = 100.0 - FORTRESS_ABILITY_DAMAGE;
+        assert_eq!(health(&app, near), hit);
+        assert_eq!(health(&app, edge), hit);
+        assert_eq!(health(&app, far), 100.0);
+    }
+
+    #[test]
+    fn cooldown_blocks_immediate_second_use() {
+        let mut app = create_ability_test_app();
+        let enemy = spawn_damageable(&mut app, Team::Enemy, 100.0);
+
+        press_ability_key(&mut app);
+        press_ability_key(&mut app);
+
+        assert_eq!(health(&app, enemy), 100.0 - FORTRESS_ABILITY_DAMAGE);
+    }
+
+    #[test]
+    fn blast_is_ready_again_after_cooldown() {
+        let mut app = create_ability_test_app();
+        let enemy = spawn_damageable(&mut app, Team::Enemy, 100.0);
+
+        press_ability_key(&mut app);
+        crate::testing::nearly_expire_timer(
+            &mut app.world_mut().resource_mut::<FortressAbilityCooldown>().0,
+        );
+        press_ability_key(&mut app);
+
+        assert_eq!(health(&app, enemy), 100.0 - 2.0 * FORTRESS_ABILITY_DAMAGE);
+    }
+
+    #[test]
+    fn blast_leaves_friendly_units_unharmed() {
+        let mut app = create_ability_test_app();
+        let friendly = spawn_damageable(&mut app, Team::Player, 50.0);
+
+        press_ability_key(&mut app);
+
+        assert_eq!(health(&app, friendly), 100.0);
+    }
+
+    #[test]
+    fn entering_ingame_makes_blast_ready() {
+        let mut app = create_ability_test_app();
+        app.world_mut()
+            .resource_mut::<FortressAbilityCooldown>()
+            .0
+            .reset();
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::MainMenu);
+        app.update();
+        transition_to_ingame(&mut app);
+
+        assert!(
+            app.world()
+                .resource::<FortressAbilityCooldown>()
+                .0
+                .is_finished()
+        );
+    }
+}
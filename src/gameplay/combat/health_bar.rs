@@ -18,6 +18,10 @@ pub const UNIT_HEALTH_BAR_HEIGHT: f32 = 2.0;
 /// Default health bar Y offset for units (pixels above center).
 pub const UNIT_HEALTH_BAR_Y_OFFSET: f32 = 10.0;
 
+/// Orthographic scale above which health bars are hidden.
+/// Past 2x zoom-out a unit bar is ~5 screen pixels wide — noise, not information.
+const HEALTH_BAR_HIDE_SCALE: f32 = 2.0;
+
 // === Components ===
 
 /// Marker: red background bar (full width, shows "missing" HP).
@@ -25,7 +29,7 @@ pub const UNIT_HEALTH_BAR_Y_OFFSET: f32 = 10.0;
 #[reflect(Component)]
 pub struct HealthBarBackground;
 
-/// Marker: green foreground bar (scales with current/max HP).
+/// Marker: foreground bar (scales with current/max HP, green to red as it drains).
 #[derive(Component, Debug, Clone, Copy, Reflect)]
 #[reflect(Component)]
 pub struct HealthBarFill;
@@ -39,6 +43,28 @@ pub struct HealthBarConfig {
     pub y_offset: f32,
 }
 
+// === Helpers ===
+
+/// Fill color for an HP `ratio`: green when full, yellow at half, red near death.
+#[must_use]
+pub fn health_bar_color(ratio: f32) -> Color {
+    let ratio = ratio.clamp(0.0, 1.0);
+    let (from, to, t) = if ratio >= 0.5 {
+        (
+            palette::HEALTH_BAR_FILL_HALF,
+            palette::HEALTH_BAR_FILL,
+            ratio.mul_add(2.0, -1.0),
+        )
+    } else {
+        (
+            palette::HEALTH_BAR_FILL_LOW,
+            palette::HEALTH_BAR_FILL_HALF,
+            ratio * 2.0,
+        )
+    };
+    Srgba::from(from).mix(&Srgba::from(to), t).into()
+}
+
 // === Systems ===
 
 /// Spawns health bar child entities when `Health` is added to an entity with `HealthBarConfig`.
@@ -74,16 +100,17 @@ fn spawn_health_bars(
     });
 }
 
-/// Updates health bar fill width based on current/max HP.
+/// Updates health bar fill width and color based on current/max HP.
 /// Runs in `GameSet::Ui`.
 fn update_health_bars(
     health_query: Query<(&Health, &Children, &HealthBarConfig), Changed<Health>>,
-    mut bar_query: Query<&mut Transform, With<HealthBarFill>>,
+    mut bar_query: Query<(&mut Transform, &mut Sprite), With<HealthBarFill>>,
 ) {
     for (health, children, config) in &health_query {
         let ratio = (health.current / health.max).clamp(0.0, 1.0);
         for child in children.iter() {
-            if let Ok(mut transform) = bar_query.get_mut(child) {
+            if let Ok((mut transform, mut sprite)) = bar_query.get_mut(child) {
+                sprite.color = health_bar_color(ratio);
                 transform.scale.x = ratio;
                 // Shift left to keep bar left-aligned as it shrinks
                 transform.translation.x = config.width.mul_add(-(1.0 - ratio), 0.0) / 2.0;
@@ -92,6 +119,26 @@ fn update_health_bars(
     }
 }
 
+/// Hides health bars when the camera is zoomed out past `HEALTH_BAR_HIDE_SCALE`
+/// and re-shows them when zoomed back in.
+/// Runs in `GameSet::Ui`.
+fn update_health_bar_visibility(
+    camera: Single<&Projection, With<Camera2d>>,
+    mut bars: Query<&mut Visibility, Or<(With<HealthBarBackground>, With<HealthBarFill>)>>,
+) {
+    let Projection::Orthographic(ortho) = *camera else {
+        return;
+    };
+    let target = if ortho.scale > HEALTH_BAR_HIDE_SCALE {
+        Visibility::Hidden
+    } else {
+        Visibility::Inherited
+    };
+    for mut visibility in &mut bars {
+        visibility.set_if_neq(target);
+    }
+}
+
 // === Plugin ===
 
 pub(super) fn plugin(app: &mut App) {
@@ -105,7 +152,7 @@ pub(super) fn plugin(app: &mut App) {
     // System: update health bar fill each frame (no longer needs chain)
     app.add_systems(
         Update,
-        update_health_bars
+        (update_health_bars, update_health_bar_visibility)
             .in_set(GameSet::Ui)
             .run_if(gameplay_running),
     );
@@ -121,6 +168,7 @@ mod tests {
         assert!(UNIT_HEALTH_BAR_WIDTH > 0.0);
         assert!(UNIT_HEALTH_BAR_HEIGHT > 0.0);
         assert!(UNIT_HEALTH_BAR_Y_OFFSET > 0.0);
+        assert!(HEALTH_BAR_HIDE_SCALE > 1.0);
     }
 }
 
@@ -128,6 +176,7 @@ mod tests {
 mod integration_tests {
     use super::*;
     use crate::testing::assert_entity_count;
+    use pretty_assertions::assert_eq;
 
     fn create_health_bar_test_app() -> App {
         let mut app = App::new();
@@ -185,6 +234,48 @@ mod integration_tests {
         );
     }
 
+    /// Health bar fill color (sRGB) after damaging a fresh bar to `current` of 100 HP.
+    fn fill_color_at(current: f32) -> Srgba {
+        let mut app = create_health_bar_test_app();
+        let entity = app
+            .world_mut()
+            .spawn((Health::new(100.0), unit_health_bar_config()))
+            .id();
+        app.update(); // spawn health bars
+        app.update(); // apply deferred
+
+        app.world_mut().get_mut::<Health>(entity).unwrap().current = current;
+        app.update(); // update_health_bars
+
+        let mut bar_query = app
+            .world_mut()
+            .query_filtered::<&Sprite, With<HealthBarFill>>();
+        bar_query.single(app.world()).unwrap().color.to_srgba()
+    }
+
+    #[test]
+    fn health_bar_fill_is_green_at_full_health() {
+        let color = fill_color_at(100.0);
+        assert!(color.green > 0.8, "{color:?}");
+        assert!(color.red < 0.2, "{color:?}");
+    }
+
+    #[test]
+    fn health_bar_fill_is_yellow_at_half_health() {
+        let color = fill_color_at(50.0);
+        assert!(color.red > 0.8, "{color:?}");
+        assert!(color.green > 0.7, "{color:?}");
+        assert!(color.blue < 0.3, "{color:?}");
+    }
+
+    #[test]
+    fn health_bar_fill_is_red_near_death() {
+        let color = fill_color_at(2.0);
+        assert!(color.red > 0.8, "{color:?}");
+        assert!(color.green < 0.3, "{color:?}");
+        assert!(color.blue < 0.3, "{color:?}");
+    }
+
     #[test]
     fn health_bar_despawned_with_parent() {
         let mut app = create_health_bar_test_app();
@@ -259,4 +350,68 @@ mod integration_tests {
             bar_transform.translation.x
         );
     }
+
+    // === Zoom LOD Tests ===
+
+    fn create_visibility_test_app() -> (App, Entity) {
+        let mut app = create_health_bar_test_app();
+        app.add_systems(Update, update_health_bar_visibility);
+        let camera = app
+            .world_mut()
+            .spawn((
+                Camera2d,
+                Projection::Orthographic(OrthographicProjection::default_2d()),
+            ))
+            .id();
+        app.world_mut()
+            .spawn((Health::new(100.0), unit_health_bar_config()));
+        app.update(); // spawn health bars
+        app.update(); // apply deferred
+        (app, camera)
+    }
+
+    fn set_camera_scale(app: &mut App, camera: Entity, scale: f32) {
+        let mut projection = app.world_mut().get_mut::<Projection>(camera).unwrap();
+        if let Projection::Orthographic(ref mut ortho) = *projection {
+            ortho.scale = scale;
+        }
+    }
+
+    fn hidden_bar_count(app: &mut App) -> usize {
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&Visibility, Or<(With<HealthBarBackground>, With<HealthBarFill>)>>();
+        query
+            .iter(app.world())
+            .filter(|v| **v == Visibility::Hidden)
+            .count()
+    }
+
+    #[test]
+    fn health_bars_visible_at_default_zoom() {
+        let (mut app, _camera) = create_visibility_test_app();
+        assert_eq!(hidden_bar_count(&mut app), 0);
+    }
+
+    #[test]
+    fn health_bars_hidden_beyond_zoom_threshold() {
+        let (mut app, camera) = create_visibility_test_app();
+
+        set_camera_scale(&mut app, camera, HEALTH_BAR_HIDE_SCALE + 0.5);
+        app.update();
+
+        assert_eq!(hidden_bar_count(&mut app), 2);
+    }
+
+    #[test]
+    fn health_bars_reshown_when_zoomed_back_in() {
+        let (mut app, camera) = create_visibility_test_app();
+
+        set_camera_scale(&mut app, camera, HEALTH_BAR_HIDE_SCALE + 0.5);
+        app.update();
+        set_camera_scale(&mut app, camera, 1.0);
+        app.update();
+
+        assert_eq!(hidden_bar_count(&mut app), 0);
+    }
 }
@@ -1,21 +1,32 @@
-//! Combat systems: attack mechanics, death detection, and health bars.
+//! Combat systems: attack mechanics, the fortress ability, status effects, health regen,
+//! death detection, and health bars.
 
 mod attack;
 mod death;
+mod fortress_ability;
 mod health_bar;
+mod regen;
+mod status;
 
 #[allow(unused_imports)]
 // Hitbox re-exported for external use (currently only used within combat)
-pub use attack::{AttackTimer, Hitbox};
+pub use attack::{AttackTimer, Hitbox, LastHitBy, MeleeAttacker, Projectile};
 pub use death::DeathCheck;
+#[cfg(test)]
+pub use health_bar::HealthBarBackground;
 pub use health_bar::{
     HealthBarConfig, UNIT_HEALTH_BAR_HEIGHT, UNIT_HEALTH_BAR_WIDTH, UNIT_HEALTH_BAR_Y_OFFSET,
 };
+pub use regen::{HealthRegen, LastDamagedAt};
+pub use status::{StatusEffect, StatusEffects};
 
 use bevy::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
     attack::plugin(app);
     death::plugin(app);
+    fortress_ability::plugin(app);
     health_bar::plugin(app);
+    regen::plugin(app);
+    status::plugin(app);
 }
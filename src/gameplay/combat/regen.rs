@@ -0,0 +1,189 @@
+//! Health regeneration for entities that have gone a while without taking damage.
+
+use bevy::prelude::*;
+
+use crate::gameplay::{Health, HealthChanged};
+use crate::{GameSet, gameplay_running};
+
+/// Heals `per_sec` health per second once `delay` seconds have passed since the
+/// entity last lost health. Never heals past `Health::max` or revives at zero.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+#[require(LastDamagedAt)]
+pub struct HealthRegen {
+    pub per_sec: f32,
+    pub delay: f32,
+}
+
+/// When the entity last lost health (virtual-time seconds). Any drop below
+/// `seen_health` counts as damage, whatever its source (hits, contact, burning).
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct LastDamagedAt {
+    pub secs: f32,
+    /// Health as of the previous check.
+    pub seen_health: f32,
+}
+
+/// Stamps `LastDamagedAt` on any health drop, otherwise heals entities whose
+/// regen delay has elapsed. Runs in `GameSet::Combat`; damage dealt later in the
+/// frame is picked up on the next one.
+fn regenerate_health(
+    time: Res<Time>,
+    mut regenerating: Query<(Entity, &HealthRegen, &mut LastDamagedAt, &mut Health)>,
+    mut health_changes: MessageWriter<HealthChanged>,
+) {
+    let now = time.elapsed_secs();
+    for (entity, regen, mut last_damaged, mut health) in &mut regenerating {
+        if health.current < last_damaged.seen_health {
+            last_damaged.secs = now;
+        } else if health.current > 0.0
+            && health.current < health.max
+            && now - last_damaged.secs >= regen.delay
+        {
+            let new = regen
+                .per_sec
+                .mul_add(time.delta_secs(), health.current)
+                .min(health.max);
+            health.set_current(entity, new, &mut health_changes);
+        }
+        last_damaged.seen_health = health.current;
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<HealthRegen>()
+        .register_type::<LastDamagedAt>();
+
+    app.add_systems(
+        Update,
+        regenerate_health
+            .in_set(GameSet::Combat)
+            .run_if(gameplay_running),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    /// Time advanced per `app.update()`.
+    const FRAME: Duration = Duration::from_millis(500);
+
+    const REGEN: HealthRegen = HealthRegen {
+        per_sec: 2.0,
+        delay: 5.0,
+    };
+
+    fn create_regen_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+        app.add_message::<HealthChanged>();
+        app.add_systems(Update, regenerate_health);
+        app.update(); // Initialize time
+        app
+    }
+
+    fn run_frames(app: &mut App, frames: u32) {
+        for _ in 0..frames {
+            app.update();
+        }
+    }
+
+    /// Spawn a regenerating entity at `current` of 100 max health.
+    fn spawn_regenerating(app: &mut App, current: f32) -> Entity {
+        let entity = app
+            .world_mut()
+            .spawn((
+                REGEN,
+                Health {
+                    current,
+                    max: 100.0,
+                },
+            ))
+            .id();
+        app.update(); // Record starting health
+        entity
+    }
+
+    fn damage(app: &mut App, entity: Entity, amount: f32) {
+        app.world_mut().get_mut::<Health>(entity).unwrap().current -= amount;
+    }
+
+    fn health(app: &App, entity: Entity) -> f32 {
+        app.world().get::<Health>(entity).unwrap().current
+    }
+
+    #[test]
+    fn recently_damaged_entity_does_not_regen() {
+        let mut app = create_regen_test_app();
+        let entity = spawn_regenerating(&mut app, 100.0);
+        run_frames(&mut app, 12); // Well past the delay since spawn
+
+        damage(&mut app, entity, 20.0);
+        run_frames(&mut app, 8); // 4s: still inside the 5s delay
+
+        assert_eq!(health(&app, entity), 80.0);
+    }
+
+    #[test]
+    fn entity_past_cooldown_regenerates() {
+        let mut app = create_regen_test_app();
+        let entity = spawn_regenerating(&mut app, 100.0);
+
+        damage(&mut app, entity, 20.0);
+        run_frames(&mut app, 14); // 7s: regen for the last ~2s
+
+        let healed = health(&app, entity);
+        assert!(healed > 80.0, "expected regen, got {healed}");
+        assert!(healed < 100.0, "regen should be gradual, got {healed}");
+    }
+
+    #[test]
+    fn regen_reports_health_change() {
+        let mut app = create_regen_test_app();
+        let entity = spawn_regenerating(&mut app, 100.0);
+        damage(&mut app, entity, 20.0);
+        run_frames(&mut app, 11); // 5.5s: the delay has just elapsed
+
+        let before = health(&app, entity);
+        app.update();
+
+        let messages = app.world().resource::<Messages<HealthChanged>>();
+        let changes: Vec<HealthChanged> =
+            messages.iter_current_update_messages().copied().collect();
+        assert_eq!(
+            changes,
+            vec![HealthChanged {
+                entity,
+                old: before,
+                new: health(&app, entity),
+            }]
+        );
+        assert!(changes[0].new > changes[0].old);
+    }
+
+    #[test]
+    fn regen_caps_at_max_health() {
+        let mut app = create_regen_test_app();
+        let entity = spawn_regenerating(&mut app, 99.5);
+
+        run_frames(&mut app, 20);
+
+        assert_eq!(health(&app, entity), 100.0);
+    }
+
+    #[test]
+    fn dead_entity_stays_dead() {
+        let mut app = create_regen_test_app();
+        let entity = spawn_regenerating(&mut app, 0.0);
+
+        run_frames(&mut app, 20);
+
+        assert_eq!(health(&app, entity), 0.0);
+    }
+}
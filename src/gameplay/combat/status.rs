@@ -0,0 +1,171 @@
+//! Status effects: lingering conditions applied by hits, ticked every frame.
+
+use bevy::prelude::*;
+
+use crate::gameplay::{Health, HealthChanged};
+use crate::{GameSet, gameplay_running};
+
+/// A timed condition on an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum StatusEffect {
+    /// Loses `dps` health per second for `remaining` more seconds.
+    Burning { dps: f32, remaining: f32 },
+}
+
+impl StatusEffect {
+    /// Seconds left before the effect wears off.
+    #[must_use]
+    pub const fn remaining(&self) -> f32 {
+        match *self {
+            Self::Burning { remaining, .. } => remaining,
+        }
+    }
+
+    /// Whether `self` and `other` are the same kind of effect.
+    const fn same_kind(&self, other: &Self) -> bool {
+        matches!((self, other), (Self::Burning { .. }, Self::Burning { .. }))
+    }
+}
+
+/// Active status effects on an entity. At most one effect of each kind.
+#[derive(Component, Debug, Clone, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct StatusEffects(pub Vec<StatusEffect>);
+
+impl StatusEffects {
+    /// Add `effect`, replacing an existing effect of the same kind. Re-igniting
+    /// a burning target refreshes the burn instead of stacking it.
+    pub fn apply(&mut self, effect: StatusEffect) {
+        if let Some(existing) = self.0.iter_mut().find(|e| e.same_kind(&effect)) {
+            *existing = effect;
+        } else {
+            self.0.push(effect);
+        }
+    }
+}
+
+/// Applies each effect for this frame (capped at its remaining time, so the
+/// total over the duration is exact) and drops effects that have worn off.
+/// Runs in `GameSet::Combat`.
+fn tick_status_effects(
+    time: Res<Time>,
+    mut affected: Query<(Entity, &mut StatusEffects, &mut Health)>,
+    mut health_changes: MessageWriter<HealthChanged>,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut effects, mut health) in &mut affected {
+        for effect in &mut effects.0 {
+            match effect {
+                StatusEffect::Burning { dps, remaining } => {
+                    let step = delta.min(*remaining);
+                    let new = dps.mul_add(-step, health.current).max(0.0);
+                    health.set_current(entity, new, &mut health_changes);
+                    *remaining -= step;
+                }
+            }
+        }
+        effects.0.retain(|effect| effect.remaining() > 0.0);
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<StatusEffects>();
+
+    app.add_systems(
+        Update,
+        tick_status_effects
+            .in_set(GameSet::Combat)
+            .run_if(gameplay_running),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    /// Time advanced per `app.update()`.
+    const FRAME: Duration = Duration::from_millis(250);
+
+    fn create_status_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+        app.add_message::<HealthChanged>();
+        app.add_systems(Update, tick_status_effects);
+        app.update(); // Initialize time
+        app
+    }
+
+    fn run_frames(app: &mut App, frames: u32) {
+        for _ in 0..frames {
+            app.update();
+        }
+    }
+
+    fn spawn_burning(app: &mut App, dps: f32, remaining: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Health::new(100.0),
+                StatusEffects(vec![StatusEffect::Burning { dps, remaining }]),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn burning_deals_dps_times_duration_then_wears_off() {
+        let mut app = create_status_test_app();
+        let entity = spawn_burning(&mut app, 4.0, 3.0);
+
+        // 3.5s of frames: the last two find nothing left to burn
+        run_frames(&mut app, 14);
+
+        let health = app.world().get::<Health>(entity).unwrap();
+        assert!(
+            (health.current - 88.0).abs() < 1e-3,
+            "expected 12 total burn damage, got {}",
+            100.0 - health.current
+        );
+        assert_eq!(
+            app.world().get::<StatusEffects>(entity).unwrap().0,
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn burning_is_partial_mid_duration() {
+        let mut app = create_status_test_app();
+        let entity = spawn_burning(&mut app, 4.0, 3.0);
+
+        run_frames(&mut app, 4);
+
+        let health = app.world().get::<Health>(entity).unwrap();
+        assert!((health.current - 96.0).abs() < 1e-3);
+        let effects = &app.world().get::<StatusEffects>(entity).unwrap().0;
+        assert_eq!(effects.len(), 1);
+        assert!((effects[0].remaining() - 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn reapplying_burn_refreshes_instead_of_stacking() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusEffect::Burning {
+            dps: 4.0,
+            remaining: 1.0,
+        });
+        effects.apply(StatusEffect::Burning {
+            dps: 4.0,
+            remaining: 3.0,
+        });
+
+        assert_eq!(
+            effects.0,
+            vec![StatusEffect::Burning {
+                dps: 4.0,
+                remaining: 3.0,
+            }]
+        );
+    }
+}
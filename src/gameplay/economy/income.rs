@@ -1,10 +1,15 @@
-//! Income systems: farm income and kill rewards.
+//! Income systems: farm income, kill rewards, wave-clear bonuses, and interest on
+//! banked gold.
 
 use bevy::prelude::*;
 
-use super::Gold;
-use crate::gameplay::combat::DeathCheck;
-use crate::gameplay::{Health, Team};
+use super::{Gold, RunStats};
+use crate::gameplay::Health;
+use crate::gameplay::battlefield::PlayerFortress;
+use crate::gameplay::combat::{DeathCheck, LastHitBy};
+use crate::gameplay::units::spawn::WaveCleared;
+use crate::gameplay::units::{EnemyUnit, PlayerUnit};
+use crate::screens::GameState;
 use crate::{GameSet, gameplay_running};
 
 // === Components ===
@@ -14,44 +19,193 @@ use crate::{GameSet, gameplay_running};
 #[reflect(Component)]
 pub struct IncomeTimer(pub Timer);
 
+// === Resources ===
+
+/// Consecutive enemy kills. Each kill restarts the window; when it runs out
+/// without a kill, the streak drops back to zero.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct KillStreak {
+    pub count: u32,
+    pub window: Timer,
+}
+
+impl Default for KillStreak {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            window: Timer::from_seconds(super::KILL_STREAK_WINDOW_SECS, TimerMode::Once),
+        }
+    }
+}
+
+impl KillStreak {
+    /// Multiplier applied to `KILL_REWARD` for the current streak length.
+    #[must_use]
+    pub fn multiplier(&self) -> u32 {
+        self.count.clamp(1, super::KILL_STREAK_MAX_MULTIPLIER)
+    }
+}
+
+/// Periodic interest on banked gold. Each time `timer` fires the player earns
+/// `rate` gold per full 100 gold held, up to `cap`.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct InterestConfig {
+    /// Interest gold per full 100 gold banked (10 = 10%).
+    pub rate: u32,
+    /// Most gold a single payout can grant.
+    pub cap: u32,
+    /// Payout cadence.
+    pub timer: Timer,
+}
+
+impl Default for InterestConfig {
+    fn default() -> Self {
+        Self {
+            rate: super::INTEREST_RATE,
+            cap: super::INTEREST_CAP,
+            timer: Timer::from_seconds(super::INTEREST_INTERVAL_SECS, TimerMode::Repeating),
+        }
+    }
+}
+
+impl InterestConfig {
+    /// Interest paid on `gold` banked. Partial hundreds earn nothing.
+    #[must_use]
+    pub const fn interest_on(&self, gold: u32) -> u32 {
+        (gold / 100).saturating_mul(self.rate).min(self.cap)
+    }
+}
+
 // === Systems ===
 
 /// Ticks income timers and adds gold when they fire.
 /// Runs in `GameSet::Production`.
-fn tick_farm_income(time: Res<Time>, mut farms: Query<&mut IncomeTimer>, mut gold: ResMut<Gold>) {
+fn tick_farm_income(
+    time: Res<Time>,
+    mut farms: Query<&mut IncomeTimer>,
+    mut gold: ResMut<Gold>,
+    mut run_stats: ResMut<RunStats>,
+) {
     for mut timer in &mut farms {
         timer.0.tick(time.delta());
         if timer.0.just_finished() {
             gold.0 += super::FARM_INCOME_PER_TICK;
+            run_stats.record_earnings(super::FARM_INCOME_PER_TICK);
+        }
+    }
+}
+
+/// Pays interest on banked gold each time the interest timer fires.
+/// Runs in `GameSet::Production`.
+fn pay_interest(
+    time: Res<Time>,
+    mut interest: ResMut<InterestConfig>,
+    mut gold: ResMut<Gold>,
+    mut run_stats: ResMut<RunStats>,
+) {
+    interest.timer.tick(time.delta());
+    if interest.timer.just_finished() {
+        let payout = interest.interest_on(gold.0);
+        gold.0 += payout;
+        run_stats.record_earnings(payout);
+    }
+}
+
+/// Pays `wave_clear_bonus` for each cleared wave while the player fortress still
+/// stands (any segment above 0 HP). Runs in `GameSet::Production`.
+fn pay_wave_clear_bonus(
+    mut cleared: MessageReader<WaveCleared>,
+    fortress: Query<&Health, With<PlayerFortress>>,
+    mut gold: ResMut<Gold>,
+    mut run_stats: ResMut<RunStats>,
+) {
+    let fortress_stands = fortress.iter().any(|health| health.current > 0.0);
+    for WaveCleared { wave } in cleared.read() {
+        if fortress_stands {
+            let bonus = super::wave_clear_bonus(*wave);
+            gold.0 += bonus;
+            run_stats.record_earnings(bonus);
         }
     }
 }
 
-/// Awards gold for each enemy that is about to die (Health <= 0).
+/// Awards gold for each enemy unit that is about to die (Health <= 0), scaled by
+/// the current kill streak. Enemy fortress segments are not kills and pay nothing.
 /// Runs in `GameSet::Death` BEFORE `check_death` so entities still exist.
-fn award_kill_gold(mut gold: ResMut<Gold>, query: Query<(&Health, &Team)>) {
-    for (health, team) in &query {
-        if health.current <= 0.0 && *team == Team::Enemy {
-            gold.0 += super::KILL_REWARD;
+fn award_kill_gold(
+    time: Res<Time>,
+    mut gold: ResMut<Gold>,
+    mut streak: ResMut<KillStreak>,
+    mut run_stats: ResMut<RunStats>,
+    query: Query<&Health, With<EnemyUnit>>,
+) {
+    streak.window.tick(time.delta());
+    if streak.window.is_finished() {
+        streak.count = 0;
+    }
+
+    for health in &query {
+        if health.current <= 0.0 {
+            streak.count = streak.count.saturating_add(1);
+            streak.window.reset();
+            run_stats.record_kill();
+            let reward = super::KILL_REWARD * streak.multiplier();
+            gold.0 += reward;
+            run_stats.record_earnings(reward);
         }
     }
 }
 
+/// Credits each dying enemy unit whose killing blow came from a player unit,
+/// valued at the base `KILL_REWARD`, for the run's trade ratio.
+/// Runs in `GameSet::Death` BEFORE `check_death` so entities still exist.
+fn record_unit_kills(
+    mut run_stats: ResMut<RunStats>,
+    dying: Query<(&Health, &LastHitBy), With<EnemyUnit>>,
+    player_units: Query<(), With<PlayerUnit>>,
+) {
+    for (health, last_hit) in &dying {
+        if health.current <= 0.0 && player_units.contains(last_hit.0) {
+            run_stats.record_unit_kill(super::KILL_REWARD);
+        }
+    }
+}
+
+fn reset_kill_streak(mut commands: Commands) {
+    commands.insert_resource(KillStreak::default());
+}
+
+/// Restarts the interest timer for a new run, keeping the configured rate and cap.
+fn reset_interest_timer(mut interest: ResMut<InterestConfig>) {
+    interest.timer.reset();
+}
+
 // === Plugin ===
 
 pub(super) fn plugin(app: &mut App) {
-    app.register_type::<IncomeTimer>();
+    app.register_type::<IncomeTimer>()
+        .register_type::<KillStreak>()
+        .register_type::<InterestConfig>()
+        .init_resource::<KillStreak>()
+        .init_resource::<InterestConfig>();
+
+    app.add_systems(
+        OnEnter(GameState::InGame),
+        (reset_kill_streak, reset_interest_timer),
+    );
 
     app.add_systems(
         Update,
-        tick_farm_income
+        (tick_farm_income, pay_interest, pay_wave_clear_bonus)
             .in_set(GameSet::Production)
             .run_if(gameplay_running),
     );
 
     app.add_systems(
         Update,
-        award_kill_gold
+        (award_kill_gold, record_unit_kills)
             .in_set(GameSet::Death)
             .before(DeathCheck)
             .run_if(gameplay_running),
@@ -61,6 +215,7 @@ pub(super) fn plugin(app: &mut App) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gameplay::Team;
     use pretty_assertions::assert_eq;
 
     // === Farm Income Tests ===
@@ -69,6 +224,7 @@ mod tests {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.init_resource::<Gold>();
+        app.init_resource::<RunStats>();
         app.add_systems(Update, tick_farm_income);
         app.update(); // Initialize time (first frame delta=0)
         app
@@ -95,6 +251,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn farm_income_records_earnings_in_run_stats() {
+        let mut app = create_farm_income_test_app();
+
+        app.world_mut().spawn(nearly_elapsed_income_timer());
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<RunStats>().gold_earned,
+            super::super::FARM_INCOME_PER_TICK
+        );
+    }
+
     #[test]
     fn multiple_farms_add_gold_independently() {
         let mut app = create_farm_income_test_app();
@@ -120,16 +289,139 @@ mod tests {
         assert_eq!(gold.0, super::super::STARTING_GOLD);
     }
 
+    // === Wave Clear Bonus Tests ===
+
+    fn create_wave_bonus_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<Gold>();
+        app.init_resource::<RunStats>();
+        app.add_message::<WaveCleared>();
+        app.add_systems(Update, pay_wave_clear_bonus);
+        app
+    }
+
+    fn spawn_fortress_segment(app: &mut App, hp: f32) {
+        let mut health = Health::new(100.0);
+        health.current = hp;
+        app.world_mut().spawn((PlayerFortress, health));
+    }
+
+    #[test]
+    fn clearing_wave_three_credits_bonus() {
+        let mut app = create_wave_bonus_test_app();
+        spawn_fortress_segment(&mut app, 100.0);
+
+        app.world_mut().write_message(WaveCleared { wave: 3 });
+        app.update();
+
+        let bonus = super::super::wave_clear_bonus(3);
+        assert_eq!(
+            app.world().resource::<Gold>().0,
+            super::super::STARTING_GOLD + bonus
+        );
+        assert_eq!(app.world().resource::<RunStats>().gold_earned, bonus);
+    }
+
+    #[test]
+    fn no_wave_bonus_once_fortress_has_fallen() {
+        let mut app = create_wave_bonus_test_app();
+        spawn_fortress_segment(&mut app, 0.0);
+
+        app.world_mut().write_message(WaveCleared { wave: 3 });
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Gold>().0,
+            super::super::STARTING_GOLD
+        );
+    }
+
+    // === Interest Tests ===
+
+    fn create_interest_test_app(gold: u32, config: InterestConfig) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(Gold(gold));
+        app.insert_resource(config);
+        app.init_resource::<RunStats>();
+        app.add_systems(Update, pay_interest);
+        app.update(); // Initialize time (first frame delta=0)
+        app
+    }
+
+    /// Interest config that pays on the next tick with any positive delta.
+    fn due_interest(rate: u32, cap: u32) -> InterestConfig {
+        let mut timer = Timer::from_seconds(0.001, TimerMode::Repeating);
+        crate::testing::nearly_expire_timer(&mut timer);
+        InterestConfig { rate, cap, timer }
+    }
+
+    #[test]
+    fn interest_pays_per_full_hundred_banked() {
+        let mut app = create_interest_test_app(250, due_interest(10, 50));
+        app.update();
+        assert_eq!(app.world().resource::<Gold>().0, 270);
+    }
+
+    #[test]
+    fn interest_is_capped() {
+        let mut app = create_interest_test_app(250, due_interest(10, 15));
+        app.update();
+        assert_eq!(app.world().resource::<Gold>().0, 265);
+
+        assert_eq!(due_interest(10, 20).interest_on(10_000), 20);
+    }
+
+    #[test]
+    fn no_interest_on_empty_bank() {
+        let mut app = create_interest_test_app(0, due_interest(10, 50));
+        app.update();
+        assert_eq!(app.world().resource::<Gold>().0, 0);
+    }
+
+    #[test]
+    fn no_interest_before_timer_fires() {
+        let mut app = create_interest_test_app(250, InterestConfig::default());
+        app.update();
+        assert_eq!(app.world().resource::<Gold>().0, 250);
+    }
+
     // === Kill Reward Tests ===
 
     fn create_kill_reward_test_app() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.init_resource::<Gold>();
+        app.init_resource::<KillStreak>();
+        app.init_resource::<RunStats>();
         app.add_systems(Update, award_kill_gold);
         app
     }
 
+    fn spawn_dying_enemy(app: &mut App) -> Entity {
+        app.world_mut()
+            .spawn((
+                Health {
+                    current: 0.0,
+                    max: 100.0,
+                },
+                Team::Enemy,
+                EnemyUnit,
+            ))
+            .id()
+    }
+
+    /// Spawns a dying enemy, runs one frame, then despawns it (standing in for `check_death`).
+    /// Returns the gold awarded for the kill.
+    fn kill_one(app: &mut App) -> u32 {
+        let before = app.world().resource::<Gold>().0;
+        let enemy = spawn_dying_enemy(app);
+        app.update();
+        app.world_mut().despawn(enemy);
+        app.world().resource::<Gold>().0 - before
+    }
+
     #[test]
     fn kill_reward_for_enemy_death() {
         let mut app = create_kill_reward_test_app();
@@ -141,6 +433,7 @@ mod tests {
                 max: 100.0,
             },
             Team::Enemy,
+            EnemyUnit,
         ));
         app.update();
 
@@ -151,6 +444,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn enemy_death_records_kill_and_earnings_in_run_stats() {
+        let mut app = create_kill_reward_test_app();
+
+        kill_one(&mut app);
+
+        let stats = app.world().resource::<RunStats>();
+        assert_eq!(stats.kills, 1);
+        assert_eq!(stats.gold_earned, super::super::KILL_REWARD);
+    }
+
     #[test]
     fn kill_reward_for_negative_hp_enemy() {
         let mut app = create_kill_reward_test_app();
@@ -161,6 +465,7 @@ mod tests {
                 max: 100.0,
             },
             Team::Enemy,
+            EnemyUnit,
         ));
         app.update();
 
@@ -182,6 +487,7 @@ mod tests {
                 max: 100.0,
             },
             Team::Player,
+            PlayerUnit,
         ));
         app.update();
 
@@ -200,6 +506,7 @@ mod tests {
                 max: 100.0,
             },
             Team::Enemy,
+            EnemyUnit,
         ));
         app.update();
 
@@ -207,6 +514,30 @@ mod tests {
         assert_eq!(gold.0, super::super::STARTING_GOLD);
     }
 
+    #[test]
+    fn destroying_a_fortress_segment_pays_nothing() {
+        use crate::gameplay::battlefield::EnemyFortress;
+
+        let mut app = create_kill_reward_test_app();
+
+        app.world_mut().spawn((
+            Health {
+                current: 0.0,
+                max: 100.0,
+            },
+            Team::Enemy,
+            EnemyFortress,
+        ));
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Gold>().0,
+            super::super::STARTING_GOLD
+        );
+        assert_eq!(app.world().resource::<RunStats>().kills, 0);
+        assert_eq!(app.world().resource::<KillStreak>().count, 0);
+    }
+
     #[test]
     fn multiple_enemy_kills_award_multiple_rewards() {
         let mut app = create_kill_reward_test_app();
@@ -218,14 +549,144 @@ mod tests {
                     max: 100.0,
                 },
                 Team::Enemy,
+                EnemyUnit,
             ));
         }
         app.update();
 
+        // Simultaneous kills count as a streak: 1x + 2x + 3x.
         let gold = app.world().resource::<Gold>();
         assert_eq!(
             gold.0,
-            super::super::STARTING_GOLD + super::super::KILL_REWARD * 3
+            super::super::STARTING_GOLD + super::super::KILL_REWARD * 6
         );
     }
+
+    // === Kill Streak Tests ===
+
+    #[test]
+    fn rapid_kills_escalate_reward() {
+        let mut app = create_kill_reward_test_app();
+
+        let rewards: Vec<u32> = (0..3).map(|_| kill_one(&mut app)).collect();
+
+        let base = super::super::KILL_REWARD;
+        assert_eq!(rewards, vec![base, base * 2, base * 3]);
+        assert_eq!(app.world().resource::<KillStreak>().count, 3);
+    }
+
+    #[test]
+    fn streak_multiplier_is_capped() {
+        let mut app = create_kill_reward_test_app();
+
+        let max = super::super::KILL_STREAK_MAX_MULTIPLIER;
+        let mut last = 0;
+        for _ in 0..max + 2 {
+            last = kill_one(&mut app);
+        }
+
+        assert_eq!(last, super::super::KILL_REWARD * max);
+    }
+
+    #[test]
+    fn lull_resets_streak_to_base_reward() {
+        let mut app = create_kill_reward_test_app();
+
+        kill_one(&mut app);
+        kill_one(&mut app);
+
+        // Let the streak window run out without a kill.
+        {
+            let mut streak = app.world_mut().resource_mut::<KillStreak>();
+            let duration = streak.window.duration();
+            streak.window.set_elapsed(duration);
+        }
+        app.update();
+        assert_eq!(app.world().resource::<KillStreak>().count, 0);
+
+        assert_eq!(kill_one(&mut app), super::super::KILL_REWARD);
+    }
+
+    #[test]
+    fn enemy_kills_recorded_in_run_stats() {
+        let mut app = create_kill_reward_test_app();
+
+        kill_one(&mut app);
+        kill_one(&mut app);
+
+        assert_eq!(app.world().resource::<RunStats>().kills, 2);
+    }
+
+    // === Trade Ratio Tests ===
+
+    fn create_unit_kill_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<RunStats>();
+        app.add_systems(Update, record_unit_kills);
+        app
+    }
+
+    fn spawn_dying_enemy_unit(app: &mut App, killer: Entity) {
+        app.world_mut().spawn((
+            EnemyUnit,
+            Health {
+                current: 0.0,
+                max: 100.0,
+            },
+            LastHitBy(killer),
+        ));
+    }
+
+    #[test]
+    fn enemy_killed_by_player_unit_counts_toward_trade_ratio() {
+        let mut app = create_unit_kill_test_app();
+        app.world_mut().resource_mut::<RunStats>().production_spent = 50;
+        let soldier = app.world_mut().spawn(PlayerUnit).id();
+
+        spawn_dying_enemy_unit(&mut app, soldier);
+        spawn_dying_enemy_unit(&mut app, soldier);
+        app.update();
+
+        let stats = app.world().resource::<RunStats>();
+        assert_eq!(stats.unit_kills, 2);
+        assert_eq!(stats.unit_kill_value, 2 * super::super::KILL_REWARD);
+        #[allow(clippy::cast_precision_loss)]
+        let expected = (2 * super::super::KILL_REWARD) as f32 / 50.0;
+        assert_eq!(stats.trade_ratio(), Some(expected));
+    }
+
+    #[test]
+    fn enemy_killed_by_non_unit_is_not_credited() {
+        let mut app = create_unit_kill_test_app();
+        let tower = app.world_mut().spawn_empty().id();
+
+        spawn_dying_enemy_unit(&mut app, tower);
+        app.update();
+
+        assert_eq!(app.world().resource::<RunStats>().unit_kills, 0);
+    }
+
+    #[test]
+    fn wounded_enemy_is_not_credited() {
+        let mut app = create_unit_kill_test_app();
+        let soldier = app.world_mut().spawn(PlayerUnit).id();
+
+        app.world_mut().spawn((
+            EnemyUnit,
+            Health {
+                current: 1.0,
+                max: 100.0,
+            },
+            LastHitBy(soldier),
+        ));
+        app.update();
+
+        assert_eq!(app.world().resource::<RunStats>().unit_kills, 0);
+    }
+
+    #[test]
+    fn multiplier_is_base_without_streak() {
+        assert_eq!(KillStreak::default().multiplier(), 1);
+    }
 }
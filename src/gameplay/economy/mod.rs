@@ -1,26 +1,68 @@
 //! Economy: gold resource, building costs, income, and shop.
 
 pub mod income;
+pub mod reinforcement;
 pub mod shop;
 pub mod shop_ui;
 pub mod ui;
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::gameplay::building::BuildingType;
+use crate::gameplay::units::Unit;
+use crate::gameplay::{GameStartTime, Health};
 use crate::screens::GameState;
+use crate::{GameSet, gameplay_running};
 
 // === Constants ===
 
-/// Starting gold when entering `InGame`.
+/// Starting gold when entering `InGame` on `Difficulty::Normal`.
 pub const STARTING_GOLD: u32 = 200;
 
+/// Default starting gold on `Difficulty::Easy`.
+pub const EASY_STARTING_GOLD: u32 = 300;
+
+/// Default starting gold on `Difficulty::Hard`.
+pub const HARD_STARTING_GOLD: u32 = 120;
+
 /// Gold awarded per enemy kill.
 pub const KILL_REWARD: u32 = 5;
 
+/// Seconds without a kill before the kill streak resets.
+pub const KILL_STREAK_WINDOW_SECS: f32 = 3.0;
+
+/// Highest multiplier a kill streak can apply to `KILL_REWARD`.
+pub const KILL_STREAK_MAX_MULTIPLIER: u32 = 4;
+
 /// Gold generated per Farm per tick.
 pub const FARM_INCOME_PER_TICK: u32 = 3;
 
+/// Seconds between interest payouts on banked gold.
+pub const INTEREST_INTERVAL_SECS: f32 = 10.0;
+
+/// Interest gold per full 100 gold banked (10 = 10%).
+pub const INTEREST_RATE: u32 = 10;
+
+/// Most gold a single interest payout can grant.
+pub const INTEREST_CAP: u32 = 50;
+
+/// Gold awarded for clearing the first wave.
+pub const WAVE_CLEAR_BASE_BONUS: u32 = 20;
+
+/// Extra wave-clear gold per wave after the first.
+pub const WAVE_CLEAR_BONUS_PER_WAVE: u32 = 10;
+
+/// Gold rebated when a building is placed orthogonally next to its combo partner
+/// (Farm next to Barracks, or Barracks next to Farm).
+pub const COMBO_REBATE: u32 = 15;
+
+/// Percent of a building's cost refunded when sold.
+pub const SELL_REFUND_PERCENT: u32 = 50;
+
+/// Extra refund percent per unit a building has produced (loyalty discount).
+pub const SELL_LOYALTY_PERCENT_PER_UNIT: u32 = 2;
+
 // === Resources ===
 
 /// The player's current gold.
@@ -34,6 +76,147 @@ impl Default for Gold {
     }
 }
 
+/// How hard the run is. Harder modes can start the player with more gold (to
+/// offset tougher enemies) or less (for a challenge) via `BalanceConfig`.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect, Serialize, Deserialize)]
+#[reflect(Resource)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// The next difficulty in menu order, wrapping from `Hard` back to `Easy`.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Easy => Self::Normal,
+            Self::Normal => Self::Hard,
+            Self::Hard => Self::Easy,
+        }
+    }
+}
+
+/// Starting gold for each `Difficulty`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub struct StartingGold {
+    pub easy: u32,
+    pub normal: u32,
+    pub hard: u32,
+}
+
+impl StartingGold {
+    /// Starting gold on `difficulty`.
+    #[must_use]
+    pub const fn for_difficulty(&self, difficulty: Difficulty) -> u32 {
+        match difficulty {
+            Difficulty::Easy => self.easy,
+            Difficulty::Normal => self.normal,
+            Difficulty::Hard => self.hard,
+        }
+    }
+}
+
+impl Default for StartingGold {
+    fn default() -> Self {
+        Self {
+            easy: EASY_STARTING_GOLD,
+            normal: STARTING_GOLD,
+            hard: HARD_STARTING_GOLD,
+        }
+    }
+}
+
+/// Per-difficulty economy tuning. Read on entering `InGame`.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct BalanceConfig {
+    pub starting_gold: StartingGold,
+}
+
+/// Per-run economy statistics shown on the endgame screen. Reset on entering `InGame`.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Resource)]
+pub struct RunStats {
+    /// Total gold spent on buildings, upgrades, and rerolls.
+    pub gold_spent: u32,
+    /// Enemy kills.
+    pub kills: u32,
+    /// Gold earned from farms, interest, and kill rewards.
+    pub gold_earned: u32,
+    /// Player units produced by buildings.
+    pub units_produced: u32,
+    /// Seconds of (unpaused) play since the run started, per `GameStartTime`.
+    pub elapsed_secs: f32,
+    /// Gold spent on unit production: unit-producing buildings, their
+    /// upgrades, and reinforcements.
+    pub production_spent: u32,
+    /// Enemies killed by player units (not towers or fortress fire).
+    pub unit_kills: u32,
+    /// Bounty value of the enemies counted in `unit_kills`.
+    pub unit_kill_value: u32,
+    /// Most units (both teams) alive at the same moment this run.
+    pub peak_units: u32,
+}
+
+impl RunStats {
+    /// Adds `amount` to the gold spent this run.
+    pub const fn record_spend(&mut self, amount: u32) {
+        self.gold_spent = self.gold_spent.saturating_add(amount);
+    }
+
+    /// Counts one enemy kill.
+    pub const fn record_kill(&mut self) {
+        self.kills = self.kills.saturating_add(1);
+    }
+
+    /// Adds `amount` to the gold earned this run.
+    pub const fn record_earnings(&mut self, amount: u32) {
+        self.gold_earned = self.gold_earned.saturating_add(amount);
+    }
+
+    /// Counts one unit produced.
+    pub const fn record_production(&mut self) {
+        self.units_produced = self.units_produced.saturating_add(1);
+    }
+
+    /// Adds `amount` to the gold spent on unit production.
+    pub const fn record_production_spend(&mut self, amount: u32) {
+        self.production_spent = self.production_spent.saturating_add(amount);
+    }
+
+    /// Counts one enemy killed by a player unit, worth `value` gold.
+    pub const fn record_unit_kill(&mut self, value: u32) {
+        self.unit_kills = self.unit_kills.saturating_add(1);
+        self.unit_kill_value = self.unit_kill_value.saturating_add(value);
+    }
+
+    /// Raises `peak_units` to `alive` if more units are alive now than ever before.
+    pub const fn record_unit_count(&mut self, alive: u32) {
+        if alive > self.peak_units {
+            self.peak_units = alive;
+        }
+    }
+
+    /// Value of unit kills per gold spent on production, or `None` before any
+    /// production spend. Above 1.0 means units are paying for themselves.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn trade_ratio(&self) -> Option<f32> {
+        (self.production_spent > 0)
+            .then(|| self.unit_kill_value as f32 / self.production_spent as f32)
+    }
+
+    /// Gold spent per kill, or `None` before the first kill.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn gold_per_kill(&self) -> Option<f32> {
+        (self.kills > 0).then(|| self.gold_spent as f32 / self.kills as f32)
+    }
+}
+
 // === Helper Functions ===
 
 /// Get the gold cost for a building type.
@@ -42,21 +225,95 @@ pub const fn building_cost(building_type: BuildingType) -> u32 {
     crate::gameplay::building::building_stats(building_type).cost
 }
 
+/// Gold to upgrade a building from `level` to `level + 1`: its base cost times `level`.
+#[must_use]
+pub const fn upgrade_cost(building_type: BuildingType, level: u8) -> u32 {
+    building_cost(building_type) * level as u32
+}
+
+/// Gold refunded for selling a building that has produced `produced_count` units.
+/// Starts at `SELL_REFUND_PERCENT` of the cost and grows with each produced unit,
+/// capped at the full cost.
+#[must_use]
+pub const fn sell_refund(building_type: BuildingType, produced_count: u32) -> u32 {
+    let percent = SELL_REFUND_PERCENT
+        .saturating_add(produced_count.saturating_mul(SELL_LOYALTY_PERCENT_PER_UNIT));
+    let percent = if percent > 100 { 100 } else { percent };
+    building_cost(building_type) * percent / 100
+}
+
+/// `refund` scaled by the seller's remaining HP fraction, rounded down.
+#[must_use]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub fn scale_refund_by_health(refund: u32, health: &Health) -> u32 {
+    if health.max <= 0.0 {
+        return 0;
+    }
+    let fraction = (health.current / health.max).clamp(0.0, 1.0);
+    (refund as f32 * fraction).floor() as u32
+}
+
+/// Lump-sum gold for clearing `wave` (1-based) with the fortress intact.
+#[must_use]
+pub const fn wave_clear_bonus(wave: u32) -> u32 {
+    WAVE_CLEAR_BASE_BONUS
+        .saturating_add(WAVE_CLEAR_BONUS_PER_WAVE.saturating_mul(wave.saturating_sub(1)))
+}
+
 // === Systems ===
 
-fn reset_gold(mut gold: ResMut<Gold>) {
-    gold.0 = STARTING_GOLD;
+/// Sets gold to the `BalanceConfig` starting gold for the current `Difficulty`.
+fn reset_gold(mut gold: ResMut<Gold>, difficulty: Res<Difficulty>, balance: Res<BalanceConfig>) {
+    gold.0 = balance.starting_gold.for_difficulty(*difficulty);
+}
+
+fn reset_run_stats(mut commands: Commands) {
+    commands.insert_resource(RunStats::default());
+}
+
+/// Keeps the run's elapsed time current while the game runs, so it is frozen
+/// at the moment an endgame overlay pauses play.
+fn track_run_time(
+    time: Res<Time<Virtual>>,
+    start: Res<GameStartTime>,
+    mut run_stats: ResMut<RunStats>,
+) {
+    run_stats.elapsed_secs = time.elapsed_secs() - start.0;
+}
+
+/// Compares the living unit count against the run's peak each frame.
+fn track_peak_units(units: Query<(), With<Unit>>, mut run_stats: ResMut<RunStats>) {
+    let alive = u32::try_from(units.iter().count()).unwrap_or(u32::MAX);
+    run_stats.record_unit_count(alive);
 }
 
 // === Plugin ===
 
 pub(super) fn plugin(app: &mut App) {
-    app.register_type::<Gold>().init_resource::<Gold>();
+    app.register_type::<Gold>()
+        .register_type::<RunStats>()
+        .register_type::<Difficulty>()
+        .register_type::<BalanceConfig>()
+        .init_resource::<Gold>()
+        .init_resource::<RunStats>()
+        .init_resource::<Difficulty>()
+        .init_resource::<BalanceConfig>();
 
-    app.add_systems(OnEnter(GameState::InGame), reset_gold);
+    app.add_systems(OnEnter(GameState::InGame), (reset_gold, reset_run_stats));
+    app.add_systems(
+        Update,
+        (track_run_time, track_peak_units)
+            .in_set(GameSet::Ui)
+            .run_if(gameplay_running),
+    );
 
     // Sub-plugins
     income::plugin(app);
+    reinforcement::plugin(app);
     shop::plugin(app);
     shop_ui::plugin(app);
     ui::plugin(app);
@@ -85,12 +342,143 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sell_refund_grows_with_produced_units() {
+        let fresh = sell_refund(BuildingType::Barracks, 0);
+        let veteran = sell_refund(BuildingType::Barracks, 10);
+        assert_eq!(
+            fresh,
+            building_cost(BuildingType::Barracks) * SELL_REFUND_PERCENT / 100
+        );
+        assert!(veteran > fresh, "Expected {veteran} > {fresh}");
+    }
+
+    #[test]
+    fn full_health_keeps_whole_refund() {
+        assert_eq!(scale_refund_by_health(50, &Health::new(300.0)), 50);
+    }
+
+    #[test]
+    fn half_health_halves_refund_rounding_down() {
+        let health = Health {
+            current: 150.0,
+            max: 300.0,
+        };
+        assert_eq!(scale_refund_by_health(50, &health), 25);
+        assert_eq!(scale_refund_by_health(25, &health), 12);
+    }
+
+    #[test]
+    fn sell_refund_capped_at_cost() {
+        let cost = building_cost(BuildingType::Barracks);
+        assert_eq!(sell_refund(BuildingType::Barracks, 1_000), cost);
+        assert_eq!(sell_refund(BuildingType::Barracks, u32::MAX), cost);
+    }
+
+    #[test]
+    fn wave_clear_bonus_for_wave_three() {
+        assert_eq!(
+            wave_clear_bonus(3),
+            WAVE_CLEAR_BASE_BONUS + 2 * WAVE_CLEAR_BONUS_PER_WAVE
+        );
+    }
+
+    #[test]
+    fn wave_clear_bonus_grows_with_wave() {
+        for wave in 1..10 {
+            assert!(wave_clear_bonus(wave + 1) > wave_clear_bonus(wave));
+        }
+    }
+
+    #[test]
+    fn run_stats_gold_per_kill() {
+        let mut stats = RunStats::default();
+        assert_eq!(stats.gold_per_kill(), None);
+
+        stats.record_spend(100);
+        stats.record_spend(50);
+        for _ in 0..3 {
+            stats.record_kill();
+        }
+
+        assert_eq!(stats.gold_spent, 150);
+        assert_eq!(stats.kills, 3);
+        assert_eq!(stats.gold_per_kill(), Some(50.0));
+    }
+
+    #[test]
+    fn run_stats_trade_ratio() {
+        let mut stats = RunStats::default();
+        assert_eq!(stats.trade_ratio(), None);
+
+        stats.record_production_spend(40);
+        stats.record_production_spend(60);
+        assert_eq!(stats.trade_ratio(), Some(0.0));
+
+        for _ in 0..4 {
+            stats.record_unit_kill(KILL_REWARD);
+        }
+
+        assert_eq!(stats.production_spent, 100);
+        assert_eq!(stats.unit_kills, 4);
+        assert_eq!(stats.unit_kill_value, 4 * KILL_REWARD);
+        #[allow(clippy::cast_precision_loss)]
+        let expected = (4 * KILL_REWARD) as f32 / 100.0;
+        assert_eq!(stats.trade_ratio(), Some(expected));
+    }
+
+    #[test]
+    fn peak_units_records_maximum_not_current_count() {
+        use crate::gameplay::Team;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<RunStats>();
+        app.add_systems(Update, track_peak_units);
+        let world = app.world_mut();
+        let units: Vec<Entity> = (0..5)
+            .map(|i| {
+                let team = if i % 2 == 0 {
+                    Team::Player
+                } else {
+                    Team::Enemy
+                };
+                crate::testing::spawn_test_unit(world, team, 0.0, 0.0)
+            })
+            .collect();
+        app.update();
+        assert_eq!(app.world().resource::<RunStats>().peak_units, 5);
+
+        for &unit in &units[..3] {
+            app.world_mut().despawn(unit);
+        }
+        app.update();
+        assert_eq!(app.world().resource::<RunStats>().peak_units, 5);
+
+        crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 0.0, 0.0);
+        app.update();
+        assert_eq!(app.world().resource::<RunStats>().peak_units, 5);
+    }
+
+    #[test]
+    fn peak_units_rises_past_previous_peak() {
+        let mut stats = RunStats::default();
+        stats.record_unit_count(3);
+        stats.record_unit_count(1);
+        assert_eq!(stats.peak_units, 3);
+        stats.record_unit_count(7);
+        assert_eq!(stats.peak_units, 7);
+    }
+
     #[allow(clippy::assertions_on_constants)]
     #[test]
     fn constants_are_valid() {
         assert!(STARTING_GOLD > 0);
         assert!(KILL_REWARD > 0);
+        assert!(KILL_STREAK_WINDOW_SECS > 0.0);
+        assert!(KILL_STREAK_MAX_MULTIPLIER >= 1);
         assert!(FARM_INCOME_PER_TICK > 0);
+        assert!(SELL_REFUND_PERCENT <= 100);
     }
 }
 
@@ -138,6 +526,124 @@ mod integration_tests {
         assert_eq!(gold.0, STARTING_GOLD);
     }
 
+    fn create_difficulty_test_app(difficulty: Difficulty) -> App {
+        let mut app = crate::testing::create_base_test_app();
+        crate::testing::init_asset_resources(&mut app);
+        app.add_plugins(crate::gameplay::plugin);
+        app.insert_resource(difficulty);
+        app.insert_resource(BalanceConfig {
+            starting_gold: StartingGold {
+                easy: 300,
+                normal: STARTING_GOLD,
+                hard: 120,
+            },
+        });
+        transition_to_ingame(&mut app);
+        app
+    }
+
+    #[test]
+    fn hard_mode_starts_with_configured_gold() {
+        let app = create_difficulty_test_app(Difficulty::Hard);
+        assert_eq!(app.world().resource::<Gold>().0, 120);
+    }
+
+    #[test]
+    fn easy_mode_starts_with_configured_gold() {
+        let app = create_difficulty_test_app(Difficulty::Easy);
+        assert_eq!(app.world().resource::<Gold>().0, 300);
+    }
+
+    #[test]
+    fn default_balance_gives_each_difficulty_its_own_starting_gold() {
+        let starting_gold = BalanceConfig::default().starting_gold;
+        assert_eq!(
+            starting_gold.for_difficulty(Difficulty::Easy),
+            EASY_STARTING_GOLD
+        );
+        assert_eq!(
+            starting_gold.for_difficulty(Difficulty::Normal),
+            STARTING_GOLD
+        );
+        assert_eq!(
+            starting_gold.for_difficulty(Difficulty::Hard),
+            HARD_STARTING_GOLD
+        );
+        assert!(EASY_STARTING_GOLD > STARTING_GOLD);
+        assert!(HARD_STARTING_GOLD < STARTING_GOLD);
+    }
+
+    #[test]
+    fn difficulty_next_cycles_through_all() {
+        assert_eq!(Difficulty::Easy.next(), Difficulty::Normal);
+        assert_eq!(Difficulty::Normal.next(), Difficulty::Hard);
+        assert_eq!(Difficulty::Hard.next(), Difficulty::Easy);
+    }
+
+    #[test]
+    fn run_stats_reset_on_reenter_ingame() {
+        let mut app = crate::testing::create_base_test_app();
+        crate::testing::init_asset_resources(&mut app);
+        app.add_plugins(crate::gameplay::plugin);
+        transition_to_ingame(&mut app);
+
+        {
+            let mut stats = app.world_mut().resource_mut::<RunStats>();
+            stats.record_spend(100);
+            stats.record_kill();
+        }
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::MainMenu);
+        app.update();
+        app.update();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::InGame);
+        app.update();
+        app.update();
+
+        let stats = app.world().resource::<RunStats>();
+        assert_eq!(stats.gold_spent, 0);
+        assert_eq!(stats.kills, 0);
+        assert_eq!(stats.gold_per_kill(), None);
+    }
+
+    #[test]
+    fn double_click_on_card_places_building_at_hovered_cell() {
+        use crate::gameplay::building::{Building, BuildingType, LastHoveredCell};
+        use shop_ui::CardSlot;
+
+        let mut app = crate::testing::create_base_test_app();
+        crate::testing::init_asset_resources(&mut app);
+        app.add_plugins(crate::gameplay::plugin);
+        transition_to_ingame(&mut app);
+
+        app.world_mut().resource_mut::<shop::Shop>().cards[0] = Some(BuildingType::Barracks);
+        app.world_mut().resource_mut::<LastHoveredCell>().0 = Some((2, 3));
+
+        let card = app
+            .world_mut()
+            .spawn((CardSlot(0), Interaction::Pressed))
+            .id();
+        app.update();
+        assert_entity_count::<With<Building>>(&mut app, 0);
+
+        *app.world_mut().get_mut::<Interaction>(card).unwrap() = Interaction::Pressed;
+        app.update();
+
+        let mut buildings = app.world_mut().query::<&Building>();
+        let building = buildings.single(app.world()).unwrap();
+        assert_eq!(building.building_type, BuildingType::Barracks);
+        assert_eq!((building.grid_col, building.grid_row), (2, 3));
+        assert_eq!(
+            app.world().resource::<Gold>().0,
+            STARTING_GOLD - building_cost(BuildingType::Barracks)
+        );
+        assert_eq!(app.world().resource::<shop::Shop>().cards[0], None);
+    }
+
     #[test]
     fn gold_hud_spawned_on_enter_ingame() {
         let mut app = crate::testing::create_base_test_app();
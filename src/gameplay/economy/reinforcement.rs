@@ -0,0 +1,217 @@
+//! Reinforcements: an emergency purchase that instantly spawns a squad of player
+//! units around the player fortress. Press E.
+
+use bevy::prelude::*;
+use vleue_navigator::prelude::*;
+
+use super::{Gold, RunStats};
+use crate::gameplay::battlefield::{BATTLEFIELD_ROWS, PlayerFortress, fortress_center};
+use crate::gameplay::units::{
+    AssignedLane, SpawnIdCounter, UnitAssets, UnitType, random_navigable_spawn, spawn_unit,
+};
+use crate::gameplay::{GameRng, Team};
+use crate::screens::GameState;
+use crate::{GameSet, gameplay_running};
+
+/// Gold cost of one reinforcement call.
+pub const REINFORCEMENT_COST: u32 = 150;
+
+/// Units spawned per reinforcement call.
+pub const REINFORCEMENT_SQUAD_SIZE: u32 = 4;
+
+/// Radius from fortress center where reinforcements appear, like enemy wave spawns.
+/// Clears the 2×2 fortress footprint (128×128px, half-diagonal ≈ 90px).
+const REINFORCEMENT_SPAWN_RADIUS: f32 = 80.0;
+
+/// Set by input; consumed by `call_reinforcements` in `GameSet::Production`.
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct ReinforcementRequest(pub bool);
+
+/// Lane for the `index`-th squad member: the squad is spread evenly across the
+/// battlefield rows so it fans out like a barracks' round-robin output.
+#[allow(clippy::cast_possible_truncation)]
+fn reinforcement_lane(index: u32) -> AssignedLane {
+    let lane = index * u32::from(BATTLEFIELD_ROWS) / REINFORCEMENT_SQUAD_SIZE;
+    AssignedLane(lane as u16)
+}
+
+fn request_reinforcements(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut request: ResMut<ReinforcementRequest>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyE) {
+        request.0 = true;
+    }
+}
+
+/// Spawns the squad and charges `REINFORCEMENT_COST`. Ignored without enough gold,
+/// and skipped once the fortress is destroyed. Each unit lands on a navigable point
+/// around the fortress (`random_navigable_spawn`) and gets its own `AssignedLane`.
+fn call_reinforcements(
+    mut commands: Commands,
+    mut request: ResMut<ReinforcementRequest>,
+    mut gold: ResMut<Gold>,
+    mut run_stats: ResMut<RunStats>,
+    fortress: Query<&Transform, With<PlayerFortress>>,
+    unit_assets: Res<UnitAssets>,
+    mut spawn_ids: ResMut<SpawnIdCounter>,
+    mut rng: ResMut<GameRng>,
+    navmeshes: Option<Res<Assets<NavMesh>>>,
+    navmesh_query: Option<Single<(&ManagedNavMesh, &NavMeshStatus)>>,
+) {
+    let Some(center) = fortress_center(&fortress) else {
+        return;
+    };
+    if !std::mem::take(&mut request.0) || gold.0 < REINFORCEMENT_COST {
+        return;
+    }
+    gold.0 -= REINFORCEMENT_COST;
+    run_stats.record_spend(REINFORCEMENT_COST);
+    run_stats.record_production_spend(REINFORCEMENT_COST);
+
+    let navmesh = navmesh_query.and_then(|inner| {
+        let (managed, status) = *inner;
+        let meshes = navmeshes.as_ref()?;
+        (*status == NavMeshStatus::Built).then(|| meshes.get(managed))?
+    });
+
+    for index in 0..REINFORCEMENT_SQUAD_SIZE {
+        let position =
+            random_navigable_spawn(center, REINFORCEMENT_SPAWN_RADIUS, navmesh, &mut rng.0);
+        let unit = spawn_unit(
+            &mut commands,
+            UnitType::Soldier,
+            Team::Player,
+            position,
+            &unit_assets,
+            &mut spawn_ids,
+        );
+        commands.entity(unit).insert(reinforcement_lane(index));
+    }
+}
+
+fn reset_reinforcement_request(mut request: ResMut<ReinforcementRequest>) {
+    request.0 = false;
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<ReinforcementRequest>()
+        .init_resource::<ReinforcementRequest>();
+
+    app.add_systems(OnEnter(GameState::InGame), reset_reinforcement_request);
+    app.add_systems(
+        Update,
+        (
+            request_reinforcements.in_set(GameSet::Input),
+            call_reinforcements.in_set(GameSet::Production),
+        )
+            .run_if(gameplay_running),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::units::PlayerUnit;
+    use crate::testing::{assert_entity_count, transition_to_ingame};
+    use pretty_assertions::assert_eq;
+
+    fn create_reinforcement_test_app() -> App {
+        let mut app = crate::testing::create_base_test_app_no_input();
+        crate::testing::init_input_resources(&mut app);
+        crate::testing::init_asset_resources(&mut app);
+        crate::testing::init_economy_resources(&mut app);
+
+        app.configure_sets(
+            Update,
+            (crate::GameSet::Input, crate::GameSet::Production).chain(),
+        );
+
+        app.add_plugins(crate::gameplay::battlefield::plugin);
+        app.add_plugins(crate::gameplay::units::plugin);
+        app.add_plugins(plugin);
+        transition_to_ingame(&mut app);
+        app
+    }
+
+    fn fortress_xy(app: &mut App) -> Vec2 {
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&Transform, With<PlayerFortress>>();
+        fortress_center(query.iter(app.world())).unwrap()
+    }
+
+    #[test]
+    fn reinforcement_spawns_squad_near_fortress_and_charges_gold() {
+        let mut app = create_reinforcement_test_app();
+        app.world_mut().resource_mut::<Gold>().0 = REINFORCEMENT_COST + 10;
+        app.world_mut().resource_mut::<ReinforcementRequest>().0 = true;
+        app.update();
+
+        assert_entity_count::<With<PlayerUnit>>(&mut app, REINFORCEMENT_SQUAD_SIZE as usize);
+        assert_eq!(app.world().resource::<Gold>().0, 10);
+        assert_eq!(
+            app.world().resource::<RunStats>().gold_spent,
+            REINFORCEMENT_COST
+        );
+        assert_eq!(
+            app.world().resource::<RunStats>().production_spent,
+            REINFORCEMENT_COST
+        );
+
+        let fortress = fortress_xy(&mut app);
+        let mut units = app
+            .world_mut()
+            .query_filtered::<&Transform, With<PlayerUnit>>();
+        for transform in units.iter(app.world()) {
+            let distance = transform.translation.xy().distance(fortress);
+            assert!(
+                (distance - REINFORCEMENT_SPAWN_RADIUS).abs() < 0.01,
+                "Unit {distance}px away"
+            );
+        }
+    }
+
+    #[test]
+    fn reinforcements_fan_out_across_lanes() {
+        let mut app = create_reinforcement_test_app();
+        app.world_mut().resource_mut::<Gold>().0 = REINFORCEMENT_COST;
+        app.world_mut().resource_mut::<ReinforcementRequest>().0 = true;
+        app.update();
+
+        let mut lanes: Vec<u16> = app
+            .world_mut()
+            .query_filtered::<&AssignedLane, With<PlayerUnit>>()
+            .iter(app.world())
+            .map(|lane| lane.0)
+            .collect();
+        lanes.sort_unstable();
+        assert_eq!(lanes, vec![0, 2, 5, 7]);
+    }
+
+    #[test]
+    fn reinforcement_blocked_without_enough_gold() {
+        let mut app = create_reinforcement_test_app();
+        app.world_mut().resource_mut::<Gold>().0 = REINFORCEMENT_COST - 1;
+        app.world_mut().resource_mut::<ReinforcementRequest>().0 = true;
+        app.update();
+
+        assert_entity_count::<With<PlayerUnit>>(&mut app, 0);
+        assert_eq!(app.world().resource::<Gold>().0, REINFORCEMENT_COST - 1);
+        assert!(!app.world().resource::<ReinforcementRequest>().0);
+    }
+
+    #[test]
+    fn e_key_requests_reinforcements() {
+        let mut app = create_reinforcement_test_app();
+        app.world_mut().resource_mut::<Gold>().0 = REINFORCEMENT_COST;
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyE);
+        app.update();
+
+        assert_entity_count::<With<PlayerUnit>>(&mut app, REINFORCEMENT_SQUAD_SIZE as usize);
+        assert_eq!(app.world().resource::<Gold>().0, 0);
+    }
+}
@@ -1,8 +1,12 @@
 //! Shop: card selection, reroll, and building purchase.
 
 use bevy::prelude::*;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+use serde::{Deserialize, Serialize};
 
-use crate::gameplay::building::BuildingType;
+use crate::gameplay::GameRng;
+use crate::gameplay::building::{BuildingType, building_stats};
 use crate::screens::GameState;
 
 // === Constants ===
@@ -19,13 +23,15 @@ const MAX_REROLL_COST: u32 = 40;
 // === Resources ===
 
 /// The player's current shop offering of building cards.
-#[derive(Resource, Debug, Clone, Reflect)]
+#[derive(Resource, Debug, Clone, PartialEq, Eq, Reflect, Serialize, Deserialize)]
 #[reflect(Resource)]
 pub struct Shop {
     /// The 4 card slots. `None` = empty (already placed or not yet drawn).
     pub cards: [Option<BuildingType>; HAND_SIZE],
     /// Which slot is currently selected (0-3), or `None`.
     pub selected: Option<usize>,
+    /// Slots whose card survives rerolls.
+    pub locked: [bool; HAND_SIZE],
     /// Number of consecutive rerolls without placing a building.
     pub consecutive_no_build_rerolls: u32,
     /// Whether the player placed a building since the last reroll.
@@ -37,6 +43,7 @@ impl Default for Shop {
         Self {
             cards: [None; HAND_SIZE],
             selected: None,
+            locked: [false; HAND_SIZE],
             consecutive_no_build_rerolls: 0,
             placed_since_last_reroll: false,
         }
@@ -44,14 +51,25 @@ impl Default for Shop {
 }
 
 impl Shop {
-    /// Generate new random cards for all slots.
-    pub fn generate_cards(&mut self) {
-        use rand::Rng;
-        let mut rng = rand::rng();
-        let pool = BuildingType::ALL;
-        for card in &mut self.cards {
-            let idx = rng.random_range(0..pool.len());
-            *card = Some(pool[idx]);
+    /// Generate new random cards for all slots from every `BuildingType::ALL` entry.
+    pub fn generate_cards(&mut self, rng: &mut impl Rng) {
+        self.generate_cards_from(BuildingType::ALL, rng);
+    }
+
+    /// Generate new random cards for all unlocked slots, drawing from `pool`
+    /// weighted by each type's rarity. Slots stay empty if `pool` has no
+    /// positive weight. Locked slots keep their card.
+    pub fn generate_cards_from(&mut self, pool: &[BuildingType], rng: &mut impl Rng) {
+        for (card, _) in self
+            .cards
+            .iter_mut()
+            .zip(self.locked)
+            .filter(|(_, locked)| !locked)
+        {
+            *card = pool
+                .choose_weighted(rng, |&bt| building_stats(bt).rarity.shop_weight())
+                .ok()
+                .copied();
         }
         self.selected = None;
     }
@@ -75,10 +93,18 @@ impl Shop {
         }
     }
 
-    /// Remove the selected card after placement.
+    /// Toggle the lock on a card slot. If the slot is empty, does nothing.
+    pub fn toggle_lock(&mut self, slot: usize) {
+        if self.cards.get(slot).is_some_and(Option::is_some) {
+            self.locked[slot] = !self.locked[slot];
+        }
+    }
+
+    /// Remove the selected card after placement. Its slot is unlocked.
     pub const fn remove_selected(&mut self) {
         if let Some(idx) = self.selected {
             self.cards[idx] = None;
+            self.locked[idx] = false;
             self.selected = None;
             self.placed_since_last_reroll = true;
             self.consecutive_no_build_rerolls = 0;
@@ -98,11 +124,11 @@ impl Shop {
 
     /// Attempt a reroll: check gold, deduct cost, and reroll cards.
     /// Returns `true` if the reroll was performed, `false` if insufficient gold.
-    pub fn try_reroll(&mut self, gold: &mut u32) -> bool {
+    pub fn try_reroll(&mut self, gold: &mut u32, rng: &mut impl Rng) -> bool {
         let cost = self.reroll_cost();
         if *gold >= cost {
             *gold -= cost;
-            self.reroll();
+            self.reroll(rng);
             true
         } else {
             false
@@ -110,20 +136,20 @@ impl Shop {
     }
 
     /// Perform a reroll: pay cost, regenerate cards, update state.
-    pub fn reroll(&mut self) {
+    pub fn reroll(&mut self, rng: &mut impl Rng) {
         if !self.placed_since_last_reroll {
             self.consecutive_no_build_rerolls += 1;
         }
         self.placed_since_last_reroll = false;
-        self.generate_cards();
+        self.generate_cards(rng);
     }
 }
 
 // === Systems ===
 
-fn initialize_shop(mut shop: ResMut<Shop>) {
+fn initialize_shop(mut shop: ResMut<Shop>, mut rng: ResMut<GameRng>) {
     *shop = Shop::default();
-    shop.generate_cards();
+    shop.generate_cards(&mut rng.0);
 }
 
 // === Plugin ===
@@ -131,7 +157,10 @@ fn initialize_shop(mut shop: ResMut<Shop>) {
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<Shop>().init_resource::<Shop>();
 
-    app.add_systems(OnEnter(GameState::InGame), initialize_shop);
+    app.add_systems(
+        OnEnter(GameState::InGame),
+        initialize_shop.after(crate::gameplay::reseed_game_rng),
+    );
 }
 
 #[cfg(test)]
@@ -141,8 +170,9 @@ mod tests {
 
     #[test]
     fn generate_cards_fills_all_slots() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
-        shop.generate_cards();
+        shop.generate_cards(&mut rng);
 
         for (i, card) in shop.cards.iter().enumerate() {
             assert!(card.is_some(), "Card slot {i} should be filled");
@@ -151,8 +181,9 @@ mod tests {
 
     #[test]
     fn generate_cards_only_uses_pool() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
-        shop.generate_cards();
+        shop.generate_cards(&mut rng);
 
         for card in &shop.cards {
             let bt = card.unwrap();
@@ -163,18 +194,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn every_building_type_can_appear_in_generated_hands() {
+        let mut rng = GameRng::seeded(0).0;
+        use std::collections::HashSet;
+
+        let mut shop = Shop::default();
+        let mut seen = HashSet::new();
+        for _ in 0..200 {
+            shop.generate_cards(&mut rng);
+            seen.extend(shop.cards.iter().flatten().copied());
+        }
+
+        let all: HashSet<_> = BuildingType::ALL.iter().copied().collect();
+        assert_eq!(seen, all);
+    }
+
+    #[test]
+    fn generate_cards_from_draws_only_from_given_pool() {
+        let mut rng = GameRng::seeded(0).0;
+        let mut shop = Shop::default();
+        shop.generate_cards_from(&[BuildingType::SlowTower], &mut rng);
+        assert_eq!(shop.cards, [Some(BuildingType::SlowTower); HAND_SIZE]);
+    }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn rare_cards_are_drawn_at_configured_weight() {
+        use crate::gameplay::building::{COMMON_SHOP_WEIGHT, RARE_SHOP_WEIGHT, Rarity};
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut shop = Shop::default();
+        let (mut common, mut rare) = (0_u32, 0_u32);
+        for _ in 0..5000 {
+            shop.generate_cards(&mut rng);
+            for &bt in shop.cards.iter().flatten() {
+                assert!(BuildingType::ALL.contains(&bt), "{bt:?}");
+                match building_stats(bt).rarity {
+                    Rarity::Common => common += 1,
+                    Rarity::Rare => rare += 1,
+                }
+            }
+        }
+
+        let count_of = |rarity| {
+            BuildingType::ALL
+                .iter()
+                .filter(|&&bt| building_stats(bt).rarity == rarity)
+                .count() as f32
+        };
+        // Per-type draw rates should compare like the weights
+        let observed =
+            (rare as f32 / count_of(Rarity::Rare)) / (common as f32 / count_of(Rarity::Common));
+        let expected = RARE_SHOP_WEIGHT as f32 / COMMON_SHOP_WEIGHT as f32;
+        assert!(
+            (observed - expected).abs() < 0.03,
+            "rare/common ratio {observed}, expected {expected}"
+        );
+    }
+
+    #[test]
+    fn generate_cards_from_empty_pool_leaves_slots_empty() {
+        let mut rng = GameRng::seeded(0).0;
+        let mut shop = Shop::default();
+        shop.generate_cards_from(&[], &mut rng);
+        assert_eq!(shop.cards, [None; HAND_SIZE]);
+    }
+
     #[test]
     fn generate_cards_clears_selection() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
         shop.selected = Some(2);
-        shop.generate_cards();
+        shop.generate_cards(&mut rng);
         assert_eq!(shop.selected, None);
     }
 
     #[test]
     fn selected_building_returns_none_when_no_selection() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
-        shop.generate_cards();
+        shop.generate_cards(&mut rng);
         assert!(shop.selected_building().is_none());
     }
 
@@ -201,8 +303,9 @@ mod tests {
 
     #[test]
     fn remove_selected_clears_card_and_selection() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
-        shop.generate_cards();
+        shop.generate_cards(&mut rng);
         shop.selected = Some(1);
         shop.remove_selected();
 
@@ -212,8 +315,9 @@ mod tests {
 
     #[test]
     fn remove_selected_sets_placed_flag() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
-        shop.generate_cards();
+        shop.generate_cards(&mut rng);
         shop.selected = Some(0);
         shop.remove_selected();
 
@@ -260,40 +364,99 @@ mod tests {
         assert_eq!(shop.reroll_cost(), 40);
     }
 
+    #[test]
+    fn locked_card_survives_reroll_while_unlocked_slots_refill() {
+        let mut rng = GameRng::seeded(0).0;
+        let mut shop = Shop::default();
+        shop.cards[1] = Some(BuildingType::SlowTower);
+        shop.toggle_lock(1);
+
+        shop.reroll(&mut rng);
+
+        assert_eq!(shop.cards[1], Some(BuildingType::SlowTower));
+        assert!(shop.locked[1]);
+        for slot in [0, 2, 3] {
+            assert!(shop.cards[slot].is_some(), "slot {slot} should refill");
+        }
+    }
+
+    #[test]
+    fn generation_skips_locked_slots() {
+        let mut rng = GameRng::seeded(0).0;
+        let mut shop = Shop::default();
+        shop.cards = [Some(BuildingType::SlowTower); HAND_SIZE];
+        shop.toggle_lock(2);
+
+        shop.generate_cards_from(&[BuildingType::Barracks], &mut rng);
+
+        assert_eq!(
+            shop.cards,
+            [
+                Some(BuildingType::Barracks),
+                Some(BuildingType::Barracks),
+                Some(BuildingType::SlowTower),
+                Some(BuildingType::Barracks),
+            ]
+        );
+    }
+
+    #[test]
+    fn toggle_lock_ignores_empty_slot() {
+        let mut shop = Shop::default();
+        shop.toggle_lock(0);
+        assert_eq!(shop.locked, [false; HAND_SIZE]);
+    }
+
+    #[test]
+    fn placing_a_locked_card_unlocks_its_slot() {
+        let mut shop = Shop::default();
+        shop.cards[0] = Some(BuildingType::Farm);
+        shop.toggle_lock(0);
+        shop.selected = Some(0);
+
+        shop.remove_selected();
+
+        assert!(!shop.locked[0]);
+    }
+
     #[test]
     fn reroll_increments_no_build_counter() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
         shop.placed_since_last_reroll = false;
-        shop.reroll();
+        shop.reroll(&mut rng);
 
         assert_eq!(shop.consecutive_no_build_rerolls, 1);
     }
 
     #[test]
     fn reroll_does_not_increment_after_placing() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
         shop.placed_since_last_reroll = true;
-        shop.reroll();
+        shop.reroll(&mut rng);
 
         assert_eq!(shop.consecutive_no_build_rerolls, 0);
     }
 
     #[test]
     fn reroll_clears_placed_flag() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
         shop.placed_since_last_reroll = true;
-        shop.reroll();
+        shop.reroll(&mut rng);
 
         assert!(!shop.placed_since_last_reroll);
     }
 
     #[test]
     fn reroll_regenerates_cards() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
         // Start with empty cards
         assert!(shop.cards.iter().all(Option::is_none));
 
-        shop.reroll();
+        shop.reroll(&mut rng);
 
         // All cards should be filled
         for (i, card) in shop.cards.iter().enumerate() {
@@ -306,12 +469,13 @@ mod tests {
 
     #[test]
     fn reroll_cost_resets_after_placement() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
         shop.placed_since_last_reroll = false;
 
         // Reroll twice without placing
-        shop.reroll();
-        shop.reroll();
+        shop.reroll(&mut rng);
+        shop.reroll(&mut rng);
         assert_eq!(shop.reroll_cost(), 10); // 5 * 2^1
 
         // Place a building
@@ -359,13 +523,14 @@ mod tests {
 
     #[test]
     fn try_reroll_deducts_gold_and_rerolls() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
-        shop.generate_cards();
+        shop.generate_cards(&mut rng);
         shop.placed_since_last_reroll = false;
-        shop.reroll(); // consecutive = 1, next cost = 5
+        shop.reroll(&mut rng); // consecutive = 1, next cost = 5
         let mut gold = 200u32;
 
-        let result = shop.try_reroll(&mut gold);
+        let result = shop.try_reroll(&mut gold, &mut rng);
 
         assert!(result);
         assert_eq!(gold, 195);
@@ -376,13 +541,14 @@ mod tests {
 
     #[test]
     fn try_reroll_blocked_insufficient_gold() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
         shop.placed_since_last_reroll = false;
         shop.consecutive_no_build_rerolls = 2; // cost = 10
         let old_cards = shop.cards;
         let mut gold = 5u32;
 
-        let result = shop.try_reroll(&mut gold);
+        let result = shop.try_reroll(&mut gold, &mut rng);
 
         assert!(!result);
         assert_eq!(gold, 5);
@@ -391,12 +557,13 @@ mod tests {
 
     #[test]
     fn try_reroll_free_after_placement() {
+        let mut rng = GameRng::seeded(0).0;
         let mut shop = Shop::default();
-        shop.generate_cards();
+        shop.generate_cards(&mut rng);
         shop.placed_since_last_reroll = true;
         let mut gold = 200u32;
 
-        let result = shop.try_reroll(&mut gold);
+        let result = shop.try_reroll(&mut gold, &mut rng);
 
         assert!(result);
         assert_eq!(gold, 200);
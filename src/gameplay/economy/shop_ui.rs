@@ -4,11 +4,75 @@
 
 use bevy::prelude::*;
 
-use super::Gold;
 use super::shop::Shop;
+use super::{Gold, RunStats};
+use crate::gameplay::GameRng;
+use crate::menus::PauseInputGuard;
 use crate::theme::palette;
 use crate::{GameSet, gameplay_running};
 
+/// Max seconds between two clicks on the same card to count as a double-click.
+const DOUBLE_CLICK_SECS: f32 = 0.35;
+
+/// Min seconds between two reroll button clicks. Presses inside it are ignored.
+const REROLL_COOLDOWN_SECS: f32 = 0.3;
+
+/// `SystemSet` for shop input. Placement orders against this so a quick-buy
+/// requested this frame is placed the same frame.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShopInput;
+
+// === Resources ===
+
+/// Tracks the last clicked card to detect double-clicks.
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct CardDoubleClick {
+    slot: Option<usize>,
+    window: Timer,
+}
+
+impl Default for CardDoubleClick {
+    fn default() -> Self {
+        Self {
+            slot: None,
+            window: Timer::from_seconds(DOUBLE_CLICK_SECS, TimerMode::Once),
+        }
+    }
+}
+
+impl CardDoubleClick {
+    /// Records a click on `slot`. Returns `true` if it completes a double-click.
+    /// A third click starts a new pair rather than chaining.
+    pub fn register(&mut self, slot: usize) -> bool {
+        let double = self.slot == Some(slot) && !self.window.is_finished();
+        self.slot = if double { None } else { Some(slot) };
+        self.window.reset();
+        double
+    }
+}
+
+/// Debounces rerolls (button and R key) so a fast double press doesn't reroll
+/// (and charge) twice.
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct RerollCooldown(Timer);
+
+impl Default for RerollCooldown {
+    fn default() -> Self {
+        // Starts finished so the first click goes through.
+        let mut timer = Timer::from_seconds(REROLL_COOLDOWN_SECS, TimerMode::Once);
+        let duration = timer.duration();
+        timer.tick(duration);
+        Self(timer)
+    }
+}
+
+/// Card slot double-clicked this frame. Placement buys it and places it at once.
+#[derive(Resource, Default, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct QuickBuyRequest(pub Option<usize>);
+
 // === Components ===
 
 #[derive(Component, Debug, Clone, Copy, Reflect)]
@@ -34,35 +98,105 @@ pub struct RerollCostText;
 // === Systems ===
 
 /// Handle card button clicks — select the clicked card.
+/// A double-click requests a quick-buy instead of toggling the selection off.
 fn handle_card_click(
+    time: Res<Time>,
     cards: Query<(&Interaction, &CardSlot), Changed<Interaction>>,
     mut shop: ResMut<Shop>,
+    mut double_click: ResMut<CardDoubleClick>,
+    mut quick_buy: ResMut<QuickBuyRequest>,
+    guard: Res<PauseInputGuard>,
 ) {
+    double_click.window.tick(time.delta());
+    if guard.is_armed() {
+        return;
+    }
     for (interaction, slot) in &cards {
         if *interaction == Interaction::Pressed {
-            shop.toggle_select(slot.0);
+            if double_click.register(slot.0) {
+                quick_buy.0 = Some(slot.0);
+            } else {
+                shop.toggle_select(slot.0);
+            }
         }
     }
 }
 
-/// Handle reroll button click.
+/// Right-clicking a hovered card toggles its lock.
+fn handle_card_lock(
+    mouse: Res<ButtonInput<MouseButton>>,
+    cards: Query<(&Interaction, &CardSlot)>,
+    mut shop: ResMut<Shop>,
+    guard: Res<PauseInputGuard>,
+) {
+    if !mouse.just_pressed(MouseButton::Right) || guard.is_armed() {
+        return;
+    }
+    for (interaction, slot) in &cards {
+        if *interaction != Interaction::None {
+            shop.toggle_lock(slot.0);
+        }
+    }
+}
+
+/// Rerolls unless the `RerollCooldown` is still running, restarting it on success.
+fn debounced_reroll(
+    shop: &mut Shop,
+    gold: &mut Gold,
+    run_stats: &mut RunStats,
+    cooldown: &mut RerollCooldown,
+    rng: &mut GameRng,
+) {
+    if !cooldown.0.is_finished() {
+        return;
+    }
+    let cost = shop.reroll_cost();
+    if shop.try_reroll(&mut gold.0, &mut rng.0) {
+        run_stats.record_spend(cost);
+        cooldown.0.reset();
+    }
+}
+
+/// Ticks the `RerollCooldown`. Runs before the reroll click and key handlers.
+fn tick_reroll_cooldown(time: Res<Time>, mut cooldown: ResMut<RerollCooldown>) {
+    cooldown.0.tick(time.delta());
+}
+
+/// Handle reroll button click. Clicks during the `RerollCooldown` are ignored.
 fn handle_reroll_click(
     reroll_btn: Query<&Interaction, (Changed<Interaction>, With<RerollButton>)>,
     mut shop: ResMut<Shop>,
     mut gold: ResMut<Gold>,
+    mut run_stats: ResMut<RunStats>,
+    mut cooldown: ResMut<RerollCooldown>,
+    mut rng: ResMut<GameRng>,
+    guard: Res<PauseInputGuard>,
 ) {
+    if guard.is_armed() {
+        return;
+    }
     for interaction in &reroll_btn {
         if *interaction == Interaction::Pressed {
-            shop.try_reroll(&mut gold.0);
+            debounced_reroll(
+                &mut shop,
+                &mut gold,
+                &mut run_stats,
+                &mut cooldown,
+                &mut rng,
+            );
         }
     }
 }
 
 /// Handle keyboard shortcuts for card selection (1-4) and reroll (R).
+/// R shares the button's `RerollCooldown`.
 fn handle_shop_keyboard(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut shop: ResMut<Shop>,
     mut gold: ResMut<Gold>,
+    mut run_stats: ResMut<RunStats>,
+    mut cooldown: ResMut<RerollCooldown>,
+    mut rng: ResMut<GameRng>,
 ) {
     const CARD_KEYS: [KeyCode; 4] = [
         KeyCode::Digit1,
@@ -79,7 +213,13 @@ fn handle_shop_keyboard(
     }
 
     if keyboard.just_pressed(KeyCode::KeyR) {
-        shop.try_reroll(&mut gold.0);
+        debounced_reroll(
+            &mut shop,
+            &mut gold,
+            &mut run_stats,
+            &mut cooldown,
+            &mut rng,
+        );
     }
 }
 
@@ -96,6 +236,8 @@ fn update_card_visuals(
             BackgroundColor(palette::CARD_EMPTY)
         } else if is_selected {
             BackgroundColor(palette::CARD_SELECTED)
+        } else if shop.locked[slot.0] {
+            BackgroundColor(palette::CARD_LOCKED)
         } else if *interaction == Interaction::Hovered {
             BackgroundColor(palette::CARD_HOVER)
         } else {
@@ -151,11 +293,27 @@ pub(super) fn plugin(app: &mut App) {
         .register_type::<CardNameText>()
         .register_type::<CardCostText>()
         .register_type::<RerollButton>()
-        .register_type::<RerollCostText>();
+        .register_type::<RerollCostText>()
+        .register_type::<CardDoubleClick>()
+        .register_type::<QuickBuyRequest>()
+        .register_type::<RerollCooldown>()
+        .init_resource::<CardDoubleClick>()
+        .init_resource::<QuickBuyRequest>()
+        .init_resource::<RerollCooldown>();
 
     app.add_systems(
         Update,
-        (handle_card_click, handle_reroll_click, handle_shop_keyboard)
+        (
+            handle_card_click,
+            handle_card_lock,
+            (
+                tick_reroll_cooldown,
+                handle_reroll_click,
+                handle_shop_keyboard,
+            )
+                .chain(),
+        )
+            .in_set(ShopInput)
             .in_set(GameSet::Input)
             .run_if(gameplay_running),
     );
@@ -179,6 +337,9 @@ mod tests {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.init_resource::<Shop>();
+        app.init_resource::<PauseInputGuard>();
+        app.init_resource::<CardDoubleClick>();
+        app.init_resource::<QuickBuyRequest>();
         app.add_systems(Update, handle_card_click);
         app
     }
@@ -188,7 +349,11 @@ mod tests {
         app.add_plugins(MinimalPlugins);
         app.init_resource::<Shop>();
         app.init_resource::<Gold>();
-        app.add_systems(Update, handle_reroll_click);
+        app.init_resource::<GameRng>();
+        app.init_resource::<RunStats>();
+        app.init_resource::<PauseInputGuard>();
+        app.init_resource::<RerollCooldown>();
+        app.add_systems(Update, (tick_reroll_cooldown, handle_reroll_click).chain());
         app
     }
 
@@ -226,6 +391,60 @@ mod tests {
         assert_eq!(shop.selected, None);
     }
 
+    #[test]
+    fn card_click_ignored_while_pause_guard_armed() {
+        let mut app = create_card_click_test_app();
+        app.insert_resource(PauseInputGuard::armed());
+        app.world_mut().resource_mut::<Shop>().cards[1] = Some(BuildingType::Farm);
+
+        // Click a card on the frame the pause menu closes
+        app.world_mut().spawn((CardSlot(1), Interaction::Pressed));
+        app.update();
+
+        let shop = app.world().resource::<Shop>();
+        assert_eq!(shop.selected, None);
+    }
+
+    #[test]
+    fn double_click_requests_quick_buy() {
+        let mut app = create_card_click_test_app();
+        app.world_mut().resource_mut::<Shop>().cards[1] = Some(BuildingType::Farm);
+
+        let card = app
+            .world_mut()
+            .spawn((CardSlot(1), Interaction::Pressed))
+            .id();
+        app.update();
+        assert_eq!(app.world().resource::<QuickBuyRequest>().0, None);
+
+        // Second press on the same card within the window
+        *app.world_mut().get_mut::<Interaction>(card).unwrap() = Interaction::Pressed;
+        app.update();
+
+        assert_eq!(app.world().resource::<QuickBuyRequest>().0, Some(1));
+        // Not toggled off by the second click
+        assert_eq!(app.world().resource::<Shop>().selected, Some(1));
+    }
+
+    #[test]
+    fn double_click_expires_after_window() {
+        let mut tracker = CardDoubleClick::default();
+        assert!(!tracker.register(0));
+        let duration = tracker.window.duration();
+        tracker.window.tick(duration);
+        assert!(!tracker.register(0));
+        assert!(tracker.register(0));
+        // A third click starts a new pair.
+        assert!(!tracker.register(0));
+    }
+
+    #[test]
+    fn clicks_on_different_cards_are_not_a_double_click() {
+        let mut tracker = CardDoubleClick::default();
+        assert!(!tracker.register(0));
+        assert!(!tracker.register(1));
+    }
+
     #[test]
     fn card_click_empty_slot_ignored() {
         let mut app = create_card_click_test_app();
@@ -239,10 +458,13 @@ mod tests {
 
     #[test]
     fn reroll_click_regenerates_cards_and_deducts_gold() {
+        let mut rng = GameRng::seeded(0).0;
         let mut app = create_reroll_click_test_app();
 
-        app.world_mut().resource_mut::<Shop>().generate_cards();
-        app.world_mut().resource_mut::<Shop>().reroll();
+        app.world_mut()
+            .resource_mut::<Shop>()
+            .generate_cards(&mut rng);
+        app.world_mut().resource_mut::<Shop>().reroll(&mut rng);
         let initial_gold = app.world().resource::<Gold>().0;
 
         app.world_mut().spawn((RerollButton, Interaction::Pressed));
@@ -259,6 +481,7 @@ mod tests {
         }
 
         assert_eq!(gold.0, initial_gold - 5);
+        assert_eq!(app.world().resource::<RunStats>().gold_spent, 5);
     }
 
     #[test]
@@ -282,12 +505,129 @@ mod tests {
         assert_eq!(gold.0, 5);
     }
 
+    #[test]
+    fn reroll_double_click_within_cooldown_rerolls_once() {
+        let mut rng = GameRng::seeded(0).0;
+        let mut app = create_reroll_click_test_app();
+
+        app.world_mut()
+            .resource_mut::<Shop>()
+            .generate_cards(&mut rng);
+        app.world_mut().resource_mut::<Shop>().reroll(&mut rng); // next cost = 5
+        let initial_gold = app.world().resource::<Gold>().0;
+
+        let button = app
+            .world_mut()
+            .spawn((RerollButton, Interaction::Pressed))
+            .id();
+        app.update();
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+        app.update();
+
+        assert_eq!(app.world().resource::<Gold>().0, initial_gold - 5);
+        assert_eq!(app.world().resource::<RunStats>().gold_spent, 5);
+        assert_eq!(
+            app.world().resource::<Shop>().consecutive_no_build_rerolls,
+            2
+        );
+    }
+
+    #[test]
+    fn reroll_click_after_cooldown_rerolls_again() {
+        let mut rng = GameRng::seeded(0).0;
+        let mut app = create_reroll_click_test_app();
+
+        app.world_mut()
+            .resource_mut::<Shop>()
+            .generate_cards(&mut rng);
+        app.world_mut().resource_mut::<Shop>().reroll(&mut rng); // next costs = 5, then 10
+        let initial_gold = app.world().resource::<Gold>().0;
+
+        let button = app
+            .world_mut()
+            .spawn((RerollButton, Interaction::Pressed))
+            .id();
+        app.update();
+
+        let mut cooldown = app.world_mut().resource_mut::<RerollCooldown>();
+        let duration = cooldown.0.duration();
+        cooldown.0.tick(duration);
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+        app.update();
+
+        assert_eq!(app.world().resource::<Gold>().0, initial_gold - 15);
+        assert_eq!(app.world().resource::<RunStats>().gold_spent, 15);
+    }
+
     #[test]
     fn no_placement_without_card_selected() {
         let shop = Shop::default();
         assert!(shop.selected_building().is_none());
     }
 
+    // === Card lock tests ===
+
+    fn create_card_lock_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<Shop>();
+        app.init_resource::<PauseInputGuard>();
+        app.init_resource::<ButtonInput<MouseButton>>();
+        app.add_systems(Update, (handle_card_lock, update_card_visuals).chain());
+        app
+    }
+
+    #[test]
+    fn right_click_on_hovered_card_toggles_lock() {
+        let mut app = create_card_lock_test_app();
+        app.world_mut().resource_mut::<Shop>().cards[1] = Some(BuildingType::Farm);
+        let card = app
+            .world_mut()
+            .spawn((
+                CardSlot(1),
+                Interaction::Hovered,
+                BackgroundColor(palette::CARD_BACKGROUND),
+            ))
+            .id();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Right);
+        app.update();
+
+        assert!(app.world().resource::<Shop>().locked[1]);
+        assert_eq!(
+            app.world().get::<BackgroundColor>(card).unwrap().0,
+            palette::CARD_LOCKED
+        );
+
+        let mut mouse = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+        mouse.release_all();
+        mouse.clear();
+        mouse.press(MouseButton::Right);
+        app.update();
+
+        assert!(!app.world().resource::<Shop>().locked[1]);
+    }
+
+    #[test]
+    fn right_click_ignores_cards_not_under_cursor() {
+        let mut app = create_card_lock_test_app();
+        app.world_mut().resource_mut::<Shop>().cards[0] = Some(BuildingType::Farm);
+        app.world_mut().spawn((
+            CardSlot(0),
+            Interaction::None,
+            BackgroundColor(palette::CARD_BACKGROUND),
+        ));
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Right);
+        app.update();
+
+        assert!(!app.world().resource::<Shop>().locked[0]);
+    }
+
     // === Keyboard system tests ===
 
     fn create_keyboard_test_app() -> App {
@@ -295,8 +635,11 @@ mod tests {
         app.add_plugins(MinimalPlugins);
         app.init_resource::<Shop>();
         app.init_resource::<Gold>();
+        app.init_resource::<GameRng>();
+        app.init_resource::<RunStats>();
         app.init_resource::<ButtonInput<KeyCode>>();
-        app.add_systems(Update, handle_shop_keyboard);
+        app.init_resource::<RerollCooldown>();
+        app.add_systems(Update, (tick_reroll_cooldown, handle_shop_keyboard).chain());
         app
     }
 
@@ -342,8 +685,11 @@ mod tests {
 
     #[test]
     fn keyboard_r_rerolls() {
+        let mut rng = GameRng::seeded(0).0;
         let mut app = create_keyboard_test_app();
-        app.world_mut().resource_mut::<Shop>().generate_cards();
+        app.world_mut()
+            .resource_mut::<Shop>()
+            .generate_cards(&mut rng);
 
         app.world_mut()
             .resource_mut::<ButtonInput<KeyCode>>()
@@ -361,21 +707,50 @@ mod tests {
 
     #[test]
     fn keyboard_r_deducts_gold() {
+        let mut rng = GameRng::seeded(0).0;
         let mut app = create_keyboard_test_app();
         let mut shop = app.world_mut().resource_mut::<Shop>();
-        shop.generate_cards();
+        shop.generate_cards(&mut rng);
         shop.placed_since_last_reroll = false;
-        shop.reroll(); // consecutive = 1, cost = 5
+        shop.reroll(&mut rng); // consecutive = 1, cost = 5
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyR);
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<Gold>().0,
+            crate::gameplay::economy::STARTING_GOLD - 5
+        );
+    }
+
+    #[test]
+    fn keyboard_r_within_cooldown_rerolls_once() {
+        let mut rng = GameRng::seeded(0).0;
+        let mut app = create_keyboard_test_app();
+        let mut shop = app.world_mut().resource_mut::<Shop>();
+        shop.generate_cards(&mut rng);
+        shop.reroll(&mut rng); // next costs = 5, then 10
 
         app.world_mut()
             .resource_mut::<ButtonInput<KeyCode>>()
             .press(KeyCode::KeyR);
         app.update();
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release_all();
+        keyboard.clear();
+        keyboard.press(KeyCode::KeyR);
+        app.update();
 
         assert_eq!(
             app.world().resource::<Gold>().0,
             crate::gameplay::economy::STARTING_GOLD - 5
         );
+        assert_eq!(
+            app.world().resource::<Shop>().consecutive_no_build_rerolls,
+            2
+        );
     }
 
     #[test]
@@ -2,28 +2,88 @@
 //!
 //! Spawning is handled by `gameplay/hud/bottom_bar.rs`.
 
+use std::cmp::Ordering;
+
 use bevy::prelude::*;
 
 use super::Gold;
+use crate::screens::GameState;
+use crate::theme::palette;
 use crate::{GameSet, gameplay_running};
 
+/// Seconds the gold display stays tinted after a change.
+const GOLD_FLASH_SECS: f32 = 0.4;
+
 /// Marker for the gold display text entity.
 #[derive(Component, Debug, Clone, Copy, Reflect)]
 #[reflect(Component)]
 pub struct GoldDisplay;
 
+/// Tints the gold display green on income and red on spend for `GOLD_FLASH_SECS`.
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct GoldFlash {
+    /// Gold seen on the previous change. `None` until the first one (no flash).
+    previous: Option<u32>,
+    timer: Timer,
+}
+
+impl Default for GoldFlash {
+    fn default() -> Self {
+        Self {
+            previous: None,
+            timer: Timer::from_seconds(GOLD_FLASH_SECS, TimerMode::Once),
+        }
+    }
+}
+
 fn update_gold_display(gold: Res<Gold>, mut query: Single<&mut Text, With<GoldDisplay>>) {
     if gold.is_changed() {
         **query = Text::new(format!("Gold: {}", gold.0));
     }
 }
 
+fn flash_gold_display(
+    time: Res<Time>,
+    gold: Res<Gold>,
+    mut flash: ResMut<GoldFlash>,
+    mut color: Single<&mut TextColor, With<GoldDisplay>>,
+) {
+    flash.timer.tick(time.delta());
+
+    let flash_color = if gold.is_changed() {
+        let delta = flash.previous.map(|previous| gold.0.cmp(&previous));
+        flash.previous = Some(gold.0);
+        match delta {
+            Some(Ordering::Greater) => Some(palette::GOLD_GAIN_TEXT),
+            Some(Ordering::Less) => Some(palette::GOLD_SPEND_TEXT),
+            Some(Ordering::Equal) | None => None,
+        }
+    } else {
+        None
+    };
+
+    if let Some(flash_color) = flash_color {
+        color.0 = flash_color;
+        flash.timer.reset();
+    } else if flash.timer.just_finished() {
+        color.0 = palette::GOLD_TEXT;
+    }
+}
+
+fn reset_gold_flash(mut commands: Commands) {
+    commands.insert_resource(GoldFlash::default());
+}
+
 pub(super) fn plugin(app: &mut App) {
-    app.register_type::<GoldDisplay>();
+    app.register_type::<GoldDisplay>()
+        .register_type::<GoldFlash>()
+        .init_resource::<GoldFlash>();
 
+    app.add_systems(OnEnter(GameState::InGame), reset_gold_flash);
     app.add_systems(
         Update,
-        update_gold_display
+        (update_gold_display, flash_gold_display)
             .in_set(GameSet::Ui)
             .run_if(gameplay_running),
     );
@@ -35,6 +95,7 @@ mod tests {
 
     use super::GoldDisplay;
     use crate::gameplay::economy::Gold;
+    use crate::theme::palette;
 
     #[test]
     fn gold_display_updates_on_change() {
@@ -55,4 +116,66 @@ mod tests {
             .unwrap();
         assert_eq!(**text, "Gold: 999");
     }
+
+    fn create_flash_test_app() -> App {
+        let mut app = crate::testing::create_test_app();
+        app.init_resource::<Gold>();
+        app.init_resource::<super::GoldFlash>();
+        app.add_systems(Update, super::flash_gold_display);
+
+        app.world_mut().spawn((
+            Text::new("Gold: 0"),
+            TextColor(palette::GOLD_TEXT),
+            GoldDisplay,
+        ));
+        app.update(); // Records the starting gold without flashing
+        app
+    }
+
+    fn gold_color(app: &mut App) -> Color {
+        app.world_mut()
+            .query_filtered::<&TextColor, With<GoldDisplay>>()
+            .single(app.world())
+            .unwrap()
+            .0
+    }
+
+    fn expire_flash(app: &mut App) {
+        let mut flash = app.world_mut().resource_mut::<super::GoldFlash>();
+        crate::testing::nearly_expire_timer(&mut flash.timer);
+        app.world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .advance_by(std::time::Duration::from_millis(1));
+        app.update();
+    }
+
+    #[test]
+    fn starting_gold_does_not_flash() {
+        let mut app = create_flash_test_app();
+        assert_eq!(gold_color(&mut app), palette::GOLD_TEXT);
+    }
+
+    #[test]
+    fn gold_gain_flashes_gain_color_then_reverts() {
+        let mut app = create_flash_test_app();
+
+        app.world_mut().resource_mut::<Gold>().0 += 10;
+        app.update();
+        assert_eq!(gold_color(&mut app), palette::GOLD_GAIN_TEXT);
+
+        expire_flash(&mut app);
+        assert_eq!(gold_color(&mut app), palette::GOLD_TEXT);
+    }
+
+    #[test]
+    fn gold_spend_flashes_spend_color_then_reverts() {
+        let mut app = create_flash_test_app();
+
+        app.world_mut().resource_mut::<Gold>().0 -= 10;
+        app.update();
+        assert_eq!(gold_color(&mut app), palette::GOLD_SPEND_TEXT);
+
+        expire_flash(&mut app);
+        assert_eq!(gold_color(&mut app), palette::GOLD_TEXT);
+    }
 }
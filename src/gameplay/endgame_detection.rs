@@ -1,14 +1,34 @@
-//! Endgame detection: checks fortress health and triggers victory/defeat.
+//! Endgame detection: checks fortress segment health (and the run objective)
+//! and triggers victory/defeat.
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use crate::gameplay::Health;
 use crate::gameplay::battlefield::{EnemyFortress, PlayerFortress};
 use crate::gameplay::combat::DeathCheck;
+use crate::gameplay::{GameStartTime, Health};
 use crate::menus::Menu;
 use crate::{GameSet, gameplay_running};
 
+/// Time limit for the survive objective (5 minutes).
+pub const SURVIVE_SECS: f32 = 300.0;
+
+/// Win condition for the current run, chosen from the main menu.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Default, Reflect, Serialize, Deserialize)]
+#[reflect(Resource)]
+pub enum Objective {
+    /// Destroy the enemy fortress.
+    #[default]
+    DestroyFortress,
+    /// Keep the player fortress standing for this many seconds
+    /// (destroying the enemy fortress still wins early).
+    Survive { secs: f32 },
+}
+
 pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Objective>()
+        .init_resource::<Objective>();
+
     app.add_systems(
         Update,
         detect_endgame
@@ -18,24 +38,48 @@ pub(super) fn plugin(app: &mut App) {
     );
 }
 
-/// Checks fortress health each frame. If either fortress is dead, transitions
-/// to the appropriate Menu overlay (Victory or Defeat).
+/// Whether every segment of a fortress is at zero health. Segments destroyed
+/// in earlier frames are already despawned, so only the survivors are checked.
+/// A fortress with no segments at all (e.g. despawned by a dev tool) never
+/// counts as destroyed.
+fn all_segments_destroyed<'a>(segments: impl IntoIterator<Item = &'a Health>) -> bool {
+    let mut any = false;
+    for health in segments {
+        if health.current > 0.0 {
+            return false;
+        }
+        any = true;
+    }
+    any
+}
+
+/// Checks fortress health each frame. Once every segment of either fortress is
+/// dead, transitions to the appropriate Menu overlay (Victory or Defeat).
+///
+/// Under [`Objective::Survive`], reaching the time limit is also a victory.
 fn detect_endgame(
     player_fortress: Query<&Health, With<PlayerFortress>>,
     enemy_fortress: Query<&Health, With<EnemyFortress>>,
+    objective: Res<Objective>,
+    time: Res<Time<Virtual>>,
+    start: Res<GameStartTime>,
     mut next_menu: ResMut<NextState<Menu>>,
 ) {
     // Check defeat first (player fortress destroyed)
-    if let Ok(health) = player_fortress.single() {
-        if health.current <= 0.0 {
-            next_menu.set(Menu::Defeat);
-            return;
-        }
+    if all_segments_destroyed(&player_fortress) {
+        next_menu.set(Menu::Defeat);
+        return;
     }
 
     // Check victory (enemy fortress destroyed)
-    if let Ok(health) = enemy_fortress.single() {
-        if health.current <= 0.0 {
+    if all_segments_destroyed(&enemy_fortress) {
+        next_menu.set(Menu::Victory);
+        return;
+    }
+
+    // Check victory (survived the time limit)
+    if let Objective::Survive { secs } = *objective {
+        if time.elapsed_secs() - start.0 >= secs {
             next_menu.set(Menu::Victory);
         }
     }
@@ -44,6 +88,7 @@ fn detect_endgame(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gameplay::battlefield::FortressSegment;
     use crate::screens::GameState;
     use bevy::state::app::StatesPlugin;
 
@@ -53,6 +98,8 @@ mod tests {
         app.add_plugins(StatesPlugin);
         app.init_state::<GameState>();
         app.init_state::<Menu>();
+        app.init_resource::<Objective>();
+        app.init_resource::<GameStartTime>();
         // Must be in InGame + Menu::None for system to run
         app.world_mut()
             .resource_mut::<NextState<GameState>>()
@@ -160,4 +207,202 @@ mod tests {
             next_menu
         );
     }
+
+    #[test]
+    fn detect_endgame_does_nothing_without_fortresses() {
+        let mut app = create_detection_test_app();
+
+        app.update();
+
+        let next_menu = app.world().resource::<NextState<Menu>>();
+        assert!(
+            matches!(*next_menu, NextState::Unchanged),
+            "Expected no menu change, got {next_menu:?}",
+        );
+    }
+
+    #[test]
+    fn detect_endgame_does_nothing_without_player_fortress() {
+        let mut app = create_detection_test_app();
+
+        // Only a healthy enemy fortress; the missing player fortress is not a defeat
+        app.world_mut().spawn((EnemyFortress, Health::new(2000.0)));
+
+        app.update();
+
+        let next_menu = app.world().resource::<NextState<Menu>>();
+        assert!(
+            matches!(*next_menu, NextState::Unchanged),
+            "Expected no menu change, got {next_menu:?}",
+        );
+    }
+
+    #[test]
+    fn detect_endgame_still_detects_victory_without_player_fortress() {
+        let mut app = create_detection_test_app();
+
+        app.world_mut().spawn((
+            EnemyFortress,
+            Health {
+                current: 0.0,
+                max: 2000.0,
+            },
+        ));
+
+        app.update();
+
+        let next_menu = app.world().resource::<NextState<Menu>>();
+        assert!(
+            matches!(*next_menu, NextState::Pending(Menu::Victory)),
+            "Expected Menu::Victory, got {next_menu:?}",
+        );
+    }
+
+    fn spawn_segment(app: &mut App, marker: impl Bundle, row: u16, hp: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                marker,
+                FortressSegment { row },
+                Health {
+                    current: hp,
+                    max: 1000.0,
+                },
+            ))
+            .id()
+    }
+
+    #[test]
+    fn destroying_one_player_segment_does_not_end_the_game() {
+        let mut app = create_detection_test_app();
+        spawn_segment(&mut app, PlayerFortress, 0, 0.0);
+        spawn_segment(&mut app, PlayerFortress, 1, 1000.0);
+        spawn_segment(&mut app, EnemyFortress, 0, 1000.0);
+
+        app.update();
+
+        let next_menu = app.world().resource::<NextState<Menu>>();
+        assert!(
+            matches!(*next_menu, NextState::Unchanged),
+            "Expected no menu change, got {next_menu:?}",
+        );
+    }
+
+    #[test]
+    fn destroying_every_player_segment_triggers_defeat() {
+        let mut app = create_detection_test_app();
+        let first = spawn_segment(&mut app, PlayerFortress, 0, 0.0);
+        let last = spawn_segment(&mut app, PlayerFortress, 1, 1000.0);
+        spawn_segment(&mut app, EnemyFortress, 0, 1000.0);
+        app.update();
+
+        // The first segment has since been despawned by death detection
+        app.world_mut().despawn(first);
+        app.world_mut().get_mut::<Health>(last).unwrap().current = 0.0;
+        app.update();
+
+        let next_menu = app.world().resource::<NextState<Menu>>();
+        assert!(
+            matches!(*next_menu, NextState::Pending(Menu::Defeat)),
+            "Expected Menu::Defeat, got {next_menu:?}",
+        );
+    }
+
+    #[test]
+    fn destroying_every_enemy_segment_triggers_victory() {
+        let mut app = create_detection_test_app();
+        spawn_segment(&mut app, PlayerFortress, 0, 1000.0);
+        let first = spawn_segment(&mut app, EnemyFortress, 0, 1000.0);
+        spawn_segment(&mut app, EnemyFortress, 1, 0.0);
+        app.update();
+        assert!(matches!(
+            *app.world().resource::<NextState<Menu>>(),
+            NextState::Unchanged
+        ));
+
+        app.world_mut().get_mut::<Health>(first).unwrap().current = 0.0;
+        app.update();
+
+        let next_menu = app.world().resource::<NextState<Menu>>();
+        assert!(
+            matches!(*next_menu, NextState::Pending(Menu::Victory)),
+            "Expected Menu::Victory, got {next_menu:?}",
+        );
+    }
+
+    /// Pretend the run started `secs` seconds ago.
+    fn set_elapsed(app: &mut App, secs: f32) {
+        let now = app.world().resource::<Time<Virtual>>().elapsed_secs();
+        app.world_mut().resource_mut::<GameStartTime>().0 = now - secs;
+    }
+
+    #[test]
+    fn survive_mode_wins_at_time_limit_with_intact_fortress() {
+        let mut app = create_detection_test_app();
+        app.insert_resource(Objective::Survive { secs: SURVIVE_SECS });
+        app.world_mut().spawn((PlayerFortress, Health::new(2000.0)));
+        app.world_mut().spawn((EnemyFortress, Health::new(2000.0)));
+
+        set_elapsed(&mut app, SURVIVE_SECS + 1.0);
+        app.update();
+
+        let next_menu = app.world().resource::<NextState<Menu>>();
+        assert!(
+            matches!(*next_menu, NextState::Pending(Menu::Victory)),
+            "Expected Menu::Victory, got {next_menu:?}",
+        );
+    }
+
+    #[test]
+    fn survive_mode_does_nothing_before_time_limit() {
+        let mut app = create_detection_test_app();
+        app.insert_resource(Objective::Survive { secs: SURVIVE_SECS });
+        app.world_mut().spawn((PlayerFortress, Health::new(2000.0)));
+
+        set_elapsed(&mut app, SURVIVE_SECS / 2.0);
+        app.update();
+
+        let next_menu = app.world().resource::<NextState<Menu>>();
+        assert!(
+            matches!(*next_menu, NextState::Unchanged),
+            "Expected no menu change, got {next_menu:?}",
+        );
+    }
+
+    #[test]
+    fn survive_mode_still_loses_when_fortress_falls_at_time_limit() {
+        let mut app = create_detection_test_app();
+        app.insert_resource(Objective::Survive { secs: SURVIVE_SECS });
+        app.world_mut().spawn((
+            PlayerFortress,
+            Health {
+                current: 0.0,
+                max: 2000.0,
+            },
+        ));
+
+        set_elapsed(&mut app, SURVIVE_SECS + 1.0);
+        app.update();
+
+        let next_menu = app.world().resource::<NextState<Menu>>();
+        assert!(
+            matches!(*next_menu, NextState::Pending(Menu::Defeat)),
+            "Expected Menu::Defeat, got {next_menu:?}",
+        );
+    }
+
+    #[test]
+    fn destroy_mode_ignores_time_limit() {
+        let mut app = create_detection_test_app();
+        app.world_mut().spawn((PlayerFortress, Health::new(2000.0)));
+        app.world_mut().spawn((EnemyFortress, Health::new(2000.0)));
+
+        set_elapsed(&mut app, SURVIVE_SECS + 1.0);
+        app.update();
+
+        let next_menu = app.world().resource::<NextState<Menu>>();
+        assert!(
+            matches!(*next_menu, NextState::Unchanged),
+            "Expected no menu change, got {next_menu:?}",
+        );
+    }
 }
@@ -0,0 +1,279 @@
+//! Alert camera focus: with `Settings::focus_camera_on_alerts` on, a breakthrough
+//! or a hit on the player fortress briefly pans the camera to the threat, then
+//! returns it to where the player left it. Panning during the detour cancels the
+//! return. Alerts right after one that moved the camera are ignored, so a
+//! sustained siege doesn't keep yanking it around.
+
+use bevy::prelude::*;
+
+use super::breakthrough::{BreakthroughAlert, detect_breakthrough};
+use crate::gameplay::HealthChanged;
+use crate::gameplay::battlefield::{
+    BattlefieldBounds, CameraFollow, PlayerFortress, player_moving_camera,
+};
+use crate::screens::GameState;
+use crate::settings::Settings;
+use crate::{GameSet, gameplay_running};
+
+/// How long the camera stays on an alert before returning (seconds).
+pub const ALERT_FOCUS_SECS: f32 = 2.0;
+
+/// After an alert moves the camera, further alerts are ignored for this long
+/// (seconds). Longer than `ALERT_FOCUS_SECS` so the player gets the camera back
+/// for a while before the next detour.
+pub const ALERT_DEBOUNCE_SECS: f32 = 5.0;
+
+// === Resources ===
+
+/// An in-progress camera detour to an alert.
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
+pub struct AlertFocus {
+    /// Camera position before the detour, or `None` when not focusing.
+    pub return_to: Option<Vec2>,
+    /// Time left before the camera returns.
+    pub timer: Timer,
+    /// Time left before another alert may move the camera.
+    pub debounce: Timer,
+}
+
+// === Systems ===
+
+fn reset_alert_focus(mut commands: Commands) {
+    commands.insert_resource(AlertFocus::default());
+}
+
+/// Pans the camera to a new breakthrough or fortress hit, clamped to the
+/// battlefield, and returns it after `ALERT_FOCUS_SECS`. Only the first alert in
+/// each `ALERT_DEBOUNCE_SECS` window moves the camera. If the player pans during
+/// the detour the return is cancelled, so their pan sticks. Skipped in follow
+/// mode, which already owns the camera.
+/// Runs in `GameSet::Ui`, after `detect_breakthrough`.
+fn focus_camera_on_alerts(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Res<Settings>,
+    follow: Res<CameraFollow>,
+    bounds: Res<BattlefieldBounds>,
+    alert: Res<BreakthroughAlert>,
+    mut was_breaking_through: Local<bool>,
+    mut health_changes: MessageReader<HealthChanged>,
+    fortresses: Query<&Transform, (With<PlayerFortress>, Without<Camera2d>)>,
+    mut camera: Single<&mut Transform, With<Camera2d>>,
+    mut focus: ResMut<AlertFocus>,
+) {
+    let fortress_hit = health_changes
+        .read()
+        .filter(|change| change.new < change.old)
+        .find_map(|change| fortresses.get(change.entity).ok())
+        .map(|transform| transform.translation.xy());
+    let breakthrough = alert.0.filter(|_| !*was_breaking_through);
+    *was_breaking_through = alert.is_active();
+
+    focus.debounce.tick(time.delta());
+    let accepting =
+        settings.focus_camera_on_alerts && !follow.0 && focus.debounce.remaining().is_zero();
+    let threat = fortress_hit.or(breakthrough);
+    if let Some(threat) = threat.filter(|_| accepting) {
+        if focus.return_to.is_none() {
+            focus.return_to = Some(camera.translation.xy());
+        }
+        let target = threat.clamp(Vec2::ZERO, Vec2::new(bounds.width, bounds.height));
+        camera.translation.x = target.x;
+        camera.translation.y = target.y;
+        focus.timer = Timer::from_seconds(ALERT_FOCUS_SECS, TimerMode::Once);
+        focus.debounce = Timer::from_seconds(ALERT_DEBOUNCE_SECS, TimerMode::Once);
+        return;
+    }
+
+    let Some(return_to) = focus.return_to else {
+        return;
+    };
+    if player_moving_camera(&keyboard) {
+        focus.return_to = None;
+        return;
+    }
+    focus.timer.tick(time.delta());
+    if focus.timer.is_finished() {
+        camera.translation.x = return_to.x;
+        camera.translation.y = return_to.y;
+        focus.return_to = None;
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<AlertFocus>()
+        .init_resource::<AlertFocus>();
+
+    app.add_systems(OnEnter(GameState::InGame), reset_alert_focus);
+    app.add_systems(
+        Update,
+        focus_camera_on_alerts
+            .after(detect_breakthrough)
+            .in_set(GameSet::Ui)
+            .run_if(gameplay_running),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::Health;
+    use pretty_assertions::assert_eq;
+
+    const FORTRESS_XY: Vec2 = Vec2::new(64.0, 320.0);
+    const CAMERA_XY: Vec2 = Vec2::new(2000.0, 300.0);
+
+    fn create_alert_focus_test_app(enabled: bool) -> (App, Entity) {
+        let mut app = crate::testing::create_base_test_app();
+        app.insert_resource(Settings {
+            focus_camera_on_alerts: enabled,
+            ..default()
+        });
+        app.init_resource::<CameraFollow>();
+        app.init_resource::<BattlefieldBounds>();
+        app.init_resource::<BreakthroughAlert>();
+        app.init_resource::<AlertFocus>();
+        app.add_systems(Update, focus_camera_on_alerts);
+        let fortress = spawn_fortress_segment(&mut app, FORTRESS_XY);
+        set_camera_xy(&mut app, CAMERA_XY);
+        (app, fortress)
+    }
+
+    fn camera_xy(app: &mut App) -> Vec2 {
+        let mut cameras = app
+            .world_mut()
+            .query_filtered::<&Transform, With<Camera2d>>();
+        cameras.single(app.world()).unwrap().translation.xy()
+    }
+
+    fn set_camera_xy(app: &mut App, xy: Vec2) {
+        let mut cameras = app
+            .world_mut()
+            .query_filtered::<&mut Transform, With<Camera2d>>();
+        let mut camera = cameras.single_mut(app.world_mut()).unwrap();
+        camera.translation.x = xy.x;
+        camera.translation.y = xy.y;
+    }
+
+    fn spawn_fortress_segment(app: &mut App, xy: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                PlayerFortress,
+                Health::new(100.0),
+                Transform::from_translation(xy.extend(0.0)),
+            ))
+            .id()
+    }
+
+    fn hit_fortress(app: &mut App, fortress: Entity) {
+        app.world_mut().write_message(HealthChanged {
+            entity: fortress,
+            old: 100.0,
+            new: 90.0,
+        });
+        app.update();
+    }
+
+    #[test]
+    fn fortress_hit_focuses_camera_then_restores_it() {
+        let (mut app, fortress) = create_alert_focus_test_app(true);
+
+        hit_fortress(&mut app, fortress);
+        assert_eq!(camera_xy(&mut app), FORTRESS_XY);
+
+        crate::testing::advance_secs(&mut app, ALERT_FOCUS_SECS + 0.1);
+        assert_eq!(camera_xy(&mut app), CAMERA_XY);
+        assert_eq!(app.world().resource::<AlertFocus>().return_to, None);
+    }
+
+    #[test]
+    fn panning_during_detour_cancels_the_return() {
+        let (mut app, fortress) = create_alert_focus_test_app(true);
+        let panned_to = FORTRESS_XY + Vec2::new(400.0, 0.0);
+
+        hit_fortress(&mut app, fortress);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyD);
+        set_camera_xy(&mut app, panned_to);
+        app.update();
+        assert_eq!(app.world().resource::<AlertFocus>().return_to, None);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KeyCode::KeyD);
+        crate::testing::advance_secs(&mut app, ALERT_FOCUS_SECS + 0.1);
+        assert_eq!(camera_xy(&mut app), panned_to);
+    }
+
+    #[test]
+    fn camera_stays_on_alert_within_window() {
+        let (mut app, fortress) = create_alert_focus_test_app(true);
+
+        hit_fortress(&mut app, fortress);
+        crate::testing::advance_secs(&mut app, ALERT_FOCUS_SECS / 2.0);
+
+        assert_eq!(camera_xy(&mut app), FORTRESS_XY);
+    }
+
+    #[test]
+    fn fortress_hit_is_ignored_when_option_is_off() {
+        let (mut app, fortress) = create_alert_focus_test_app(false);
+
+        hit_fortress(&mut app, fortress);
+
+        assert_eq!(camera_xy(&mut app), CAMERA_XY);
+    }
+
+    #[test]
+    fn breakthrough_focuses_camera_once_when_it_starts() {
+        let (mut app, _) = create_alert_focus_test_app(true);
+        let intruder = Vec2::new(300.0, 200.0);
+        app.insert_resource(BreakthroughAlert(Some(intruder)));
+
+        app.update();
+        assert_eq!(camera_xy(&mut app), intruder);
+
+        // The same breakthrough does not hold the camera past the window
+        crate::testing::advance_secs(&mut app, ALERT_FOCUS_SECS + 0.1);
+        assert_eq!(camera_xy(&mut app), CAMERA_XY);
+    }
+
+    #[test]
+    fn repeated_hits_within_window_do_not_refocus() {
+        let (mut app, fortress) = create_alert_focus_test_app(true);
+        let other_segment = spawn_fortress_segment(&mut app, FORTRESS_XY + Vec2::Y * 64.0);
+
+        hit_fortress(&mut app, fortress);
+        crate::testing::advance_secs(&mut app, ALERT_FOCUS_SECS / 2.0);
+        hit_fortress(&mut app, other_segment);
+        assert_eq!(camera_xy(&mut app), FORTRESS_XY);
+
+        // The later hit did not extend the detour either
+        crate::testing::advance_secs(&mut app, ALERT_FOCUS_SECS / 2.0 + 0.1);
+        assert_eq!(camera_xy(&mut app), CAMERA_XY);
+
+        // Still inside the debounce window: the camera stays with the player
+        hit_fortress(&mut app, fortress);
+        assert_eq!(camera_xy(&mut app), CAMERA_XY);
+
+        crate::testing::advance_secs(&mut app, ALERT_DEBOUNCE_SECS);
+        hit_fortress(&mut app, fortress);
+        assert_eq!(camera_xy(&mut app), FORTRESS_XY);
+    }
+
+    #[test]
+    fn alert_target_is_clamped_to_battlefield() {
+        let (mut app, _) = create_alert_focus_test_app(true);
+        let bounds = *app.world().resource::<BattlefieldBounds>();
+        app.insert_resource(BreakthroughAlert(Some(Vec2::new(
+            -50.0,
+            bounds.height + 50.0,
+        ))));
+
+        app.update();
+
+        assert_eq!(camera_xy(&mut app), Vec2::new(0.0, bounds.height));
+    }
+}
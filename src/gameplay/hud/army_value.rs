@@ -0,0 +1,202 @@
+//! Army value readout: a top-of-screen bar comparing living-unit strength per team.
+
+use bevy::prelude::*;
+
+use crate::gameplay::units::{EnemyUnit, PlayerUnit};
+use crate::gameplay::{CombatStats, Health};
+use crate::screens::GameState;
+use crate::theme::palette;
+use crate::{GameSet, gameplay_running};
+
+const BAR_WIDTH: f32 = 300.0;
+const BAR_HEIGHT: f32 = 10.0;
+const BAR_TOP: f32 = 4.0;
+
+/// Seconds of sustained damage a unit's DPS is worth, in HP-equivalent value.
+const DPS_VALUE_SECS: f32 = 10.0;
+
+// === Resources ===
+
+/// Summed value of all living units per team. Recomputed every frame.
+#[derive(Resource, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Resource)]
+pub struct ArmyValue {
+    pub player: f32,
+    pub enemy: f32,
+}
+
+impl ArmyValue {
+    /// Player fraction of the combined value, in `0.0..=1.0`.
+    /// Even (0.5) when both armies are empty.
+    #[must_use]
+    pub const fn player_share(&self) -> f32 {
+        let total = self.player + self.enemy;
+        if total <= 0.0 {
+            0.5
+        } else {
+            self.player / total
+        }
+    }
+}
+
+// === Components ===
+
+/// Marker for the army value bar container (enemy color background).
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct ArmyValueBar;
+
+/// Marker for the player portion of the army value bar (width = player share).
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct ArmyValueFill;
+
+/// Value of one unit: current HP plus a damage proxy (DPS over `DPS_VALUE_SECS`).
+#[must_use]
+pub const fn unit_value(health: &Health, stats: &CombatStats) -> f32 {
+    health.current.max(0.0) + stats.damage * stats.attack_speed * DPS_VALUE_SECS
+}
+
+// === Systems ===
+
+fn spawn_army_value_bar(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Army Value Bar"),
+        ArmyValueBar,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(BAR_TOP),
+            left: Val::Percent(50.0),
+            margin: UiRect::left(Val::Px(-BAR_WIDTH / 2.0)),
+            width: Val::Px(BAR_WIDTH),
+            height: Val::Px(BAR_HEIGHT),
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BackgroundColor(palette::ENEMY_UNIT),
+        BorderColor::all(palette::PANEL_BORDER),
+        Pickable::IGNORE,
+        DespawnOnExit(GameState::InGame),
+        children![(
+            Name::new("Army Value Fill"),
+            ArmyValueFill,
+            Node {
+                width: Val::Percent(50.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            BackgroundColor(palette::PLAYER_UNIT),
+        )],
+    ));
+}
+
+fn compute_army_value(
+    mut army_value: ResMut<ArmyValue>,
+    players: Query<(&Health, &CombatStats), With<PlayerUnit>>,
+    enemies: Query<(&Health, &CombatStats), With<EnemyUnit>>,
+) {
+    army_value.player = players.iter().map(|(h, s)| unit_value(h, s)).sum();
+    army_value.enemy = enemies.iter().map(|(h, s)| unit_value(h, s)).sum();
+}
+
+fn update_army_value_bar(
+    army_value: Res<ArmyValue>,
+    mut fills: Query<&mut Node, With<ArmyValueFill>>,
+) {
+    let width = Val::Percent(army_value.player_share() * 100.0);
+    for mut node in &mut fills {
+        node.width = width;
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<ArmyValue>()
+        .register_type::<ArmyValueBar>()
+        .register_type::<ArmyValueFill>()
+        .init_resource::<ArmyValue>();
+
+    app.add_systems(OnEnter(GameState::InGame), spawn_army_value_bar);
+    app.add_systems(
+        Update,
+        (compute_army_value, update_army_value_bar)
+            .chain()
+            .in_set(GameSet::Ui)
+            .run_if(gameplay_running),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::Team;
+    use crate::testing::spawn_test_unit;
+
+    fn create_army_value_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<ArmyValue>();
+        app.add_systems(Update, (compute_army_value, update_army_value_bar).chain());
+        app
+    }
+
+    fn share(app: &App) -> f32 {
+        app.world().resource::<ArmyValue>().player_share()
+    }
+
+    #[test]
+    fn empty_battlefield_is_even() {
+        let mut app = create_army_value_test_app();
+        app.update();
+        assert!((share(&app) - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn equal_armies_are_even() {
+        let mut app = create_army_value_test_app();
+        spawn_test_unit(app.world_mut(), Team::Player, 0.0, 0.0);
+        spawn_test_unit(app.world_mut(), Team::Enemy, 100.0, 0.0);
+        app.update();
+        assert!((share(&app) - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn more_player_units_shift_ratio_toward_player() {
+        let mut app = create_army_value_test_app();
+        spawn_test_unit(app.world_mut(), Team::Player, 0.0, 0.0);
+        spawn_test_unit(app.world_mut(), Team::Enemy, 100.0, 0.0);
+        app.update();
+        let before = share(&app);
+
+        spawn_test_unit(app.world_mut(), Team::Player, 0.0, 20.0);
+        app.update();
+        assert!(
+            share(&app) > before,
+            "{} should exceed {before}",
+            share(&app)
+        );
+    }
+
+    #[test]
+    fn stronger_player_unit_shifts_ratio_toward_player() {
+        let mut app = create_army_value_test_app();
+        let player = spawn_test_unit(app.world_mut(), Team::Player, 0.0, 0.0);
+        spawn_test_unit(app.world_mut(), Team::Enemy, 100.0, 0.0);
+        app.world_mut()
+            .get_mut::<CombatStats>(player)
+            .unwrap()
+            .damage *= 2.0;
+        app.update();
+        assert!(share(&app) > 0.5);
+    }
+
+    #[test]
+    fn fill_width_tracks_player_share() {
+        let mut app = create_army_value_test_app();
+        let fill = app.world_mut().spawn((ArmyValueFill, Node::default())).id();
+        spawn_test_unit(app.world_mut(), Team::Player, 0.0, 0.0);
+        app.update();
+
+        let node = app.world().get::<Node>(fill).unwrap();
+        assert_eq!(node.width, Val::Percent(100.0));
+    }
+}
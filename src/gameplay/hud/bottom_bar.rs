@@ -1,8 +1,10 @@
-//! Bottom bar layout: Gold (left) | Cards + Reroll (center) | Timer + Minimap (right).
+//! Bottom bar layout: Gold (left) | Cards + Reroll (center) | Wave + Timer + Minimap (right).
 
 use bevy::prelude::*;
 
 use super::elapsed_time::ElapsedTimeDisplay;
+use super::minimap::Minimap;
+use super::wave_counter::WaveCounterDisplay;
 use crate::gameplay::GameStartTime;
 use crate::gameplay::economy::STARTING_GOLD;
 use crate::gameplay::economy::shop::HAND_SIZE;
@@ -74,7 +76,7 @@ fn spawn_bottom_bar(
             ),
             // === Center section: Cards + Reroll ===
             center_section(),
-            // === Right section: Timer + Minimap ===
+            // === Right section: Wave + Timer + Minimap ===
             (
                 Name::new("Bar Right"),
                 Node {
@@ -85,6 +87,14 @@ fn spawn_bottom_bar(
                     ..default()
                 },
                 children![
+                    // Wave counter (blank unless wave mode is on)
+                    (
+                        Name::new("Wave Counter"),
+                        WaveCounterDisplay,
+                        Text::new(""),
+                        TextFont::from_font_size(palette::FONT_SIZE_HUD),
+                        TextColor(palette::BODY_TEXT),
+                    ),
                     // Elapsed time
                     (
                         Name::new("Elapsed Time"),
@@ -93,9 +103,10 @@ fn spawn_bottom_bar(
                         TextFont::from_font_size(palette::FONT_SIZE_HUD),
                         TextColor(palette::BODY_TEXT),
                     ),
-                    // Minimap placeholder
+                    // Minimap (combat heat cells spawned by `minimap::spawn_heat_cells`)
                     (
-                        Name::new("Minimap Placeholder"),
+                        Name::new("Minimap"),
+                        Minimap,
                         Node {
                             width: Val::Px(MINIMAP_SIZE),
                             height: Val::Px(MINIMAP_SIZE),
@@ -200,6 +211,10 @@ mod tests {
         let mut app = crate::testing::create_base_test_app();
         crate::testing::init_economy_resources(&mut app);
         app.init_resource::<GameStartTime>();
+        app.init_resource::<crate::gameplay::units::spawn::WaveSpawner>();
+        app.init_resource::<crate::gameplay::units::spawn::WaveCounter>();
+        app.init_resource::<crate::settings::Settings>();
+        app.init_resource::<crate::gameplay::battlefield::CameraFollow>();
         app.add_plugins(super::super::plugin);
         crate::testing::transition_to_ingame(&mut app);
         app
@@ -228,6 +243,12 @@ mod tests {
         assert_entity_count::<With<ElapsedTimeDisplay>>(&mut app, 1);
     }
 
+    #[test]
+    fn bottom_bar_has_wave_counter_display() {
+        let mut app = create_bottom_bar_test_app();
+        assert_entity_count::<With<WaveCounterDisplay>>(&mut app, 1);
+    }
+
     #[test]
     fn bottom_bar_has_four_card_slots() {
         let mut app = create_bottom_bar_test_app();
@@ -240,6 +261,15 @@ mod tests {
         assert_entity_count::<With<RerollButton>>(&mut app, 1);
     }
 
+    #[test]
+    fn bottom_bar_has_minimap_with_heat_cells() {
+        use super::super::minimap::{HEAT_COLS, HEAT_ROWS, MinimapHeatCell};
+
+        let mut app = create_bottom_bar_test_app();
+        assert_entity_count::<With<Minimap>>(&mut app, 1);
+        assert_entity_count::<With<MinimapHeatCell>>(&mut app, HEAT_COLS * HEAT_ROWS);
+    }
+
     #[test]
     fn bottom_bar_height_constant_is_positive() {
         assert!(BOTTOM_BAR_HEIGHT > 0.0);
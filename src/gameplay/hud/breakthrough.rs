@@ -0,0 +1,289 @@
+//! Breakthrough alert: a banner and minimap ping while an enemy unit is inside the
+//! build zone, meaning it slipped past the player's defenses.
+
+use bevy::prelude::*;
+
+use super::minimap::Minimap;
+use crate::gameplay::battlefield::{BUILD_ZONE_END_X, BattlefieldBounds};
+use crate::gameplay::units::EnemyUnit;
+use crate::screens::GameState;
+use crate::theme::palette;
+use crate::{GameSet, gameplay_running};
+
+/// Banner distance from the top of the screen, below the army value bar.
+const BANNER_TOP: f32 = 24.0;
+
+/// Minimap ping size (percent of the minimap).
+const PING_SIZE_PERCENT: f32 = 6.0;
+
+// === Resources ===
+
+/// Position of the enemy deepest past the build-zone boundary, or `None` when
+/// no enemy has broken through. Recomputed every frame.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct BreakthroughAlert(pub Option<Vec2>);
+
+impl BreakthroughAlert {
+    /// Whether an enemy is currently inside the build zone.
+    #[must_use]
+    pub const fn is_active(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+// === Components ===
+
+/// Marker for the on-screen breakthrough banner.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct BreakthroughBanner;
+
+/// Marker for the minimap ping at the deepest intruder's position.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct MinimapPing;
+
+// === Systems ===
+
+fn reset_breakthrough_alert(mut commands: Commands) {
+    commands.insert_resource(BreakthroughAlert::default());
+}
+
+fn spawn_breakthrough_banner(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Breakthrough Banner"),
+        BreakthroughBanner,
+        Text::new("Enemies broke through!"),
+        TextFont::from_font_size(palette::FONT_SIZE_LABEL),
+        TextColor(palette::BREAKTHROUGH_TEXT),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(BANNER_TOP),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        TextLayout::new_with_justify(Justify::Center),
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        DespawnOnExit(GameState::InGame),
+    ));
+}
+
+/// Spawns the (hidden) ping under the minimap panel.
+fn spawn_minimap_ping(add: On<Add, Minimap>, mut commands: Commands) {
+    commands.entity(add.entity).with_child((
+        Name::new("Minimap Ping"),
+        MinimapPing,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(PING_SIZE_PERCENT),
+            height: Val::Percent(PING_SIZE_PERCENT),
+            ..default()
+        },
+        BackgroundColor(palette::MINIMAP_PING),
+        Visibility::Hidden,
+        Pickable::IGNORE,
+    ));
+}
+
+/// Flags a breakthrough when any enemy is left of `BUILD_ZONE_END_X` (toward the
+/// player fortress), tracking the deepest one.
+pub(super) fn detect_breakthrough(
+    mut alert: ResMut<BreakthroughAlert>,
+    enemies: Query<&Transform, With<EnemyUnit>>,
+) {
+    let deepest = enemies
+        .iter()
+        .map(|transform| transform.translation.xy())
+        .filter(|position| position.x < BUILD_ZONE_END_X)
+        .min_by(|a, b| a.x.total_cmp(&b.x));
+    alert.set_if_neq(BreakthroughAlert(deepest));
+}
+
+fn update_breakthrough_banner(
+    alert: Res<BreakthroughAlert>,
+    mut banners: Query<&mut Visibility, With<BreakthroughBanner>>,
+) {
+    let visibility = if alert.is_active() {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut banner in &mut banners {
+        banner.set_if_neq(visibility);
+    }
+}
+
+/// Moves the minimap ping to the deepest intruder and hides it when there is none.
+fn update_minimap_ping(
+    alert: Res<BreakthroughAlert>,
+    bounds: Res<BattlefieldBounds>,
+    mut pings: Query<(&mut Node, &mut Visibility), With<MinimapPing>>,
+) {
+    for (mut node, mut visibility) in &mut pings {
+        let Some(position) = alert.0 else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        let u = (position.x / bounds.width).clamp(0.0, 1.0);
+        let v = (position.y / bounds.height).clamp(0.0, 1.0);
+        node.left = Val::Percent(u.mul_add(100.0, -PING_SIZE_PERCENT / 2.0));
+        node.top = Val::Percent((1.0 - v).mul_add(100.0, -PING_SIZE_PERCENT / 2.0));
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<BreakthroughAlert>()
+        .register_type::<BreakthroughBanner>()
+        .register_type::<MinimapPing>()
+        .init_resource::<BreakthroughAlert>();
+
+    app.add_observer(spawn_minimap_ping);
+
+    app.add_systems(
+        OnEnter(GameState::InGame),
+        (reset_breakthrough_alert, spawn_breakthrough_banner),
+    );
+    app.add_systems(
+        Update,
+        (
+            detect_breakthrough,
+            (update_breakthrough_banner, update_minimap_ping),
+        )
+            .chain()
+            .in_set(GameSet::Ui)
+            .run_if(gameplay_running),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::Team;
+    use crate::testing::spawn_test_unit;
+
+    fn create_breakthrough_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<BreakthroughAlert>()
+            .init_resource::<BattlefieldBounds>();
+        app.add_systems(
+            Update,
+            (
+                detect_breakthrough,
+                (update_breakthrough_banner, update_minimap_ping),
+            )
+                .chain(),
+        );
+        app
+    }
+
+    fn alert_active(app: &App) -> bool {
+        app.world().resource::<BreakthroughAlert>().is_active()
+    }
+
+    #[test]
+    fn enemy_outside_build_zone_raises_no_alert() {
+        let mut app = create_breakthrough_test_app();
+        spawn_test_unit(
+            app.world_mut(),
+            Team::Enemy,
+            BUILD_ZONE_END_X + 100.0,
+            100.0,
+        );
+        app.update();
+        assert!(!alert_active(&app));
+    }
+
+    #[test]
+    fn enemy_past_build_zone_boundary_raises_alert() {
+        let mut app = create_breakthrough_test_app();
+        let enemy = spawn_test_unit(app.world_mut(), Team::Enemy, BUILD_ZONE_END_X + 10.0, 100.0);
+        app.update();
+        assert!(!alert_active(&app));
+
+        app.world_mut()
+            .get_mut::<Transform>(enemy)
+            .unwrap()
+            .translation
+            .x = BUILD_ZONE_END_X - 10.0;
+        app.update();
+        assert!(alert_active(&app));
+    }
+
+    #[test]
+    fn player_unit_in_build_zone_raises_no_alert() {
+        let mut app = create_breakthrough_test_app();
+        spawn_test_unit(
+            app.world_mut(),
+            Team::Player,
+            BUILD_ZONE_END_X - 10.0,
+            100.0,
+        );
+        app.update();
+        assert!(!alert_active(&app));
+    }
+
+    #[test]
+    fn alert_clears_when_no_enemy_is_past_boundary() {
+        let mut app = create_breakthrough_test_app();
+        let enemy = spawn_test_unit(app.world_mut(), Team::Enemy, BUILD_ZONE_END_X - 10.0, 100.0);
+        app.update();
+        assert!(alert_active(&app));
+
+        app.world_mut().entity_mut(enemy).despawn();
+        app.update();
+        assert!(!alert_active(&app));
+    }
+
+    #[test]
+    fn banner_and_ping_follow_alert() {
+        let mut app = create_breakthrough_test_app();
+        let banner = app
+            .world_mut()
+            .spawn((BreakthroughBanner, Visibility::Hidden))
+            .id();
+        let ping = app
+            .world_mut()
+            .spawn((MinimapPing, Node::default(), Visibility::Hidden))
+            .id();
+        spawn_test_unit(app.world_mut(), Team::Enemy, BUILD_ZONE_END_X - 10.0, 100.0);
+        app.update();
+
+        assert_eq!(
+            *app.world().get::<Visibility>(banner).unwrap(),
+            Visibility::Inherited
+        );
+        assert_eq!(
+            *app.world().get::<Visibility>(ping).unwrap(),
+            Visibility::Inherited
+        );
+    }
+
+    #[test]
+    fn ping_maps_against_live_battlefield_bounds() {
+        let mut app = create_breakthrough_test_app();
+        let bounds = BattlefieldBounds::default();
+        app.insert_resource(BattlefieldBounds {
+            height: bounds.height * 2.0,
+            ..bounds
+        });
+        let ping = app
+            .world_mut()
+            .spawn((MinimapPing, Node::default(), Visibility::Hidden))
+            .id();
+        spawn_test_unit(
+            app.world_mut(),
+            Team::Enemy,
+            BUILD_ZONE_END_X - 10.0,
+            bounds.height,
+        );
+        app.update();
+
+        let top = app.world().get::<Node>(ping).unwrap().top;
+        assert_eq!(top, Val::Percent(50.0 - PING_SIZE_PERCENT / 2.0));
+    }
+}
@@ -0,0 +1,465 @@
+//! Minimap overlays: recent projectile and death activity glows on the minimap so
+//! off-screen fights stand out, and an outline marks what the camera currently shows.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::gameplay::Health;
+use crate::gameplay::battlefield::BattlefieldBounds;
+use crate::gameplay::combat::{DeathCheck, Projectile};
+use crate::screens::GameState;
+use crate::theme::palette;
+use crate::{GameSet, gameplay_running};
+
+/// Heat grid columns (battlefield x axis).
+pub const HEAT_COLS: usize = 16;
+
+/// Heat grid rows (battlefield y axis).
+pub const HEAT_ROWS: usize = 4;
+
+/// Intensity added per unit death.
+const DEATH_HEAT: f32 = 1.0;
+
+/// Intensity added per projectile fired.
+const PROJECTILE_HEAT: f32 = 0.15;
+
+/// Intensity lost per second.
+const HEAT_DECAY_PER_SEC: f32 = 0.5;
+
+/// Border thickness of the camera viewport outline (pixels).
+const VIEWPORT_BORDER: f32 = 1.0;
+
+// === Components ===
+
+/// Marker for the minimap panel in the bottom bar. Heat cells are spawned under it.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct Minimap;
+
+/// A minimap cell tinted by the heat at (`col`, `row`).
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct MinimapHeatCell {
+    pub col: usize,
+    pub row: usize,
+}
+
+/// Outline on the minimap marking the battlefield area the camera shows.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct MinimapViewport;
+
+// === Resources ===
+
+/// Decaying combat intensity per minimap cell, in `0.0..=1.0`.
+/// Row 0 is the bottom of the battlefield.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct CombatHeatmap {
+    cells: Vec<f32>,
+}
+
+impl Default for CombatHeatmap {
+    fn default() -> Self {
+        Self {
+            cells: vec![0.0; HEAT_COLS * HEAT_ROWS],
+        }
+    }
+}
+
+impl CombatHeatmap {
+    /// Maps a battlefield world position to its heat cell, or `None` if off the battlefield.
+    #[must_use]
+    pub fn cell_at(position: Vec2, bounds: BattlefieldBounds) -> Option<(usize, usize)> {
+        let u = position.x / bounds.width;
+        let v = position.y / bounds.height;
+        if !(0.0..=1.0).contains(&u) || !(0.0..=1.0).contains(&v) {
+            return None;
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let col = ((u * HEAT_COLS as f32) as usize).min(HEAT_COLS - 1);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let row = ((v * HEAT_ROWS as f32) as usize).min(HEAT_ROWS - 1);
+        Some((col, row))
+    }
+
+    /// Current intensity of a cell.
+    #[must_use]
+    pub fn intensity(&self, col: usize, row: usize) -> f32 {
+        self.cells[row * HEAT_COLS + col]
+    }
+
+    /// Adds `amount` heat at a world position. Off-battlefield positions are ignored.
+    pub fn record(&mut self, position: Vec2, amount: f32, bounds: BattlefieldBounds) {
+        if let Some((col, row)) = Self::cell_at(position, bounds) {
+            let cell = &mut self.cells[row * HEAT_COLS + col];
+            *cell = (*cell + amount).min(1.0);
+        }
+    }
+
+    /// Cools every cell by `HEAT_DECAY_PER_SEC` over `delta`.
+    pub fn decay(&mut self, delta: Duration) {
+        let amount = HEAT_DECAY_PER_SEC * delta.as_secs_f32();
+        for cell in &mut self.cells {
+            *cell = (*cell - amount).max(0.0);
+        }
+    }
+}
+
+/// World-space rect the camera shows, centered on `center`. `FixedVertical`
+/// scaling fits the battlefield height to the window at `ortho_scale` 1.0; the
+/// width follows the window's aspect ratio.
+#[must_use]
+pub fn camera_view_rect(
+    center: Vec2,
+    window_size: Vec2,
+    ortho_scale: f32,
+    bounds: BattlefieldBounds,
+) -> Rect {
+    let height = bounds.height * ortho_scale;
+    let width = height * window_size.x / window_size.y;
+    Rect::from_center_size(center, Vec2::new(width, height))
+}
+
+/// Maps a world-space view rect to minimap percentages: `min` is the (left, top)
+/// corner and `max` the (right, bottom) corner, clipped to the battlefield.
+#[must_use]
+pub fn minimap_viewport_rect(view: Rect, bounds: BattlefieldBounds) -> Rect {
+    let battlefield = Rect::new(0.0, 0.0, bounds.width, bounds.height);
+    let visible = view.intersect(battlefield);
+    let to_u = |x: f32| x / bounds.width * 100.0;
+    let to_v = |y: f32| (1.0 - y / bounds.height) * 100.0;
+    Rect {
+        min: Vec2::new(to_u(visible.min.x), to_v(visible.max.y)),
+        max: Vec2::new(to_u(visible.max.x), to_v(visible.min.y)),
+    }
+}
+
+// === Systems ===
+
+fn reset_heatmap(mut commands: Commands) {
+    commands.insert_resource(CombatHeatmap::default());
+}
+
+/// Spawns the heat cell grid under the minimap panel.
+fn spawn_heat_cells(add: On<Add, Minimap>, mut commands: Commands) {
+    #[allow(clippy::cast_precision_loss)]
+    let (cell_w, cell_h) = (100.0 / HEAT_COLS as f32, 100.0 / HEAT_ROWS as f32);
+    commands.entity(add.entity).with_children(|parent| {
+        for row in 0..HEAT_ROWS {
+            for col in 0..HEAT_COLS {
+                #[allow(clippy::cast_precision_loss)]
+                let (left, top) = (col as f32 * cell_w, (HEAT_ROWS - 1 - row) as f32 * cell_h);
+                parent.spawn((
+                    Name::new(format!("Minimap Heat ({col}, {row})")),
+                    MinimapHeatCell { col, row },
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(left),
+                        top: Val::Percent(top),
+                        width: Val::Percent(cell_w),
+                        height: Val::Percent(cell_h),
+                        ..default()
+                    },
+                    BackgroundColor(Color::NONE),
+                    Pickable::IGNORE,
+                ));
+            }
+        }
+    });
+}
+
+/// Spawns the camera viewport outline under the minimap panel, above the heat cells.
+fn spawn_viewport_outline(add: On<Add, Minimap>, mut commands: Commands) {
+    commands.entity(add.entity).with_child((
+        Name::new("Minimap Viewport"),
+        MinimapViewport,
+        Node {
+            position_type: PositionType::Absolute,
+            border: UiRect::all(Val::Px(VIEWPORT_BORDER)),
+            ..default()
+        },
+        BorderColor::all(palette::MINIMAP_VIEWPORT),
+        ZIndex(1),
+        Pickable::IGNORE,
+    ));
+}
+
+/// Records heat for entities about to die and projectiles fired this frame.
+/// Runs in `GameSet::Death` BEFORE `check_death` so dying entities still exist.
+fn record_combat_heat(
+    mut heatmap: ResMut<CombatHeatmap>,
+    bounds: Res<BattlefieldBounds>,
+    dying: Query<(&Health, &Transform)>,
+    projectiles: Query<&Transform, Added<Projectile>>,
+) {
+    for (health, transform) in &dying {
+        if health.current <= 0.0 {
+            heatmap.record(transform.translation.xy(), DEATH_HEAT, *bounds);
+        }
+    }
+    for transform in &projectiles {
+        heatmap.record(transform.translation.xy(), PROJECTILE_HEAT, *bounds);
+    }
+}
+
+fn decay_combat_heat(time: Res<Time>, mut heatmap: ResMut<CombatHeatmap>) {
+    heatmap.decay(time.delta());
+}
+
+/// Tints each heat cell by its intensity.
+fn update_heat_cells(
+    heatmap: Res<CombatHeatmap>,
+    mut cells: Query<(&MinimapHeatCell, &mut BackgroundColor)>,
+) {
+    for (cell, mut background) in &mut cells {
+        let intensity = heatmap.intensity(cell.col, cell.row);
+        let color = palette::MINIMAP_HEAT.with_alpha(intensity * palette::MINIMAP_HEAT.alpha());
+        background.set_if_neq(BackgroundColor(color));
+    }
+}
+
+/// Moves the viewport outline to the camera's current view as it pans and zooms.
+fn update_minimap_viewport(
+    camera: Single<(&Transform, &Projection), With<Camera2d>>,
+    window: Single<&Window>,
+    bounds: Res<BattlefieldBounds>,
+    mut outlines: Query<&mut Node, With<MinimapViewport>>,
+) {
+    let (transform, projection) = *camera;
+    let scale = match projection {
+        Projection::Orthographic(ortho) => ortho.scale,
+        _ => 1.0,
+    };
+    let view = camera_view_rect(transform.translation.xy(), window.size(), scale, *bounds);
+    let rect = minimap_viewport_rect(view, *bounds);
+    for mut node in &mut outlines {
+        node.left = Val::Percent(rect.min.x);
+        node.top = Val::Percent(rect.min.y);
+        node.width = Val::Percent(rect.width());
+        node.height = Val::Percent(rect.height());
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Minimap>()
+        .register_type::<MinimapHeatCell>()
+        .register_type::<MinimapViewport>()
+        .register_type::<CombatHeatmap>()
+        .init_resource::<CombatHeatmap>();
+
+    app.add_observer(spawn_heat_cells)
+        .add_observer(spawn_viewport_outline);
+
+    app.add_systems(OnEnter(GameState::InGame), reset_heatmap);
+    app.add_systems(
+        Update,
+        (
+            record_combat_heat.in_set(GameSet::Death).before(DeathCheck),
+            (
+                decay_combat_heat,
+                update_heat_cells,
+                update_minimap_viewport,
+            )
+                .chain()
+                .in_set(GameSet::Ui),
+        )
+            .run_if(gameplay_running),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::battlefield::{BATTLEFIELD_HEIGHT, BATTLEFIELD_WIDTH};
+    use pretty_assertions::assert_eq;
+
+    const BOUNDS: BattlefieldBounds = BattlefieldBounds {
+        width: BATTLEFIELD_WIDTH,
+        height: BATTLEFIELD_HEIGHT,
+    };
+
+    #[test]
+    fn cell_at_maps_corners() {
+        assert_eq!(CombatHeatmap::cell_at(Vec2::ZERO, BOUNDS), Some((0, 0)));
+        assert_eq!(
+            CombatHeatmap::cell_at(Vec2::new(BATTLEFIELD_WIDTH, BATTLEFIELD_HEIGHT), BOUNDS),
+            Some((HEAT_COLS - 1, HEAT_ROWS - 1))
+        );
+        assert_eq!(CombatHeatmap::cell_at(Vec2::new(-1.0, 0.0), BOUNDS), None);
+    }
+
+    #[test]
+    fn recording_activity_raises_cell_intensity() {
+        let mut heatmap = CombatHeatmap::default();
+        let position = Vec2::new(BATTLEFIELD_WIDTH * 0.5, BATTLEFIELD_HEIGHT * 0.25);
+        let (col, row) = CombatHeatmap::cell_at(position, BOUNDS).unwrap();
+
+        heatmap.record(position, DEATH_HEAT, BOUNDS);
+
+        assert!(heatmap.intensity(col, row) > 0.0);
+        assert_eq!(heatmap.intensity(0, 0), 0.0);
+    }
+
+    #[test]
+    fn intensity_decays_over_time() {
+        let mut heatmap = CombatHeatmap::default();
+        heatmap.record(Vec2::ZERO, DEATH_HEAT, BOUNDS);
+        let before = heatmap.intensity(0, 0);
+
+        heatmap.decay(Duration::from_millis(500));
+        let after = heatmap.intensity(0, 0);
+        assert!(after < before, "Expected decay: {before} -> {after}");
+
+        heatmap.decay(Duration::from_secs(10));
+        assert_eq!(heatmap.intensity(0, 0), 0.0);
+    }
+
+    #[test]
+    fn intensity_is_capped() {
+        let mut heatmap = CombatHeatmap::default();
+        for _ in 0..5 {
+            heatmap.record(Vec2::ZERO, DEATH_HEAT, BOUNDS);
+        }
+        assert_eq!(heatmap.intensity(0, 0), 1.0);
+    }
+
+    #[test]
+    fn dying_entity_records_heat_at_its_cell() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<CombatHeatmap>()
+            .init_resource::<BattlefieldBounds>();
+        app.add_systems(Update, record_combat_heat);
+
+        let position = Vec2::new(BATTLEFIELD_WIDTH * 0.75, BATTLEFIELD_HEIGHT * 0.5);
+        app.world_mut().spawn((
+            Health {
+                current: 0.0,
+                max: 100.0,
+            },
+            Transform::from_translation(position.extend(0.0)),
+        ));
+        app.update();
+
+        let (col, row) = CombatHeatmap::cell_at(position, BOUNDS).unwrap();
+        let heatmap = app.world().resource::<CombatHeatmap>();
+        assert_eq!(heatmap.intensity(col, row), DEATH_HEAT);
+    }
+
+    #[test]
+    fn minimap_spawns_heat_cells() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_observer(spawn_heat_cells)
+            .add_observer(spawn_viewport_outline);
+
+        app.world_mut().spawn((Minimap, Node::default()));
+        app.update(); // observer fires
+        app.update(); // deferred with_children applied
+
+        crate::testing::assert_entity_count::<With<MinimapHeatCell>>(
+            &mut app,
+            HEAT_COLS * HEAT_ROWS,
+        );
+    }
+
+    #[test]
+    fn view_rect_follows_window_aspect_and_zoom() {
+        let view = camera_view_rect(
+            Vec2::new(1000.0, 300.0),
+            Vec2::new(1600.0, 800.0),
+            1.0,
+            BOUNDS,
+        );
+        assert_eq!(view.height(), BATTLEFIELD_HEIGHT);
+        assert_eq!(view.width(), BATTLEFIELD_HEIGHT * 2.0);
+        assert_eq!(view.center(), Vec2::new(1000.0, 300.0));
+
+        let zoomed = camera_view_rect(
+            Vec2::new(1000.0, 300.0),
+            Vec2::new(1600.0, 800.0),
+            0.5,
+            BOUNDS,
+        );
+        assert_eq!(zoomed.height(), BATTLEFIELD_HEIGHT * 0.5);
+    }
+
+    #[test]
+    fn viewport_rect_maps_and_clips_to_minimap() {
+        let view = Rect::new(
+            BATTLEFIELD_WIDTH * 0.25,
+            BATTLEFIELD_HEIGHT * 0.5,
+            BATTLEFIELD_WIDTH * 0.5,
+            BATTLEFIELD_HEIGHT * 2.0,
+        );
+        let rect = minimap_viewport_rect(view, BOUNDS);
+
+        assert_eq!(rect.min, Vec2::new(25.0, 0.0));
+        assert_eq!(rect.max, Vec2::new(50.0, 50.0));
+    }
+
+    #[test]
+    fn minimap_maps_against_live_battlefield_bounds() {
+        let wide = BattlefieldBounds {
+            width: BATTLEFIELD_WIDTH * 2.0,
+            ..BOUNDS
+        };
+        let position = Vec2::new(BATTLEFIELD_WIDTH * 1.5, 0.0);
+
+        assert_eq!(CombatHeatmap::cell_at(position, BOUNDS), None);
+        assert_eq!(
+            CombatHeatmap::cell_at(position, wide),
+            Some((HEAT_COLS * 3 / 4, 0))
+        );
+
+        let view = Rect::new(0.0, 0.0, BATTLEFIELD_WIDTH, BATTLEFIELD_HEIGHT);
+        assert_eq!(minimap_viewport_rect(view, wide).max.x, 50.0);
+    }
+
+    fn viewport_left(app: &mut App) -> Val {
+        let mut outlines = app
+            .world_mut()
+            .query_filtered::<&Node, With<MinimapViewport>>();
+        outlines.single(app.world()).unwrap().left
+    }
+
+    #[test]
+    fn panning_camera_moves_viewport_outline() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<BattlefieldBounds>();
+        app.add_observer(spawn_viewport_outline);
+        app.add_systems(Update, update_minimap_viewport);
+        app.world_mut().spawn(Window::default());
+        let camera = app
+            .world_mut()
+            .spawn((
+                Camera2d,
+                Transform::from_xyz(1000.0, BATTLEFIELD_HEIGHT / 2.0, 0.0),
+                Projection::Orthographic(OrthographicProjection::default_2d()),
+            ))
+            .id();
+        app.world_mut().spawn((Minimap, Node::default()));
+        app.update(); // outline spawned
+        app.update(); // outline positioned
+        let before = viewport_left(&mut app);
+
+        app.world_mut()
+            .get_mut::<Transform>(camera)
+            .unwrap()
+            .translation
+            .x += BATTLEFIELD_WIDTH * 0.1;
+        app.update();
+        let after = viewport_left(&mut app);
+
+        let (Val::Percent(before), Val::Percent(after)) = (before, after) else {
+            panic!("expected percent positions, got {before:?} and {after:?}");
+        };
+        assert!(
+            (after - before - 10.0).abs() < 1e-3,
+            "expected a 10% shift: {before} -> {after}"
+        );
+    }
+}
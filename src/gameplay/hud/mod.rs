@@ -1,10 +1,29 @@
-//! In-game HUD: bottom bar with gold, cards, reroll, elapsed time, minimap.
+//! In-game HUD: bottom bar with gold, cards, reroll, wave counter, elapsed time,
+//! minimap, popups, the army value bar, the breakthrough alert, camera focus on alerts,
+//! and the unit tooltip.
 
+mod alert_focus;
+pub mod army_value;
 pub mod bottom_bar;
+pub mod breakthrough;
 mod elapsed_time;
+pub mod minimap;
+pub mod popup;
+mod unit_tooltip;
+mod wave_counter;
 
 use bevy::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_plugins((bottom_bar::plugin, elapsed_time::plugin));
+    app.add_plugins((
+        alert_focus::plugin,
+        army_value::plugin,
+        bottom_bar::plugin,
+        breakthrough::plugin,
+        elapsed_time::plugin,
+        minimap::plugin,
+        popup::plugin,
+        unit_tooltip::plugin,
+        wave_counter::plugin,
+    ));
 }
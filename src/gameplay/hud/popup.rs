@@ -0,0 +1,305 @@
+//! Floating text popups with a bounded, recycling pool. Every hit shows a damage
+//! popup over the entity it landed on.
+//!
+//! At most `MAX_POPUPS` popups exist at once. Spawning past the cap reuses the
+//! oldest popup entity instead of creating a new one, so a burst of hits can't
+//! flood the world with text entities.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::gameplay::HealthChanged;
+use crate::gameplay::combat::DeathCheck;
+use crate::screens::GameState;
+use crate::theme::palette;
+use crate::{GameSet, Z_POPUP, gameplay_running};
+
+/// Maximum number of popups alive at once.
+pub const MAX_POPUPS: usize = 64;
+
+/// Seconds a popup stays on screen.
+const POPUP_LIFETIME_SECS: f32 = 0.8;
+
+/// Upward drift in pixels per second.
+const POPUP_RISE_SPEED: f32 = 30.0;
+
+/// Height above the hit entity's center where its damage popup starts.
+const DAMAGE_POPUP_Y_OFFSET: f32 = 12.0;
+
+// === Components ===
+
+/// A floating text popup. Rises and fades out over its lifetime.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Popup {
+    pub lifetime: Timer,
+    /// Base color; alpha is scaled down as the popup fades.
+    pub color: Color,
+}
+
+// === Resources ===
+
+/// Live popup entities, oldest first.
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct PopupPool {
+    active: VecDeque<Entity>,
+}
+
+impl PopupPool {
+    /// Number of live popups.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    /// Whether no popups are live.
+    #[must_use]
+    #[allow(dead_code)] // Used in tests; pairs with `len`.
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+}
+
+// === Spawning ===
+
+/// Shows `text` at `position`. Past `MAX_POPUPS`, the oldest popup is recycled
+/// in place rather than spawning a new entity.
+pub fn spawn_popup(
+    commands: &mut Commands,
+    pool: &mut PopupPool,
+    text: impl Into<String>,
+    position: Vec2,
+    color: Color,
+) -> Entity {
+    let bundle = (
+        Popup {
+            lifetime: Timer::from_seconds(POPUP_LIFETIME_SECS, TimerMode::Once),
+            color,
+        },
+        Text2d::new(text),
+        TextFont::from_font_size(palette::FONT_SIZE_SMALL),
+        TextColor(color),
+        Transform::from_translation(position.extend(Z_POPUP)),
+    );
+
+    let recycled = if pool.active.len() >= MAX_POPUPS {
+        pool.active.pop_front()
+    } else {
+        None
+    };
+    let entity = match recycled {
+        Some(oldest) => {
+            commands.entity(oldest).insert(bundle);
+            oldest
+        }
+        None => commands
+            .spawn((Name::new("Popup"), bundle, DespawnOnExit(GameState::InGame)))
+            .id(),
+    };
+    pool.active.push_back(entity);
+    entity
+}
+
+// === Systems ===
+
+fn reset_popup_pool(mut commands: Commands) {
+    commands.insert_resource(PopupPool::default());
+}
+
+/// Shows a damage popup over every entity whose health dropped this frame.
+/// Healing is ignored. Runs in `GameSet::Death` BEFORE `check_death` so a killing
+/// blow still finds its target.
+fn spawn_damage_popups(
+    mut commands: Commands,
+    mut pool: ResMut<PopupPool>,
+    mut changes: MessageReader<HealthChanged>,
+    transforms: Query<&Transform>,
+) {
+    for change in changes.read() {
+        let damage = change.old - change.new;
+        if damage <= 0.0 {
+            continue;
+        }
+        let Ok(transform) = transforms.get(change.entity) else {
+            continue;
+        };
+        let position = transform.translation.xy() + Vec2::Y * DAMAGE_POPUP_Y_OFFSET;
+        spawn_popup(
+            &mut commands,
+            &mut pool,
+            format!("-{damage:.0}"),
+            position,
+            palette::DAMAGE_POPUP_TEXT,
+        );
+    }
+}
+
+/// Rises and fades popups, despawning them when their lifetime ends.
+fn animate_popups(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut pool: ResMut<PopupPool>,
+    mut popups: Query<(Entity, &mut Popup, &mut Transform, &mut TextColor)>,
+) {
+    for (entity, mut popup, mut transform, mut text_color) in &mut popups {
+        popup.lifetime.tick(time.delta());
+        if popup.lifetime.is_finished() {
+            commands.entity(entity).despawn();
+            pool.active.retain(|&active| active != entity);
+            continue;
+        }
+        transform.translation.y += POPUP_RISE_SPEED * time.delta_secs();
+        let alpha = popup.color.alpha() * (1.0 - popup.lifetime.fraction());
+        text_color.0 = popup.color.with_alpha(alpha);
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Popup>()
+        .register_type::<PopupPool>()
+        .init_resource::<PopupPool>();
+
+    app.add_systems(OnEnter(GameState::InGame), reset_popup_pool);
+    app.add_systems(
+        Update,
+        (
+            spawn_damage_popups
+                .in_set(GameSet::Death)
+                .before(DeathCheck),
+            animate_popups.in_set(GameSet::Ui),
+        )
+            .run_if(gameplay_running),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use pretty_assertions::assert_eq;
+
+    fn create_popup_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<PopupPool>();
+        app
+    }
+
+    /// Spawns `count` popups labelled "0", "1", ... and returns their entities.
+    fn spawn_popups(app: &mut App, count: usize) -> Vec<Entity> {
+        app.world_mut()
+            .run_system_once(move |mut commands: Commands, mut pool: ResMut<PopupPool>| {
+                (0..count)
+                    .map(|i| {
+                        spawn_popup(
+                            &mut commands,
+                            &mut pool,
+                            i.to_string(),
+                            Vec2::ZERO,
+                            Color::WHITE,
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn popups_below_cap_each_get_an_entity() {
+        let mut app = create_popup_test_app();
+        spawn_popups(&mut app, 3);
+
+        crate::testing::assert_entity_count::<With<Popup>>(&mut app, 3);
+        assert_eq!(app.world().resource::<PopupPool>().len(), 3);
+    }
+
+    #[test]
+    fn exceeding_cap_recycles_oldest_popup() {
+        let mut app = create_popup_test_app();
+        let entities = spawn_popups(&mut app, MAX_POPUPS + 5);
+
+        crate::testing::assert_entity_count::<With<Popup>>(&mut app, MAX_POPUPS);
+        assert_eq!(app.world().resource::<PopupPool>().len(), MAX_POPUPS);
+
+        // The first popup entity was reused for popup #MAX_POPUPS.
+        assert_eq!(entities[MAX_POPUPS], entities[0]);
+        let text = app.world().get::<Text2d>(entities[0]).unwrap();
+        assert_eq!(text.0, MAX_POPUPS.to_string());
+    }
+
+    fn create_damage_popup_test_app() -> App {
+        let mut app = create_popup_test_app();
+        app.add_message::<HealthChanged>();
+        app.add_systems(Update, spawn_damage_popups);
+        app
+    }
+
+    fn write_health_change(app: &mut App, entity: Entity, old: f32, new: f32) {
+        app.world_mut()
+            .write_message(HealthChanged { entity, old, new });
+    }
+
+    #[test]
+    fn hit_shows_damage_popup_above_target() {
+        let mut app = create_damage_popup_test_app();
+        let target = app
+            .world_mut()
+            .spawn(Transform::from_xyz(100.0, 50.0, 0.0))
+            .id();
+        write_health_change(&mut app, target, 100.0, 88.0);
+        app.update();
+
+        let mut popups = app
+            .world_mut()
+            .query_filtered::<(&Text2d, &Transform), With<Popup>>();
+        let (text, transform) = popups.single(app.world()).unwrap();
+        assert_eq!(text.0, "-12");
+        assert_eq!(
+            transform.translation.xy(),
+            Vec2::new(100.0, 50.0 + DAMAGE_POPUP_Y_OFFSET)
+        );
+    }
+
+    #[test]
+    fn healing_shows_no_popup() {
+        let mut app = create_damage_popup_test_app();
+        let target = app.world_mut().spawn(Transform::default()).id();
+        write_health_change(&mut app, target, 50.0, 60.0);
+        app.update();
+
+        crate::testing::assert_entity_count::<With<Popup>>(&mut app, 0);
+    }
+
+    #[test]
+    fn burst_of_hits_stays_within_cap() {
+        let mut app = create_damage_popup_test_app();
+        let target = app.world_mut().spawn(Transform::default()).id();
+        for hit in 0..MAX_POPUPS * 2 {
+            #[allow(clippy::cast_precision_loss)]
+            let old = 1000.0 - hit as f32;
+            write_health_change(&mut app, target, old, old - 1.0);
+        }
+        app.update();
+
+        crate::testing::assert_entity_count::<With<Popup>>(&mut app, MAX_POPUPS);
+    }
+
+    #[test]
+    fn expired_popups_leave_the_pool() {
+        let mut app = create_popup_test_app();
+        app.add_systems(Update, animate_popups);
+        let entities = spawn_popups(&mut app, 2);
+
+        for &entity in &entities {
+            let mut popup = app.world_mut().get_mut::<Popup>(entity).unwrap();
+            let duration = popup.lifetime.duration();
+            popup.lifetime.set_elapsed(duration);
+        }
+        app.update();
+
+        crate::testing::assert_entity_count::<With<Popup>>(&mut app, 0);
+        assert!(app.world().resource::<PopupPool>().is_empty());
+    }
+}
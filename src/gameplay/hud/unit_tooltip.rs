@@ -0,0 +1,146 @@
+//! Unit tooltip: hovering a unit shows its type and attack reach next to the cursor.
+
+use bevy::prelude::*;
+
+use crate::gameplay::units::{Unit, UnitType};
+use crate::gameplay::{CombatStats, EntityExtent};
+use crate::screens::GameState;
+use crate::theme::palette;
+use crate::{GameSet, gameplay_running};
+
+/// Tooltip offset from the cursor, in pixels, so it doesn't sit under the pointer.
+const TOOLTIP_CURSOR_OFFSET: Vec2 = Vec2::new(16.0, 16.0);
+
+// === Components ===
+
+/// Marker for the floating unit tooltip.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct UnitTooltip;
+
+// === Helper Functions ===
+
+/// Tooltip text for a unit: its name, then its [`CombatStats::range_label`].
+#[must_use]
+pub fn unit_tooltip_text(unit_type: UnitType, stats: &CombatStats) -> String {
+    format!("{}\n{}", unit_type.display_name(), stats.range_label())
+}
+
+// === Systems ===
+
+fn spawn_unit_tooltip(mut commands: Commands) {
+    commands.spawn((
+        Name::new("Unit Tooltip"),
+        UnitTooltip,
+        Text::default(),
+        TextFont::from_font_size(palette::FONT_SIZE_SMALL),
+        TextColor(palette::BODY_TEXT),
+        Node {
+            position_type: PositionType::Absolute,
+            padding: UiRect::all(Val::Px(4.0)),
+            ..default()
+        },
+        BackgroundColor(palette::PANEL_BACKGROUND),
+        Visibility::Hidden,
+        Pickable::IGNORE,
+        DespawnOnExit(GameState::InGame),
+    ));
+}
+
+/// Shows the tooltip next to the cursor while it is over a unit, hidden otherwise.
+fn update_unit_tooltip(
+    window: Single<&Window>,
+    camera: Single<(&Camera, &GlobalTransform), With<Camera2d>>,
+    units: Query<(&Transform, &EntityExtent, &UnitType, &CombatStats), With<Unit>>,
+    mut tooltip: Single<(&mut Text, &mut Node, &mut Visibility), With<UnitTooltip>>,
+) {
+    let (camera, camera_global) = *camera;
+    let (text, node, visibility) = &mut *tooltip;
+    let hovered = window.cursor_position().and_then(|screen_pos| {
+        let world_pos = camera
+            .viewport_to_world_2d(camera_global, screen_pos)
+            .ok()?;
+        units
+            .iter()
+            .find(|(transform, extent, ..)| {
+                extent.surface_distance_from(transform.translation.xy(), world_pos) <= 0.0
+            })
+            .map(|(_, _, unit_type, stats)| (screen_pos, unit_tooltip_text(*unit_type, stats)))
+    });
+    let Some((screen_pos, label)) = hovered else {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+    if text.0 != label {
+        text.0 = label;
+    }
+    node.left = Val::Px(screen_pos.x + TOOLTIP_CURSOR_OFFSET.x);
+    node.top = Val::Px(screen_pos.y + TOOLTIP_CURSOR_OFFSET.y);
+    visibility.set_if_neq(Visibility::Inherited);
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<UnitTooltip>();
+
+    app.add_systems(OnEnter(GameState::InGame), spawn_unit_tooltip);
+    app.add_systems(
+        Update,
+        update_unit_tooltip
+            .in_set(GameSet::Ui)
+            .run_if(gameplay_running),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::units::unit_stats;
+
+    fn combat_stats_for(unit_type: UnitType) -> CombatStats {
+        let stats = unit_stats(unit_type);
+        CombatStats {
+            damage: stats.damage,
+            attack_speed: stats.attack_speed,
+            range: stats.attack_range,
+        }
+    }
+
+    #[test]
+    fn soldier_tooltip_shows_its_attack_range() {
+        let soldier = unit_stats(UnitType::Soldier);
+        let text = unit_tooltip_text(UnitType::Soldier, &combat_stats_for(UnitType::Soldier));
+
+        assert!(text.starts_with("Soldier\n"), "got {text:?}");
+        assert!(
+            text.contains(&format!("Range: {:.0}", soldier.attack_range)),
+            "got {text:?}"
+        );
+    }
+
+    #[test]
+    fn archer_tooltip_shows_a_larger_range_than_soldier() {
+        let soldier = unit_stats(UnitType::Soldier);
+        let archer = unit_stats(UnitType::Archer);
+        assert!(archer.attack_range > soldier.attack_range);
+
+        let text = unit_tooltip_text(UnitType::Archer, &combat_stats_for(UnitType::Archer));
+        assert!(text.starts_with("Archer\n"), "got {text:?}");
+        assert!(
+            text.contains(&format!("Range: {:.0}", archer.attack_range)),
+            "got {text:?}"
+        );
+    }
+
+    #[test]
+    fn tooltip_spawns_hidden() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Startup, spawn_unit_tooltip);
+        app.update();
+
+        let mut tooltips = app
+            .world_mut()
+            .query_filtered::<&Visibility, With<UnitTooltip>>();
+        assert_eq!(*tooltips.single(app.world()).unwrap(), Visibility::Hidden);
+    }
+}
@@ -0,0 +1,86 @@
+//! Wave counter display, shown while discrete wave spawning is on.
+
+use bevy::prelude::*;
+
+use crate::gameplay::units::spawn::{WaveCounter, WaveSpawner};
+use crate::{GameSet, gameplay_running};
+
+/// Marker for the wave counter text in the bottom bar.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct WaveCounterDisplay;
+
+/// "Wave N" while wave mode is on; empty otherwise (continuous spawning has no waves).
+fn wave_label(waves: &WaveSpawner, counter: WaveCounter) -> String {
+    if waves.enabled {
+        format!("Wave {}", counter.0)
+    } else {
+        String::new()
+    }
+}
+
+fn update_wave_counter(
+    waves: Res<WaveSpawner>,
+    counter: Res<WaveCounter>,
+    mut text: Single<&mut Text, With<WaveCounterDisplay>>,
+) {
+    let label = wave_label(&waves, *counter);
+    if text.0 != label {
+        text.0 = label;
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<WaveCounterDisplay>();
+
+    app.add_systems(
+        Update,
+        update_wave_counter
+            .in_set(GameSet::Ui)
+            .run_if(gameplay_running),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn create_wave_counter_test_app(enabled: bool, wave: u32) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(WaveSpawner {
+            enabled,
+            ..default()
+        });
+        app.insert_resource(WaveCounter(wave));
+        app.add_systems(Update, update_wave_counter);
+        app.world_mut().spawn((Text::new(""), WaveCounterDisplay));
+        app
+    }
+
+    fn displayed(app: &mut App) -> String {
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&Text, With<WaveCounterDisplay>>();
+        query.single(app.world()).unwrap().0.clone()
+    }
+
+    #[test]
+    fn shows_current_wave_in_wave_mode() {
+        let mut app = create_wave_counter_test_app(true, 3);
+        app.update();
+        assert_eq!(displayed(&mut app), "Wave 3");
+
+        app.world_mut().resource_mut::<WaveCounter>().0 = 4;
+        app.update();
+        assert_eq!(displayed(&mut app), "Wave 4");
+    }
+
+    #[test]
+    fn stays_blank_with_continuous_spawning() {
+        let mut app = create_wave_counter_test_app(false, 0);
+        app.update();
+        assert_eq!(displayed(&mut app), "");
+    }
+}
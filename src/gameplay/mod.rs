@@ -2,15 +2,31 @@
 //!
 //! # Entity Archetypes
 //!
-//! **Units**: `Unit`, `Team`, `Target`, `TargetingState`, `Health`, `CombatStats`, `Movement`,
-//!           `AttackTimer`, `HealthBarConfig`, `EntityExtent`, `Mesh2d`, `MeshMaterial2d`,
-//!           `RigidBody::Dynamic`, `Collider`, `CollisionLayers`, `LockedAxes`, `LinearVelocity`
+//! **Units**: `Unit`, `PlayerUnit`/`EnemyUnit`, `SpawnId`, `Team`, `Target`, `TargetingState`,
+//!           `Health`, `CombatStats`, `Movement`, `AttackTimer`, `HealthBarConfig`, `EntityExtent`,
+//!           `Mesh2d` + `MeshMaterial2d` (or `Sprite` under `UnitRenderMode::Sprites`), `RigidBody::Dynamic`, `Collider`, `CollisionLayers`, `LockedAxes`, `LinearVelocity`,
+//!           `AggroRadius` (optional, defensive units), `SiegeBonus` (optional, siege units),
+//!           `Knockback` (optional, heavy hitters that shove what they hit),
+//!           `MeleeAttacker` (optional, soldiers),
+//!           `Incendiary` (optional, mages), `StatusEffects` (optional, while burning),
+//!           `Recall` (optional, units that return home after a kill),
+//!           `TargetPreference` (optional, units biased toward structures or units),
+//!           `TargetPriority` (optional, units that rank targets other than by distance),
+//!           `Taunt` (optional, tank units while taunting),
+//!           `AssignedLane` (optional, produced units),
+//!           `MovingToRally` (optional, player units walking to the rally point),
+//!           `AttackMove` (optional, units under an attack-move order),
+//!           `Selected` (optional, player units picked with Alt-click)
 //!
 //! **Buildings**: `Building`, `Team`, `Target`, `Health`, `HealthBarConfig`, `EntityExtent`,
-//!           `ProductionTimer` or `IncomeTimer`, `RigidBody::Static`, `Collider`, `CollisionLayers`
+//!           `ProductionTimer` or `IncomeTimer`, `Phalanx` (optional, squad producers),
+//!           `FiringArc` (directional only), `Defense` (optional, armored buildings),
+//!           `HealthRegen` + `LastDamagedAt` (player buildings),
+//!           `RigidBody::Static`, `Collider`, `CollisionLayers`
 //!
-//! **Fortresses**: `PlayerFortress`/`EnemyFortress`, `Team`, `Target`, `TargetingState`,
-//!           `Health`, `CombatStats`, `AttackTimer`, `HealthBarConfig`, `EntityExtent`,
+//! **Fortress segments** (one per fortress row): `PlayerFortress`/`EnemyFortress`,
+//!           `FortressSegment`, `Team`, `Target`, `TargetingState`,
+//!           `Health`, `Defense`, `CombatStats`, `AttackTimer`, `HealthBarConfig`, `EntityExtent`,
 //!           `RigidBody::Static`, `Collider`, `CollisionLayers`
 //!
 //! **Projectiles**: `Projectile`, `Team`, `Hitbox`, `Sensor`, `RigidBody::Kinematic`,
@@ -23,10 +39,16 @@ pub mod combat;
 pub mod economy;
 pub mod endgame_detection;
 mod hud;
+pub mod save;
 pub mod spatial_hash;
+pub mod tutorial;
 pub mod units;
 
 use bevy::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::screens::GameState;
 
 // === Cross-Cutting Components ===
 
@@ -62,6 +84,47 @@ impl Health {
     pub const fn new(max: f32) -> Self {
         Self { current: max, max }
     }
+
+    /// Sets `current` to `new` and reports it as a [`HealthChanged`] for `entity`.
+    /// Nothing is reported if the value does not change.
+    pub fn set_current(
+        &mut self,
+        entity: Entity,
+        new: f32,
+        changes: &mut MessageWriter<HealthChanged>,
+    ) {
+        let old = self.current;
+        #[allow(clippy::float_cmp)]
+        let unchanged = new == old;
+        if unchanged {
+            return;
+        }
+        self.current = new;
+        changes.write(HealthChanged { entity, old, new });
+    }
+}
+
+/// Written whenever damage or healing changes an entity's [`Health::current`].
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct HealthChanged {
+    pub entity: Entity,
+    pub old: f32,
+    pub new: f32,
+}
+
+/// Flat damage reduction applied to every hit. Entities without it take full damage.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct Defense {
+    pub armor: f32,
+}
+
+impl Defense {
+    /// Damage left after armor. A hit always deals at least 1.
+    #[must_use]
+    pub const fn mitigate(self, damage: f32) -> f32 {
+        (damage - self.armor).max(1.0)
+    }
 }
 
 /// Marker: this entity can be targeted by units.
@@ -103,10 +166,123 @@ pub struct EngagementLeash {
     pub max_distance: f32,
 }
 
+/// Holds position (no targeting) until an enemy comes within this many pixels
+/// (surface distance).
+/// Only limits acquiring a first target; once engaged, retargeting is unrestricted.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct AggroRadius(pub f32);
+
+/// Target-selection bias toward structures (buildings and fortresses), in pixels.
+/// Subtracted from a structure's distance when picking the nearest target:
+/// positive values prefer structures (siege), negative values prefer units.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct TargetPreference(pub f32);
+
+/// How an entity ranks opposing targets. Without this component (or with
+/// `Nearest`) it picks the nearest one; the other modes fall back to nearest
+/// when no candidate qualifies.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum TargetPriority {
+    #[default]
+    Nearest,
+    /// The opposing target with the least current health nearby.
+    LowestHealth,
+    /// The nearest opposing building.
+    Buildings,
+    /// The opposing fortress, wherever it is.
+    Fortress,
+}
+
+/// Returns the unit to `home` once its target dies and no enemy is within `radius`
+/// pixels (surface distance), instead of advancing on the enemy fortress.
+/// While returning it only acquires enemies inside the radius.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct Recall {
+    pub home: Vec2,
+    pub radius: f32,
+    /// Set by targeting when the target died and nothing was in range.
+    pub returning: bool,
+}
+
+impl Recall {
+    #[must_use]
+    pub const fn new(home: Vec2, radius: f32) -> Self {
+        Self {
+            home,
+            radius,
+            returning: false,
+        }
+    }
+
+    /// Home point to walk back to, if currently returning.
+    #[must_use]
+    pub const fn returning_home(&self) -> Option<Vec2> {
+        if self.returning {
+            Some(self.home)
+        } else {
+            None
+        }
+    }
+}
+
+/// Tank ability: while active, every mobile enemy whose surface was within `radius`
+/// pixels when it was inserted is locked onto this entity. Inserting the component
+/// activates it; it removes itself when `timer` finishes.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Taunt {
+    pub radius: f32,
+    pub timer: Timer,
+}
+
+impl Taunt {
+    #[must_use]
+    pub fn new(radius: f32, duration_secs: f32) -> Self {
+        Self {
+            radius,
+            timer: Timer::from_seconds(duration_secs, TimerMode::Once),
+        }
+    }
+}
+
+/// Damage multiplier against buildings and fortresses. Damage to units is unchanged.
+/// Copied onto the attacker's projectiles so the bonus applies on hit.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct SiegeBonus(pub f32);
+
+/// Speed (px/s) the attacker's projectiles add to a dynamic target on hit,
+/// pushing it away from where the shot was fired. Copied onto each projectile.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct Knockback(pub f32);
+
+/// Radius (px) of the falloff blast the attacker's projectiles deal around their
+/// impact point. Copied onto each projectile as `Projectile::splash_radius`.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct Splash(pub f32);
+
+/// Sets direct hits burning: `dps` health per second for `duration` seconds.
+/// Copied onto the attacker's projectiles so the burn applies on hit.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct Incendiary {
+    pub dps: f32,
+    pub duration: f32,
+}
+
 /// Default leash distance in pixels (3 cells).
 #[allow(dead_code)]
 pub const LEASH_DISTANCE: f32 = 192.0;
 
+/// Default recall radius in pixels (4 cells).
+pub const RECALL_RADIUS: f32 = 256.0;
+
 /// Movement speed for any mobile entity.
 #[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component)]
@@ -123,9 +299,49 @@ pub struct CombatStats {
     pub range: f32,
 }
 
+impl CombatStats {
+    /// Tooltip line for attack reach: the range in pixels plus a ring glyph
+    /// that grows with reach (`.` melee, `o` short, `O` long).
+    #[must_use]
+    pub fn range_label(&self) -> String {
+        let glyph = if self.range <= battlefield::CELL_SIZE / 4.0 {
+            '.'
+        } else if self.range <= battlefield::CELL_SIZE * 2.0 {
+            'o'
+        } else {
+            'O'
+        };
+        format!("Range: {:.0} ({glyph})", self.range)
+    }
+}
+
+/// Restricts attacks to targets within `half_angle` radians of `direction`.
+/// Entities without a firing arc attack in every direction.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct FiringArc {
+    /// World-space unit vector the arc is centered on.
+    pub direction: Vec2,
+    /// Half of the arc's opening angle, in radians.
+    pub half_angle: f32,
+}
+
+impl FiringArc {
+    /// Whether `point` lies inside the arc when fired from `origin`.
+    /// A point at the origin is always inside.
+    #[must_use]
+    pub fn contains(&self, origin: Vec2, point: Vec2) -> bool {
+        let offset = point - origin;
+        if offset.length_squared() < f32::EPSILON {
+            return true;
+        }
+        self.direction.angle_to(offset).abs() <= self.half_angle
+    }
+}
+
 /// Physical extent of a targetable entity, used for surface-distance range checks.
 /// Replaces GJK `surface_distance()` with simple geometry.
-#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
 #[reflect(Component)]
 pub enum EntityExtent {
     /// Circular extent with the given radius (units).
@@ -185,17 +401,76 @@ pub fn extent_distance(a: &EntityExtent, a_pos: Vec2, b: &EntityExtent, b_pos: V
 #[reflect(Resource)]
 pub struct GameStartTime(pub f32);
 
+/// Seed for the current run. [`GameRng`] is reseeded from it on entering
+/// `InGame`, so setting it beforehand replays a run. A fresh seed is rolled
+/// when a run ends.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct RunSeed(pub u64);
+
+impl Default for RunSeed {
+    fn default() -> Self {
+        Self(rand::random())
+    }
+}
+
+/// Seeded RNG that all gameplay randomness (spawn points, enemy spawn rows,
+/// shop draws) goes through. Reseeded from [`RunSeed`] on entering `InGame`.
+#[derive(Resource, Debug)]
+pub struct GameRng(pub StdRng);
+
+impl GameRng {
+    /// An RNG whose sequence is fully determined by `seed`.
+    #[must_use]
+    pub fn seeded(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self(StdRng::from_os_rng())
+    }
+}
+
+/// Starts the run's RNG from its [`RunSeed`]. Runs on entering `InGame`,
+/// before anything rolls.
+pub fn reseed_game_rng(seed: Res<RunSeed>, mut rng: ResMut<GameRng>) {
+    *rng = GameRng::seeded(seed.0);
+}
+
+fn roll_run_seed(mut seed: ResMut<RunSeed>) {
+    *seed = RunSeed::default();
+}
+
 pub fn plugin(app: &mut App) {
     app.register_type::<Team>()
         .register_type::<Health>()
+        .register_type::<Defense>()
         .register_type::<Target>()
         .register_type::<TargetingState>()
         .register_type::<EntityExtent>()
         .register_type::<EngagementLeash>()
+        .register_type::<AggroRadius>()
+        .register_type::<TargetPreference>()
+        .register_type::<TargetPriority>()
+        .register_type::<Recall>()
+        .register_type::<Taunt>()
+        .register_type::<SiegeBonus>()
+        .register_type::<Knockback>()
+        .register_type::<Splash>()
+        .register_type::<Incendiary>()
         .register_type::<Movement>()
         .register_type::<CombatStats>()
+        .register_type::<FiringArc>()
         .register_type::<GameStartTime>()
-        .init_resource::<GameStartTime>();
+        .init_resource::<GameStartTime>()
+        .register_type::<RunSeed>()
+        .init_resource::<RunSeed>()
+        .init_resource::<GameRng>()
+        .add_message::<HealthChanged>();
+    app.add_systems(OnEnter(GameState::InGame), reseed_game_rng);
+    app.add_systems(OnExit(GameState::InGame), roll_run_seed);
 
     app.add_plugins((
         ai::plugin,
@@ -205,6 +480,8 @@ pub fn plugin(app: &mut App) {
         economy::plugin,
         endgame_detection::plugin,
         hud::plugin,
+        save::plugin,
+        tutorial::plugin,
         units::plugin,
     ));
 }
@@ -213,6 +490,61 @@ pub fn plugin(app: &mut App) {
 mod tests {
     use super::*;
 
+    // === CombatStats tests ===
+
+    fn combat_stats_with_range(range: f32) -> CombatStats {
+        CombatStats {
+            damage: 1.0,
+            attack_speed: 1.0,
+            range,
+        }
+    }
+
+    #[test]
+    fn range_label_shows_soldier_range() {
+        let soldier = units::unit_stats(units::UnitType::Soldier);
+        let label = combat_stats_with_range(soldier.attack_range).range_label();
+        assert_eq!(label, "Range: 5 (.)");
+    }
+
+    #[test]
+    fn range_label_glyph_grows_with_range() {
+        let short = combat_stats_with_range(64.0).range_label();
+        let long = combat_stats_with_range(battlefield::FORTRESS_RANGE).range_label();
+        assert_eq!(short, "Range: 64 (o)");
+        assert_eq!(long, "Range: 300 (O)");
+    }
+
+    // === FiringArc tests ===
+
+    #[test]
+    fn firing_arc_contains_point_ahead() {
+        let arc = FiringArc {
+            direction: Vec2::X,
+            half_angle: std::f32::consts::FRAC_PI_4,
+        };
+        assert!(arc.contains(Vec2::ZERO, Vec2::new(100.0, 50.0)));
+    }
+
+    #[test]
+    fn firing_arc_rejects_point_behind() {
+        let arc = FiringArc {
+            direction: Vec2::X,
+            half_angle: std::f32::consts::FRAC_PI_4,
+        };
+        assert!(!arc.contains(Vec2::ZERO, Vec2::new(-100.0, 0.0)));
+        assert!(!arc.contains(Vec2::ZERO, Vec2::new(0.0, 100.0)));
+    }
+
+    #[test]
+    fn firing_arc_contains_origin() {
+        let arc = FiringArc {
+            direction: Vec2::Y,
+            half_angle: 0.1,
+        };
+        assert!(arc.contains(Vec2::new(5.0, 5.0), Vec2::new(5.0, 5.0)));
+    }
+
     // === surface_distance_from tests ===
 
     #[test]
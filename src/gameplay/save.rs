@@ -0,0 +1,632 @@
+//! Save and load: the run's gold, buildings (with level and health), shop, seed,
+//! clock, stats, wave progress, production focus, and run settings (difficulty,
+//! objective, tutorial step) written to a RON file in the platform data
+//! directory from the pause menu. Loading re-enters `InGame` and rebuilds the run.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::gameplay::battlefield::GridIndex;
+use crate::gameplay::building::{
+    Building, BuildingLevel, BuildingType, Facing, MAX_BUILDING_LEVEL, Occupied, ProductionFocus,
+    ProductionTimer, building_stats_at_level, phalanx_at_level, spawn_building,
+};
+use crate::gameplay::economy::income::IncomeTimer;
+use crate::gameplay::economy::shop::Shop;
+use crate::gameplay::economy::{Difficulty, Gold, RunStats};
+use crate::gameplay::endgame_detection::Objective;
+use crate::gameplay::tutorial::{Tutorial, TutorialStep};
+use crate::gameplay::units::spawn::{WaveCounter, WaveSpawner};
+use crate::gameplay::{GameRng, GameStartTime, Health, RunSeed};
+use crate::menus::Menu;
+use crate::screens::GameState;
+
+/// Save file name inside the game's data directory.
+pub const SAVE_FILE: &str = "savegame.ron";
+
+/// Name of the game's folder inside the platform data directory.
+const DATA_DIR_NAME: &str = "auto-battle";
+
+/// The game's data directory, looking environment variables up with `var`:
+/// `$XDG_DATA_HOME` or `~/.local/share` on Linux, `~/Library/Application Support`
+/// on macOS, `%APPDATA%` on Windows. `None` when the variables are unset.
+fn data_dir(var: impl Fn(&str) -> Option<OsString>) -> Option<PathBuf> {
+    let base = if cfg!(target_os = "windows") {
+        var("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|home| PathBuf::from(home).join("Library/Application Support"))
+    } else {
+        var("XDG_DATA_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| var("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+    };
+    base.map(|base| base.join(DATA_DIR_NAME))
+}
+
+// === Resources ===
+
+/// Where `save_game` writes and `load_game` reads. Defaults to [`SAVE_FILE`]
+/// in the platform data directory, or the working directory when there is none.
+#[derive(Resource, Debug, Clone)]
+pub struct SavePath(pub PathBuf);
+
+impl Default for SavePath {
+    fn default() -> Self {
+        let dir = data_dir(|name| std::env::var_os(name)).unwrap_or_default();
+        Self(dir.join(SAVE_FILE))
+    }
+}
+
+/// A loaded save waiting for `InGame` to be re-entered. `armed` is set on
+/// `OnEnter(InGame)`, so the save is applied after every run reset has run.
+#[derive(Resource, Debug)]
+struct PendingLoad {
+    save: SaveData,
+    armed: bool,
+}
+
+// === Save data ===
+
+/// A placed building as stored in a save.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SavedBuilding {
+    pub building_type: BuildingType,
+    pub grid_col: u16,
+    pub grid_row: u16,
+    pub facing: Facing,
+    /// Upgrade level (`BuildingLevel`). Saves without it load at level 1.
+    #[serde(default = "default_level")]
+    pub level: u8,
+    /// Current HP as a fraction of max. Stored as a fraction so it stays valid if
+    /// the level's max HP is rebalanced. Saves without it load at full health.
+    #[serde(default = "default_hp_fraction")]
+    pub hp_fraction: f32,
+}
+
+const fn default_level() -> u8 {
+    1
+}
+
+const fn default_hp_fraction() -> f32 {
+    1.0
+}
+
+/// Wave-mode progress as stored in a save.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct SavedWaves {
+    /// Whether wave mode is on (`WaveSpawner::enabled`).
+    pub enabled: bool,
+    /// Waves spawned so far (`WaveCounter`).
+    pub count: u32,
+    /// Seconds left on the countdown to the next wave.
+    pub countdown_secs: f32,
+    pub wave_in_progress: bool,
+}
+
+/// Everything needed to resume a run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaveData {
+    pub gold: u32,
+    pub buildings: Vec<SavedBuilding>,
+    pub shop: Shop,
+    pub run_seed: u64,
+    /// Seconds of play since the run started. Stored instead of
+    /// `GameStartTime`, which is only meaningful within one session.
+    pub elapsed_secs: f32,
+    // Fields below default when missing, so older saves still load.
+    #[serde(default)]
+    pub stats: RunStats,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub objective: Objective,
+    #[serde(default)]
+    pub waves: SavedWaves,
+    /// Current `Tutorial::step`; `None` outside the tutorial.
+    #[serde(default)]
+    pub tutorial: Option<TutorialStep>,
+    /// Grid cell of the `ProductionFocus` building, if any.
+    #[serde(default)]
+    pub production_focus: Option<(u16, u16)>,
+}
+
+impl SaveData {
+    /// Writes the save to `path` as pretty-printed RON, creating its directory
+    /// if needed.
+    ///
+    /// # Errors
+    /// If serialization, creating the directory, or the file write fails.
+    pub fn write(&self, path: &Path) -> std::io::Result<()> {
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(std::io::Error::other)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, text)
+    }
+
+    /// Reads a save from `path`.
+    ///
+    /// # Errors
+    /// If the file is missing, unreadable, or not a valid save.
+    pub fn read(path: &Path) -> std::io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        ron::from_str(&text).map_err(std::io::Error::other)
+    }
+}
+
+// === Systems ===
+
+/// Run state saved alongside gold and buildings.
+#[derive(SystemParam)]
+pub struct RunState<'w> {
+    stats: ResMut<'w, RunStats>,
+    difficulty: ResMut<'w, Difficulty>,
+    objective: ResMut<'w, Objective>,
+    waves: ResMut<'w, WaveSpawner>,
+    wave_counter: ResMut<'w, WaveCounter>,
+    tutorial: ResMut<'w, Tutorial>,
+    focus: ResMut<'w, ProductionFocus>,
+}
+
+/// Writes the current run to `SavePath`. Failures are logged and otherwise ignored.
+pub fn save_game(
+    path: Res<SavePath>,
+    gold: Res<Gold>,
+    shop: Res<Shop>,
+    seed: Res<RunSeed>,
+    start: Res<GameStartTime>,
+    time: Res<Time<Virtual>>,
+    run: RunState,
+    buildings: Query<(&Building, &BuildingLevel, &Health)>,
+) {
+    let save = SaveData {
+        gold: gold.0,
+        buildings: buildings
+            .iter()
+            .map(|(building, level, health)| SavedBuilding {
+                building_type: building.building_type,
+                grid_col: building.grid_col,
+                grid_row: building.grid_row,
+                facing: building.facing,
+                level: level.0,
+                hp_fraction: if health.max > 0.0 {
+                    health.current / health.max
+                } else {
+                    0.0
+                },
+            })
+            .collect(),
+        shop: shop.clone(),
+        run_seed: seed.0,
+        elapsed_secs: time.elapsed_secs() - start.0,
+        stats: run.stats.clone(),
+        difficulty: *run.difficulty,
+        objective: *run.objective,
+        waves: SavedWaves {
+            enabled: run.waves.enabled,
+            count: run.wave_counter.0,
+            countdown_secs: run.waves.countdown.remaining_secs(),
+            wave_in_progress: run.waves.wave_in_progress,
+        },
+        tutorial: run.tutorial.step,
+        production_focus: run
+            .focus
+            .0
+            .and_then(|focused| buildings.get(focused).ok())
+            .map(|(building, ..)| (building.grid_col, building.grid_row)),
+    };
+    if let Err(error) = save.write(&path.0) {
+        warn!("Could not save game to {}: {error}", path.0.display());
+    }
+}
+
+/// Reads `SavePath` and restarts `InGame` to resume it. A missing or corrupt
+/// file is logged and leaves the current run untouched.
+///
+/// Run settings (difficulty, objective, wave mode, tutorial step) are restored
+/// here, before `InGame` is re-entered, so its `OnEnter` systems see them.
+pub fn load_game(
+    mut commands: Commands,
+    path: Res<SavePath>,
+    mut run: RunState,
+    mut next_game: ResMut<NextState<GameState>>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    let save = match SaveData::read(&path.0) {
+        Ok(save) => save,
+        Err(error) => {
+            warn!("Could not load game from {}: {error}", path.0.display());
+            return;
+        }
+    };
+    *run.difficulty = save.difficulty;
+    *run.objective = save.objective;
+    run.waves.enabled = save.waves.enabled;
+    run.tutorial.step = save.tutorial;
+    commands.insert_resource(PendingLoad { save, armed: false });
+    next_menu.set(Menu::None);
+    next_game.set(GameState::InGame);
+}
+
+fn arm_pending_load(pending: Option<ResMut<PendingLoad>>) {
+    if let Some(mut pending) = pending {
+        pending.armed = true;
+    }
+}
+
+fn pending_load_armed(pending: Option<Res<PendingLoad>>) -> bool {
+    pending.is_some_and(|pending| pending.armed)
+}
+
+/// Restores the pending save over the freshly reset run and respawns its buildings
+/// at their saved level and health.
+fn apply_pending_load(
+    mut commands: Commands,
+    pending: Res<PendingLoad>,
+    grid_index: Res<GridIndex>,
+    time: Res<Time<Virtual>>,
+    mut gold: ResMut<Gold>,
+    mut shop: ResMut<Shop>,
+    mut seed: ResMut<RunSeed>,
+    mut rng: ResMut<GameRng>,
+    mut start: ResMut<GameStartTime>,
+    mut run: RunState,
+) {
+    let save = &pending.save;
+    gold.0 = save.gold;
+    *shop = save.shop.clone();
+    *seed = RunSeed(save.run_seed);
+    *rng = GameRng::seeded(save.run_seed);
+    start.0 = time.elapsed_secs() - save.elapsed_secs;
+    *run.stats = save.stats.clone();
+    run.wave_counter.0 = save.waves.count;
+    run.waves.countdown = Timer::from_seconds(save.waves.countdown_secs, TimerMode::Once);
+    run.waves.wave_in_progress = save.waves.wave_in_progress;
+    run.focus.0 = None;
+
+    for building in &save.buildings {
+        let Some(slot) = grid_index.get(building.grid_col, building.grid_row) else {
+            continue;
+        };
+        commands.entity(slot).insert(Occupied);
+        let entity = spawn_building(
+            &mut commands,
+            building.building_type,
+            building.grid_col,
+            building.grid_row,
+            building.facing,
+        );
+        restore_level_and_health(&mut commands.entity(entity), building);
+        if save.production_focus == Some((building.grid_col, building.grid_row)) {
+            run.focus.0 = Some(entity);
+        }
+    }
+    commands.remove_resource::<PendingLoad>();
+}
+
+/// Overrides a freshly spawned level-1 building with the saved level's stats and
+/// the saved HP fraction.
+fn restore_level_and_health(entity: &mut EntityCommands, saved: &SavedBuilding) {
+    let stats = building_stats_at_level(saved.building_type, saved.level);
+    let mut health = Health::new(stats.hp);
+    health.current = stats.hp * saved.hp_fraction.clamp(0.0, 1.0);
+    entity.insert((
+        BuildingLevel(saved.level.clamp(1, MAX_BUILDING_LEVEL)),
+        health,
+    ));
+    if let Some(interval) = stats.production_interval {
+        entity.insert(ProductionTimer(Timer::from_seconds(
+            interval,
+            TimerMode::Repeating,
+        )));
+    }
+    if let Some(interval) = stats.income_interval {
+        entity.insert(IncomeTimer(Timer::from_seconds(
+            interval,
+            TimerMode::Repeating,
+        )));
+    }
+    if let Some(phalanx) = phalanx_at_level(saved.building_type, saved.level) {
+        entity.insert(phalanx);
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<SavePath>();
+
+    app.add_systems(OnEnter(GameState::InGame), arm_pending_load);
+    app.add_systems(
+        Update,
+        apply_pending_load.run_if(in_state(GameState::InGame).and(pending_load_armed)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::battlefield::{BUILD_ZONE_START_COL, col_to_world_x, row_to_world_y};
+    use pretty_assertions::assert_eq;
+
+    /// A save path unique to `name` in the system temp directory.
+    fn temp_save_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("auto-battle-{name}-{}.ron", std::process::id()))
+    }
+
+    fn create_save_test_app(path: &Path) -> App {
+        let mut app = crate::testing::create_full_test_app();
+        app.insert_resource(SavePath(path.to_path_buf()));
+        crate::testing::transition_to_ingame(&mut app);
+        app
+    }
+
+    fn building_positions(app: &mut App) -> Vec<(u16, u16, Vec2)> {
+        let mut buildings = app.world_mut().query::<(&Building, &Transform)>();
+        let mut positions: Vec<_> = buildings
+            .iter(app.world())
+            .map(|(b, t)| (b.grid_col, b.grid_row, t.translation.xy()))
+            .collect();
+        positions.sort_by_key(|&(col, row, _)| (col, row));
+        positions
+    }
+
+    #[test]
+    fn save_data_round_trips_through_ron() {
+        let path = temp_save_path("ron");
+        let save = SaveData {
+            gold: 42,
+            buildings: vec![SavedBuilding {
+                building_type: BuildingType::Farm,
+                grid_col: 1,
+                grid_row: 2,
+                facing: Facing::North,
+                level: 2,
+                hp_fraction: 0.25,
+            }],
+            shop: Shop::default(),
+            run_seed: 7,
+            elapsed_secs: 12.5,
+            stats: RunStats {
+                kills: 9,
+                ..default()
+            },
+            difficulty: Difficulty::Hard,
+            objective: Objective::Survive { secs: 120.0 },
+            waves: SavedWaves {
+                enabled: true,
+                count: 3,
+                countdown_secs: 4.0,
+                wave_in_progress: true,
+            },
+            tutorial: Some(TutorialStep::SurviveWave),
+            production_focus: Some((1, 2)),
+        };
+
+        save.write(&path).unwrap();
+        let loaded = SaveData::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, save);
+    }
+
+    #[test]
+    fn saving_then_loading_restores_gold_and_buildings() {
+        let path = temp_save_path("round-trip");
+        let mut app = create_save_test_app(&path);
+        let upgraded = building_stats_at_level(BuildingType::Barracks, 2);
+        {
+            let world = app.world_mut();
+            let mut commands = world.commands();
+            let barracks =
+                spawn_building(&mut commands, BuildingType::Barracks, 2, 3, Facing::East);
+            spawn_building(&mut commands, BuildingType::Farm, 0, 7, Facing::East);
+            world.flush();
+
+            // An upgraded Barracks at half health.
+            let mut health = Health::new(upgraded.hp);
+            health.current = upgraded.hp / 2.0;
+            world
+                .entity_mut(barracks)
+                .insert((BuildingLevel(2), health));
+        }
+        app.world_mut().resource_mut::<Gold>().0 = 123;
+        app.world_mut().run_system_cached(save_game).unwrap();
+        let saved = building_positions(&mut app);
+
+        // Play on: spend the gold.
+        app.world_mut().resource_mut::<Gold>().0 = 0;
+
+        app.world_mut().run_system_cached(load_game).unwrap();
+        app.update(); // re-enter InGame, apply the save
+        app.update(); // apply deferred spawns
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(app.world().resource::<Gold>().0, 123);
+        assert_eq!(building_positions(&mut app), saved);
+        assert_eq!(
+            saved,
+            vec![
+                (
+                    0,
+                    7,
+                    Vec2::new(col_to_world_x(BUILD_ZONE_START_COL), row_to_world_y(7))
+                ),
+                (
+                    2,
+                    3,
+                    Vec2::new(col_to_world_x(BUILD_ZONE_START_COL + 2), row_to_world_y(3))
+                ),
+            ]
+        );
+        let occupied = crate::testing::count_entities::<With<Occupied>>(&mut app);
+        assert_eq!(occupied, 2);
+
+        let mut query = app
+            .world_mut()
+            .query::<(&Building, &BuildingLevel, &Health, &ProductionTimer)>();
+        let (_, level, health, timer) = query
+            .iter(app.world())
+            .find(|(building, ..)| building.building_type == BuildingType::Barracks)
+            .expect("Barracks restored");
+        assert_eq!(*level, BuildingLevel(2));
+        assert_eq!(health.max, upgraded.hp);
+        assert_eq!(health.current, upgraded.hp / 2.0);
+        assert_eq!(
+            timer.0.duration(),
+            std::time::Duration::from_secs_f32(upgraded.production_interval.unwrap())
+        );
+    }
+
+    #[test]
+    fn saving_then_loading_restores_run_state() {
+        let path = temp_save_path("run-state");
+        let mut app = create_save_test_app(&path);
+        let barracks = {
+            let world = app.world_mut();
+            let barracks = spawn_building(
+                &mut world.commands(),
+                BuildingType::Barracks,
+                4,
+                5,
+                Facing::East,
+            );
+            world.flush();
+            barracks
+        };
+        let world = app.world_mut();
+        world.resource_mut::<RunStats>().kills = 17;
+        *world.resource_mut::<Difficulty>() = Difficulty::Hard;
+        *world.resource_mut::<Objective>() = Objective::Survive { secs: 90.0 };
+        world.resource_mut::<WaveSpawner>().enabled = true;
+        world.resource_mut::<WaveCounter>().0 = 4;
+        world.resource_mut::<Tutorial>().step = Some(TutorialStep::SurviveWave);
+        world.resource_mut::<ProductionFocus>().0 = Some(barracks);
+        world.run_system_cached(save_game).unwrap();
+
+        // Play on in a different setup.
+        world.resource_mut::<RunStats>().kills = 0;
+        *world.resource_mut::<Difficulty>() = Difficulty::Easy;
+        *world.resource_mut::<Objective>() = Objective::DestroyFortress;
+        world.resource_mut::<WaveSpawner>().enabled = false;
+        world.resource_mut::<Tutorial>().step = None;
+
+        world.run_system_cached(load_game).unwrap();
+        app.update();
+        app.update();
+        std::fs::remove_file(&path).unwrap();
+
+        let world = app.world();
+        assert_eq!(world.resource::<RunStats>().kills, 17);
+        assert_eq!(*world.resource::<Difficulty>(), Difficulty::Hard);
+        assert_eq!(
+            *world.resource::<Objective>(),
+            Objective::Survive { secs: 90.0 }
+        );
+        assert!(world.resource::<WaveSpawner>().enabled);
+        assert_eq!(world.resource::<WaveCounter>().0, 4);
+        assert_eq!(
+            world.resource::<Tutorial>().step,
+            Some(TutorialStep::SurviveWave)
+        );
+        let focused = world
+            .resource::<ProductionFocus>()
+            .0
+            .expect("focus restored");
+        let building = world.get::<Building>(focused).unwrap();
+        assert_eq!((building.grid_col, building.grid_row), (4, 5));
+    }
+
+    #[test]
+    fn save_without_run_state_loads_with_defaults() {
+        /// The save format before run state was added.
+        #[derive(Serialize)]
+        struct OldSave {
+            gold: u32,
+            buildings: Vec<SavedBuilding>,
+            shop: Shop,
+            run_seed: u64,
+            elapsed_secs: f32,
+        }
+        let text = ron::to_string(&OldSave {
+            gold: 10,
+            buildings: Vec::new(),
+            shop: Shop::default(),
+            run_seed: 1,
+            elapsed_secs: 0.0,
+        })
+        .unwrap();
+
+        let save: SaveData = ron::from_str(&text).unwrap();
+        assert_eq!(save.difficulty, Difficulty::default());
+        assert_eq!(save.waves, SavedWaves::default());
+        assert_eq!(save.production_focus, None);
+    }
+
+    #[test]
+    fn data_dir_follows_platform_conventions() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| OsString::from(value))
+            }
+        };
+        assert_eq!(data_dir(env(&[])), None);
+        if cfg!(target_os = "windows") {
+            assert_eq!(
+                data_dir(env(&[("APPDATA", "C:/Users/a/AppData/Roaming")])),
+                Some(PathBuf::from("C:/Users/a/AppData/Roaming/auto-battle"))
+            );
+        } else if cfg!(target_os = "macos") {
+            assert_eq!(
+                data_dir(env(&[("HOME", "/Users/a")])),
+                Some(PathBuf::from(
+                    "/Users/a/Library/Application Support/auto-battle"
+                ))
+            );
+        } else {
+            assert_eq!(
+                data_dir(env(&[("HOME", "/home/a")])),
+                Some(PathBuf::from("/home/a/.local/share/auto-battle"))
+            );
+            assert_eq!(
+                data_dir(env(&[("HOME", "/home/a"), ("XDG_DATA_HOME", "/data")])),
+                Some(PathBuf::from("/data/auto-battle"))
+            );
+        }
+    }
+
+    #[test]
+    fn missing_save_file_is_ignored() {
+        let mut app = create_save_test_app(&temp_save_path("missing"));
+        app.world_mut().resource_mut::<Gold>().0 = 55;
+
+        app.world_mut().run_system_cached(load_game).unwrap();
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<Gold>().0, 55);
+        assert!(!app.world().contains_resource::<PendingLoad>());
+    }
+
+    #[test]
+    fn corrupt_save_file_is_ignored() {
+        let path = temp_save_path("corrupt");
+        std::fs::write(&path, "(gold: \"lots\"").unwrap();
+        let mut app = create_save_test_app(&path);
+        app.world_mut().resource_mut::<Gold>().0 = 55;
+
+        app.world_mut().run_system_cached(load_game).unwrap();
+        app.update();
+        app.update();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(app.world().resource::<Gold>().0, 55);
+        assert!(!app.world().contains_resource::<PendingLoad>());
+    }
+}
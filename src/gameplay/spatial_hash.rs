@@ -22,6 +22,12 @@ impl SpatialHash {
         }
     }
 
+    /// Side length of a grid cell, fixed at construction.
+    #[must_use]
+    pub const fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
     /// Remove all entries. Called at the start of each frame's rebuild.
     pub fn clear(&mut self) {
         for bucket in self.cells.values_mut() {
@@ -36,13 +42,23 @@ impl SpatialHash {
     }
 
     /// Query all entities within `radius` of `position`.
-    /// Returns candidates — caller must still check actual distance.
+    ///
+    /// Scans the `ceil(radius / cell_size)` ring of cells around `position`'s
+    /// cell, skipping cells that lie entirely outside the radius, so the cell
+    /// size can be chosen independently of the query radius. Returns
+    /// candidates (at most one cell diagonal beyond `radius`) — caller must
+    /// still check actual distance.
     pub fn query_neighbors(&self, position: Vec2, radius: f32) -> Vec<Entity> {
-        let min = self.cell_coords(position - Vec2::splat(radius));
-        let max = self.cell_coords(position + Vec2::splat(radius));
+        let center = self.cell_coords(position);
+        #[allow(clippy::cast_possible_truncation)]
+        let rings = (radius / self.cell_size).ceil() as i32;
+        let radius_sq = radius * radius;
         let mut result = Vec::new();
-        for x in min.0..=max.0 {
-            for y in min.1..=max.1 {
+        for x in center.0 - rings..=center.0 + rings {
+            for y in center.1 - rings..=center.1 + rings {
+                if self.cell_distance_sq((x, y), position) > radius_sq {
+                    continue;
+                }
                 if let Some(entities) = self.cells.get(&(x, y)) {
                     result.extend(entities);
                 }
@@ -51,6 +67,14 @@ impl SpatialHash {
         result
     }
 
+    /// Squared distance from `position` to the nearest point of cell `coords`.
+    #[allow(clippy::cast_precision_loss)]
+    fn cell_distance_sq(&self, coords: (i32, i32), position: Vec2) -> f32 {
+        let min = Vec2::new(coords.0 as f32, coords.1 as f32) * self.cell_size;
+        let max = min + Vec2::splat(self.cell_size);
+        position.clamp(min, max).distance_squared(position)
+    }
+
     #[allow(clippy::cast_possible_truncation)]
     fn cell_coords(&self, position: Vec2) -> (i32, i32) {
         (
@@ -128,6 +152,52 @@ mod tests {
         );
     }
 
+    /// Points on a 4px lattice across a 200px square centered on the origin.
+    fn lattice(hash: &mut SpatialHash) -> Vec<(Entity, Vec2)> {
+        let mut points = Vec::new();
+        for i in 0..50_i16 {
+            for j in 0..50_i16 {
+                let position = Vec2::new(f32::from(i), f32::from(j)) * 4.0 - 100.0;
+                let entity = Entity::from_bits(points.len() as u64 + 1);
+                hash.insert(entity, position);
+                points.push((entity, position));
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn small_cells_return_every_point_within_radius() {
+        let mut hash = SpatialHash::new(16.0);
+        let points = lattice(&mut hash);
+        let origin = Vec2::new(3.0, -5.0);
+
+        let neighbors = hash.query_neighbors(origin, 50.0);
+        for (entity, position) in &points {
+            if position.distance(origin) <= 50.0 {
+                assert!(neighbors.contains(entity), "missing point at {position}");
+            }
+        }
+    }
+
+    #[test]
+    fn small_cells_return_nothing_beyond_one_cell_past_radius() {
+        let mut hash = SpatialHash::new(16.0);
+        let points = lattice(&mut hash);
+        let origin = Vec2::new(3.0, -5.0);
+        let limit = 16.0f32.mul_add(std::f32::consts::SQRT_2, 50.0);
+
+        let neighbors = hash.query_neighbors(origin, 50.0);
+        for (entity, position) in &points {
+            if neighbors.contains(entity) {
+                assert!(
+                    position.distance(origin) <= limit,
+                    "point at {position} is too far"
+                );
+            }
+        }
+    }
+
     #[test]
     fn large_radius_covers_many_cells() {
         let mut hash = SpatialHash::new(10.0);
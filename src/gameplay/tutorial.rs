@@ -0,0 +1,263 @@
+//! Tutorial: scripted prompts that advance as the player completes each step.
+//!
+//! Started from the main menu, which also turns on wave mode so the "survive the
+//! first wave" step can complete. `Tutorial::step` is `None` in a normal battle.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::gameplay::Team;
+use crate::gameplay::building::Building;
+use crate::gameplay::economy::shop::Shop;
+use crate::gameplay::units::spawn::WaveCleared;
+use crate::screens::GameState;
+use crate::theme::palette;
+use crate::{GameSet, gameplay_running};
+
+// === Resources ===
+
+/// A scripted tutorial stage, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub enum TutorialStep {
+    SelectCard,
+    PlaceBuilding,
+    SurviveWave,
+    Complete,
+}
+
+impl TutorialStep {
+    /// Prompt shown while this step is active, or `None` once the tutorial is complete.
+    #[must_use]
+    pub const fn prompt(self) -> Option<&'static str> {
+        match self {
+            Self::SelectCard => Some("Select a card"),
+            Self::PlaceBuilding => Some("Place a Barracks"),
+            Self::SurviveWave => Some("Survive the first wave"),
+            Self::Complete => None,
+        }
+    }
+
+    /// The step that follows this one. `Complete` is terminal.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::SelectCard => Self::PlaceBuilding,
+            Self::PlaceBuilding => Self::SurviveWave,
+            Self::SurviveWave | Self::Complete => Self::Complete,
+        }
+    }
+}
+
+/// Current tutorial progress. `None` outside the tutorial.
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
+pub struct Tutorial {
+    pub step: Option<TutorialStep>,
+}
+
+// === Components ===
+
+/// Marker for the tutorial prompt text.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct TutorialPrompt;
+
+// === Systems ===
+
+fn spawn_tutorial_prompt(mut commands: Commands, tutorial: Res<Tutorial>) {
+    let Some(prompt) = tutorial.step.and_then(TutorialStep::prompt) else {
+        return;
+    };
+    commands.spawn((
+        Name::new("Tutorial Prompt"),
+        TutorialPrompt,
+        Text::new(prompt),
+        TextFont::from_font_size(palette::FONT_SIZE_PROMPT),
+        TextColor(palette::HEADER_TEXT),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(24.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        TextLayout::new_with_justify(Justify::Center),
+        Pickable::IGNORE,
+        DespawnOnExit(GameState::InGame),
+    ));
+}
+
+/// Advances the tutorial one step when the current step's goal is met.
+fn advance_tutorial(
+    mut tutorial: ResMut<Tutorial>,
+    shop: Res<Shop>,
+    buildings: Query<&Team, With<Building>>,
+    mut cleared: MessageReader<WaveCleared>,
+) {
+    let wave_cleared = cleared.read().count() > 0;
+    let Some(step) = tutorial.step else {
+        return;
+    };
+    let placed = buildings.iter().any(|team| *team == Team::Player);
+    let done = match step {
+        // Placing a building implies a card was selected, even if the selection is gone.
+        TutorialStep::SelectCard => shop.selected.is_some() || placed,
+        TutorialStep::PlaceBuilding => placed,
+        TutorialStep::SurviveWave => wave_cleared,
+        TutorialStep::Complete => false,
+    };
+    if done {
+        tutorial.step = Some(step.next());
+    }
+}
+
+fn update_tutorial_prompt(
+    tutorial: Res<Tutorial>,
+    mut prompts: Query<(&mut Text, &mut Visibility), With<TutorialPrompt>>,
+) {
+    if !tutorial.is_changed() {
+        return;
+    }
+    let prompt = tutorial.step.and_then(TutorialStep::prompt);
+    for (mut text, mut visibility) in &mut prompts {
+        match prompt {
+            Some(prompt) => {
+                **text = prompt.to_string();
+                *visibility = Visibility::Inherited;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Tutorial>()
+        .register_type::<TutorialPrompt>()
+        .init_resource::<Tutorial>();
+
+    app.add_systems(OnEnter(GameState::InGame), spawn_tutorial_prompt);
+    app.add_systems(
+        Update,
+        (advance_tutorial, update_tutorial_prompt)
+            .chain()
+            .in_set(GameSet::Ui)
+            .run_if(gameplay_running),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gameplay::building::BuildingType;
+    use pretty_assertions::assert_eq;
+
+    fn create_tutorial_test_app(step: TutorialStep) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        crate::testing::init_economy_resources(&mut app);
+        app.add_message::<WaveCleared>();
+        app.insert_resource(Tutorial { step: Some(step) });
+        app.add_systems(Update, (advance_tutorial, update_tutorial_prompt).chain());
+        app
+    }
+
+    fn spawn_building(app: &mut App, team: Team) {
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 0, 0);
+        app.world_mut().entity_mut(building).insert(team);
+    }
+
+    fn current_step(app: &App) -> Option<TutorialStep> {
+        app.world().resource::<Tutorial>().step
+    }
+
+    #[test]
+    fn steps_advance_in_order() {
+        assert_eq!(TutorialStep::SelectCard.next(), TutorialStep::PlaceBuilding);
+        assert_eq!(
+            TutorialStep::PlaceBuilding.next(),
+            TutorialStep::SurviveWave
+        );
+        assert_eq!(TutorialStep::SurviveWave.next(), TutorialStep::Complete);
+        assert_eq!(TutorialStep::Complete.next(), TutorialStep::Complete);
+        assert_eq!(TutorialStep::Complete.prompt(), None);
+    }
+
+    #[test]
+    fn selecting_a_card_advances_to_place() {
+        let mut app = create_tutorial_test_app(TutorialStep::SelectCard);
+        app.update();
+        assert_eq!(current_step(&app), Some(TutorialStep::SelectCard));
+
+        app.world_mut().resource_mut::<Shop>().selected = Some(0);
+        app.update();
+        assert_eq!(current_step(&app), Some(TutorialStep::PlaceBuilding));
+    }
+
+    #[test]
+    fn placing_a_building_advances_past_place_stage() {
+        let mut app = create_tutorial_test_app(TutorialStep::PlaceBuilding);
+        app.update();
+        assert_eq!(current_step(&app), Some(TutorialStep::PlaceBuilding));
+
+        spawn_building(&mut app, Team::Player);
+        app.update();
+        assert_eq!(current_step(&app), Some(TutorialStep::SurviveWave));
+    }
+
+    #[test]
+    fn enemy_building_does_not_advance_place_stage() {
+        let mut app = create_tutorial_test_app(TutorialStep::PlaceBuilding);
+        spawn_building(&mut app, Team::Enemy);
+        app.update();
+        assert_eq!(current_step(&app), Some(TutorialStep::PlaceBuilding));
+    }
+
+    #[test]
+    fn surviving_the_wave_completes_tutorial_and_hides_prompt() {
+        let mut app = create_tutorial_test_app(TutorialStep::SurviveWave);
+        let prompt = app
+            .world_mut()
+            .spawn((
+                TutorialPrompt,
+                Text::new("Survive the first wave"),
+                Visibility::Inherited,
+            ))
+            .id();
+
+        app.update();
+        assert_eq!(current_step(&app), Some(TutorialStep::SurviveWave));
+
+        app.world_mut().write_message(WaveCleared { wave: 1 });
+        app.update();
+
+        assert_eq!(current_step(&app), Some(TutorialStep::Complete));
+        assert_eq!(
+            app.world().get::<Visibility>(prompt),
+            Some(&Visibility::Hidden)
+        );
+    }
+
+    #[test]
+    fn wave_cleared_before_survive_step_does_not_skip_it() {
+        let mut app = create_tutorial_test_app(TutorialStep::PlaceBuilding);
+        app.world_mut().write_message(WaveCleared { wave: 1 });
+        spawn_building(&mut app, Team::Player);
+        app.update();
+        assert_eq!(current_step(&app), Some(TutorialStep::SurviveWave));
+
+        app.update();
+        assert_eq!(current_step(&app), Some(TutorialStep::SurviveWave));
+    }
+
+    #[test]
+    fn no_tutorial_means_no_prompt() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<Tutorial>();
+        app.add_systems(Update, spawn_tutorial_prompt);
+        app.update();
+
+        crate::testing::assert_entity_count::<With<TutorialPrompt>>(&mut app, 0);
+    }
+}
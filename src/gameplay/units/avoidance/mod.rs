@@ -1,4 +1,5 @@
-//! ORCA local avoidance for unit-to-unit collision prevention.
+//! ORCA local avoidance for unit-to-unit collision prevention, plus static
+//! obstacle (building and fortress) avoidance so units don't clip corners.
 
 pub mod orca;
 
@@ -6,19 +7,37 @@ use std::collections::HashMap;
 
 use avian2d::prelude::*;
 use bevy::prelude::*;
+use bevy::tasks::{ComputeTaskPool, ParallelSlice, TaskPool};
 
 use self::orca::AgentSnapshot;
 use super::{Movement, UNIT_RADIUS, Unit};
+use crate::gameplay::EntityExtent;
 use crate::gameplay::spatial_hash::SpatialHash;
+use crate::third_party::NavObstacle;
 
 // === Constants ===
 
 /// Default ORCA time horizon in seconds.
 const DEFAULT_TIME_HORIZON: f32 = 3.0;
+/// Default ORCA time horizon against static obstacles in seconds. Short, so
+/// units only react right before contact and can still close to melee range.
+const DEFAULT_OBSTACLE_TIME_HORIZON: f32 = 0.5;
 /// Maximum neighbors to consider per agent.
 const DEFAULT_MAX_NEIGHBORS: u32 = 10;
+/// Cell size (pixels) of the avoidance spatial hashes. About one unit
+/// diameter plus spacing, so a query only touches cells near its radius.
+const DEFAULT_HASH_CELL_SIZE: f32 = 32.0;
 /// Velocity smoothing blend factor (0.0 = keep old, 1.0 = fully ORCA).
 const DEFAULT_VELOCITY_SMOOTHING: f32 = 0.85;
+/// Separation push (px/s) per pixel of overlap beyond the threshold.
+const DEFAULT_SEPARATION_STRENGTH: f32 = 4.0;
+/// Overlap (px) tolerated before separation kicks in.
+const DEFAULT_SEPARATION_THRESHOLD: f32 = 1.0;
+/// Agents per task when computing avoidance in parallel.
+const AVOIDANCE_CHUNK_SIZE: usize = 32;
+/// Largest distance from an obstacle's center to its surface (128px fortress
+/// half-diagonal ≈ 90.5). Pads obstacle lookups, which are keyed by center.
+const MAX_OBSTACLE_HALF_DIAGONAL: f32 = 91.0;
 
 // === Components ===
 
@@ -37,6 +56,9 @@ pub struct AvoidanceAgent {
     /// How much of the avoidance adjustment this agent absorbs (0.0–1.0).
     /// 0.5 = symmetric (both agents dodge equally). 1.0 = this agent takes full responsibility.
     pub responsibility: f32,
+    /// Per-agent ORCA time horizon (seconds). `None` uses `AvoidanceConfig::time_horizon`.
+    /// Fast agents need a longer horizon to start avoiding earlier.
+    pub time_horizon: Option<f32>,
 }
 
 impl Default for AvoidanceAgent {
@@ -44,6 +66,7 @@ impl Default for AvoidanceAgent {
         Self {
             radius: UNIT_RADIUS,
             responsibility: 0.5,
+            time_horizon: None,
         }
     }
 }
@@ -56,21 +79,35 @@ impl Default for AvoidanceAgent {
 pub struct AvoidanceConfig {
     /// How far ahead (seconds) agents predict collisions with each other.
     pub time_horizon: f32,
+    /// How far ahead (seconds) agents predict collisions with static obstacles.
+    pub obstacle_time_horizon: f32,
     /// Max neighbors to consider per agent. Caps ORCA constraint count.
     pub max_neighbors: u32,
     /// Search radius for neighbors (pixels). Should be >= `max_speed * time_horizon`.
     pub neighbor_distance: f32,
+    /// Cell size (pixels) of `AvoidanceSpatialHash` and `ObstacleSpatialHash`.
+    /// Independent of `neighbor_distance`; read once when the hashes are built.
+    pub hash_cell_size: f32,
     /// Blend factor for velocity smoothing (0.0 = old velocity, 1.0 = raw ORCA result).
     pub velocity_smoothing: f32,
+    /// Extra push apart (px/s per px of overlap) for overlapping units, added after ORCA.
+    /// ORCA skips overlapping pairs, so this keeps dense crowds from clumping. 0 disables it.
+    pub separation_strength: f32,
+    /// Overlap (px) tolerated before separation applies.
+    pub separation_threshold: f32,
 }
 
 impl Default for AvoidanceConfig {
     fn default() -> Self {
         Self {
             time_horizon: DEFAULT_TIME_HORIZON,
+            obstacle_time_horizon: DEFAULT_OBSTACLE_TIME_HORIZON,
             neighbor_distance: DEFAULT_TIME_HORIZON * 50.0, // max_speed * time_horizon
             max_neighbors: DEFAULT_MAX_NEIGHBORS,
+            hash_cell_size: DEFAULT_HASH_CELL_SIZE,
             velocity_smoothing: DEFAULT_VELOCITY_SMOOTHING,
+            separation_strength: DEFAULT_SEPARATION_STRENGTH,
+            separation_threshold: DEFAULT_SEPARATION_THRESHOLD,
         }
     }
 }
@@ -93,6 +130,62 @@ impl std::ops::DerefMut for AvoidanceSpatialHash {
     }
 }
 
+/// Spatial hash of static `NavObstacle` entities (buildings, fortresses) for
+/// obstacle avoidance. Kept apart from [`AvoidanceSpatialHash`] so unit
+/// neighbor searches and the neighbor cap only ever see units.
+#[derive(Resource, Debug)]
+pub struct ObstacleSpatialHash(pub SpatialHash);
+
+impl std::ops::Deref for ObstacleSpatialHash {
+    type Target = SpatialHash;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for ObstacleSpatialHash {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// The obstacle as ORCA sees it from `agent_pos`: `(position, radius)` of a
+/// zero-velocity agent. Circles keep their center and radius. Rectangles
+/// collapse to their nearest surface point with zero radius, so corners are
+/// avoided without a bounding circle holding units off the faces (which would
+/// keep melee units out of range of a fortress).
+fn obstacle_agent(extent: &EntityExtent, center: Vec2, agent_pos: Vec2) -> (Vec2, f32) {
+    match *extent {
+        EntityExtent::Circle(radius) => (center, radius),
+        EntityExtent::Rect(half_w, half_h) => {
+            let half = Vec2::new(half_w, half_h);
+            (center + (agent_pos - center).clamp(-half, half), 0.0)
+        }
+    }
+}
+
+/// Repulsion velocity pushing `agent` away from every neighbor it overlaps by
+/// more than `threshold` pixels, scaled by `strength` per pixel of excess overlap.
+fn separation_velocity<'a>(
+    agent: &AgentSnapshot,
+    neighbors: impl IntoIterator<Item = &'a AgentSnapshot>,
+    strength: f32,
+    threshold: f32,
+) -> Vec2 {
+    neighbors
+        .into_iter()
+        .map(|neighbor| {
+            let offset = agent.position - neighbor.position;
+            let overlap = agent.radius + neighbor.radius - offset.length();
+            if overlap > threshold {
+                offset.normalize_or_zero() * strength * (overlap - threshold)
+            } else {
+                Vec2::ZERO
+            }
+        })
+        .sum()
+}
+
 // === Systems ===
 
 /// Rebuild the spatial hash with all unit positions. Runs every frame.
@@ -106,14 +199,218 @@ pub fn rebuild_spatial_hash(
     }
 }
 
+/// Rebuild the obstacle hash with all static obstacle positions. Runs every frame.
+pub fn rebuild_obstacle_hash(
+    mut hash: ResMut<ObstacleSpatialHash>,
+    obstacles: Query<(Entity, &GlobalTransform), With<NavObstacle>>,
+) {
+    hash.clear();
+    for (entity, transform) in &obstacles {
+        hash.insert(entity, transform.translation().xy());
+    }
+}
+
+/// `v` if both components are finite, otherwise zero.
+fn finite_or_zero(v: Vec2) -> Vec2 {
+    if v.is_finite() { v } else { Vec2::ZERO }
+}
+
+/// Static obstacles as read by [`compute_avoidance`].
+type ObstacleQuery<'w, 's> =
+    Query<'w, 's, (&'static GlobalTransform, &'static EntityExtent), With<NavObstacle>>;
+
+/// An agent's snapshot plus its effective ORCA time horizon.
+type AgentEntry = (Entity, AgentSnapshot, f32);
+
+/// Snapshot one agent for the compute phase.
+/// Non-finite velocities read as zero; a zero or non-finite speed zeroes the
+/// preferred velocity so the agent is treated as stationary.
+fn snapshot_agent(
+    config: &AvoidanceConfig,
+    (entity, transform, velocity, preferred, avoidance, movement): (
+        Entity,
+        &GlobalTransform,
+        &LinearVelocity,
+        &PreferredVelocity,
+        &AvoidanceAgent,
+        &Movement,
+    ),
+) -> AgentEntry {
+    let max_speed = if movement.speed.is_finite() {
+        movement.speed.max(0.0)
+    } else {
+        0.0
+    };
+    let preferred = if max_speed > 0.0 {
+        finite_or_zero(preferred.0)
+    } else {
+        Vec2::ZERO
+    };
+    (
+        entity,
+        AgentSnapshot {
+            position: transform.translation().xy(),
+            velocity: finite_or_zero(velocity.0),
+            preferred,
+            radius: avoidance.radius,
+            max_speed,
+            responsibility: avoidance.responsibility,
+        },
+        avoidance.time_horizon.unwrap_or(config.time_horizon),
+    )
+}
+
+/// Everything the compute phase reads. Immutable for the whole phase, so agents
+/// can be processed in any order (or in parallel) with identical results.
+struct AvoidanceFrame<'a, 'w, 's> {
+    config: &'a AvoidanceConfig,
+    hash: &'a SpatialHash,
+    obstacle_hash: &'a SpatialHash,
+    obstacles: &'a ObstacleQuery<'w, 's>,
+    snapshots: &'a [AgentEntry],
+    /// Entity -> index into `snapshots`, for neighbor access.
+    index_map: HashMap<Entity, usize>,
+}
+
+impl<'a, 'w, 's> AvoidanceFrame<'a, 'w, 's> {
+    fn new(
+        config: &'a AvoidanceConfig,
+        hash: &'a SpatialHash,
+        obstacle_hash: &'a SpatialHash,
+        obstacles: &'a ObstacleQuery<'w, 's>,
+        snapshots: &'a [AgentEntry],
+    ) -> Self {
+        let index_map = snapshots
+            .iter()
+            .enumerate()
+            .map(|(i, (e, _, _))| (*e, i))
+            .collect();
+        Self {
+            config,
+            hash,
+            obstacle_hash,
+            obstacles,
+            snapshots,
+            index_map,
+        }
+    }
+
+    /// ORCA velocity (plus overlap separation) for one agent.
+    fn velocity(&self, (entity, agent, time_horizon): &AgentEntry) -> (Entity, Vec2) {
+        let config = self.config;
+        let candidates = self
+            .hash
+            .query_neighbors(agent.position, config.neighbor_distance);
+        let separation = separation_velocity(
+            agent,
+            candidates
+                .iter()
+                .filter(|candidate| *candidate != entity)
+                .filter_map(|candidate| self.index_map.get(candidate))
+                .map(|&idx| &self.snapshots[idx].1),
+            config.separation_strength,
+            config.separation_threshold,
+        );
+
+        // Skip ORCA for stationary agents with zero preferred velocity
+        if agent.preferred.length_squared() < f32::EPSILON {
+            return (*entity, separation);
+        }
+
+        // Gather neighbor snapshots and compute ORCA lines
+        let mut lines = Vec::new();
+        let mut neighbor_count = 0u32;
+
+        for &candidate_entity in &candidates {
+            if candidate_entity == *entity {
+                continue;
+            }
+            if neighbor_count >= config.max_neighbors {
+                break;
+            }
+            if let Some(&idx) = self.index_map.get(&candidate_entity) {
+                let neighbor = &self.snapshots[idx].1;
+                if let Some(line) = orca::compute_orca_line(agent, neighbor, *time_horizon) {
+                    lines.push(line);
+                    neighbor_count += 1;
+                }
+            }
+        }
+
+        // Static obstacles: never counted against the neighbor cap
+        let obstacle_radius = config.neighbor_distance + MAX_OBSTACLE_HALF_DIAGONAL;
+        for obstacle in self
+            .obstacle_hash
+            .query_neighbors(agent.position, obstacle_radius)
+        {
+            let Ok((transform, extent)) = self.obstacles.get(obstacle) else {
+                continue;
+            };
+            let (position, radius) =
+                obstacle_agent(extent, transform.translation().xy(), agent.position);
+            if let Some(line) =
+                orca::compute_obstacle_line(agent, position, radius, config.obstacle_time_horizon)
+            {
+                lines.push(line);
+            }
+        }
+
+        // No neighbors nearby — use preferred velocity directly
+        if lines.is_empty() {
+            return (*entity, agent.preferred + separation);
+        }
+
+        let orca_vel = orca::compute_avoiding_velocity(agent.preferred, agent.max_speed, &lines);
+
+        // Velocity smoothing: blend ORCA result with current velocity
+        let smoothed = agent.velocity.lerp(orca_vel, config.velocity_smoothing);
+        (*entity, smoothed + separation)
+    }
+
+    /// Velocities for every agent, one at a time, in snapshot order.
+    #[cfg(test)]
+    fn velocities_sequential(&self) -> Vec<(Entity, Vec2)> {
+        self.snapshots
+            .iter()
+            .map(|entry| self.velocity(entry))
+            .collect()
+    }
+
+    /// Velocities for every agent, computed in chunks on the compute task pool.
+    /// Chunks come back in order, so the output matches the sequential path.
+    fn velocities_parallel(&self) -> Vec<(Entity, Vec2)> {
+        self.snapshots
+            .par_chunk_map(
+                ComputeTaskPool::get_or_init(TaskPool::default),
+                AVOIDANCE_CHUNK_SIZE,
+                |_, chunk| {
+                    chunk
+                        .iter()
+                        .map(|entry| self.velocity(entry))
+                        .collect::<Vec<_>>()
+                },
+            )
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
 /// Compute ORCA-adjusted velocities for all units.
 ///
 /// Reads `PreferredVelocity` (desired direction from pathfinding) and
 /// `LinearVelocity` (current velocity from last frame's ORCA output).
-/// Writes the ORCA result to `LinearVelocity`.
+/// Writes the ORCA result plus any overlap separation to `LinearVelocity`.
+/// Nearby `NavObstacle`s add constraints the unit alone is responsible for.
+///
+/// Non-finite velocities are read as zero, and agents with a zero or non-finite
+/// speed are treated as stationary (no ORCA), so the output is always finite.
+/// The per-agent compute phase runs in parallel on the `ComputeTaskPool`.
 pub fn compute_avoidance(
     config: Res<AvoidanceConfig>,
     hash: Res<AvoidanceSpatialHash>,
+    obstacle_hash: Res<ObstacleSpatialHash>,
+    obstacles: ObstacleQuery,
     mut agents: Query<
         (
             Entity,
@@ -127,82 +424,19 @@ pub fn compute_avoidance(
     >,
 ) {
     // Phase 1: Snapshot all agent data (immutable read via .iter())
-    let snapshots: Vec<(Entity, AgentSnapshot)> = agents
+    let snapshots: Vec<AgentEntry> = agents
         .iter()
-        .map(
-            |(entity, transform, velocity, preferred, avoidance, movement)| {
-                (
-                    entity,
-                    AgentSnapshot {
-                        position: transform.translation().xy(),
-                        velocity: velocity.0,
-                        preferred: preferred.0,
-                        radius: avoidance.radius,
-                        max_speed: movement.speed,
-                        responsibility: avoidance.responsibility,
-                    },
-                )
-            },
-        )
+        .map(|item| snapshot_agent(&config, item))
         .collect();
 
-    // Build entity -> snapshot index lookup for neighbor access
-    let index_map: HashMap<Entity, usize> = snapshots
-        .iter()
-        .enumerate()
-        .map(|(i, (e, _))| (*e, i))
-        .collect();
-
-    // Phase 2: Compute ORCA velocity for each agent
-    let results: Vec<(Entity, Vec2)> = snapshots
-        .iter()
-        .map(|(entity, agent)| {
-            // Skip ORCA for stationary agents with zero preferred velocity
-            if agent.preferred.length_squared() < f32::EPSILON {
-                return (*entity, Vec2::ZERO);
-            }
-
-            // Gather neighbor snapshots and compute ORCA lines
-            let mut lines = Vec::new();
-            let candidates = hash.query_neighbors(agent.position, config.neighbor_distance);
-            let mut neighbor_count = 0u32;
-
-            for candidate_entity in candidates {
-                if candidate_entity == *entity {
-                    continue;
-                }
-                if neighbor_count >= config.max_neighbors {
-                    break;
-                }
-                if let Some(&idx) = index_map.get(&candidate_entity) {
-                    let neighbor = &snapshots[idx].1;
-                    if let Some(line) =
-                        orca::compute_orca_line(agent, neighbor, config.time_horizon)
-                    {
-                        lines.push(line);
-                        neighbor_count += 1;
-                    }
-                }
-            }
-
-            // No neighbors nearby — use preferred velocity directly
-            if lines.is_empty() {
-                return (*entity, agent.preferred);
-            }
-
-            let orca_vel =
-                orca::compute_avoiding_velocity(agent.preferred, agent.max_speed, &lines);
-
-            // Velocity smoothing: blend ORCA result with current velocity
-            let smoothed = agent.velocity.lerp(orca_vel, config.velocity_smoothing);
-            (*entity, smoothed)
-        })
-        .collect();
+    // Phase 2: Compute ORCA velocity (plus overlap separation) for each agent
+    let frame = AvoidanceFrame::new(&config, &hash, &obstacle_hash, &obstacles, &snapshots);
+    let results = frame.velocities_parallel();
 
     // Phase 3: Write results
     for (entity, new_velocity) in results {
         if let Ok((_, _, mut linear_vel, _, _, _)) = agents.get_mut(entity) {
-            linear_vel.0 = new_velocity;
+            linear_vel.0 = finite_or_zero(new_velocity);
         }
     }
 }
@@ -216,11 +450,19 @@ mod integration_tests {
         app.add_plugins(MinimalPlugins);
         app.init_resource::<AvoidanceConfig>();
         app.insert_resource(AvoidanceSpatialHash(SpatialHash::new(
-            AvoidanceConfig::default().neighbor_distance,
+            AvoidanceConfig::default().hash_cell_size,
+        )));
+        app.insert_resource(ObstacleSpatialHash(SpatialHash::new(
+            AvoidanceConfig::default().hash_cell_size,
         )));
         app.add_systems(
             Update,
-            (rebuild_spatial_hash, compute_avoidance).chain_ignore_deferred(),
+            (
+                rebuild_spatial_hash,
+                rebuild_obstacle_hash,
+                compute_avoidance,
+            )
+                .chain_ignore_deferred(),
         );
         app.update(); // Initialize time
         app
@@ -265,6 +507,67 @@ mod integration_tests {
         );
     }
 
+    #[test]
+    fn nan_preferred_velocity_yields_zero_velocity() {
+        let mut app = create_avoidance_test_app();
+        let unit = spawn_avoidance_unit(
+            app.world_mut(),
+            100.0,
+            100.0,
+            Vec2::new(f32::NAN, 0.0),
+            Vec2::ZERO,
+        );
+        app.update();
+        let vel = app.world().get::<LinearVelocity>(unit).unwrap();
+        assert_eq!(vel.0, Vec2::ZERO);
+    }
+
+    #[test]
+    fn nan_preferred_velocity_does_not_poison_neighbors() {
+        let mut app = create_avoidance_test_app();
+        let broken = spawn_avoidance_unit(
+            app.world_mut(),
+            100.0,
+            100.0,
+            Vec2::new(f32::NAN, f32::INFINITY),
+            Vec2::new(f32::NAN, 0.0),
+        );
+        let neighbor = spawn_avoidance_unit(
+            app.world_mut(),
+            115.0,
+            100.0,
+            Vec2::new(-50.0, 0.0),
+            Vec2::new(-50.0, 0.0),
+        );
+        app.update();
+        for unit in [broken, neighbor] {
+            let vel = app.world().get::<LinearVelocity>(unit).unwrap();
+            assert!(
+                vel.0.is_finite(),
+                "Velocity should be finite, got {:?}",
+                vel.0
+            );
+        }
+    }
+
+    #[test]
+    fn zero_speed_unit_is_stationary() {
+        let mut app = create_avoidance_test_app();
+        let unit = spawn_avoidance_unit(
+            app.world_mut(),
+            100.0,
+            100.0,
+            Vec2::new(50.0, 0.0),
+            Vec2::ZERO,
+        );
+        app.world_mut()
+            .entity_mut(unit)
+            .insert(Movement { speed: 0.0 });
+        app.update();
+        let vel = app.world().get::<LinearVelocity>(unit).unwrap();
+        assert_eq!(vel.0, Vec2::ZERO);
+    }
+
     #[test]
     fn head_on_units_steer_apart() {
         let mut app = create_avoidance_test_app();
@@ -294,6 +597,78 @@ mod integration_tests {
         );
     }
 
+    /// A 40px building (`NavObstacle` with a rect extent) centered at `(x, y)`.
+    fn spawn_wall(world: &mut World, x: f32, y: f32) -> Entity {
+        world
+            .spawn((
+                NavObstacle,
+                EntityExtent::Rect(20.0, 20.0),
+                Transform::from_xyz(x, y, 0.0),
+                GlobalTransform::from(Transform::from_xyz(x, y, 0.0)),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn unit_steered_toward_wall_gets_lateral_velocity() {
+        let mut app = create_avoidance_test_app();
+        // Heading east just inside unit radius of the wall's top-left corner (140, 120)
+        let unit = spawn_avoidance_unit(
+            app.world_mut(),
+            125.0,
+            124.0,
+            Vec2::new(50.0, 0.0),
+            Vec2::new(50.0, 0.0),
+        );
+        spawn_wall(app.world_mut(), 160.0, 100.0);
+        app.update();
+
+        let vel = app.world().get::<LinearVelocity>(unit).unwrap().0;
+        assert!(
+            vel.y.abs() > 0.1,
+            "Unit heading into a wall should steer laterally, got {vel:?}"
+        );
+    }
+
+    #[test]
+    fn unit_moving_away_from_wall_keeps_preferred_velocity() {
+        let mut app = create_avoidance_test_app();
+        let unit = spawn_avoidance_unit(
+            app.world_mut(),
+            100.0,
+            100.0,
+            Vec2::new(50.0, 0.0),
+            Vec2::new(50.0, 0.0),
+        );
+        spawn_wall(app.world_mut(), 60.0, 100.0);
+        app.update();
+
+        let vel = app.world().get::<LinearVelocity>(unit).unwrap().0;
+        assert!(
+            (vel - Vec2::new(50.0, 0.0)).length() < 1.0,
+            "Wall behind the unit should not deflect it, got {vel:?}"
+        );
+    }
+
+    #[test]
+    fn rect_obstacle_collapses_to_nearest_surface_point() {
+        let rect = EntityExtent::Rect(20.0, 10.0);
+        let center = Vec2::new(100.0, 100.0);
+
+        assert_eq!(
+            obstacle_agent(&rect, center, Vec2::new(50.0, 103.0)),
+            (Vec2::new(80.0, 103.0), 0.0)
+        );
+        assert_eq!(
+            obstacle_agent(&rect, center, Vec2::new(150.0, 150.0)),
+            (Vec2::new(120.0, 110.0), 0.0)
+        );
+        assert_eq!(
+            obstacle_agent(&EntityExtent::Circle(8.0), center, Vec2::ZERO),
+            (center, 8.0)
+        );
+    }
+
     #[test]
     fn zero_preferred_stays_zero() {
         let mut app = create_avoidance_test_app();
@@ -303,6 +678,56 @@ mod integration_tests {
         assert!(vel.0.length() < f32::EPSILON);
     }
 
+    #[test]
+    fn per_agent_time_horizon_avoids_earlier_than_default() {
+        // Slow head-on approach: closing speed 20px/s, gap 140px.
+        // Default 3s horizon sees no collision; a 10s horizon does.
+        let spawn_pair = |app: &mut App| {
+            let a = spawn_avoidance_unit(
+                app.world_mut(),
+                100.0,
+                100.0,
+                Vec2::new(10.0, 0.0),
+                Vec2::new(10.0, 0.0),
+            );
+            spawn_avoidance_unit(
+                app.world_mut(),
+                240.0,
+                100.0,
+                Vec2::new(-10.0, 0.0),
+                Vec2::new(-10.0, 0.0),
+            );
+            a
+        };
+
+        let mut default_app = create_avoidance_test_app();
+        let default_unit = spawn_pair(&mut default_app);
+        default_app.update();
+        let default_vel = default_app
+            .world()
+            .get::<LinearVelocity>(default_unit)
+            .unwrap()
+            .0;
+        assert!(
+            (default_vel - Vec2::new(10.0, 0.0)).length() < 0.1,
+            "Default horizon should not react yet, got {default_vel:?}"
+        );
+
+        let mut long_app = create_avoidance_test_app();
+        let long_unit = spawn_pair(&mut long_app);
+        long_app
+            .world_mut()
+            .get_mut::<AvoidanceAgent>(long_unit)
+            .unwrap()
+            .time_horizon = Some(10.0);
+        long_app.update();
+        let long_vel = long_app.world().get::<LinearVelocity>(long_unit).unwrap().0;
+        assert!(
+            (long_vel - Vec2::new(10.0, 0.0)).length() > 0.1,
+            "Longer horizon should already be avoiding, got {long_vel:?}"
+        );
+    }
+
     #[test]
     fn distant_units_no_avoidance() {
         let mut app = create_avoidance_test_app();
@@ -328,4 +753,175 @@ mod integration_tests {
             vel.0
         );
     }
+
+    #[test]
+    fn raising_neighbor_distance_at_runtime_finds_farther_neighbors() {
+        let mut app = create_avoidance_test_app();
+        let a = spawn_avoidance_unit(
+            app.world_mut(),
+            0.0,
+            0.0,
+            Vec2::new(50.0, 0.0),
+            Vec2::new(50.0, 0.0),
+        );
+        let _b = spawn_avoidance_unit(
+            app.world_mut(),
+            200.0,
+            4.0,
+            Vec2::new(-50.0, 0.0),
+            Vec2::new(-50.0, 0.0),
+        );
+        app.update();
+        let lateral = app.world().get::<LinearVelocity>(a).unwrap().0.y;
+        assert!(
+            lateral.abs() < 1e-3,
+            "200px is beyond the default neighbor distance, got {lateral}"
+        );
+
+        // Same hashes, wider query radius: the cell size doesn't depend on it.
+        app.world_mut()
+            .resource_mut::<AvoidanceConfig>()
+            .neighbor_distance = 250.0;
+        app.update();
+        let lateral = app.world().get::<LinearVelocity>(a).unwrap().0.y;
+        assert!(
+            lateral.abs() > 0.1,
+            "Expected the farther unit to be avoided, got {lateral}"
+        );
+    }
+
+    #[test]
+    fn overlapping_units_receive_opposing_separation() {
+        let mut app = create_avoidance_test_app();
+        // 4px apart with 6px radii: 8px overlap
+        let a = spawn_avoidance_unit(app.world_mut(), 100.0, 100.0, Vec2::ZERO, Vec2::ZERO);
+        let b = spawn_avoidance_unit(app.world_mut(), 104.0, 100.0, Vec2::ZERO, Vec2::ZERO);
+        app.update();
+
+        let vel_a = app.world().get::<LinearVelocity>(a).unwrap().0;
+        let vel_b = app.world().get::<LinearVelocity>(b).unwrap().0;
+        assert!(vel_a.x < 0.0, "a should be pushed left, got {vel_a:?}");
+        assert!(vel_b.x > 0.0, "b should be pushed right, got {vel_b:?}");
+        assert!((vel_a + vel_b).length() < 1e-3, "pushes should be opposite");
+    }
+
+    #[test]
+    fn separation_scales_with_configured_strength() {
+        let push_for = |strength: f32| {
+            let mut app = create_avoidance_test_app();
+            app.world_mut()
+                .resource_mut::<AvoidanceConfig>()
+                .separation_strength = strength;
+            let a = spawn_avoidance_unit(app.world_mut(), 100.0, 100.0, Vec2::ZERO, Vec2::ZERO);
+            spawn_avoidance_unit(app.world_mut(), 104.0, 100.0, Vec2::ZERO, Vec2::ZERO);
+            app.update();
+            app.world().get::<LinearVelocity>(a).unwrap().0.length()
+        };
+
+        let base = push_for(4.0);
+        let doubled = push_for(8.0);
+        assert!(base > 0.0);
+        assert!(
+            (doubled - 2.0 * base).abs() < 1e-3,
+            "Expected push to double: {base} -> {doubled}"
+        );
+        assert!(push_for(0.0) < f32::EPSILON);
+    }
+
+    #[test]
+    fn separation_ignores_overlap_within_threshold() {
+        let agent = |x: f32| AgentSnapshot {
+            position: Vec2::new(x, 0.0),
+            velocity: Vec2::ZERO,
+            preferred: Vec2::ZERO,
+            radius: 6.0,
+            max_speed: 50.0,
+            responsibility: 0.5,
+        };
+        // 11.5px apart: 0.5px overlap, under the 1px threshold
+        let push = separation_velocity(&agent(0.0), [&agent(11.5)], 4.0, 1.0);
+        assert_eq!(push, Vec2::ZERO);
+
+        // 8px apart: 4px overlap, 3px over threshold
+        let push = separation_velocity(&agent(0.0), [&agent(8.0)], 4.0, 1.0);
+        assert!(
+            (push - Vec2::new(-12.0, 0.0)).length() < 1e-4,
+            "got {push:?}"
+        );
+    }
+
+    /// Velocities from the sequential path, captured just before `compute_avoidance`.
+    #[derive(Resource, Default)]
+    struct SequentialVelocities(Vec<(Entity, Vec2)>);
+
+    fn record_sequential_velocities(
+        mut recorded: ResMut<SequentialVelocities>,
+        config: Res<AvoidanceConfig>,
+        hash: Res<AvoidanceSpatialHash>,
+        obstacle_hash: Res<ObstacleSpatialHash>,
+        obstacles: ObstacleQuery,
+        agents: Query<
+            (
+                Entity,
+                &GlobalTransform,
+                &LinearVelocity,
+                &PreferredVelocity,
+                &AvoidanceAgent,
+                &Movement,
+            ),
+            With<Unit>,
+        >,
+    ) {
+        let snapshots: Vec<AgentEntry> = agents
+            .iter()
+            .map(|item| snapshot_agent(&config, item))
+            .collect();
+        let frame = AvoidanceFrame::new(&config, &hash, &obstacle_hash, &obstacles, &snapshots);
+        let sequential = frame.velocities_sequential();
+        assert_eq!(frame.velocities_parallel(), sequential);
+        recorded.0 = sequential;
+    }
+
+    #[test]
+    fn parallel_avoidance_matches_sequential_for_large_crowd() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<AvoidanceConfig>()
+            .init_resource::<SequentialVelocities>();
+        let cell_size = AvoidanceConfig::default().hash_cell_size;
+        app.insert_resource(AvoidanceSpatialHash(SpatialHash::new(cell_size)));
+        app.insert_resource(ObstacleSpatialHash(SpatialHash::new(cell_size)));
+        app.add_systems(
+            Update,
+            (
+                rebuild_spatial_hash,
+                rebuild_obstacle_hash,
+                record_sequential_velocities,
+                compute_avoidance,
+            )
+                .chain_ignore_deferred(),
+        );
+
+        // 500 units packed 10px apart (overlapping), converging on a column of walls
+        for i in 0..25_u8 {
+            for j in 0..20_u8 {
+                let x = f32::from(i) * 10.0;
+                let y = f32::from(j) * 10.0;
+                let preferred = Vec2::new(50.0, 100.0 - y).clamp_length_max(50.0);
+                spawn_avoidance_unit(app.world_mut(), x, y, preferred, Vec2::ZERO);
+            }
+        }
+        for j in 0..5_u8 {
+            spawn_wall(app.world_mut(), 300.0, f32::from(j) * 40.0);
+        }
+
+        app.update();
+
+        let sequential = &app.world().resource::<SequentialVelocities>().0;
+        assert_eq!(sequential.len(), 500);
+        for &(entity, expected) in sequential {
+            let actual = app.world().get::<LinearVelocity>(entity).unwrap().0;
+            assert_eq!(actual, finite_or_zero(expected), "mismatch for {entity}");
+        }
+    }
 }
@@ -1,7 +1,8 @@
 //! ORCA (Optimal Reciprocal Collision Avoidance) — pure math, no Bevy dependency.
 //!
 //! Computes collision-free velocities for agents moving in 2D.
-//! Only handles agent-agent avoidance; static obstacles are handled by navmesh pathfinding.
+//! Static obstacles are modeled as zero-velocity agents the moving agent fully
+//! yields to; navmesh pathfinding still does the coarse routing around them.
 //!
 //! Based on the RVO2 reference implementation (Agent.cpp).
 
@@ -114,6 +115,32 @@ pub fn compute_orca_line(
     }
 }
 
+/// Compute the ORCA half-plane constraint for agent `a` avoiding a static
+/// obstacle of `radius` at `position`. The obstacle never moves, so `a` takes
+/// full responsibility for the avoidance.
+///
+/// Returns `None` if `a` already overlaps the obstacle.
+pub fn compute_obstacle_line(
+    a: &AgentSnapshot,
+    position: Vec2,
+    radius: f32,
+    time_horizon: f32,
+) -> Option<OrcaLine> {
+    let obstacle = AgentSnapshot {
+        position,
+        velocity: Vec2::ZERO,
+        preferred: Vec2::ZERO,
+        radius,
+        max_speed: 0.0,
+        responsibility: 0.0,
+    };
+    let a = AgentSnapshot {
+        responsibility: 1.0,
+        ..*a
+    };
+    compute_orca_line(&a, &obstacle, time_horizon)
+}
+
 /// Compute the best collision-free velocity for an agent given ORCA constraints.
 ///
 /// Finds the velocity closest to `preferred` that satisfies all half-plane
@@ -352,6 +379,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn agent_steered_at_obstacle_gets_lateral_velocity() {
+        // Heading straight for a 20px obstacle just off its path.
+        let a = agent(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(50.0, 0.0),
+            Vec2::new(50.0, 0.0),
+        );
+
+        let line = compute_obstacle_line(&a, Vec2::new(60.0, 2.0), 20.0, 3.0)
+            .expect("should produce a constraint");
+        let result = compute_avoiding_velocity(a.preferred, a.max_speed, &[line]);
+
+        assert!(
+            result.y.abs() > 0.1,
+            "Expected lateral avoidance, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn obstacle_line_ignores_agent_responsibility() {
+        // The obstacle cannot dodge, so a 0.5-responsibility agent must still
+        // absorb the whole adjustment: same line as a fully responsible agent.
+        let half = agent(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(50.0, 0.0),
+            Vec2::new(50.0, 0.0),
+        );
+        let full = AgentSnapshot {
+            responsibility: 1.0,
+            ..half
+        };
+
+        let half_line = compute_obstacle_line(&half, Vec2::new(60.0, 2.0), 20.0, 3.0).unwrap();
+        let full_line = compute_obstacle_line(&full, Vec2::new(60.0, 2.0), 20.0, 3.0).unwrap();
+
+        assert_eq!(half_line.point, full_line.point);
+        assert_eq!(half_line.direction, full_line.direction);
+    }
+
+    #[test]
+    fn overlapping_obstacle_returns_none() {
+        let a = agent(Vec2::ZERO, Vec2::new(50.0, 0.0), Vec2::new(50.0, 0.0));
+        assert!(compute_obstacle_line(&a, Vec2::new(10.0, 0.0), 20.0, 3.0).is_none());
+    }
+
     #[test]
     fn perpendicular_crossing_adjusts_velocity() {
         let a = agent(
@@ -576,4 +649,30 @@ mod tests {
             "Full-responsibility agent should deviate more: a={deviation_a}, b={deviation_b}"
         );
     }
+
+    #[test]
+    fn longer_time_horizon_avoids_earlier() {
+        // A fast agent approaching a stationary one 200px away.
+        // With a 3s horizon the collision is out of range; with 10s it is not.
+        let a = agent(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(50.0, 0.0),
+            Vec2::new(50.0, 0.0),
+        );
+        let b = agent(Vec2::new(200.0, 0.0), Vec2::ZERO, Vec2::ZERO);
+
+        let short = compute_orca_line(&a, &b, 3.0).expect("should produce constraint");
+        let long = compute_orca_line(&a, &b, 10.0).expect("should produce constraint");
+
+        // Valid velocities lie on the left of the directed line.
+        let permits = |line: &OrcaLine, v: Vec2| det(line.direction, v - line.point) >= 0.0;
+        assert!(
+            permits(&short, a.preferred),
+            "Default horizon should not constrain a distant collision"
+        );
+        assert!(
+            !permits(&long, a.preferred),
+            "Longer horizon should already constrain the preferred velocity"
+        );
+    }
 }
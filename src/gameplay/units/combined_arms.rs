@@ -0,0 +1,147 @@
+//! Combined arms: a per-team damage bonus for fielding several unit types at once.
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use super::{Unit, UnitType};
+use crate::gameplay::Team;
+use crate::{GameSet, gameplay_running};
+
+/// Damage bonus per distinct living unit type beyond the first (0.05 = +5%).
+pub const COMBINED_ARMS_BONUS_PER_TYPE: f32 = 0.05;
+
+/// Distinct `UnitType`s alive on each team, refreshed every frame.
+/// Read by combat to scale unit damage.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct CombinedArms {
+    pub player_types: u32,
+    pub enemy_types: u32,
+}
+
+impl CombinedArms {
+    /// Distinct unit types `team` currently fields.
+    #[must_use]
+    pub const fn distinct_types(&self, team: Team) -> u32 {
+        match team {
+            Team::Player => self.player_types,
+            Team::Enemy => self.enemy_types,
+        }
+    }
+
+    /// Damage multiplier for `team`'s units: 1.0 for a single-type (or empty)
+    /// army, plus `COMBINED_ARMS_BONUS_PER_TYPE` for each additional type.
+    #[must_use]
+    pub fn damage_multiplier(&self, team: Team) -> f32 {
+        #[allow(clippy::cast_precision_loss)]
+        let extra_types = self.distinct_types(team).saturating_sub(1) as f32;
+        COMBINED_ARMS_BONUS_PER_TYPE.mul_add(extra_types, 1.0)
+    }
+}
+
+/// Recounts the distinct unit types alive on each team.
+/// Runs in `GameSet::Ai`, after production has spawned this frame's units.
+fn count_unit_types(
+    mut combined_arms: ResMut<CombinedArms>,
+    units: Query<(&UnitType, &Team), With<Unit>>,
+) {
+    let mut player = HashSet::new();
+    let mut enemy = HashSet::new();
+    for (unit_type, team) in &units {
+        match team {
+            Team::Player => player.insert(*unit_type),
+            Team::Enemy => enemy.insert(*unit_type),
+        };
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    let counts = (player.len() as u32, enemy.len() as u32);
+    combined_arms.set_if_neq(CombinedArms {
+        player_types: counts.0,
+        enemy_types: counts.1,
+    });
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<CombinedArms>()
+        .init_resource::<CombinedArms>();
+
+    app.add_systems(
+        Update,
+        count_unit_types
+            .in_set(GameSet::Ai)
+            .run_if(gameplay_running),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn create_combined_arms_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<CombinedArms>();
+        app.add_systems(Update, count_unit_types);
+        app
+    }
+
+    fn spawn_typed_unit(app: &mut App, team: Team, unit_type: UnitType) {
+        let unit = crate::testing::spawn_test_unit(app.world_mut(), team, 0.0, 0.0);
+        app.world_mut().entity_mut(unit).insert(unit_type);
+    }
+
+    #[test]
+    fn single_type_army_has_no_bonus() {
+        let arms = CombinedArms {
+            player_types: 1,
+            enemy_types: 0,
+        };
+        assert_eq!(arms.damage_multiplier(Team::Player), 1.0);
+        assert_eq!(arms.damage_multiplier(Team::Enemy), 1.0);
+    }
+
+    #[test]
+    fn each_extra_type_adds_bonus() {
+        let arms = CombinedArms {
+            player_types: 2,
+            enemy_types: 0,
+        };
+        assert_eq!(
+            arms.damage_multiplier(Team::Player),
+            1.0 + COMBINED_ARMS_BONUS_PER_TYPE
+        );
+    }
+
+    #[test]
+    fn mixed_army_gets_larger_buff_than_single_type_army() {
+        let mut app = create_combined_arms_test_app();
+        spawn_typed_unit(&mut app, Team::Player, UnitType::Soldier);
+        spawn_typed_unit(&mut app, Team::Player, UnitType::Archer);
+        spawn_typed_unit(&mut app, Team::Enemy, UnitType::Soldier);
+        spawn_typed_unit(&mut app, Team::Enemy, UnitType::Soldier);
+
+        app.update();
+
+        let arms = app.world().resource::<CombinedArms>();
+        assert_eq!(arms.player_types, 2);
+        assert_eq!(arms.enemy_types, 1);
+        assert!(arms.damage_multiplier(Team::Player) > arms.damage_multiplier(Team::Enemy));
+    }
+
+    #[test]
+    fn bonus_drops_when_a_type_dies_out() {
+        let mut app = create_combined_arms_test_app();
+        spawn_typed_unit(&mut app, Team::Player, UnitType::Soldier);
+        let archer = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 0.0, 0.0);
+        app.world_mut().entity_mut(archer).insert(UnitType::Archer);
+        app.update();
+        assert_eq!(app.world().resource::<CombinedArms>().player_types, 2);
+
+        app.world_mut().despawn(archer);
+        app.update();
+
+        assert_eq!(app.world().resource::<CombinedArms>().player_types, 1);
+    }
+}
@@ -1,21 +1,28 @@
 //! Unit components, constants, and shared rendering assets.
 
 pub mod avoidance;
+pub mod combined_arms;
 mod movement;
 pub mod pathfinding;
 pub mod spawn;
 
 use avian2d::prelude::*;
 use bevy::prelude::*;
+use rand::Rng;
 use vleue_navigator::prelude::NavMesh;
 
-use self::avoidance::{AvoidanceAgent, AvoidanceConfig, AvoidanceSpatialHash, PreferredVelocity};
+use self::avoidance::{
+    AvoidanceAgent, AvoidanceConfig, AvoidanceSpatialHash, ObstacleSpatialHash, PreferredVelocity,
+};
 use crate::gameplay::combat::{
-    AttackTimer, HealthBarConfig, UNIT_HEALTH_BAR_HEIGHT, UNIT_HEALTH_BAR_WIDTH,
+    AttackTimer, HealthBarConfig, MeleeAttacker, UNIT_HEALTH_BAR_HEIGHT, UNIT_HEALTH_BAR_WIDTH,
     UNIT_HEALTH_BAR_Y_OFFSET,
 };
 use crate::gameplay::spatial_hash::SpatialHash;
-use crate::gameplay::{CombatStats, EntityExtent, Health, Movement, Target, TargetingState, Team};
+use crate::gameplay::{
+    CombatStats, EntityExtent, Health, Incendiary, Movement, SiegeBonus, Target, TargetingState,
+    Team,
+};
 use crate::screens::GameState;
 use crate::third_party::solid_entity_layers;
 use crate::{GameSet, Z_UNIT, gameplay_running};
@@ -25,6 +32,15 @@ use crate::{GameSet, Z_UNIT, gameplay_running};
 /// Visual radius of a unit circle.
 pub const UNIT_RADIUS: f32 = 6.0;
 
+/// Burn damage per second a mage's hit inflicts.
+pub const MAGE_BURN_DPS: f32 = 4.0;
+
+/// How long a mage's burn lasts (seconds).
+pub const MAGE_BURN_SECS: f32 = 3.0;
+
+/// Damage multiplier a ram's blows deal to buildings and fortresses.
+pub const RAM_SIEGE_BONUS: f32 = 3.0;
+
 use crate::theme::palette;
 
 // === Components ===
@@ -34,6 +50,24 @@ use crate::theme::palette;
 #[reflect(Component)]
 pub struct Unit;
 
+/// Marker for player-team units, added by `spawn_unit` alongside `Team::Player`.
+/// Lets queries filter by team (`With<PlayerUnit>`) instead of branching on `Team`.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct PlayerUnit;
+
+/// Marker for enemy-team units, added by `spawn_unit` alongside `Team::Enemy`.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct EnemyUnit;
+
+/// Battlefield row a produced unit was sent down. Until the unit enters the
+/// combat zone, its path to an eastern target runs through that row's lane
+/// entry, so one barracks' output spreads across the field.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct AssignedLane(pub u16);
+
 // === Unit Type System ===
 
 /// Types of units in the game.
@@ -41,22 +75,37 @@ pub struct Unit;
 #[reflect(Component)]
 pub enum UnitType {
     Soldier,
+    /// Fragile ranged unit that fires from well outside melee reach.
+    Archer,
+    /// Slow-firing caster whose shots set targets burning (`Incendiary`).
+    Mage,
+    /// Slow, sturdy enemy siege unit that hits structures hard (`SiegeBonus`).
+    Ram,
 }
 
 impl UnitType {
     /// All unit types, for iteration.
     #[allow(dead_code)] // Used in tests; will be used by future unit type additions
-    pub const ALL: &[Self] = &[Self::Soldier];
+    pub const ALL: &[Self] = &[Self::Soldier, Self::Archer, Self::Mage, Self::Ram];
 
     /// Human-readable display name.
     #[must_use]
     pub const fn display_name(self) -> &'static str {
         match self {
             Self::Soldier => "Soldier",
+            Self::Archer => "Archer",
+            Self::Mage => "Mage",
+            Self::Ram => "Ram",
         }
     }
 }
 
+/// Per-run spawn order of a unit, assigned by `spawn_unit`.
+/// Stable tie-breaker for deterministic ordering, debugging, and replay correlation.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+#[reflect(Component)]
+pub struct SpawnId(pub u64);
+
 /// Stats for a unit type. All values are compile-time constants.
 #[derive(Debug, Clone, Copy)]
 pub struct UnitStats {
@@ -78,6 +127,27 @@ pub const fn unit_stats(unit_type: UnitType) -> UnitStats {
             move_speed: 50.0,
             attack_range: 5.0,
         },
+        UnitType::Archer => UnitStats {
+            hp: 60.0,
+            damage: 10.0,
+            attack_speed: 0.8,
+            move_speed: 45.0,
+            attack_range: 120.0,
+        },
+        UnitType::Mage => UnitStats {
+            hp: 50.0,
+            damage: 6.0,
+            attack_speed: 0.5,
+            move_speed: 40.0,
+            attack_range: 100.0,
+        },
+        UnitType::Ram => UnitStats {
+            hp: 220.0,
+            damage: 12.0,
+            attack_speed: 0.5,
+            move_speed: 30.0,
+            attack_range: 5.0,
+        },
     }
 }
 
@@ -89,18 +159,21 @@ pub fn spawn_unit(
     team: Team,
     position: Vec2,
     assets: &UnitAssets,
+    spawn_ids: &mut SpawnIdCounter,
 ) -> Entity {
     let stats = unit_stats(unit_type);
-    let material = match team {
-        Team::Player => assets.player_material.clone(),
-        Team::Enemy => assets.enemy_material.clone(),
-    };
+    let spawn_id = spawn_ids.next_id();
 
-    commands
+    let mut unit = commands
         .spawn((
-            Name::new(format!("{team:?} {}", unit_type.display_name())),
+            Name::new(format!(
+                "{team:?} {} #{}",
+                unit_type.display_name(),
+                spawn_id.0
+            )),
             Unit,
             unit_type,
+            spawn_id,
             team,
             Target,
             Health::new(stats.hp),
@@ -121,8 +194,6 @@ pub fn spawn_unit(
                 1.0 / stats.attack_speed,
                 TimerMode::Repeating,
             )),
-            Mesh2d(assets.mesh.clone()),
-            MeshMaterial2d(material),
             Transform::from_xyz(position.x, position.y, Z_UNIT),
             DespawnOnExit(GameState::InGame),
         ))
@@ -135,10 +206,53 @@ pub fn spawn_unit(
             solid_entity_layers(),
             LockedAxes::ROTATION_LOCKED,
             LinearVelocity::ZERO,
+            CollidingEntities::default(),
             PreferredVelocity::default(),
             AvoidanceAgent::default(),
-        ))
-        .id()
+        ));
+    match team {
+        Team::Player => unit.insert(PlayerUnit),
+        Team::Enemy => unit.insert(EnemyUnit),
+    };
+    if matches!(unit_type, UnitType::Soldier | UnitType::Ram) {
+        unit.insert(MeleeAttacker);
+    }
+    if unit_type == UnitType::Ram {
+        unit.insert(SiegeBonus(RAM_SIEGE_BONUS));
+    }
+    if unit_type == UnitType::Mage {
+        unit.insert(Incendiary {
+            dps: MAGE_BURN_DPS,
+            duration: MAGE_BURN_SECS,
+        });
+    }
+    match assets.render_mode {
+        UnitRenderMode::Circles => {
+            let material = match (unit_type, team) {
+                (UnitType::Soldier, Team::Player) => assets.player_material.clone(),
+                (UnitType::Archer, Team::Player) => assets.archer_material.clone(),
+                (UnitType::Mage, Team::Player) => assets.mage_material.clone(),
+                (UnitType::Ram, _) | (_, Team::Enemy) => assets.enemy_material.clone(),
+            };
+            unit.insert((Mesh2d(assets.mesh.clone()), MeshMaterial2d(material)))
+        }
+        UnitRenderMode::Sprites => unit.insert(Sprite::from_color(
+            unit_color(unit_type, team),
+            Vec2::splat(UNIT_RADIUS * 2.0),
+        )),
+    };
+    unit.id()
+}
+
+/// Body color for a unit. Enemy archers share the enemy color so they read as enemies,
+/// as do rams, which only the enemy fields.
+const fn unit_color(unit_type: UnitType, team: Team) -> Color {
+    match (unit_type, team) {
+        (UnitType::Soldier, Team::Player) => palette::PLAYER_UNIT,
+        (UnitType::Archer, Team::Player) => palette::ARCHER_UNIT,
+        (UnitType::Mage, Team::Player) => palette::MAGE_UNIT,
+        (UnitType::Ram, _) | (_, Team::Enemy) => palette::ENEMY_UNIT,
+    }
 }
 
 // === Spawn Placement ===
@@ -148,39 +262,81 @@ const SPAWN_PLACEMENT_ATTEMPTS: u32 = 8;
 
 /// Pick a random position at `radius` from `center` that is navigable.
 ///
-/// Tries up to `SPAWN_PLACEMENT_ATTEMPTS` random angles. When `navmesh` is `Some`,
+/// Tries up to `SPAWN_PLACEMENT_ATTEMPTS` angles rolled from `rng`. When `navmesh` is `Some`,
 /// each candidate is validated with `is_in_mesh()`. When `None` (navmesh not built
 /// yet), returns the first random point without validation.
 ///
-/// Falls back to `center` if all attempts land outside the mesh.
-pub fn random_navigable_spawn(center: Vec2, radius: f32, navmesh: Option<&NavMesh>) -> Vec2 {
-    use rand::Rng;
-    let mut rng = rand::rng();
-
-    for _ in 0..SPAWN_PLACEMENT_ATTEMPTS {
+/// Returns `None` if all attempts land outside the mesh.
+#[must_use]
+pub fn try_navigable_spawn(
+    center: Vec2,
+    radius: f32,
+    navmesh: Option<&NavMesh>,
+    rng: &mut impl Rng,
+) -> Option<Vec2> {
+    (0..SPAWN_PLACEMENT_ATTEMPTS).find_map(|_| {
         let angle = rng.random_range(0.0..std::f32::consts::TAU);
         let point = Vec2::new(
             radius.mul_add(angle.cos(), center.x),
             radius.mul_add(angle.sin(), center.y),
         );
+        navmesh
+            .is_none_or(|mesh| mesh.is_in_mesh(point))
+            .then_some(point)
+    })
+}
 
-        if navmesh.is_none_or(|mesh| mesh.is_in_mesh(point)) {
-            return point;
-        }
-    }
-
-    // All attempts failed — spawn at center (pathfinding handles off-mesh start)
-    center
+/// Like [`try_navigable_spawn`], but falls back to `center` if all attempts
+/// land outside the mesh (pathfinding handles off-mesh start).
+#[must_use]
+pub fn random_navigable_spawn(
+    center: Vec2,
+    radius: f32,
+    navmesh: Option<&NavMesh>,
+    rng: &mut impl Rng,
+) -> Vec2 {
+    try_navigable_spawn(center, radius, navmesh, rng).unwrap_or(center)
 }
 
 // === Resources ===
 
-/// Shared mesh and material handles for unit circle rendering.
+/// How units are drawn. Read once by `setup_unit_assets` on entering `InGame`.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Resource)]
+pub enum UnitRenderMode {
+    /// Colored circle meshes (`Mesh2d` + `MeshMaterial2d`).
+    #[default]
+    Circles,
+    /// Colored squares (`Sprite`), no mesh.
+    Sprites,
+}
+
+/// Shared mesh and material handles for unit circle rendering, plus the
+/// render mode `spawn_unit` uses.
 #[derive(Resource, Debug)]
 pub struct UnitAssets {
+    pub render_mode: UnitRenderMode,
     pub mesh: Handle<Mesh>,
     pub player_material: Handle<ColorMaterial>,
     pub enemy_material: Handle<ColorMaterial>,
+    /// Player archers. Enemy archers keep `enemy_material` so they read as enemies.
+    pub archer_material: Handle<ColorMaterial>,
+    /// Player mages. Enemy mages keep `enemy_material`, like archers.
+    pub mage_material: Handle<ColorMaterial>,
+}
+
+/// Hands out monotonically increasing `SpawnId`s. Reset on entering `InGame`.
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct SpawnIdCounter(u64);
+
+impl SpawnIdCounter {
+    /// Returns the next id and advances the counter.
+    pub const fn next_id(&mut self) -> SpawnId {
+        let id = SpawnId(self.0);
+        self.0 += 1;
+        id
+    }
 }
 
 // === Systems ===
@@ -189,15 +345,19 @@ fn setup_unit_assets(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    render_mode: Res<UnitRenderMode>,
     existing: Option<Res<UnitAssets>>,
 ) {
-    if existing.is_some() {
+    if existing.is_some_and(|assets| assets.render_mode == *render_mode) {
         return; // Already created — don't leak handles
     }
     commands.insert_resource(UnitAssets {
+        render_mode: *render_mode,
         mesh: meshes.add(Circle::new(UNIT_RADIUS)),
         player_material: materials.add(palette::PLAYER_UNIT),
         enemy_material: materials.add(palette::ENEMY_UNIT),
+        archer_material: materials.add(palette::ARCHER_UNIT),
+        mage_material: materials.add(palette::MAGE_UNIT),
     });
 }
 
@@ -205,30 +365,46 @@ fn reset_path_refresh_timer(mut commands: Commands) {
     commands.insert_resource(pathfinding::PathRefreshTimer::default());
 }
 
+fn reset_spawn_ids(mut commands: Commands) {
+    commands.insert_resource(SpawnIdCounter::default());
+}
+
 // === Plugin ===
 
 pub(super) fn plugin(app: &mut App) {
     app.register_type::<Unit>()
+        .register_type::<PlayerUnit>()
+        .register_type::<EnemyUnit>()
+        .register_type::<AssignedLane>()
         .register_type::<UnitType>()
+        .register_type::<SpawnId>()
+        .register_type::<SpawnIdCounter>()
         .register_type::<PreferredVelocity>()
         .register_type::<AvoidanceAgent>()
         .register_type::<AvoidanceConfig>()
+        .register_type::<UnitRenderMode>()
         .register_type::<pathfinding::NavPath>()
         .register_type::<pathfinding::PathRefreshTimer>()
+        .register_type::<pathfinding::PathDetourCap>()
         .init_resource::<pathfinding::PathRefreshTimer>()
-        .init_resource::<AvoidanceConfig>();
+        .init_resource::<pathfinding::PathDetourCap>()
+        .init_resource::<AvoidanceConfig>()
+        .init_resource::<UnitRenderMode>()
+        .init_resource::<SpawnIdCounter>();
 
     let config = AvoidanceConfig::default();
     app.insert_resource(AvoidanceSpatialHash(SpatialHash::new(
-        config.neighbor_distance,
+        config.hash_cell_size,
     )));
+    app.insert_resource(ObstacleSpatialHash(SpatialHash::new(config.hash_cell_size)));
 
     app.add_systems(
         OnEnter(GameState::InGame),
-        (setup_unit_assets, reset_path_refresh_timer),
+        (setup_unit_assets, reset_path_refresh_timer, reset_spawn_ids),
     );
 
     spawn::plugin(app);
+    combined_arms::plugin(app);
 
     app.add_systems(
         Update,
@@ -238,7 +414,9 @@ pub(super) fn plugin(app: &mut App) {
                 .after(crate::gameplay::ai::find_target),
             (
                 movement::unit_movement,
+                movement::apply_slow_fields,
                 avoidance::rebuild_spatial_hash,
+                avoidance::rebuild_obstacle_hash,
                 avoidance::compute_avoidance,
             )
                 .chain_ignore_deferred()
@@ -283,9 +461,20 @@ mod tests {
         assert!(stats.attack_range > 0.0);
     }
 
+    #[test]
+    fn archer_outranges_soldier_but_is_frailer() {
+        let archer = unit_stats(UnitType::Archer);
+        let soldier = unit_stats(UnitType::Soldier);
+        assert!(archer.attack_range > soldier.attack_range);
+        assert!(archer.hp < soldier.hp);
+        assert!(archer.attack_speed < soldier.attack_speed);
+        assert_eq!(archer.damage, soldier.damage);
+    }
+
     #[test]
     fn unit_type_display_name() {
         assert_eq!(UnitType::Soldier.display_name(), "Soldier");
+        assert_eq!(UnitType::Archer.display_name(), "Archer");
     }
 
     #[test]
@@ -293,23 +482,78 @@ mod tests {
         assert!(UnitType::ALL.contains(&UnitType::Soldier));
     }
 
+    #[test]
+    fn unit_type_all_contains_archer() {
+        assert!(UnitType::ALL.contains(&UnitType::Archer));
+    }
+
+    #[test]
+    fn unit_type_all_contains_mage() {
+        assert!(UnitType::ALL.contains(&UnitType::Mage));
+        assert_eq!(UnitType::Mage.display_name(), "Mage");
+    }
+
+    #[test]
+    fn unit_type_all_contains_ram() {
+        assert!(UnitType::ALL.contains(&UnitType::Ram));
+        assert_eq!(UnitType::Ram.display_name(), "Ram");
+    }
+
+    #[test]
+    fn spawn_id_counter_is_monotonic() {
+        let mut counter = SpawnIdCounter::default();
+        assert_eq!(counter.next_id(), SpawnId(0));
+        assert_eq!(counter.next_id(), SpawnId(1));
+        assert_eq!(counter.next_id(), SpawnId(2));
+    }
+
     #[test]
     fn random_navigable_spawn_correct_distance_without_navmesh() {
         let center = Vec2::new(100.0, 200.0);
         let radius = 40.0;
-        let result = random_navigable_spawn(center, radius, None);
+        let mut rng = crate::gameplay::GameRng::seeded(0);
+        let result = random_navigable_spawn(center, radius, None, &mut rng.0);
         let dist = center.distance(result);
         assert!(
             (dist - radius).abs() < 0.01,
             "Expected distance {radius}, got {dist}"
         );
     }
+
+    #[test]
+    fn try_navigable_spawn_none_when_ring_off_mesh() {
+        let mesh: polyanya::Mesh = polyanya::Trimesh {
+            vertices: vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(10.0, 0.0),
+                Vec2::new(10.0, 10.0),
+                Vec2::new(0.0, 10.0),
+            ],
+            triangles: vec![[0, 1, 2], [0, 2, 3]],
+        }
+        .try_into()
+        .expect("valid trimesh");
+        let navmesh = NavMesh::from_polyanya_mesh(mesh);
+
+        // Every point 40px from (5, 5) lies outside the 10x10 mesh.
+        let center = Vec2::new(5.0, 5.0);
+        let mut rng = crate::gameplay::GameRng::seeded(0);
+        assert_eq!(
+            try_navigable_spawn(center, 40.0, Some(&navmesh), &mut rng.0),
+            None
+        );
+        assert_eq!(
+            random_navigable_spawn(center, 40.0, Some(&navmesh), &mut rng.0),
+            center
+        );
+    }
 }
 
 #[cfg(test)]
 mod integration_tests {
     use super::*;
     use crate::testing::transition_to_ingame;
+    use pretty_assertions::assert_eq;
 
     #[test]
     fn unit_assets_created_on_enter_ingame() {
@@ -320,4 +564,292 @@ mod integration_tests {
 
         assert!(app.world().get_resource::<UnitAssets>().is_some());
     }
+
+    fn spawn_soldier(
+        mut commands: Commands,
+        assets: Res<UnitAssets>,
+        mut ids: ResMut<SpawnIdCounter>,
+    ) {
+        spawn_unit(
+            &mut commands,
+            UnitType::Soldier,
+            Team::Player,
+            Vec2::ZERO,
+            &assets,
+            &mut ids,
+        );
+    }
+
+    fn create_spawn_id_test_app() -> App {
+        let mut app = crate::testing::create_base_test_app();
+        crate::testing::init_asset_resources(&mut app);
+        app.add_plugins(plugin);
+        transition_to_ingame(&mut app);
+        app
+    }
+
+    fn sorted_spawn_ids(app: &mut App) -> Vec<SpawnId> {
+        let mut ids: Vec<SpawnId> = app
+            .world_mut()
+            .query::<&SpawnId>()
+            .iter(app.world())
+            .copied()
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Spawn one soldier in an app whose `UnitRenderMode` is `mode`.
+    fn spawn_soldier_with_render_mode(mode: UnitRenderMode) -> (App, Entity) {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = crate::testing::create_base_test_app();
+        crate::testing::init_asset_resources(&mut app);
+        app.add_plugins(plugin);
+        app.insert_resource(mode);
+        transition_to_ingame(&mut app);
+
+        app.world_mut().run_system_once(spawn_soldier).unwrap();
+        let unit = app
+            .world_mut()
+            .query_filtered::<Entity, With<Unit>>()
+            .single(app.world())
+            .unwrap();
+        (app, unit)
+    }
+
+    #[test]
+    fn sprite_mode_units_carry_sprite_and_no_mesh() {
+        let (app, unit) = spawn_soldier_with_render_mode(UnitRenderMode::Sprites);
+
+        let world = app.world();
+        assert!(world.get::<Sprite>(unit).is_some());
+        assert!(world.get::<Mesh2d>(unit).is_none());
+        assert!(world.get::<MeshMaterial2d<ColorMaterial>>(unit).is_none());
+    }
+
+    #[test]
+    fn circle_mode_units_carry_mesh_and_no_sprite() {
+        let (app, unit) = spawn_soldier_with_render_mode(UnitRenderMode::Circles);
+
+        let world = app.world();
+        assert!(world.get::<Mesh2d>(unit).is_some());
+        assert!(world.get::<MeshMaterial2d<ColorMaterial>>(unit).is_some());
+        assert!(world.get::<Sprite>(unit).is_none());
+    }
+
+    #[test]
+    fn render_mode_change_applies_on_next_enter_ingame() {
+        let mut app = create_spawn_id_test_app();
+        assert_eq!(
+            app.world().resource::<UnitAssets>().render_mode,
+            UnitRenderMode::Circles
+        );
+
+        app.insert_resource(UnitRenderMode::Sprites);
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::MainMenu);
+        app.update();
+        transition_to_ingame(&mut app);
+
+        assert_eq!(
+            app.world().resource::<UnitAssets>().render_mode,
+            UnitRenderMode::Sprites
+        );
+    }
+
+    #[test]
+    fn units_spawned_in_sequence_get_consecutive_ids() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = create_spawn_id_test_app();
+        app.world_mut().run_system_once(spawn_soldier).unwrap();
+        app.world_mut().run_system_once(spawn_soldier).unwrap();
+
+        assert_eq!(sorted_spawn_ids(&mut app), vec![SpawnId(0), SpawnId(1)]);
+    }
+
+    #[test]
+    fn spawn_id_counter_resets_on_enter_ingame() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = create_spawn_id_test_app();
+        app.world_mut().run_system_once(spawn_soldier).unwrap();
+        app.world_mut().run_system_once(spawn_soldier).unwrap();
+
+        // Leave and re-enter InGame
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::MainMenu);
+        app.update();
+        transition_to_ingame(&mut app);
+
+        let mut counter = app.world_mut().resource_mut::<SpawnIdCounter>();
+        assert_eq!(counter.next_id(), SpawnId(0));
+    }
+
+    #[test]
+    fn spawned_units_carry_their_team_marker() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = create_spawn_id_test_app();
+        let (player, enemy) = app
+            .world_mut()
+            .run_system_once(
+                |mut commands: Commands,
+                 assets: Res<UnitAssets>,
+                 mut ids: ResMut<SpawnIdCounter>| {
+                    let mut spawn = |team| {
+                        spawn_unit(
+                            &mut commands,
+                            UnitType::Soldier,
+                            team,
+                            Vec2::ZERO,
+                            &assets,
+                            &mut ids,
+                        )
+                    };
+                    (spawn(Team::Player), spawn(Team::Enemy))
+                },
+            )
+            .unwrap();
+
+        let world = app.world();
+        assert!(world.get::<PlayerUnit>(player).is_some());
+        assert!(world.get::<EnemyUnit>(player).is_none());
+        assert!(world.get::<EnemyUnit>(enemy).is_some());
+        assert!(world.get::<PlayerUnit>(enemy).is_none());
+    }
+
+    #[test]
+    fn mage_spawns_incendiary() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = create_spawn_id_test_app();
+        let mage = app
+            .world_mut()
+            .run_system_once(
+                |mut commands: Commands,
+                 assets: Res<UnitAssets>,
+                 mut ids: ResMut<SpawnIdCounter>| {
+                    spawn_unit(
+                        &mut commands,
+                        UnitType::Mage,
+                        Team::Player,
+                        Vec2::ZERO,
+                        &assets,
+                        &mut ids,
+                    )
+                },
+            )
+            .unwrap();
+
+        let world = app.world();
+        let incendiary = world.get::<Incendiary>(mage).unwrap();
+        assert_eq!(incendiary.dps, MAGE_BURN_DPS);
+        assert_eq!(incendiary.duration, MAGE_BURN_SECS);
+        assert_eq!(
+            world.get::<MeshMaterial2d<ColorMaterial>>(mage).unwrap().0,
+            world.resource::<UnitAssets>().mage_material
+        );
+    }
+
+    #[test]
+    fn ram_spawns_as_melee_siege_unit() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = create_spawn_id_test_app();
+        let ram = app
+            .world_mut()
+            .run_system_once(
+                |mut commands: Commands,
+                 assets: Res<UnitAssets>,
+                 mut ids: ResMut<SpawnIdCounter>| {
+                    spawn_unit(
+                        &mut commands,
+                        UnitType::Ram,
+                        Team::Enemy,
+                        Vec2::ZERO,
+                        &assets,
+                        &mut ids,
+                    )
+                },
+            )
+            .unwrap();
+
+        let world = app.world();
+        assert!(world.get::<MeleeAttacker>(ram).is_some());
+        assert_eq!(world.get::<SiegeBonus>(ram).unwrap().0, RAM_SIEGE_BONUS);
+    }
+
+    #[test]
+    fn team_markers_filter_units_by_team() {
+        let mut app = create_spawn_id_test_app();
+        let world = app.world_mut();
+        let players = [
+            crate::testing::spawn_test_unit(world, Team::Player, 0.0, 0.0),
+            crate::testing::spawn_test_unit(world, Team::Player, 10.0, 0.0),
+        ];
+        let enemy = crate::testing::spawn_test_unit(world, Team::Enemy, 20.0, 0.0);
+
+        let mut player_query = world.query_filtered::<Entity, With<PlayerUnit>>();
+        let mut found: Vec<Entity> = player_query.iter(world).collect();
+        found.sort();
+        let mut expected = players.to_vec();
+        expected.sort();
+        assert_eq!(found, expected);
+
+        let mut enemy_query = world.query_filtered::<Entity, With<EnemyUnit>>();
+        assert_eq!(enemy_query.iter(world).collect::<Vec<_>>(), vec![enemy]);
+    }
+
+    #[test]
+    fn player_archer_uses_archer_material() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = create_spawn_id_test_app();
+        let (archer, enemy_archer, soldier) = app
+            .world_mut()
+            .run_system_once(
+                |mut commands: Commands,
+                 assets: Res<UnitAssets>,
+                 mut ids: ResMut<SpawnIdCounter>| {
+                    let mut spawn = |unit_type, team| {
+                        spawn_unit(
+                            &mut commands,
+                            unit_type,
+                            team,
+                            Vec2::ZERO,
+                            &assets,
+                            &mut ids,
+                        )
+                    };
+                    (
+                        spawn(UnitType::Archer, Team::Player),
+                        spawn(UnitType::Archer, Team::Enemy),
+                        spawn(UnitType::Soldier, Team::Player),
+                    )
+                },
+            )
+            .unwrap();
+
+        let world = app.world();
+        let assets = world.resource::<UnitAssets>();
+        let material = |entity: Entity| {
+            world
+                .get::<MeshMaterial2d<ColorMaterial>>(entity)
+                .unwrap()
+                .0
+                .clone()
+        };
+        assert_eq!(material(archer), assets.archer_material);
+        assert_eq!(material(enemy_archer), assets.enemy_material);
+        assert_eq!(material(soldier), assets.player_material);
+        assert!(world.get::<Incendiary>(archer).is_none());
+        assert_eq!(
+            world.get::<CombatStats>(archer).unwrap().range,
+            unit_stats(UnitType::Archer).attack_range
+        );
+    }
 }
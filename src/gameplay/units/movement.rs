@@ -5,12 +5,18 @@ use bevy::prelude::*;
 use super::avoidance::PreferredVelocity;
 use super::pathfinding::NavPath;
 use super::{CombatStats, Movement, TargetingState, Unit};
-use crate::gameplay::{EntityExtent, extent_distance};
+use crate::gameplay::ai::{AttackMove, MovingToRally};
+use crate::gameplay::building::SlowField;
+use crate::gameplay::{EntityExtent, Recall, Team, extent_distance};
 
 /// Distance threshold for reaching a waypoint — when the unit's center
 /// is within this distance of a waypoint, advance to the next one.
 const WAYPOINT_REACHED_DISTANCE: f32 = 4.0;
 
+/// Inward margin (pixels) on attack range when deciding to stop, so float
+/// jitter can't leave a unit halted a hair outside the range `attack` checks.
+pub const ATTACK_RANGE_BUFFER: f32 = 0.5;
+
 /// Sets unit `PreferredVelocity` toward their current navmesh waypoint.
 ///
 /// If the unit has a `NavPath` with remaining waypoints, steers toward
@@ -18,8 +24,12 @@ const WAYPOINT_REACHED_DISTANCE: f32 = 4.0;
 /// When all waypoints are consumed (or no path exists), stops the unit
 /// and waits for path recomputation — never steers directly at the target.
 ///
-/// Always checks attack range against the actual target — if in range,
-/// stops regardless of remaining waypoints.
+/// Always checks attack range against the actual target — if within range
+/// (less `ATTACK_RANGE_BUFFER`), stops regardless of remaining waypoints.
+///
+/// Units without a target stop, unless they are heading to a rally point
+/// (`MovingToRally`), attack-moving (`AttackMove`) or returning home (`Recall`),
+/// in which case they follow their path there.
 ///
 /// The downstream `compute_avoidance` system reads `PreferredVelocity`
 /// and writes the final `LinearVelocity`.
@@ -35,6 +45,9 @@ pub(super) fn unit_movement(
             &EntityExtent,
             &mut PreferredVelocity,
             &mut NavPath,
+            Option<&Recall>,
+            Option<&MovingToRally>,
+            Option<&AttackMove>,
         ),
         With<Unit>,
     >,
@@ -48,23 +61,31 @@ pub(super) fn unit_movement(
         unit_extent,
         mut preferred,
         mut nav_path,
+        recall,
+        rally,
+        attack_move,
     ) in &mut units
     {
-        let Some(target_entity) = targeting_state.target_entity() else {
-            preferred.0 = Vec2::ZERO;
-            continue;
-        };
-        let Ok((target_pos, target_extent)) = targets.get(target_entity) else {
-            preferred.0 = Vec2::ZERO;
-            continue;
-        };
-
         let current_xy = global_transform.translation().xy();
-        let target_xy = target_pos.translation().xy();
-        let distance_to_target = extent_distance(unit_extent, current_xy, target_extent, target_xy);
 
-        // Already within attack range — stop
-        if distance_to_target <= stats.range {
+        if let Some(target_entity) = targeting_state.target_entity() {
+            let Ok((target_pos, target_extent)) = targets.get(target_entity) else {
+                preferred.0 = Vec2::ZERO;
+                continue;
+            };
+            let target_xy = target_pos.translation().xy();
+            let distance_to_target =
+                extent_distance(unit_extent, current_xy, target_extent, target_xy);
+
+            // Already within attack range (with margin) — stop
+            if distance_to_target <= (stats.range - ATTACK_RANGE_BUFFER).max(0.0) {
+                preferred.0 = Vec2::ZERO;
+                continue;
+            }
+        } else if rally.is_none()
+            && attack_move.is_none()
+            && recall.and_then(Recall::returning_home).is_none()
+        {
             preferred.0 = Vec2::ZERO;
             continue;
         }
@@ -95,8 +116,42 @@ pub(super) fn unit_movement(
             continue;
         }
 
+        // A zero or non-finite speed (bad stats, upstream NaN) means stationary.
+        let speed = if movement.speed.is_finite() {
+            movement.speed.max(0.0)
+        } else {
+            0.0
+        };
         let direction = diff / dist;
-        preferred.0 = direction * movement.speed;
+        preferred.0 = direction * speed;
+    }
+}
+
+/// Scales `PreferredVelocity` of units standing inside an opposing `SlowField`.
+///
+/// `unit_movement` rewrites the full-speed velocity every frame, so a unit
+/// regains full speed as soon as it leaves the field. Overlapping fields don't
+/// stack — the strongest one applies.
+///
+/// Runs in `GameSet::Movement`, after `unit_movement` and before avoidance.
+pub(super) fn apply_slow_fields(
+    fields: Query<(&SlowField, &Team, &GlobalTransform)>,
+    mut units: Query<(&Team, &GlobalTransform, &mut PreferredVelocity), With<Unit>>,
+) {
+    if fields.is_empty() {
+        return;
+    }
+    for (unit_team, unit_transform, mut preferred) in &mut units {
+        let unit_xy = unit_transform.translation().xy();
+        let multiplier = fields
+            .iter()
+            .filter(|(field, field_team, field_transform)| {
+                *field_team != unit_team
+                    && field_transform.translation().xy().distance(unit_xy) <= field.radius
+            })
+            .map(|(field, _, _)| field.speed_multiplier)
+            .fold(1.0_f32, f32::min);
+        preferred.0 *= multiplier;
     }
 }
 
@@ -129,6 +184,48 @@ mod tests {
         crate::testing::spawn_test_target(world, Team::Player, x, 100.0)
     }
 
+    fn spawn_slow_field_at(world: &mut World, x: f32) -> SlowField {
+        let field = SlowField {
+            radius: 50.0,
+            speed_multiplier: 0.5,
+        };
+        world.spawn((
+            field,
+            Team::Player,
+            GlobalTransform::from(Transform::from_xyz(x, 100.0, 0.0)),
+        ));
+        field
+    }
+
+    /// Enemy walking east toward a target at x = 900 along a straight path.
+    fn spawn_walking_enemy(world: &mut World, x: f32, speed: f32) -> Entity {
+        let target = spawn_target_at(world, 900.0);
+        let unit = crate::testing::spawn_test_unit(world, Team::Enemy, x, 100.0);
+        world
+            .entity_mut(unit)
+            .insert((Movement { speed }, TargetingState::Engaging(target)));
+        world
+            .get_mut::<NavPath>(unit)
+            .unwrap()
+            .set(vec![Vec2::new(900.0, 100.0)], Some(target));
+        unit
+    }
+
+    fn set_unit_x(world: &mut World, unit: Entity, x: f32) {
+        let transform = Transform::from_xyz(x, 100.0, 0.0);
+        world
+            .entity_mut(unit)
+            .insert((transform, GlobalTransform::from(transform)));
+    }
+
+    fn preferred_speed(app: &App, unit: Entity) -> f32 {
+        app.world()
+            .get::<PreferredVelocity>(unit)
+            .unwrap()
+            .0
+            .length()
+    }
+
     #[test]
     fn unit_sets_velocity_toward_target() {
         let mut app = create_movement_test_app();
@@ -160,6 +257,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn returning_unit_without_target_heads_home() {
+        use crate::gameplay::Recall;
+
+        let mut app = create_movement_test_app();
+        let unit = spawn_unit_at(app.world_mut(), 500.0, 50.0, None);
+        let home = Vec2::new(100.0, 100.0);
+        let mut recall = Recall::new(home, 100.0);
+        recall.returning = true;
+        app.world_mut().entity_mut(unit).insert(recall);
+        app.world_mut()
+            .get_mut::<NavPath>(unit)
+            .unwrap()
+            .set(vec![home], None);
+
+        app.update();
+
+        let velocity = app.world().get::<PreferredVelocity>(unit).unwrap().0;
+        assert!(velocity.x < 0.0, "expected to head home, got {velocity}");
+        assert!((velocity.length() - 50.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn rallying_unit_without_target_heads_to_rally_point() {
+        let mut app = create_movement_test_app();
+        let unit = spawn_unit_at(app.world_mut(), 500.0, 50.0, None);
+        let rally = Vec2::new(900.0, 50.0);
+        app.world_mut()
+            .entity_mut(unit)
+            .insert(MovingToRally(rally));
+        app.world_mut()
+            .get_mut::<NavPath>(unit)
+            .unwrap()
+            .set(vec![rally], None);
+
+        app.update();
+
+        let velocity = app.world().get::<PreferredVelocity>(unit).unwrap().0;
+        assert!(
+            velocity.x > 0.0,
+            "expected to head to rally, got {velocity}"
+        );
+        assert!((velocity.length() - 50.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn attack_moving_unit_without_target_continues_to_destination() {
+        let mut app = create_movement_test_app();
+        let unit = spawn_unit_at(app.world_mut(), 500.0, 50.0, None);
+        let destination = Vec2::new(900.0, 50.0);
+        app.world_mut()
+            .entity_mut(unit)
+            .insert(AttackMove::new(destination));
+        app.world_mut()
+            .get_mut::<NavPath>(unit)
+            .unwrap()
+            .set(vec![destination], None);
+
+        app.update();
+
+        let velocity = app.world().get::<PreferredVelocity>(unit).unwrap().0;
+        assert!(
+            velocity.x > 0.0,
+            "expected to resume the move, got {velocity}"
+        );
+    }
+
+    #[test]
+    fn idle_unit_with_recall_not_returning_stays_put() {
+        use crate::gameplay::Recall;
+
+        let mut app = create_movement_test_app();
+        let unit = spawn_unit_at(app.world_mut(), 500.0, 50.0, None);
+        let home = Vec2::new(100.0, 100.0);
+        app.world_mut()
+            .entity_mut(unit)
+            .insert(Recall::new(home, 100.0));
+        app.world_mut()
+            .get_mut::<NavPath>(unit)
+            .unwrap()
+            .set(vec![home], None);
+
+        app.update();
+
+        assert_eq!(preferred_speed(&app, unit), 0.0);
+    }
+
     #[test]
     fn unit_stops_at_attack_range() {
         let mut app = create_movement_test_app();
@@ -184,6 +368,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn approaching_unit_stops_strictly_inside_attack_range() {
+        let mut app = create_movement_test_app();
+        let stats = unit_stats(UnitType::Soldier);
+        let target = spawn_target_at(app.world_mut(), 500.0);
+        let unit = spawn_unit_at(app.world_mut(), 460.0, stats.move_speed, Some(target));
+        app.world_mut()
+            .get_mut::<NavPath>(unit)
+            .unwrap()
+            .set(vec![Vec2::new(500.0, 100.0)], Some(target));
+
+        // Walk the unit along its preferred velocity at 60 FPS until it halts
+        let mut x = 460.0;
+        for _ in 0..600 {
+            app.update();
+            let velocity = app.world().get::<PreferredVelocity>(unit).unwrap().0;
+            if velocity == Vec2::ZERO {
+                break;
+            }
+            x += velocity.x / 60.0;
+            set_unit_x(app.world_mut(), unit, x);
+        }
+        assert_eq!(preferred_speed(&app, unit), 0.0, "unit never stopped");
+
+        let world = app.world();
+        let distance = extent_distance(
+            world.get::<EntityExtent>(unit).unwrap(),
+            Vec2::new(x, 100.0),
+            world.get::<EntityExtent>(target).unwrap(),
+            Vec2::new(500.0, 100.0),
+        );
+        assert!(
+            distance < stats.attack_range,
+            "stopped at surface distance {distance}, range {}",
+            stats.attack_range
+        );
+    }
+
+    /// Spawns a unit of `unit_type` 100px (center to center) west of a target,
+    /// with a path toward it, and returns its speed after one movement update.
+    fn speed_at_100px_from_target(unit_type: UnitType) -> f32 {
+        let mut app = create_movement_test_app();
+        let stats = unit_stats(unit_type);
+
+        let target = spawn_target_at(app.world_mut(), 500.0);
+        let unit = spawn_unit_at(app.world_mut(), 400.0, stats.move_speed, Some(target));
+        app.world_mut().entity_mut(unit).insert(CombatStats {
+            damage: stats.damage,
+            attack_speed: stats.attack_speed,
+            range: stats.attack_range,
+        });
+        app.world_mut()
+            .get_mut::<NavPath>(unit)
+            .unwrap()
+            .set(vec![Vec2::new(500.0, 100.0)], Some(target));
+
+        app.update();
+        preferred_speed(&app, unit)
+    }
+
+    #[test]
+    fn archer_stops_further_from_target_than_soldier() {
+        // Surface distance 89px: inside archer range, outside soldier range
+        assert_eq!(speed_at_100px_from_target(UnitType::Archer), 0.0);
+        assert!(speed_at_100px_from_target(UnitType::Soldier) > 0.0);
+    }
+
     #[test]
     fn unit_zero_velocity_without_target() {
         let mut app = create_movement_test_app();
@@ -394,4 +645,91 @@ mod tests {
             velocity.0
         );
     }
+
+    #[test]
+    fn nan_speed_unit_stays_stationary() {
+        let mut app = create_movement_test_app();
+        let target = spawn_target_at(app.world_mut(), 500.0);
+        let unit = spawn_unit_at(app.world_mut(), 100.0, f32::NAN, Some(target));
+        app.world_mut()
+            .get_mut::<NavPath>(unit)
+            .unwrap()
+            .set(vec![Vec2::new(500.0, 100.0)], Some(target));
+
+        app.update();
+
+        let velocity = app.world().get::<PreferredVelocity>(unit).unwrap();
+        assert_eq!(velocity.0, Vec2::ZERO);
+    }
+
+    // --- Slow field tests ---
+
+    fn create_slow_field_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, (unit_movement, apply_slow_fields).chain());
+        app
+    }
+
+    #[test]
+    fn enemy_inside_slow_field_is_slowed() {
+        let mut app = create_slow_field_test_app();
+        let speed = unit_stats(UnitType::Soldier).move_speed;
+        let field = spawn_slow_field_at(app.world_mut(), 200.0);
+        let unit = spawn_walking_enemy(app.world_mut(), 200.0, speed);
+
+        app.update();
+
+        let slowed = preferred_speed(&app, unit);
+        assert!(
+            (slowed - speed * field.speed_multiplier).abs() < 0.01,
+            "Expected {} inside the field, got {slowed}",
+            speed * field.speed_multiplier
+        );
+    }
+
+    #[test]
+    fn enemy_regains_full_speed_after_leaving_slow_field() {
+        let mut app = create_slow_field_test_app();
+        let speed = unit_stats(UnitType::Soldier).move_speed;
+        spawn_slow_field_at(app.world_mut(), 200.0);
+        let unit = spawn_walking_enemy(app.world_mut(), 200.0, speed);
+        app.update();
+        assert!(preferred_speed(&app, unit) < speed);
+
+        set_unit_x(app.world_mut(), unit, 400.0);
+        app.update();
+
+        assert!(
+            (preferred_speed(&app, unit) - speed).abs() < 0.01,
+            "Expected full speed {speed} outside the field, got {}",
+            preferred_speed(&app, unit)
+        );
+    }
+
+    #[test]
+    fn slow_field_ignores_own_team() {
+        let mut app = create_slow_field_test_app();
+        let speed = unit_stats(UnitType::Soldier).move_speed;
+        spawn_slow_field_at(app.world_mut(), 200.0);
+        let unit = spawn_walking_enemy(app.world_mut(), 200.0, speed);
+        app.world_mut().entity_mut(unit).insert(Team::Player);
+
+        app.update();
+
+        assert!((preferred_speed(&app, unit) - speed).abs() < 0.01);
+    }
+
+    #[test]
+    fn overlapping_slow_fields_do_not_stack() {
+        let mut app = create_slow_field_test_app();
+        let speed = unit_stats(UnitType::Soldier).move_speed;
+        let field = spawn_slow_field_at(app.world_mut(), 200.0);
+        spawn_slow_field_at(app.world_mut(), 210.0);
+        let unit = spawn_walking_enemy(app.world_mut(), 200.0, speed);
+
+        app.update();
+
+        assert!((preferred_speed(&app, unit) - speed * field.speed_multiplier).abs() < 0.01);
+    }
 }
@@ -3,8 +3,10 @@
 use bevy::prelude::*;
 use vleue_navigator::prelude::*;
 
-use super::Unit;
-use crate::gameplay::TargetingState;
+use super::{AssignedLane, Unit};
+use crate::gameplay::ai::{AttackMove, MovingToRally};
+use crate::gameplay::battlefield::{CELL_SIZE, COMBAT_ZONE_START_COL, row_to_world_y};
+use crate::gameplay::{Recall, TargetingState};
 
 /// Seconds between periodic path recomputations for units that already have a path.
 /// Picks up navmesh changes from building placement/destruction.
@@ -17,6 +19,32 @@ const SNAP_STEP_SIZE: f32 = 8.0;
 /// Covers the largest obstacle (fortress: 64px half-width + 6px `agent_radius` = 70px).
 const SNAP_MAX_STEPS: u32 = 20;
 
+/// Sample spacing in pixels when checking that a straight segment stays on the mesh.
+const LINE_OF_SIGHT_STEP: f32 = 4.0;
+
+/// Default [`PathDetourCap`]: a path may be at most this many times longer
+/// than the straight line to its destination.
+pub const DEFAULT_MAX_DETOUR_RATIO: f32 = 3.0;
+
+/// Straight-line distance below which [`PathDetourCap`] does not apply. Up close,
+/// stepping around a building corner is many times the straight line yet short,
+/// and rejecting it would freeze the unit against the corner.
+pub const DETOUR_CAP_MIN_DISTANCE: f32 = 3.0 * CELL_SIZE;
+
+/// Longest acceptable navmesh route, as a multiple of the straight-line distance.
+/// `compute_paths` rejects longer routes and the unit holds until the next refresh
+/// instead of taking a bizarre detour. Only applies to destinations at least
+/// `DETOUR_CAP_MIN_DISTANCE` away.
+#[derive(Resource, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct PathDetourCap(pub f32);
+
+impl Default for PathDetourCap {
+    fn default() -> Self {
+        Self(DEFAULT_MAX_DETOUR_RATIO)
+    }
+}
+
 /// Timer controlling periodic path refresh for all units.
 /// Exposed as a resource so tests can manipulate it.
 #[derive(Resource, Debug, Reflect)]
@@ -86,6 +114,66 @@ impl NavPath {
     pub fn needs_recompute(&self, target: Option<Entity>) -> bool {
         self.target != target
     }
+
+    /// String-pull `waypoints` (starting from `from`): from each kept point, skip
+    /// ahead to the farthest waypoint with a clear straight line, dropping the
+    /// ones in between. Corners that block line of sight are kept, and the
+    /// final waypoint (the destination) is always kept.
+    #[must_use]
+    pub fn smoothed(
+        from: Vec2,
+        waypoints: &[Vec2],
+        has_line_of_sight: impl Fn(Vec2, Vec2) -> bool,
+    ) -> Vec<Vec2> {
+        let mut smoothed = Vec::with_capacity(waypoints.len());
+        let mut anchor = from;
+        let mut next = 0;
+        while next < waypoints.len() {
+            let farthest = (next + 1..waypoints.len())
+                .rev()
+                .find(|&j| has_line_of_sight(anchor, waypoints[j]))
+                .unwrap_or(next);
+            anchor = waypoints[farthest];
+            smoothed.push(anchor);
+            next = farthest + 1;
+        }
+        smoothed
+    }
+}
+
+/// Whether the polyline from `from` through `waypoints` is more than `max_ratio`
+/// times longer than the straight line from `from` to the last waypoint.
+/// Destinations closer than `DETOUR_CAP_MIN_DISTANCE` never exceed it.
+#[must_use]
+pub fn exceeds_detour_cap(from: Vec2, waypoints: &[Vec2], max_ratio: f32) -> bool {
+    let Some(&destination) = waypoints.last() else {
+        return false;
+    };
+    if from.distance(destination) < DETOUR_CAP_MIN_DISTANCE {
+        return false;
+    }
+    let mut previous = from;
+    let length: f32 = waypoints
+        .iter()
+        .map(|&next| {
+            let leg = previous.distance(next);
+            previous = next;
+            leg
+        })
+        .sum();
+    length > from.distance(destination) * max_ratio
+}
+
+/// Whether the straight segment `a`→`b` stays inside the navmesh,
+/// sampled every `LINE_OF_SIGHT_STEP` pixels.
+fn segment_in_mesh(navmesh: &NavMesh, a: Vec2, b: Vec2) -> bool {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let steps = (a.distance(b) / LINE_OF_SIGHT_STEP).ceil().max(1.0) as u32;
+    (0..=steps).all(|i| {
+        #[allow(clippy::cast_precision_loss)]
+        let t = i as f32 / steps as f32;
+        navmesh.is_in_mesh(a.lerp(b, t))
+    })
 }
 
 /// Find the nearest navigable point to `target` by walking toward `from`.
@@ -117,12 +205,38 @@ fn snap_to_mesh(navmesh: &NavMesh, target: Vec2, from: Vec2) -> Option<Vec2> {
     None
 }
 
+/// Where a unit with `lane` at `from` should head first on its way to `target`:
+/// the entry of its lane at the combat zone's first column, while the unit is
+/// still west of the combat zone and the target lies beyond the entry.
+fn lane_entry(lane: Option<&AssignedLane>, from: Vec2, target: Vec2) -> Option<Vec2> {
+    let combat_zone_x = f32::from(COMBAT_ZONE_START_COL) * CELL_SIZE;
+    let entry = Vec2::new(combat_zone_x + CELL_SIZE / 2.0, row_to_world_y(lane?.0));
+    (from.x < combat_zone_x && target.x > entry.x).then_some(entry)
+}
+
 /// Computes navmesh paths for units whose target changed or whose path needs refreshing.
+/// Produced units heading east go through their lane entry first (`AssignedLane`).
+/// Units without a target get a path to their rally point (`MovingToRally`), their
+/// attack-move destination (`AttackMove`) or, when returning home (`Recall`), their
+/// home point.
+/// Paths longer than the [`PathDetourCap`] allows are rejected like missing paths.
 /// Runs in `GameSet::Ai` after `find_target`.
 pub(super) fn compute_paths(
     time: Res<Time>,
     mut refresh_timer: ResMut<PathRefreshTimer>,
-    mut units: Query<(&TargetingState, &GlobalTransform, &mut NavPath), With<Unit>>,
+    detour_cap: Res<PathDetourCap>,
+    mut units: Query<
+        (
+            &TargetingState,
+            &GlobalTransform,
+            &mut NavPath,
+            Option<&Recall>,
+            Option<&MovingToRally>,
+            Option<&AttackMove>,
+            Option<&AssignedLane>,
+        ),
+        With<Unit>,
+    >,
     targets: Query<&GlobalTransform>,
     navmeshes: Option<Res<Assets<NavMesh>>>,
     navmesh_query: Option<Single<(&ManagedNavMesh, &NavMeshStatus)>>,
@@ -144,7 +258,7 @@ pub(super) fn compute_paths(
     refresh_timer.0.tick(time.delta());
     let refresh_due = refresh_timer.0.just_finished();
 
-    for (targeting_state, transform, mut nav_path) in &mut units {
+    for (targeting_state, transform, mut nav_path, recall, rally, attack_move, lane) in &mut units {
         let target_changed = nav_path.needs_recompute(targeting_state.target_entity());
 
         // Recompute if: target changed, periodic refresh due, or path fully consumed
@@ -153,18 +267,32 @@ pub(super) fn compute_paths(
             continue;
         }
 
-        let Some(target_entity) = targeting_state.target_entity() else {
-            nav_path.clear();
-            continue;
-        };
-
-        let Ok(target_transform) = targets.get(target_entity) else {
-            nav_path.clear();
-            continue;
+        let idle_destination = rally
+            .map(|rally| rally.0)
+            .or_else(|| attack_move.map(|order| order.destination))
+            .or_else(|| recall.and_then(Recall::returning_home));
+        let to = match (targeting_state.target_entity(), idle_destination) {
+            (Some(target_entity), _) => {
+                let Ok(target_transform) = targets.get(target_entity) else {
+                    nav_path.clear();
+                    continue;
+                };
+                let target_xy = target_transform.translation().xy();
+                lane_entry(lane, transform.translation().xy(), target_xy).unwrap_or(target_xy)
+            }
+            // Destination reached — hold there until the next periodic refresh
+            (None, Some(_)) if path_consumed && !target_changed => {
+                nav_path.clear();
+                continue;
+            }
+            (None, Some(destination)) => destination,
+            (None, None) => {
+                nav_path.clear();
+                continue;
+            }
         };
 
         let from = transform.translation().xy();
-        let to = target_transform.translation().xy();
 
         // Snap off-mesh destinations to nearest navigable point. Targets like
         // fortresses and buildings are NavObstacles — their centers are carved
@@ -172,12 +300,16 @@ pub(super) fn compute_paths(
         // nearest mesh edge on the correct approach side.
         let destination = snap_to_mesh(navmesh, to, from).unwrap_or(to);
 
-        if let Some(path) = navmesh.path(from, destination) {
-            nav_path.set(path.path, targeting_state.target_entity());
-        } else {
-            // No valid path — store empty waypoints, unit stops until next refresh
-            nav_path.set(Vec::new(), targeting_state.target_entity());
-        }
+        let waypoints = navmesh
+            .path(from, destination)
+            .map(|path| NavPath::smoothed(from, &path.path, |a, b| segment_in_mesh(navmesh, a, b)))
+            .filter(|waypoints| !exceeds_detour_cap(from, waypoints, detour_cap.0));
+        // No valid (or only a pathological) path — store empty waypoints, unit
+        // stops until next refresh
+        nav_path.set(
+            waypoints.unwrap_or_default(),
+            targeting_state.target_entity(),
+        );
     }
 }
 
@@ -202,6 +334,35 @@ mod tests {
         NavMesh::from_polyanya_mesh(mesh)
     }
 
+    #[test]
+    fn lane_entry_routes_western_units_through_their_row() {
+        let combat_zone_x = f32::from(COMBAT_ZONE_START_COL) * CELL_SIZE;
+        let from = Vec2::new(combat_zone_x - 100.0, 320.0);
+        let target = Vec2::new(5000.0, 320.0);
+
+        assert_eq!(
+            lane_entry(Some(&AssignedLane(7)), from, target),
+            Some(Vec2::new(
+                combat_zone_x + CELL_SIZE / 2.0,
+                row_to_world_y(7)
+            ))
+        );
+        assert_eq!(lane_entry(None, from, target), None);
+    }
+
+    #[test]
+    fn lane_entry_ignored_inside_combat_zone_or_for_western_targets() {
+        let combat_zone_x = f32::from(COMBAT_ZONE_START_COL) * CELL_SIZE;
+        let lane = Some(&AssignedLane(2));
+
+        let past_entry = Vec2::new(combat_zone_x + 10.0, 100.0);
+        assert_eq!(lane_entry(lane, past_entry, Vec2::new(5000.0, 100.0)), None);
+
+        let in_build_zone = Vec2::new(combat_zone_x - 100.0, 100.0);
+        let invader = Vec2::new(combat_zone_x - 200.0, 300.0);
+        assert_eq!(lane_entry(lane, in_build_zone, invader), None);
+    }
+
     #[test]
     fn nav_path_default_is_empty() {
         let path = NavPath::default();
@@ -330,4 +491,298 @@ mod tests {
         let result = snap_to_mesh(&navmesh, point, point);
         assert!(result.is_none());
     }
+
+    // === Path Smoothing Tests ===
+
+    #[test]
+    fn smoothing_removes_collinear_waypoints() {
+        let waypoints = [
+            Vec2::new(50.0, 0.0),
+            Vec2::new(100.0, 0.0),
+            Vec2::new(150.0, 0.0),
+        ];
+
+        let smoothed = NavPath::smoothed(Vec2::ZERO, &waypoints, |_, _| true);
+
+        assert_eq!(smoothed, vec![Vec2::new(150.0, 0.0)]);
+    }
+
+    #[test]
+    fn smoothing_keeps_blocking_corner() {
+        // L-shaped path around an obstacle: only axis-aligned segments are clear.
+        let waypoints = [
+            Vec2::new(50.0, 0.0),
+            Vec2::new(100.0, 0.0),
+            Vec2::new(100.0, 50.0),
+            Vec2::new(100.0, 100.0),
+        ];
+        let axis_aligned =
+            |a: Vec2, b: Vec2| (a.x - b.x).abs() < f32::EPSILON || (a.y - b.y).abs() < f32::EPSILON;
+
+        let smoothed = NavPath::smoothed(Vec2::ZERO, &waypoints, axis_aligned);
+
+        assert_eq!(
+            smoothed,
+            vec![Vec2::new(100.0, 0.0), Vec2::new(100.0, 100.0)]
+        );
+    }
+
+    #[test]
+    fn smoothing_keeps_every_waypoint_without_line_of_sight() {
+        let waypoints = [Vec2::new(10.0, 0.0), Vec2::new(20.0, 5.0)];
+
+        let smoothed = NavPath::smoothed(Vec2::ZERO, &waypoints, |_, _| false);
+
+        assert_eq!(smoothed, waypoints.to_vec());
+    }
+
+    #[test]
+    fn smoothing_empty_path_stays_empty() {
+        assert!(NavPath::smoothed(Vec2::ZERO, &[], |_, _| true).is_empty());
+    }
+
+    #[test]
+    fn segment_in_mesh_detects_leaving_mesh() {
+        let navmesh = build_test_navmesh();
+        assert!(segment_in_mesh(
+            &navmesh,
+            Vec2::new(10.0, 10.0),
+            Vec2::new(190.0, 190.0)
+        ));
+        assert!(!segment_in_mesh(
+            &navmesh,
+            Vec2::new(10.0, 10.0),
+            Vec2::new(300.0, 10.0)
+        ));
+    }
+
+    #[test]
+    fn straight_path_is_within_detour_cap() {
+        let from = Vec2::ZERO;
+        let waypoints = [Vec2::new(150.0, 0.0), Vec2::new(300.0, 0.0)];
+        assert!(!exceeds_detour_cap(from, &waypoints, 1.0));
+    }
+
+    #[test]
+    fn path_longer_than_cap_exceeds_it() {
+        // Out 300px, across 300px, back 300px: 900px to reach a point 300px away.
+        let from = Vec2::ZERO;
+        let waypoints = [
+            Vec2::new(0.0, 300.0),
+            Vec2::new(300.0, 300.0),
+            Vec2::new(300.0, 0.0),
+        ];
+        assert!(exceeds_detour_cap(from, &waypoints, 2.5));
+        assert!(!exceeds_detour_cap(from, &waypoints, 3.0));
+    }
+
+    #[test]
+    fn short_hop_around_a_corner_is_never_capped() {
+        // Around a corner to a point 20px away: 60px of path, three times the
+        // straight line, but well inside the minimum distance.
+        let from = Vec2::ZERO;
+        let waypoints = [
+            Vec2::new(0.0, 20.0),
+            Vec2::new(20.0, 20.0),
+            Vec2::new(20.0, 0.0),
+        ];
+        assert!(exceeds_detour_cap(from, &waypoints.map(|p| p * 10.0), 2.5));
+        assert!(!exceeds_detour_cap(from, &waypoints, 2.5));
+    }
+
+    #[test]
+    fn empty_path_never_exceeds_cap() {
+        assert!(!exceeds_detour_cap(Vec2::ZERO, &[], 1.0));
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::gameplay::Team;
+    use crate::gameplay::battlefield::{BUILD_ZONE_START_COL, col_to_world_x, row_to_world_y};
+    use crate::gameplay::building::{Building, BuildingType, LastHoveredCell};
+    use crate::gameplay::economy::Gold;
+    use crate::gameplay::economy::shop::Shop;
+    use crate::gameplay::economy::shop_ui::QuickBuyRequest;
+
+    /// Build-zone cell the obstacle is placed on.
+    const CELL: (u16, u16) = (2, 4);
+
+    /// Half-size of a building's footprint (40px sprite).
+    const BUILDING_HALF_SIZE: f32 = 20.0;
+
+    /// Frames to wait for a navmesh rebuild before failing.
+    const MAX_REBUILD_FRAMES: usize = 20;
+
+    fn cell_center() -> Vec2 {
+        Vec2::new(
+            col_to_world_x(BUILD_ZONE_START_COL + CELL.0),
+            row_to_world_y(CELL.1),
+        )
+    }
+
+    /// Whether the built navmesh covers `point`, or `None` if no mesh is built yet.
+    fn navmesh_covers(app: &mut App, point: Vec2) -> Option<bool> {
+        let mut query = app.world_mut().query::<(&ManagedNavMesh, &NavMeshStatus)>();
+        let (managed, status) = query.single(app.world()).ok()?;
+        if *status != NavMeshStatus::Built {
+            return None;
+        }
+        let navmeshes = app.world().resource::<Assets<NavMesh>>();
+        navmeshes.get(managed).map(|mesh| mesh.is_in_mesh(point))
+    }
+
+    /// Runs frames until the navmesh at `point` reaches `covered`.
+    fn update_until_navmesh_covers(app: &mut App, point: Vec2, covered: bool) {
+        for _ in 0..MAX_REBUILD_FRAMES {
+            if navmesh_covers(app, point) == Some(covered) {
+                return;
+            }
+            app.update();
+        }
+        panic!(
+            "Navmesh never reached covered={covered} at {point} after {MAX_REBUILD_FRAMES} frames"
+        );
+    }
+
+    /// Places a Farm on `CELL` through the regular quick-buy placement path.
+    fn place_building(app: &mut App) -> Entity {
+        let world = app.world_mut();
+        world.resource_mut::<Shop>().cards[0] = Some(BuildingType::Farm);
+        world.resource_mut::<Gold>().0 = 1000;
+        world.resource_mut::<LastHoveredCell>().0 = Some(CELL);
+        world.resource_mut::<QuickBuyRequest>().0 = Some(0);
+        app.update();
+
+        let (entity, building) = app
+            .world_mut()
+            .query::<(Entity, &Building)>()
+            .single(app.world())
+            .expect("one building placed");
+        assert_eq!((building.grid_col, building.grid_row), CELL);
+        entity
+    }
+
+    /// Spawns a unit `offset` px left of the cell, aimed at a target `offset` px
+    /// right of it, runs `compute_paths` once, and returns the waypoints.
+    fn compute_path_across_cell(app: &mut App, offset: f32) -> Vec<Vec2> {
+        let center = cell_center();
+        compute_path(app, center - Vec2::X * offset, center + Vec2::X * offset)
+    }
+
+    /// Spawns a unit at `from` aimed at a target at `to`, runs `compute_paths`
+    /// once, and returns the waypoints. The unit and target are despawned so
+    /// later frames don't move them.
+    fn compute_path(app: &mut App, from: Vec2, to: Vec2) -> Vec<Vec2> {
+        let target = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(to.extend(0.0)),
+                GlobalTransform::from_translation(to.extend(0.0)),
+            ))
+            .id();
+        let unit = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, from.x, from.y);
+        app.world_mut()
+            .entity_mut(unit)
+            .insert(TargetingState::Engaging(target));
+
+        app.world_mut().run_system_once(compute_paths).unwrap();
+
+        let waypoints = app.world().get::<NavPath>(unit).unwrap().waypoints.clone();
+        app.world_mut().despawn(unit);
+        app.world_mut().despawn(target);
+        waypoints
+    }
+
+    /// Whether the polyline from `from` through `waypoints` enters the building footprint.
+    fn path_crosses_cell(from: Vec2, waypoints: &[Vec2]) -> bool {
+        let center = cell_center();
+        let mut previous = from;
+        waypoints.iter().any(|&next| {
+            let crosses = (0..=100).any(|i| {
+                #[allow(clippy::cast_precision_loss)]
+                let point = previous.lerp(next, i as f32 / 100.0);
+                (point - center).abs().max_element() < BUILDING_HALF_SIZE
+            });
+            previous = next;
+            crosses
+        })
+    }
+
+    #[test]
+    fn path_routes_around_placed_building_and_straight_after_destruction() {
+        const OFFSET: f32 = 100.0;
+        let mut app = crate::testing::create_full_test_app();
+        crate::testing::transition_to_ingame(&mut app);
+        let center = cell_center();
+        let from = center - Vec2::X * OFFSET;
+        update_until_navmesh_covers(&mut app, center, true);
+
+        // Placed: NavObstacle carves the cell and the path detours around it.
+        let building = place_building(&mut app);
+        update_until_navmesh_covers(&mut app, center, false);
+        let detour = compute_path_across_cell(&mut app, OFFSET);
+        assert!(
+            detour.len() > 1,
+            "Expected a detour waypoint around the building, got {detour:?}"
+        );
+        assert!(
+            !path_crosses_cell(from, &detour),
+            "Path must not cross the building, got {detour:?}"
+        );
+
+        // Destroyed: the cell is navigable again and the path goes straight through.
+        app.world_mut().despawn(building);
+        update_until_navmesh_covers(&mut app, center, true);
+        let straight = compute_path_across_cell(&mut app, OFFSET);
+        assert_eq!(straight, vec![center + Vec2::X * OFFSET]);
+        assert!(path_crosses_cell(from, &straight));
+    }
+
+    #[test]
+    fn detour_beyond_cap_is_rejected_and_unit_holds() {
+        const OFFSET: f32 = 100.0;
+        let mut app = crate::testing::create_full_test_app();
+        crate::testing::transition_to_ingame(&mut app);
+        let center = cell_center();
+        update_until_navmesh_covers(&mut app, center, true);
+        place_building(&mut app);
+        update_until_navmesh_covers(&mut app, center, false);
+
+        // Any detour around the building is longer than the straight line.
+        app.insert_resource(PathDetourCap(1.0));
+        let rejected = compute_path_across_cell(&mut app, OFFSET);
+        assert!(
+            rejected.is_empty(),
+            "Expected the detour to be rejected, got {rejected:?}"
+        );
+
+        app.insert_resource(PathDetourCap::default());
+        assert!(!compute_path_across_cell(&mut app, OFFSET).is_empty());
+    }
+
+    #[test]
+    fn unit_hugging_building_corner_keeps_its_path() {
+        let mut app = crate::testing::create_full_test_app();
+        crate::testing::transition_to_ingame(&mut app);
+        let center = cell_center();
+        update_until_navmesh_covers(&mut app, center, true);
+        place_building(&mut app);
+        update_until_navmesh_covers(&mut app, center, false);
+
+        // Just off the bottom-left corner, heading past the bottom-right one: the
+        // straight line clips the building, so any route is a detour.
+        app.insert_resource(PathDetourCap(1.0));
+        let from = center + Vec2::new(-30.0, -30.0);
+        let to = center + Vec2::new(30.0, -10.0);
+        let path = compute_path(&mut app, from, to);
+        assert!(
+            !path.is_empty(),
+            "Expected a short path around the corner, got {path:?}"
+        );
+        assert!(!path_crosses_cell(from, &path));
+    }
 }
@@ -1,15 +1,18 @@
-//! Continuous enemy spawning with ramping difficulty.
+//! Enemy spawning: continuous with ramping difficulty, or in discrete waves.
 
 use bevy::prelude::*;
 use vleue_navigator::prelude::*;
 
-use crate::gameplay::battlefield::EnemyFortress;
+use crate::gameplay::battlefield::{
+    BATTLEFIELD_ROWS, CELL_SIZE, EnemyFortress, fortress_center, row_to_world_y,
+};
+use crate::gameplay::building::Building;
 use crate::screens::GameState;
 use crate::{GameSet, gameplay_running};
 
-use crate::gameplay::Team;
+use crate::gameplay::{GameRng, Team};
 
-use super::UnitAssets;
+use super::{EnemyUnit, PlayerUnit, SpawnIdCounter, UnitAssets, UnitType};
 
 /// Radius from fortress center where spawned enemies appear.
 /// Clears the 2×2 fortress footprint (128×128px, half-diagonal ≈ 90px).
@@ -29,6 +32,21 @@ pub const MIN_INTERVAL: f32 = 0.5;
 /// Duration (seconds) over which the interval ramps from START to MIN.
 pub const RAMP_DURATION: f32 = 600.0; // 10 minutes
 
+/// Enemies in a wave before the per-wave growth (see [`wave_size`]).
+pub const WAVE_BASE_SIZE: u32 = 3;
+
+/// Extra enemies each successive wave brings.
+pub const WAVE_SIZE_STEP: u32 = 2;
+
+/// Countdown (seconds) from one wave's spawn to the next.
+pub const WAVE_COUNTDOWN: f32 = 20.0;
+
+/// Every this many continuous spawns, the enemy sends a ram instead of a soldier.
+pub const RAM_SPAWN_EVERY: u32 = 10;
+
+/// First wave that leads with a ram.
+pub const RAM_FIRST_WAVE: u32 = 3;
+
 // === Resource ===
 
 /// Tracks enemy spawn timing with ramping difficulty.
@@ -42,6 +60,8 @@ pub struct EnemySpawnTimer {
     pub timer: Timer,
     /// Total elapsed time (seconds) since entering `InGame`. Used for ramp calculation.
     pub elapsed_secs: f32,
+    /// Enemies spawned so far. Picks which spawns are rams.
+    pub spawned: u32,
 }
 
 impl Default for EnemySpawnTimer {
@@ -49,10 +69,53 @@ impl Default for EnemySpawnTimer {
         Self {
             timer: Timer::from_seconds(INITIAL_DELAY, TimerMode::Once),
             elapsed_secs: 0.0,
+            spawned: 0,
         }
     }
 }
 
+/// When enabled, enemies spawn in front of their fortress on a row picked by
+/// [`pick_spawn_row`], favouring rows with few player buildings and units.
+/// Off by default: enemies spawn at a random point around the fortress.
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct DefenseWeightedSpawns(pub bool);
+
+/// Discrete wave spawning. When `enabled`, replaces continuous spawning: each
+/// time `countdown` runs out the next wave spawns [`wave_size`] enemies at once.
+/// Off by default. Progress is reset (mode kept) each time `InGame` is entered.
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct WaveSpawner {
+    pub enabled: bool,
+    /// Time until the next wave. Starts at `INITIAL_DELAY`, then `WAVE_COUNTDOWN`.
+    pub countdown: Timer,
+    /// Whether the latest wave still has enemies alive (not yet cleared).
+    pub wave_in_progress: bool,
+}
+
+impl Default for WaveSpawner {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            countdown: Timer::from_seconds(INITIAL_DELAY, TimerMode::Once),
+            wave_in_progress: false,
+        }
+    }
+}
+
+/// Waves spawned so far this run (0 before the first). Shown in the HUD.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct WaveCounter(pub u32);
+
+/// Written when the player clears a wave.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct WaveCleared {
+    /// The cleared wave (1-based).
+    pub wave: u32,
+}
+
 // === Pure Functions ===
 
 /// Compute the current spawn interval based on elapsed time.
@@ -66,6 +129,78 @@ pub fn current_interval(elapsed_secs: f32) -> f32 {
     (MIN_INTERVAL - START_INTERVAL).mul_add(t, START_INTERVAL)
 }
 
+/// Enemies in wave `wave`: `WAVE_BASE_SIZE + wave * WAVE_SIZE_STEP`.
+#[must_use]
+pub const fn wave_size(wave: u32) -> u32 {
+    WAVE_BASE_SIZE + wave * WAVE_SIZE_STEP
+}
+
+/// Unit type of the `nth` (1-based) continuous spawn: every `RAM_SPAWN_EVERY`th
+/// is a ram, the rest soldiers.
+#[must_use]
+pub const fn continuous_enemy_type(nth: u32) -> UnitType {
+    if nth > 0 && nth % RAM_SPAWN_EVERY == 0 {
+        UnitType::Ram
+    } else {
+        UnitType::Soldier
+    }
+}
+
+/// Unit type of the `index`th (0-based) enemy in wave `wave`: from
+/// `RAM_FIRST_WAVE` on, each wave leads with a ram, the rest soldiers.
+#[must_use]
+pub const fn wave_enemy_type(wave: u32, index: u32) -> UnitType {
+    if wave >= RAM_FIRST_WAVE && index == 0 {
+        UnitType::Ram
+    } else {
+        UnitType::Soldier
+    }
+}
+
+/// Player defenses per battlefield row: one per building on the row plus one per
+/// unit whose `y` lies in it. Positions outside the battlefield are ignored.
+#[must_use]
+pub fn row_defense_density(
+    building_rows: impl IntoIterator<Item = u16>,
+    unit_ys: impl IntoIterator<Item = f32>,
+) -> [u32; BATTLEFIELD_ROWS as usize] {
+    let mut density = [0; BATTLEFIELD_ROWS as usize];
+    let unit_rows = unit_ys.into_iter().filter_map(|y| {
+        let row = (y / CELL_SIZE).floor();
+        if !(0.0..f32::from(BATTLEFIELD_ROWS)).contains(&row) {
+            return None;
+        }
+        // Safety: range check above guarantees a non-negative value below BATTLEFIELD_ROWS.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let row = row as u16;
+        Some(row)
+    });
+    for row in building_rows.into_iter().chain(unit_rows) {
+        if let Some(count) = density.get_mut(usize::from(row)) {
+            *count += 1;
+        }
+    }
+    density
+}
+
+/// Picks a spawn row with probability proportional to `1 / (1 + defenses)`, so a
+/// row with no defenses is twice as likely as a row with one. `roll` is uniform
+/// in `[0, 1)`.
+#[must_use]
+pub fn pick_spawn_row(density: &[u32], roll: f32) -> u16 {
+    #[allow(clippy::cast_precision_loss)]
+    let weight = |count: u32| 1.0 / (1.0 + count as f32);
+    let total: f32 = density.iter().copied().map(weight).sum();
+    let mut remaining = roll * total;
+    for (row, &count) in density.iter().enumerate() {
+        remaining -= weight(count);
+        if remaining < 0.0 {
+            return u16::try_from(row).unwrap_or(0);
+        }
+    }
+    u16::try_from(density.len().saturating_sub(1)).unwrap_or(0)
+}
+
 // === Systems ===
 
 /// Reset (or insert) the spawn timer when entering `InGame`.
@@ -73,18 +208,42 @@ fn reset_enemy_spawn_timer(mut commands: Commands) {
     commands.insert_resource(EnemySpawnTimer::default());
 }
 
+/// Restart wave progress when entering `InGame`, keeping the chosen mode.
+fn reset_waves(mut waves: ResMut<WaveSpawner>, mut counter: ResMut<WaveCounter>) {
+    *waves = WaveSpawner {
+        enabled: waves.enabled,
+        ..default()
+    };
+    counter.0 = 0;
+}
+
+/// Run condition: discrete wave spawning is on.
+fn wave_mode(waves: Res<WaveSpawner>) -> bool {
+    waves.enabled
+}
+
 /// Tick the spawn timer and spawn an enemy when it fires.
-/// Uses `Single` to read the enemy fortress position — if the fortress is destroyed
-/// (despawned), this system is silently skipped and no more enemies spawn.
+/// Spawns around the center of the enemy fortress's remaining segments — once the
+/// fortress is destroyed (despawned), this system does nothing and no more enemies spawn.
+/// With [`DefenseWeightedSpawns`] on, the spawn row favours under-defended rows
+/// and is rolled from [`GameRng`], so the row sequence is reproducible per seed.
 fn tick_enemy_spawner(
     time: Res<Time>,
     mut spawn_timer: ResMut<EnemySpawnTimer>,
     unit_assets: Res<UnitAssets>,
-    enemy_fortress: Single<&Transform, With<EnemyFortress>>,
+    mut spawn_ids: ResMut<SpawnIdCounter>,
+    weighted: Res<DefenseWeightedSpawns>,
+    mut rng: ResMut<GameRng>,
+    buildings: Query<(&Building, &Team)>,
+    player_units: Query<&Transform, With<PlayerUnit>>,
+    enemy_fortress: Query<&Transform, With<EnemyFortress>>,
     navmeshes: Option<Res<Assets<NavMesh>>>,
     navmesh_query: Option<Single<(&ManagedNavMesh, &NavMeshStatus)>>,
     mut commands: Commands,
 ) {
+    let Some(fortress_pos) = fortress_center(&enemy_fortress) else {
+        return;
+    };
     spawn_timer.elapsed_secs += time.delta_secs();
     spawn_timer.timer.tick(time.delta());
 
@@ -92,8 +251,6 @@ fn tick_enemy_spawner(
         return;
     }
 
-    let fortress_pos = enemy_fortress.translation;
-
     // Extract navmesh if available and built
     let navmesh = navmesh_query.and_then(|inner| {
         let (managed, status) = *inner;
@@ -101,14 +258,31 @@ fn tick_enemy_spawner(
         (*status == NavMeshStatus::Built).then(|| meshes.get(managed))?
     });
 
-    let spawn_xy = super::random_navigable_spawn(fortress_pos.xy(), FORTRESS_SPAWN_RADIUS, navmesh);
+    let weighted_xy = weighted.0.then(|| {
+        let density = row_defense_density(
+            buildings
+                .iter()
+                .filter(|(_, team)| **team == Team::Player)
+                .map(|(building, _)| building.grid_row),
+            player_units.iter().map(|transform| transform.translation.y),
+        );
+        let row = pick_spawn_row(&density, rng.0.random_range(0.0..1.0));
+        Vec2::new(fortress_pos.x - FORTRESS_SPAWN_RADIUS, row_to_world_y(row))
+    });
+    let spawn_xy = weighted_xy
+        .filter(|point| navmesh.is_none_or(|mesh| mesh.is_in_mesh(*point)))
+        .unwrap_or_else(|| {
+            super::random_navigable_spawn(fortress_pos, FORTRESS_SPAWN_RADIUS, navmesh, &mut rng.0)
+        });
 
+    spawn_timer.spawned += 1;
     super::spawn_unit(
         &mut commands,
-        super::UnitType::Soldier,
+        continuous_enemy_type(spawn_timer.spawned),
         Team::Enemy,
         spawn_xy,
         &unit_assets,
+        &mut spawn_ids,
     );
 
     // Set next spawn interval based on elapsed time
@@ -116,16 +290,82 @@ fn tick_enemy_spawner(
     spawn_timer.timer = Timer::from_seconds(next_interval, TimerMode::Once);
 }
 
+/// Wave mode: report the latest wave cleared once its enemies are all dead, then
+/// tick the countdown and spawn the next wave around the enemy fortress when it
+/// runs out. Does nothing once the enemy fortress is destroyed.
+fn tick_wave_spawner(
+    time: Res<Time>,
+    mut waves: ResMut<WaveSpawner>,
+    mut counter: ResMut<WaveCounter>,
+    mut cleared: MessageWriter<WaveCleared>,
+    unit_assets: Res<UnitAssets>,
+    mut spawn_ids: ResMut<SpawnIdCounter>,
+    mut rng: ResMut<GameRng>,
+    enemies: Query<(), With<EnemyUnit>>,
+    enemy_fortress: Query<&Transform, With<EnemyFortress>>,
+    navmeshes: Option<Res<Assets<NavMesh>>>,
+    navmesh_query: Option<Single<(&ManagedNavMesh, &NavMeshStatus)>>,
+    mut commands: Commands,
+) {
+    let Some(fortress_pos) = fortress_center(&enemy_fortress) else {
+        return;
+    };
+    if waves.wave_in_progress && enemies.is_empty() {
+        waves.wave_in_progress = false;
+        cleared.write(WaveCleared { wave: counter.0 });
+    }
+
+    waves.countdown.tick(time.delta());
+    if !waves.countdown.just_finished() {
+        return;
+    }
+
+    let navmesh = navmesh_query.and_then(|inner| {
+        let (managed, status) = *inner;
+        let meshes = navmeshes.as_ref()?;
+        (*status == NavMeshStatus::Built).then(|| meshes.get(managed))?
+    });
+
+    counter.0 += 1;
+    for index in 0..wave_size(counter.0) {
+        let spawn_xy =
+            super::random_navigable_spawn(fortress_pos, FORTRESS_SPAWN_RADIUS, navmesh, &mut rng.0);
+        super::spawn_unit(
+            &mut commands,
+            wave_enemy_type(counter.0, index),
+            Team::Enemy,
+            spawn_xy,
+            &unit_assets,
+            &mut spawn_ids,
+        );
+    }
+    waves.wave_in_progress = true;
+    waves.countdown = Timer::from_seconds(WAVE_COUNTDOWN, TimerMode::Once);
+}
+
 // === Plugin ===
 
 pub(super) fn plugin(app: &mut App) {
-    app.register_type::<EnemySpawnTimer>();
+    app.register_type::<EnemySpawnTimer>()
+        .register_type::<DefenseWeightedSpawns>()
+        .register_type::<WaveSpawner>()
+        .register_type::<WaveCounter>()
+        .init_resource::<DefenseWeightedSpawns>()
+        .init_resource::<WaveSpawner>()
+        .init_resource::<WaveCounter>();
+    app.add_message::<WaveCleared>();
 
-    app.add_systems(OnEnter(GameState::InGame), reset_enemy_spawn_timer);
+    app.add_systems(
+        OnEnter(GameState::InGame),
+        (reset_enemy_spawn_timer, reset_waves),
+    );
 
     app.add_systems(
         Update,
-        tick_enemy_spawner
+        (
+            tick_enemy_spawner.run_if(not(wave_mode)),
+            tick_wave_spawner.run_if(wave_mode),
+        )
             .in_set(GameSet::Production)
             .run_if(gameplay_running),
     );
@@ -183,11 +423,70 @@ mod tests {
         let interval = current_interval(0.0);
         assert!((interval - START_INTERVAL).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn wave_size_grows_by_step_per_wave() {
+        for wave in 1..=5 {
+            assert_eq!(wave_size(wave), WAVE_BASE_SIZE + wave * WAVE_SIZE_STEP);
+        }
+        assert!(wave_size(2) > wave_size(1));
+    }
+
+    #[test]
+    fn every_tenth_continuous_spawn_is_a_ram() {
+        assert_eq!(continuous_enemy_type(1), UnitType::Soldier);
+        assert_eq!(
+            continuous_enemy_type(RAM_SPAWN_EVERY - 1),
+            UnitType::Soldier
+        );
+        assert_eq!(continuous_enemy_type(RAM_SPAWN_EVERY), UnitType::Ram);
+        assert_eq!(continuous_enemy_type(RAM_SPAWN_EVERY * 2), UnitType::Ram);
+    }
+
+    #[test]
+    fn waves_lead_with_a_ram_from_first_ram_wave() {
+        assert_eq!(wave_enemy_type(RAM_FIRST_WAVE - 1, 0), UnitType::Soldier);
+        assert_eq!(wave_enemy_type(RAM_FIRST_WAVE, 0), UnitType::Ram);
+        assert_eq!(wave_enemy_type(RAM_FIRST_WAVE, 1), UnitType::Soldier);
+    }
+
+    #[test]
+    fn row_defense_density_counts_buildings_and_units_per_row() {
+        let density = row_defense_density([2, 2, 9], [10.0, 64.0, 130.0, -5.0, 700.0]);
+        assert_eq!(density, [1, 1, 3, 0, 0, 0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn pick_spawn_row_is_uniform_without_defenses() {
+        let density = [0; BATTLEFIELD_ROWS as usize];
+        assert_eq!(pick_spawn_row(&density, 0.0), 0);
+        assert_eq!(pick_spawn_row(&density, 0.55), 5);
+        assert_eq!(pick_spawn_row(&density, 0.999), BATTLEFIELD_ROWS - 1);
+    }
+
+    #[test]
+    fn pick_spawn_row_favours_sparse_rows() {
+        // Rows 3–6 heavily defended, the rest empty.
+        let mut density = [0; BATTLEFIELD_ROWS as usize];
+        density[3..=6].fill(20);
+
+        let samples = 1000;
+        #[allow(clippy::cast_precision_loss)]
+        let defended = (0..samples)
+            .map(|i| pick_spawn_row(&density, i as f32 / samples as f32))
+            .filter(|row| (3..=6).contains(row))
+            .count();
+        // Uniform picks would land 40% in the defended band; weighting keeps it under 5%.
+        assert!(
+            defended < samples / 20,
+            "{defended} of {samples} in defended rows"
+        );
+    }
 }
 
 #[cfg(test)]
 mod integration_tests {
-    use super::super::{CombatStats, Movement, TargetingState, Unit, UnitType};
+    use super::super::{CombatStats, EnemyUnit, Movement, TargetingState, Unit, UnitType};
     use super::*;
     use crate::gameplay::{Health, Target, Team};
     use crate::testing::{assert_entity_count, transition_to_ingame};
@@ -198,6 +497,7 @@ mod integration_tests {
         crate::testing::init_asset_resources(&mut app);
 
         // Register unit assets setup + spawn plugin
+        app.init_resource::<super::super::SpawnIdCounter>();
         app.add_systems(OnEnter(GameState::InGame), super::super::setup_unit_assets);
         plugin(&mut app);
         transition_to_ingame(&mut app);
@@ -326,6 +626,175 @@ mod integration_tests {
         );
     }
 
+    #[test]
+    fn weighted_spawns_avoid_defended_rows() {
+        let mut app = create_spawn_test_app();
+        app.insert_resource(DefenseWeightedSpawns(true));
+        for row in 3..=6 {
+            for i in 0..20_u16 {
+                let x = f32::from(i).mul_add(10.0, 400.0);
+                crate::testing::spawn_test_unit(
+                    app.world_mut(),
+                    Team::Player,
+                    x,
+                    row_to_world_y(row),
+                );
+            }
+        }
+
+        let spawns = 40;
+        for _ in 0..spawns {
+            nearly_expire_spawn_timer(&mut app);
+            app.update();
+        }
+
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&Team, &Transform), With<Unit>>();
+        let enemy_ys: Vec<f32> = query
+            .iter(app.world())
+            .filter(|(team, _)| **team == Team::Enemy)
+            .map(|(_, transform)| transform.translation.y)
+            .collect();
+        assert_eq!(enemy_ys.len(), spawns);
+        let defended = enemy_ys
+            .iter()
+            .filter(|&&y| (row_to_world_y(3)..=row_to_world_y(6)).contains(&y))
+            .count();
+        // Uniform rows would put ~16 of 40 here; weighting expects ~1.
+        assert!(
+            defended < 8,
+            "{defended} of {spawns} enemies in defended rows"
+        );
+    }
+
+    /// World `y` of each weighted enemy spawn, in spawn order, for a run seeded with `seed`.
+    fn weighted_spawn_ys(seed: u64, spawns: usize) -> Vec<f32> {
+        let mut app = create_spawn_test_app();
+        app.insert_resource(DefenseWeightedSpawns(true));
+        app.insert_resource(GameRng::seeded(seed));
+        for _ in 0..spawns {
+            nearly_expire_spawn_timer(&mut app);
+            app.update();
+        }
+
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&super::super::SpawnId, &Transform), With<Unit>>();
+        let mut spawned: Vec<_> = query
+            .iter(app.world())
+            .map(|(id, transform)| (*id, transform.translation.y))
+            .collect();
+        spawned.sort_by_key(|(id, _)| *id);
+        spawned.into_iter().map(|(_, y)| y).collect()
+    }
+
+    #[test]
+    fn same_seed_produces_same_spawn_rows() {
+        let first = weighted_spawn_ys(42, 8);
+        let second = weighted_spawn_ys(42, 8);
+
+        assert_eq!(first.len(), 8);
+        assert_eq!(first, second);
+    }
+
+    /// Switch `app` to wave mode and run frames until the next wave spawns.
+    fn spawn_next_wave(app: &mut App) {
+        let mut waves = app.world_mut().resource_mut::<WaveSpawner>();
+        waves.enabled = true;
+        crate::testing::nearly_expire_timer(&mut waves.countdown);
+        app.update();
+    }
+
+    fn enemy_count(app: &mut App) -> usize {
+        app.world_mut()
+            .query_filtered::<(), With<EnemyUnit>>()
+            .iter(app.world())
+            .count()
+    }
+
+    #[test]
+    fn wave_n_spawns_base_plus_n_steps() {
+        let mut app = create_spawn_test_app();
+
+        spawn_next_wave(&mut app);
+        assert_eq!(app.world().resource::<WaveCounter>().0, 1);
+        assert_eq!(enemy_count(&mut app), wave_size(1) as usize);
+
+        spawn_next_wave(&mut app);
+        assert_eq!(app.world().resource::<WaveCounter>().0, 2);
+        assert_eq!(
+            enemy_count(&mut app),
+            (wave_size(1) + wave_size(2)) as usize
+        );
+    }
+
+    #[test]
+    fn wave_counter_holds_during_countdown() {
+        let mut app = create_spawn_test_app();
+
+        spawn_next_wave(&mut app);
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<WaveCounter>().0, 1);
+        let waves = app.world().resource::<WaveSpawner>();
+        assert_eq!(waves.countdown.duration().as_secs_f32(), WAVE_COUNTDOWN);
+    }
+
+    #[test]
+    fn wave_mode_suppresses_continuous_spawns() {
+        let mut app = create_spawn_test_app();
+        app.world_mut().resource_mut::<WaveSpawner>().enabled = true;
+
+        nearly_expire_spawn_timer(&mut app);
+        app.update();
+
+        assert_eq!(enemy_count(&mut app), 0);
+    }
+
+    #[test]
+    fn killing_a_wave_writes_wave_cleared() {
+        let mut app = create_spawn_test_app();
+        spawn_next_wave(&mut app);
+        assert!(app.world().resource::<WaveSpawner>().wave_in_progress);
+
+        let enemies: Vec<Entity> = app
+            .world_mut()
+            .query_filtered::<Entity, With<EnemyUnit>>()
+            .iter(app.world())
+            .collect();
+        for enemy in enemies {
+            app.world_mut().despawn(enemy);
+        }
+        app.update();
+
+        assert!(!app.world().resource::<WaveSpawner>().wave_in_progress);
+        let messages = app.world().resource::<Messages<WaveCleared>>();
+        let waves: Vec<u32> = messages
+            .iter_current_update_messages()
+            .map(|cleared| cleared.wave)
+            .collect();
+        assert_eq!(waves, vec![1]);
+    }
+
+    #[test]
+    fn entering_ingame_resets_wave_progress_but_keeps_mode() {
+        let mut app = create_spawn_test_app();
+        spawn_next_wave(&mut app);
+
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::MainMenu);
+        app.update();
+        transition_to_ingame(&mut app);
+
+        assert_eq!(app.world().resource::<WaveCounter>().0, 0);
+        let waves = app.world().resource::<WaveSpawner>();
+        assert!(waves.enabled);
+        assert!(!waves.wave_in_progress);
+    }
+
     #[test]
     fn no_enemies_spawn_when_fortress_destroyed() {
         let mut app = create_spawn_test_app();
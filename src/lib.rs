@@ -5,6 +5,7 @@ pub(crate) mod dev_tools;
 pub(crate) mod gameplay;
 pub(crate) mod menus;
 pub(crate) mod screens;
+pub(crate) mod settings;
 #[cfg(test)]
 pub(crate) mod testing;
 pub(crate) mod theme;
@@ -35,6 +36,8 @@ pub(crate) const Z_BUILDING: f32 = 3.0;
 pub(crate) const Z_UNIT: f32 = 4.0;
 /// Projectiles (above units).
 pub(crate) const Z_PROJECTILE: f32 = 4.5;
+/// Floating text popups (above everything in the world).
+pub(crate) const Z_POPUP: f32 = 5.0;
 
 // === Global System Ordering ===
 // Domain plugins register their Update systems in the appropriate set.
@@ -90,6 +93,7 @@ pub fn plugin(app: &mut App) {
         third_party::plugin,
         ui_camera::plugin,
         screens::plugin,
+        settings::plugin,
         menus::plugin,
         gameplay::plugin,
         theme::plugin,
@@ -140,5 +144,321 @@ mod tests {
         assert!(Z_GRID_CURSOR < Z_BUILDING);
         assert!(Z_BUILDING < Z_UNIT);
         assert!(Z_UNIT < Z_PROJECTILE);
+        assert!(Z_PROJECTILE < Z_POPUP);
+    }
+}
+
+#[cfg(test)]
+mod frame_order_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    use crate::gameplay::building::{BuildingType, ProductionTimer};
+    use crate::gameplay::units::Unit;
+    use crate::gameplay::{Health, Team};
+    use crate::screens::GameState;
+
+    /// Sets in the order they must run each frame.
+    const EXPECTED_ORDER: [GameSet; 7] = [
+        GameSet::Input,
+        GameSet::Production,
+        GameSet::Ai,
+        GameSet::Movement,
+        GameSet::Combat,
+        GameSet::Death,
+        GameSet::Ui,
+    ];
+
+    /// Sets in the order their probe systems ran this frame.
+    #[derive(Resource, Default)]
+    struct FrameLog(Vec<GameSet>);
+
+    /// Player units visible to systems in `GameSet::Ai` this frame.
+    #[derive(Resource, Default)]
+    struct UnitsSeenByAi(usize);
+
+    /// Marker: `GameSet::Combat` drops this entity's health to 0.
+    #[derive(Component)]
+    struct KilledInCombat;
+
+    fn create_frame_order_test_app() -> App {
+        let mut app = crate::testing::create_full_test_app();
+        app.init_resource::<FrameLog>()
+            .init_resource::<UnitsSeenByAi>();
+        for set in EXPECTED_ORDER {
+            app.add_systems(
+                Update,
+                (move |mut log: ResMut<FrameLog>| log.0.push(set))
+                    .in_set(set)
+                    .run_if(gameplay_running),
+            );
+        }
+        app.add_systems(
+            Update,
+            (
+                (|units: Query<&Team, With<Unit>>, mut seen: ResMut<UnitsSeenByAi>| {
+                    seen.0 = units.iter().filter(|team| **team == Team::Player).count();
+                })
+                .in_set(GameSet::Ai),
+                (|mut doomed: Query<&mut Health, With<KilledInCombat>>| {
+                    for mut health in &mut doomed {
+                        health.current = 0.0;
+                    }
+                })
+                .in_set(GameSet::Combat),
+            )
+                .run_if(gameplay_running),
+        );
+        crate::testing::transition_to_ingame(&mut app);
+        app.world_mut().resource_mut::<FrameLog>().0.clear();
+        app
+    }
+
+    #[test]
+    fn game_sets_run_in_chain_order() {
+        let mut app = create_frame_order_test_app();
+        app.update();
+
+        assert_eq!(app.world().resource::<FrameLog>().0, EXPECTED_ORDER);
+    }
+
+    #[test]
+    fn produced_unit_is_visible_to_ai_in_the_same_frame() {
+        let mut app = create_frame_order_test_app();
+
+        let mut timer = Timer::from_seconds(0.001, TimerMode::Repeating);
+        crate::testing::nearly_expire_timer(&mut timer);
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 2, 3);
+        app.world_mut().entity_mut(building).insert((
+            Team::Player,
+            ProductionTimer(timer),
+            Transform::from_xyz(320.0, 160.0, Z_BUILDING),
+            DespawnOnExit(GameState::InGame),
+        ));
+        app.update();
+
+        assert_eq!(app.world().resource::<UnitsSeenByAi>().0, 1);
+    }
+
+    #[test]
+    fn unit_killed_in_combat_is_despawned_the_same_frame() {
+        let mut app = create_frame_order_test_app();
+
+        let unit = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 1500.0, 160.0);
+        app.world_mut().entity_mut(unit).insert(KilledInCombat);
+        app.update();
+
+        assert!(
+            app.world().get_entity(unit).is_err(),
+            "Death must run after Combat within one frame"
+        );
+    }
+
+    /// Kills a unit of `team` through the real Combat → Death pipeline and
+    /// returns the gold gained that frame.
+    fn gold_for_killing(team: Team) -> u32 {
+        use crate::gameplay::economy::Gold;
+
+        let mut app = create_frame_order_test_app();
+        let unit = crate::testing::spawn_test_unit(app.world_mut(), team, 1500.0, 160.0);
+        app.world_mut().entity_mut(unit).insert(KilledInCombat);
+        let before = app.world().resource::<Gold>().0;
+        app.update();
+
+        assert!(app.world().get_entity(unit).is_err());
+        app.world().resource::<Gold>().0 - before
+    }
+
+    #[test]
+    fn killing_enemy_unit_awards_kill_reward() {
+        assert_eq!(
+            gold_for_killing(Team::Enemy),
+            crate::gameplay::economy::KILL_REWARD
+        );
+    }
+
+    #[test]
+    fn killing_player_unit_awards_nothing() {
+        assert_eq!(gold_for_killing(Team::Player), 0);
+    }
+
+    #[test]
+    fn reinforcement_kill_updates_trade_ratio() {
+        use crate::gameplay::combat::LastHitBy;
+        use crate::gameplay::economy::reinforcement::{REINFORCEMENT_COST, ReinforcementRequest};
+        use crate::gameplay::economy::{Gold, KILL_REWARD, RunStats};
+        use crate::gameplay::units::PlayerUnit;
+
+        let mut app = create_frame_order_test_app();
+        app.world_mut().resource_mut::<Gold>().0 = REINFORCEMENT_COST;
+        app.world_mut().resource_mut::<ReinforcementRequest>().0 = true;
+        app.update();
+
+        let soldier = app
+            .world_mut()
+            .query_filtered::<Entity, With<PlayerUnit>>()
+            .iter(app.world())
+            .next()
+            .expect("reinforcements should spawn player units");
+        let enemy = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 1500.0, 160.0);
+        app.world_mut()
+            .entity_mut(enemy)
+            .insert((KilledInCombat, LastHitBy(soldier)));
+        app.update();
+
+        let stats = app.world().resource::<RunStats>();
+        assert_eq!(stats.production_spent, REINFORCEMENT_COST);
+        assert_eq!(stats.unit_kills, 1);
+        #[allow(clippy::cast_precision_loss)]
+        let expected = KILL_REWARD as f32 / REINFORCEMENT_COST as f32;
+        assert_eq!(stats.trade_ratio(), Some(expected));
+    }
+}
+
+#[cfg(test)]
+mod pause_contract_tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+    use crate::gameplay::Team;
+    use crate::gameplay::building::{BuildingType, ProductionTimer};
+    use crate::gameplay::combat::{AttackTimer, Projectile};
+    use crate::menus::Menu;
+    use crate::screens::GameState;
+    use crate::testing::assert_entity_count;
+
+    /// Wall-clock time advanced per `app.update()`.
+    const FRAME: Duration = Duration::from_millis(50);
+
+    /// Full game in `InGame` with a fixed wall-clock step, one player unit facing a
+    /// nearby enemy, and a Barracks. Returns `(app, unit, barracks)`.
+    fn create_pause_contract_test_app() -> (App, Entity, Entity) {
+        let mut app = crate::testing::create_full_test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(FRAME));
+        crate::testing::transition_to_ingame(&mut app);
+
+        let unit = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 1500.0, 160.0);
+        crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 1520.0, 160.0);
+        let barracks =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 2, 3);
+        app.world_mut().entity_mut(barracks).insert((
+            Team::Player,
+            ProductionTimer(Timer::from_seconds(3.0, TimerMode::Repeating)),
+            Transform::from_xyz(320.0, 160.0, Z_BUILDING),
+            DespawnOnExit(GameState::InGame),
+        ));
+        (app, unit, barracks)
+    }
+
+    fn set_menu(app: &mut App, menu: Menu) {
+        app.world_mut().resource_mut::<NextState<Menu>>().set(menu);
+        app.update();
+    }
+
+    fn attack_elapsed(app: &App, unit: Entity) -> Duration {
+        app.world().get::<AttackTimer>(unit).unwrap().0.elapsed()
+    }
+
+    fn production_elapsed(app: &App, building: Entity) -> Duration {
+        app.world()
+            .get::<ProductionTimer>(building)
+            .unwrap()
+            .0
+            .elapsed()
+    }
+
+    #[test]
+    fn open_menu_halts_combat_and_production_timers() {
+        let (mut app, unit, barracks) = create_pause_contract_test_app();
+        set_menu(&mut app, Menu::Pause);
+        assert!(app.world().resource::<Time<Virtual>>().is_paused());
+
+        // Ready to fire on the very next tick, if time were running
+        let mut entity = app.world_mut().entity_mut(unit);
+        crate::testing::nearly_expire_timer(&mut entity.get_mut::<AttackTimer>().unwrap().0);
+        let attack_before = attack_elapsed(&app, unit);
+        let production_before = production_elapsed(&app, barracks);
+
+        for _ in 0..10 {
+            app.update();
+        }
+
+        assert_eq!(attack_elapsed(&app, unit), attack_before);
+        assert_eq!(production_elapsed(&app, barracks), production_before);
+        assert_entity_count::<With<Projectile>>(&mut app, 0);
+    }
+
+    #[test]
+    fn closing_menu_resumes_timers() {
+        let (mut app, unit, barracks) = create_pause_contract_test_app();
+        set_menu(&mut app, Menu::Pause);
+        let production_paused = production_elapsed(&app, barracks);
+        let attack_paused = attack_elapsed(&app, unit);
+
+        set_menu(&mut app, Menu::None);
+        assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+        // Virtual time was still paused during this frame's time update
+        app.update();
+
+        assert!(production_elapsed(&app, barracks) > production_paused);
+        assert_ne!(attack_elapsed(&app, unit), attack_paused);
+    }
+}
+
+#[cfg(test)]
+mod determinism_tests {
+    use super::*;
+    use crate::gameplay::RunSeed;
+    use crate::gameplay::building::BuildingType;
+    use crate::gameplay::economy::shop::{HAND_SIZE, Shop};
+    use crate::gameplay::units::EnemyUnit;
+    use crate::gameplay::units::spawn::{DefenseWeightedSpawns, EnemySpawnTimer};
+    use pretty_assertions::assert_eq;
+
+    /// World `y` of each enemy at the moment it spawned, in spawn order.
+    #[derive(Resource, Default)]
+    struct EnemySpawnYs(Vec<f32>);
+
+    fn record_enemy_spawn(
+        add: On<Add, EnemyUnit>,
+        transforms: Query<&Transform>,
+        mut ys: ResMut<EnemySpawnYs>,
+    ) {
+        if let Ok(transform) = transforms.get(add.entity) {
+            ys.0.push(transform.translation.y);
+        }
+    }
+
+    /// Opening shop hand and the first `spawns` enemy spawn rows of a full run
+    /// started with `seed`.
+    fn seeded_run(seed: u64, spawns: usize) -> ([Option<BuildingType>; HAND_SIZE], Vec<f32>) {
+        let mut app = crate::testing::create_full_test_app();
+        app.insert_resource(RunSeed(seed));
+        app.insert_resource(DefenseWeightedSpawns(true));
+        app.init_resource::<EnemySpawnYs>();
+        app.add_observer(record_enemy_spawn);
+        crate::testing::transition_to_ingame(&mut app);
+
+        let cards = app.world().resource::<Shop>().cards;
+        for _ in 0..spawns {
+            let mut spawner = app.world_mut().resource_mut::<EnemySpawnTimer>();
+            crate::testing::nearly_expire_timer(&mut spawner.timer);
+            app.update();
+        }
+        let ys = std::mem::take(&mut app.world_mut().resource_mut::<EnemySpawnYs>().0);
+        (cards, ys)
+    }
+
+    #[test]
+    fn same_run_seed_replays_shop_cards_and_spawn_rows() {
+        let (first_cards, first_ys) = seeded_run(1234, 6);
+        let (second_cards, second_ys) = seeded_run(1234, 6);
+
+        assert_eq!(first_ys.len(), 6);
+        assert_eq!(first_cards, second_cards);
+        assert_eq!(first_ys, second_ys);
     }
 }
@@ -1,8 +1,14 @@
 //! Victory/Defeat overlay UI with bordered panel and clickable buttons.
+//! Enter/Space on the overlay quick-restarts a fresh run: the auto-focused
+//! Play Again button takes them, and so does the overlay while no button has focus.
 
 use bevy::prelude::*;
 
+use bevy::input_focus::InputFocus;
+
 use super::Menu;
+use crate::GameSet;
+use crate::gameplay::economy::RunStats;
 use crate::screens::GameState;
 use crate::theme::palette;
 use crate::theme::widget::{self, Activate};
@@ -10,28 +16,101 @@ use crate::theme::widget::{self, Activate};
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Menu::Victory), spawn_victory_screen);
     app.add_systems(OnEnter(Menu::Defeat), spawn_defeat_screen);
+    app.add_systems(
+        Update,
+        quick_restart.in_set(GameSet::Input).run_if(
+            in_state(GameState::InGame).and(in_state(Menu::Victory).or(in_state(Menu::Defeat))),
+        ),
+    );
+}
+
+/// Enter/Space on the victory or defeat overlay starts a fresh run, unless a
+/// button has keyboard focus (Enter/Space then activates that button instead).
+/// Covers the overlay once focus has left its buttons, e.g. after a click on the panel.
+fn quick_restart(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    focus: Option<Res<InputFocus>>,
+    buttons: Query<(), With<Button>>,
+    mut next_game: ResMut<NextState<GameState>>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    if !keyboard.any_just_pressed([KeyCode::Enter, KeyCode::Space]) {
+        return;
+    }
+    let button_focused = focus
+        .and_then(|focus| focus.0)
+        .is_some_and(|entity| buttons.contains(entity));
+    if !button_focused {
+        super::request_restart(&mut next_game, &mut next_menu);
+    }
+}
+
+/// Restarts the run (see [`super::request_restart`]).
+fn play_again(
+    _: On<Activate>,
+    mut next_game: ResMut<NextState<GameState>>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    super::request_restart(&mut next_game, &mut next_menu);
 }
 
-fn spawn_victory_screen(mut commands: Commands) {
+fn spawn_victory_screen(mut commands: Commands, stats: Res<RunStats>) {
     spawn_endgame_overlay(
         &mut commands,
         "VICTORY!",
         palette::HEALTH_BAR_FILL,
         Menu::Victory,
+        &stats,
     );
 }
 
-fn spawn_defeat_screen(mut commands: Commands) {
+fn spawn_defeat_screen(mut commands: Commands, stats: Res<RunStats>) {
     spawn_endgame_overlay(
         &mut commands,
         "DEFEAT",
         palette::ENEMY_FORTRESS,
         Menu::Defeat,
+        &stats,
     );
 }
 
+/// One-line run summary: gold spent, kills, and gold-per-kill efficiency.
+fn run_summary(stats: &RunStats) -> String {
+    let efficiency = stats
+        .gold_per_kill()
+        .map_or_else(|| "-".to_string(), |ratio| format!("{ratio:.1}"));
+    format!(
+        "Gold spent: {}  Kills: {}  Gold/kill: {efficiency}",
+        stats.gold_spent, stats.kills
+    )
+}
+
+/// Second summary line: units produced, gold earned, run length (mm:ss), the
+/// trade ratio of unit kills to production spend, and peak simultaneous units.
+fn run_details(stats: &RunStats) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let total_secs = stats.elapsed_secs.max(0.0) as u32;
+    let trade = stats
+        .trade_ratio()
+        .map_or_else(|| "-".to_string(), |ratio| format!("{ratio:.2}"));
+    format!(
+        "Units produced: {}  Gold earned: {}  Time: {:02}:{:02}  Trade ratio: {trade}  Peak units: {}",
+        stats.units_produced,
+        stats.gold_earned,
+        total_secs / 60,
+        total_secs % 60,
+        stats.peak_units
+    )
+}
+
 /// Shared overlay spawning for both victory and defeat screens.
-fn spawn_endgame_overlay(commands: &mut Commands, title: &str, title_color: Color, menu: Menu) {
+fn spawn_endgame_overlay(
+    commands: &mut Commands,
+    title: &str,
+    title_color: Color,
+    menu: Menu,
+    stats: &RunStats,
+) {
     commands.spawn((
         widget::ui_root("Endgame Screen"),
         BackgroundColor(palette::OVERLAY_BACKGROUND),
@@ -61,11 +140,24 @@ fn spawn_endgame_overlay(commands: &mut Commands, title: &str, title_color: Colo
                         TextFont::from_font_size(palette::FONT_SIZE_HEADER),
                         TextColor(title_color),
                     ),
+                    // Run statistics
+                    (
+                        Text::new(run_summary(stats)),
+                        TextFont::from_font_size(palette::FONT_SIZE_BODY),
+                        TextColor(palette::BODY_TEXT),
+                    ),
+                    (
+                        Text::new(run_details(stats)),
+                        TextFont::from_font_size(palette::FONT_SIZE_BODY),
+                        TextColor(palette::BODY_TEXT),
+                    ),
+                    // Play Again button (default focus, so Enter/Space restarts)
+                    widget::button("Play Again", 0, true, play_again),
                     // Exit to Menu button
                     widget::button(
                         "Exit to Menu",
-                        0,
-                        true,
+                        1,
+                        false,
                         |_: On<Activate>, mut next_game: ResMut<NextState<GameState>>| {
                             next_game.set(GameState::MainMenu);
                         },
@@ -89,6 +181,8 @@ mod tests {
         app.add_plugins(StatesPlugin);
         app.init_state::<GameState>();
         app.init_state::<Menu>();
+        app.init_resource::<RunStats>();
+        app.init_resource::<ButtonInput<KeyCode>>();
         app.add_plugins(plugin);
         // Transition to InGame first
         app.world_mut()
@@ -103,20 +197,170 @@ mod tests {
     }
 
     #[test]
-    fn victory_screen_spawns_panel_and_button() {
+    fn victory_screen_spawns_panel_and_buttons() {
         let mut app = create_overlay_test_app(Menu::Victory);
 
-        // Title + 1 button label
-        assert_entity_count::<With<Text>>(&mut app, 2);
-        // Exit to Menu
-        assert_entity_count::<With<Button>>(&mut app, 1);
+        // Title + 2 run summary lines + 2 button labels
+        assert_entity_count::<With<Text>>(&mut app, 5);
+        // Play Again, Exit to Menu
+        assert_entity_count::<With<Button>>(&mut app, 2);
+    }
+
+    #[test]
+    fn defeat_screen_spawns_panel_and_buttons() {
+        let mut app = create_overlay_test_app(Menu::Defeat);
+
+        assert_entity_count::<With<Text>>(&mut app, 5);
+        assert_entity_count::<With<Button>>(&mut app, 2);
     }
 
     #[test]
-    fn defeat_screen_spawns_panel_and_button() {
+    fn play_again_button_has_default_focus() {
         let mut app = create_overlay_test_app(Menu::Defeat);
 
-        assert_entity_count::<With<Text>>(&mut app, 2);
-        assert_entity_count::<With<Button>>(&mut app, 1);
+        let mut focused = app
+            .world_mut()
+            .query_filtered::<&Children, (With<Button>, With<bevy::input_focus::AutoFocus>)>();
+        let children = focused.single(app.world()).unwrap();
+        let label = app.world().get::<Text>(children[0]).unwrap();
+        assert_eq!(label.0, "Play Again");
+    }
+
+    #[test]
+    fn run_summary_shows_gold_per_kill() {
+        let stats = RunStats {
+            gold_spent: 150,
+            kills: 4,
+            ..default()
+        };
+        assert_eq!(
+            run_summary(&stats),
+            "Gold spent: 150  Kills: 4  Gold/kill: 37.5"
+        );
+    }
+
+    #[test]
+    fn run_details_shows_production_earnings_and_time() {
+        let stats = RunStats {
+            units_produced: 12,
+            gold_earned: 340,
+            elapsed_secs: 245.7,
+            production_spent: 200,
+            unit_kill_value: 90,
+            peak_units: 57,
+            ..default()
+        };
+        assert_eq!(
+            run_details(&stats),
+            "Units produced: 12  Gold earned: 340  Time: 04:05  Trade ratio: 0.45  Peak units: 57"
+        );
+    }
+
+    #[test]
+    fn run_details_without_production_spend() {
+        assert_eq!(
+            run_details(&RunStats::default()),
+            "Units produced: 0  Gold earned: 0  Time: 00:00  Trade ratio: -  Peak units: 0"
+        );
+    }
+
+    #[test]
+    fn run_summary_without_kills() {
+        let stats = RunStats {
+            gold_spent: 100,
+            kills: 0,
+            ..default()
+        };
+        assert_eq!(
+            run_summary(&stats),
+            "Gold spent: 100  Kills: 0  Gold/kill: -"
+        );
+    }
+
+    // === Quick Restart ===
+
+    /// Full game with one player unit, one Barracks, and spent gold, sitting on
+    /// the defeat overlay.
+    fn create_defeated_run() -> App {
+        use crate::gameplay::Team;
+        use crate::gameplay::building::BuildingType;
+        use crate::gameplay::economy::Gold;
+
+        let mut app = crate::testing::create_full_test_app();
+        crate::testing::transition_to_ingame(&mut app);
+        let unit = crate::testing::spawn_test_unit(app.world_mut(), Team::Player, 600.0, 160.0);
+        app.world_mut()
+            .entity_mut(unit)
+            .insert(DespawnOnExit(GameState::InGame));
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 2, 3);
+        app.world_mut()
+            .entity_mut(building)
+            .insert((Team::Player, DespawnOnExit(GameState::InGame)));
+        app.world_mut().resource_mut::<Gold>().0 = 3;
+
+        app.world_mut()
+            .resource_mut::<NextState<Menu>>()
+            .set(Menu::Defeat);
+        app.update();
+        app.update();
+        app
+    }
+
+    /// Feeds a real key press through `InputPlugin` (which clears any
+    /// `ButtonInput::press` made between frames) and runs two frames.
+    fn press_key(app: &mut App, key_code: KeyCode, logical_key: bevy::input::keyboard::Key) {
+        use bevy::input::ButtonState;
+        use bevy::input::keyboard::KeyboardInput;
+
+        let window = app
+            .world_mut()
+            .query_filtered::<Entity, With<Window>>()
+            .single(app.world())
+            .unwrap();
+        app.world_mut().write_message(KeyboardInput {
+            key_code,
+            logical_key,
+            state: ButtonState::Pressed,
+            text: None,
+            repeat: false,
+            window,
+        });
+        app.update();
+        app.update();
+    }
+
+    #[test]
+    fn enter_on_defeat_overlay_restarts_a_fresh_run() {
+        use crate::gameplay::building::Building;
+        use crate::gameplay::economy::{Gold, STARTING_GOLD};
+        use crate::gameplay::units::Unit;
+
+        let mut app = create_defeated_run();
+
+        press_key(&mut app, KeyCode::Enter, bevy::input::keyboard::Key::Enter);
+
+        assert_eq!(*app.world().resource::<State<Menu>>().get(), Menu::None);
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::InGame
+        );
+        assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+        assert_eq!(app.world().resource::<Gold>().0, STARTING_GOLD);
+        assert_entity_count::<With<Building>>(&mut app, 0);
+        assert_entity_count::<With<Unit>>(&mut app, 0);
+    }
+
+    #[test]
+    fn space_on_defeat_overlay_restarts() {
+        let mut app = create_defeated_run();
+
+        press_key(&mut app, KeyCode::Space, bevy::input::keyboard::Key::Space);
+
+        assert_eq!(*app.world().resource::<State<Menu>>().get(), Menu::None);
+        assert_eq!(
+            app.world().resource::<crate::gameplay::economy::Gold>().0,
+            crate::gameplay::economy::STARTING_GOLD
+        );
     }
 }
@@ -0,0 +1,99 @@
+//! Pause input guard: swallows gameplay clicks on the first frame after unpausing.
+
+use bevy::prelude::*;
+
+use super::Menu;
+
+/// Armed for one frame after the pause menu closes. While armed, gameplay
+/// click handlers (building placement, shop cards) ignore mouse input so a
+/// click buffered during the pause doesn't leak into the resumed game.
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct PauseInputGuard {
+    armed: bool,
+}
+
+impl PauseInputGuard {
+    /// Whether gameplay click input should be ignored this frame.
+    #[must_use]
+    pub const fn is_armed(&self) -> bool {
+        self.armed
+    }
+}
+
+#[cfg(test)]
+impl PauseInputGuard {
+    /// A guard as it is on the frame the pause menu closes.
+    pub(crate) const fn armed() -> Self {
+        Self { armed: true }
+    }
+}
+
+fn arm_pause_input_guard(mut guard: ResMut<PauseInputGuard>) {
+    guard.armed = true;
+}
+
+/// Disarms the guard at the end of the frame it was armed in.
+fn release_pause_input_guard(mut guard: ResMut<PauseInputGuard>) {
+    if guard.armed {
+        guard.armed = false;
+    }
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<PauseInputGuard>()
+        .init_resource::<PauseInputGuard>();
+
+    app.add_systems(OnExit(Menu::Pause), arm_pause_input_guard);
+    app.add_systems(Last, release_pause_input_guard);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_guard_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(bevy::state::app::StatesPlugin);
+        app.init_state::<Menu>();
+        app.add_plugins(plugin);
+        app.update();
+        app
+    }
+
+    /// Records whether the guard was armed during `Update`.
+    #[derive(Resource, Default)]
+    struct ArmedDuringUpdate(Vec<bool>);
+
+    fn record_guard(guard: Res<PauseInputGuard>, mut record: ResMut<ArmedDuringUpdate>) {
+        record.0.push(guard.is_armed());
+    }
+
+    #[test]
+    fn guard_starts_disarmed() {
+        let app = create_guard_test_app();
+        assert!(!app.world().resource::<PauseInputGuard>().is_armed());
+    }
+
+    #[test]
+    fn guard_armed_for_one_frame_after_unpause() {
+        let mut app = create_guard_test_app();
+        app.init_resource::<ArmedDuringUpdate>();
+        app.add_systems(Update, record_guard);
+
+        app.world_mut()
+            .resource_mut::<NextState<Menu>>()
+            .set(Menu::Pause);
+        app.update();
+        app.world_mut()
+            .resource_mut::<NextState<Menu>>()
+            .set(Menu::None);
+        app.update(); // Unpause frame: guard armed during Update
+        app.update(); // Next frame: released
+
+        let record = &app.world().resource::<ArmedDuringUpdate>().0;
+        assert_eq!(record.as_slice(), &[false, true, false]);
+        assert!(!app.world().resource::<PauseInputGuard>().is_armed());
+    }
+}
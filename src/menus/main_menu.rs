@@ -3,6 +3,10 @@
 use bevy::prelude::*;
 
 use super::Menu;
+use crate::gameplay::economy::Difficulty;
+use crate::gameplay::endgame_detection::{Objective, SURVIVE_SECS};
+use crate::gameplay::tutorial::{Tutorial, TutorialStep};
+use crate::gameplay::units::spawn::WaveSpawner;
 use crate::screens::GameState;
 use crate::theme::palette;
 use crate::theme::widget::{self, Activate};
@@ -11,7 +15,35 @@ pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Menu::Main), spawn_main_menu);
 }
 
-fn spawn_main_menu(mut commands: Commands) {
+/// Label for the wave mode toggle.
+const fn wave_mode_label(enabled: bool) -> &'static str {
+    if enabled { "Waves: On" } else { "Waves: Off" }
+}
+
+/// Label for the difficulty selector.
+const fn difficulty_label(difficulty: Difficulty) -> &'static str {
+    match difficulty {
+        Difficulty::Easy => "Difficulty: Easy",
+        Difficulty::Normal => "Difficulty: Normal",
+        Difficulty::Hard => "Difficulty: Hard",
+    }
+}
+
+/// Replaces the text of `button`'s label child.
+fn set_button_label(
+    button: Entity,
+    label: &str,
+    children: &Query<&Children>,
+    texts: &mut Query<&mut Text>,
+) {
+    for child in children.iter_descendants(button) {
+        if let Ok(mut text) = texts.get_mut(child) {
+            label.clone_into(&mut text.0);
+        }
+    }
+}
+
+fn spawn_main_menu(mut commands: Commands, waves: Res<WaveSpawner>, difficulty: Res<Difficulty>) {
     commands.spawn((
         widget::ui_root("Main Menu Screen"),
         DespawnOnExit(Menu::Main),
@@ -45,16 +77,91 @@ fn spawn_main_menu(mut commands: Commands) {
                         0,
                         true,
                         |_: On<Activate>,
+                         mut tutorial: ResMut<Tutorial>,
+                         mut objective: ResMut<Objective>,
                          mut next_game: ResMut<NextState<GameState>>,
                          mut next_menu: ResMut<NextState<Menu>>| {
+                            tutorial.step = None;
+                            *objective = Objective::DestroyFortress;
                             next_game.set(GameState::InGame);
                             next_menu.set(Menu::None);
                         },
                     ),
+                    // Survive objective button
+                    widget::button(
+                        "Survive 5 Minutes",
+                        1,
+                        false,
+                        |_: On<Activate>,
+                         mut tutorial: ResMut<Tutorial>,
+                         mut objective: ResMut<Objective>,
+                         mut next_game: ResMut<NextState<GameState>>,
+                         mut next_menu: ResMut<NextState<Menu>>| {
+                            tutorial.step = None;
+                            *objective = Objective::Survive { secs: SURVIVE_SECS };
+                            next_game.set(GameState::InGame);
+                            next_menu.set(Menu::None);
+                        },
+                    ),
+                    // Tutorial button
+                    widget::button(
+                        "Tutorial",
+                        2,
+                        false,
+                        |_: On<Activate>,
+                         mut tutorial: ResMut<Tutorial>,
+                         mut objective: ResMut<Objective>,
+                         mut waves: ResMut<WaveSpawner>,
+                         mut next_game: ResMut<NextState<GameState>>,
+                         mut next_menu: ResMut<NextState<Menu>>| {
+                            tutorial.step = Some(TutorialStep::SelectCard);
+                            *objective = Objective::DestroyFortress;
+                            // The "survive the first wave" step advances on `WaveCleared`
+                            waves.enabled = true;
+                            next_game.set(GameState::InGame);
+                            next_menu.set(Menu::None);
+                        },
+                    ),
+                    // Difficulty selector: cycles Easy -> Normal -> Hard
+                    widget::button(
+                        difficulty_label(*difficulty),
+                        3,
+                        false,
+                        |activate: On<Activate>,
+                         mut difficulty: ResMut<Difficulty>,
+                         children: Query<&Children>,
+                         mut texts: Query<&mut Text>| {
+                            *difficulty = difficulty.next();
+                            set_button_label(
+                                activate.0,
+                                difficulty_label(*difficulty),
+                                &children,
+                                &mut texts,
+                            );
+                        },
+                    ),
+                    // Wave mode toggle: discrete waves instead of a continuous trickle
+                    widget::button(
+                        wave_mode_label(waves.enabled),
+                        4,
+                        false,
+                        |activate: On<Activate>,
+                         mut waves: ResMut<WaveSpawner>,
+                         children: Query<&Children>,
+                         mut texts: Query<&mut Text>| {
+                            waves.enabled = !waves.enabled;
+                            set_button_label(
+                                activate.0,
+                                wave_mode_label(waves.enabled),
+                                &children,
+                                &mut texts,
+                            );
+                        },
+                    ),
                     // Exit button
                     widget::button(
                         "Exit Game",
-                        1,
+                        5,
                         false,
                         |_: On<Activate>, mut exit: MessageWriter<AppExit>| {
                             exit.write(AppExit::Success);
@@ -69,14 +176,17 @@ fn spawn_main_menu(mut commands: Commands) {
 #[cfg(test)]
 mod tests {
     use bevy::prelude::*;
+    use pretty_assertions::assert_eq;
 
+    use crate::gameplay::economy::Difficulty;
+    use crate::gameplay::units::spawn::WaveSpawner;
     use crate::menus::Menu;
+    use crate::testing::find_button;
+    use crate::theme::widget::Activate;
 
-    /// Verify that the main menu spawns UI entities when entering Menu::Main.
-    #[test]
-    fn main_menu_spawns_panel_and_buttons() {
+    /// App showing the main menu.
+    fn create_main_menu_test_app() -> App {
         use crate::screens::GameState;
-        use crate::testing::assert_entity_count;
         use bevy::state::app::StatesPlugin;
 
         let mut app = App::new();
@@ -84,6 +194,8 @@ mod tests {
         app.add_plugins(StatesPlugin);
         app.init_state::<GameState>();
         app.init_state::<Menu>();
+        app.init_resource::<WaveSpawner>();
+        app.init_resource::<Difficulty>();
         app.add_plugins(super::plugin);
 
         // Transition to Menu::Main
@@ -92,9 +204,55 @@ mod tests {
             .set(Menu::Main);
         app.update();
         app.update(); // Apply deferred
+        app
+    }
+
+    /// Verify that the main menu spawns UI entities when entering Menu::Main.
+    #[test]
+    fn main_menu_spawns_panel_and_buttons() {
+        use crate::testing::assert_entity_count;
+
+        let mut app = create_main_menu_test_app();
+
+        // Should have 1 Text entity (the title) and 6 Button entities
+        assert_entity_count::<With<Text>>(&mut app, 7); // title + 6 button labels
+        assert_entity_count::<With<Button>>(&mut app, 6); // start + survive + tutorial + difficulty + waves + exit
+    }
+
+    #[test]
+    fn wave_toggle_flips_wave_mode_and_label() {
+        let mut app = create_main_menu_test_app();
+        let (toggle, label) = find_button(&mut app, "Waves");
+        assert_eq!(label, "Waves: Off");
+
+        app.world_mut().trigger(Activate(toggle));
+        app.update();
+
+        assert!(app.world().resource::<WaveSpawner>().enabled);
+        assert_eq!(find_button(&mut app, "Waves").1, "Waves: On");
+
+        app.world_mut().trigger(Activate(toggle));
+        app.update();
+
+        assert!(!app.world().resource::<WaveSpawner>().enabled);
+    }
+
+    #[test]
+    fn difficulty_selector_cycles_difficulty_and_label() {
+        let mut app = create_main_menu_test_app();
+        let (selector, label) = find_button(&mut app, "Difficulty");
+        assert_eq!(label, "Difficulty: Normal");
+
+        app.world_mut().trigger(Activate(selector));
+        app.update();
+
+        assert_eq!(*app.world().resource::<Difficulty>(), Difficulty::Hard);
+        assert_eq!(find_button(&mut app, "Difficulty").1, "Difficulty: Hard");
+
+        app.world_mut().trigger(Activate(selector));
+        app.update();
 
-        // Should have at least 1 Text entity (the title) and 2 Button entities
-        assert_entity_count::<With<Text>>(&mut app, 3); // title + 2 button labels
-        assert_entity_count::<With<Button>>(&mut app, 2); // start + exit
+        assert_eq!(*app.world().resource::<Difficulty>(), Difficulty::Easy);
+        assert_eq!(find_button(&mut app, "Difficulty").1, "Difficulty: Easy");
     }
 }
@@ -5,9 +5,12 @@
 //! is active, and `Menu::Main` appears while `GameState::MainMenu` is active.
 
 mod endgame;
+mod input_guard;
 mod main_menu;
 mod pause;
 
+pub(crate) use input_guard::PauseInputGuard;
+
 use bevy::prelude::*;
 
 use crate::screens::GameState;
@@ -31,7 +34,12 @@ pub enum Menu {
 
 pub fn plugin(app: &mut App) {
     app.init_state::<Menu>();
-    app.add_plugins((main_menu::plugin, pause::plugin, endgame::plugin));
+    app.add_plugins((
+        main_menu::plugin,
+        pause::plugin,
+        endgame::plugin,
+        input_guard::plugin,
+    ));
 
     // Pause/unpause virtual time when any menu overlay opens/closes.
     // This stops physics (avian2d runs in FixedPostUpdate, which accumulates from Time<Virtual>)
@@ -46,6 +54,13 @@ pub fn plugin(app: &mut App) {
     app.add_systems(OnExit(GameState::InGame), unpause_virtual_time_on_game_exit);
 }
 
+/// Starts a fresh run from any in-game overlay: re-entering `InGame` runs every
+/// `OnExit`/`OnEnter` reset, and returning to `Menu::None` resumes virtual time.
+fn request_restart(next_game: &mut NextState<GameState>, next_menu: &mut NextState<Menu>) {
+    next_menu.set(Menu::None);
+    next_game.set(GameState::InGame);
+}
+
 fn pause_virtual_time(mut time: ResMut<Time<Virtual>>) {
     time.pause();
 }
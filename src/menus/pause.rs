@@ -1,14 +1,48 @@
-//! Pause menu UI: bordered panel with "Continue" and "Exit Game" buttons.
+//! Pause menu UI: bordered panel with "Continue", "Save", "Load", "Restart", and
+//! "Exit Game" buttons.
+//! Also opened automatically on wave clear when `Settings::pause_between_waves` is on.
 
 use bevy::prelude::*;
 
 use super::Menu;
+use crate::gameplay::save::{load_game, save_game};
+use crate::gameplay::units::spawn::WaveCleared;
 use crate::screens::GameState;
+use crate::settings::Settings;
 use crate::theme::palette;
 use crate::theme::widget::{self, Activate};
+use crate::{GameSet, gameplay_running};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Menu::Pause), spawn_pause_menu);
+    app.add_systems(
+        Update,
+        pause_on_wave_clear
+            .in_set(GameSet::Ui)
+            .run_if(gameplay_running),
+    );
+}
+
+/// Opens the pause menu (pausing virtual time) when a wave is cleared, if the
+/// player opted in. Continue resumes play where the wave left off.
+fn pause_on_wave_clear(
+    mut cleared: MessageReader<WaveCleared>,
+    settings: Res<Settings>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    let any_cleared = cleared.read().count() > 0;
+    if any_cleared && settings.pause_between_waves {
+        next_menu.set(Menu::Pause);
+    }
+}
+
+/// Restarts the run (see [`super::request_restart`]).
+fn restart_run(
+    _: On<Activate>,
+    mut next_game: ResMut<NextState<GameState>>,
+    mut next_menu: ResMut<NextState<Menu>>,
+) {
+    super::request_restart(&mut next_game, &mut next_menu);
 }
 
 fn spawn_pause_menu(mut commands: Commands) {
@@ -50,10 +84,30 @@ fn spawn_pause_menu(mut commands: Commands) {
                             next_menu.set(Menu::None);
                         },
                     ),
+                    // Save button
+                    widget::button(
+                        "Save",
+                        1,
+                        false,
+                        |_: On<Activate>, mut commands: Commands| {
+                            commands.run_system_cached(save_game);
+                        },
+                    ),
+                    // Load button
+                    widget::button(
+                        "Load",
+                        2,
+                        false,
+                        |_: On<Activate>, mut commands: Commands| {
+                            commands.run_system_cached(load_game);
+                        },
+                    ),
+                    // Restart button
+                    widget::button("Restart", 3, false, restart_run),
                     // Exit Game button
                     widget::button(
                         "Exit Game",
-                        1,
+                        4,
                         false,
                         |_: On<Activate>, mut next_game: ResMut<NextState<GameState>>| {
                             next_game.set(GameState::MainMenu);
@@ -73,6 +127,68 @@ mod tests {
     use crate::screens::GameState;
     use crate::testing::assert_entity_count;
 
+    fn create_wave_pause_test_app(pause_between_waves: bool) -> App {
+        use crate::gameplay::save::{load_game, save_game};
+        use crate::gameplay::units::spawn::WaveCleared;
+        use crate::settings::Settings;
+
+        let mut app = crate::testing::create_base_test_app();
+        app.add_message::<WaveCleared>();
+        app.insert_resource(Settings {
+            pause_between_waves,
+            ..Settings::default()
+        });
+        app.add_systems(
+            Update,
+            super::pause_on_wave_clear.run_if(crate::gameplay_running),
+        );
+        crate::testing::transition_to_ingame(&mut app);
+        app
+    }
+
+    fn clear_wave(app: &mut App, wave: u32) {
+        app.world_mut()
+            .write_message(crate::gameplay::units::spawn::WaveCleared { wave });
+        app.update();
+        app.update(); // Apply the menu transition
+    }
+
+    fn current_menu(app: &App) -> Menu {
+        *app.world().resource::<State<Menu>>().get()
+    }
+
+    #[test]
+    fn wave_clear_opens_pause_menu_when_enabled() {
+        let mut app = create_wave_pause_test_app(true);
+        clear_wave(&mut app, 1);
+        assert_eq!(current_menu(&app), Menu::Pause);
+    }
+
+    #[test]
+    fn wave_clear_does_not_pause_when_disabled() {
+        let mut app = create_wave_pause_test_app(false);
+        clear_wave(&mut app, 1);
+        assert_eq!(current_menu(&app), Menu::None);
+    }
+
+    #[test]
+    fn resuming_after_wave_pause_continues_to_next_wave() {
+        let mut app = create_wave_pause_test_app(true);
+        clear_wave(&mut app, 1);
+
+        // Continue
+        app.world_mut()
+            .resource_mut::<NextState<Menu>>()
+            .set(Menu::None);
+        app.update();
+        app.update();
+        assert_eq!(current_menu(&app), Menu::None);
+
+        // The next wave's clear pauses again
+        clear_wave(&mut app, 2);
+        assert_eq!(current_menu(&app), Menu::Pause);
+    }
+
     #[test]
     fn pause_menu_spawns_panel_and_buttons() {
         use bevy::state::app::StatesPlugin;
@@ -82,6 +198,8 @@ mod tests {
         app.add_plugins(StatesPlugin);
         app.init_state::<GameState>();
         app.init_state::<Menu>();
+        app.init_resource::<crate::settings::Settings>();
+        app.add_message::<crate::gameplay::units::spawn::WaveCleared>();
         app.add_plugins(super::plugin);
 
         // Transition to InGame then Pause
@@ -95,9 +213,52 @@ mod tests {
         app.update();
         app.update(); // Apply deferred
 
-        // Title + 2 button labels
-        assert_entity_count::<With<Text>>(&mut app, 3);
-        // Continue + Exit Game
-        assert_entity_count::<With<Button>>(&mut app, 2);
+        // Title + 5 button labels
+        assert_entity_count::<With<Text>>(&mut app, 6);
+        // Continue + Save + Load + Restart + Exit Game
+        assert_entity_count::<With<Button>>(&mut app, 5);
+    }
+
+    #[test]
+    fn restart_resets_gold_and_clears_the_battlefield() {
+        use crate::gameplay::Team;
+        use crate::gameplay::building::{Building, BuildingType};
+        use crate::gameplay::economy::{Gold, STARTING_GOLD};
+        use crate::gameplay::units::Unit;
+        use crate::theme::widget::Activate;
+
+        let mut app = crate::testing::create_full_test_app();
+        crate::testing::transition_to_ingame(&mut app);
+        let unit = crate::testing::spawn_test_unit(app.world_mut(), Team::Enemy, 1500.0, 160.0);
+        app.world_mut()
+            .entity_mut(unit)
+            .insert(DespawnOnExit(GameState::InGame));
+        let building =
+            crate::testing::spawn_test_building(app.world_mut(), BuildingType::Barracks, 2, 3);
+        app.world_mut()
+            .entity_mut(building)
+            .insert((Team::Player, DespawnOnExit(GameState::InGame)));
+        app.world_mut().resource_mut::<Gold>().0 = 7;
+
+        app.world_mut()
+            .resource_mut::<NextState<Menu>>()
+            .set(Menu::Pause);
+        app.update();
+        app.update();
+
+        let (restart, _) = crate::testing::find_button(&mut app, "Restart");
+        app.world_mut().trigger(Activate(restart));
+        app.update();
+        app.update();
+
+        assert_eq!(current_menu(&app), Menu::None);
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::InGame
+        );
+        assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+        assert_eq!(app.world().resource::<Gold>().0, STARTING_GOLD);
+        assert_entity_count::<With<Building>>(&mut app, 0);
+        assert_entity_count::<With<Unit>>(&mut app, 0);
     }
 }
@@ -0,0 +1,37 @@
+//! Player-adjustable settings shared by presentation and gameplay.
+//!
+//! [`Settings`] is a single global resource. Each consumer reads the fields it
+//! cares about: the theme applies `font_scale`, the pause menu honors
+//! `pause_between_waves`, the HUD honors `focus_camera_on_alerts`, and building
+//! production honors `recall_units_after_kill`.
+
+use bevy::prelude::*;
+
+pub fn plugin(app: &mut App) {
+    app.register_type::<Settings>().init_resource::<Settings>();
+}
+
+/// Player-adjustable settings.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct Settings {
+    /// Multiplier for every UI font size (1.0 = palette sizes).
+    pub font_scale: f32,
+    /// Open the pause menu automatically each time a wave is cleared.
+    pub pause_between_waves: bool,
+    /// Briefly pan the camera to breakthroughs and hits on the player fortress.
+    pub focus_camera_on_alerts: bool,
+    /// Produced units walk back to their building after a kill with no enemy nearby.
+    pub recall_units_after_kill: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            font_scale: 1.0,
+            pause_between_waves: false,
+            focus_camera_on_alerts: false,
+            recall_units_after_kill: false,
+        }
+    }
+}
@@ -9,16 +9,18 @@ use bevy::state::app::StatesPlugin;
 use bevy::state::state::FreelyMutableState;
 use bevy::window::WindowPlugin;
 
-use crate::gameplay::combat::AttackTimer;
+use crate::gameplay::building::{Building, BuildingType, Facing};
+use crate::gameplay::combat::{AttackTimer, HealthBarBackground, Projectile};
 use crate::gameplay::units::avoidance::{AvoidanceAgent, PreferredVelocity};
 use crate::gameplay::units::pathfinding::NavPath;
-use crate::gameplay::units::{UNIT_RADIUS, Unit, UnitType, unit_stats};
+use crate::gameplay::units::{EnemyUnit, PlayerUnit, UNIT_RADIUS, Unit, UnitType, unit_stats};
 use crate::gameplay::{CombatStats, EntityExtent, Health, Movement, Target, TargetingState, Team};
 
 /// Creates a minimal app for testing with essential plugins.
 pub fn create_test_app() -> App {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
+    app.add_message::<crate::gameplay::HealthChanged>();
     app
 }
 
@@ -42,6 +44,9 @@ pub fn create_base_test_app() -> App {
     app.add_plugins(WindowPlugin::default());
     app.init_state::<crate::screens::GameState>();
     app.init_state::<crate::menus::Menu>();
+    app.init_resource::<crate::menus::PauseInputGuard>();
+    app.init_resource::<crate::settings::Settings>();
+    app.init_resource::<crate::gameplay::GameRng>();
     app.world_mut().spawn(Camera2d);
     app
 }
@@ -58,10 +63,32 @@ pub fn create_base_test_app_no_input() -> App {
     app.add_plugins(WindowPlugin::default());
     app.init_state::<crate::screens::GameState>();
     app.init_state::<crate::menus::Menu>();
+    app.init_resource::<crate::menus::PauseInputGuard>();
+    app.init_resource::<crate::settings::Settings>();
+    app.init_resource::<crate::gameplay::GameRng>();
     app.world_mut().spawn(Camera2d);
     app
 }
 
+/// Creates a headless app running the full game plugin (`crate::plugin`).
+///
+/// Use for cross-domain ordering tests. Does NOT transition to `InGame` —
+/// call [`transition_to_ingame`].
+#[allow(dead_code)]
+pub fn create_full_test_app() -> App {
+    let mut app = create_test_app();
+    app.add_plugins(StatesPlugin);
+    app.add_plugins(InputPlugin);
+    app.add_plugins(WindowPlugin::default());
+    app.add_plugins(TransformPlugin);
+    app.add_plugins(bevy::asset::AssetPlugin::default());
+    // Gizmo support needed by vleue_navigator's debug-with-gizmos feature (active in dev builds)
+    app.add_plugins(bevy::gizmos::GizmoPlugin);
+    init_asset_resources(&mut app);
+    app.add_plugins(crate::plugin);
+    app
+}
+
 /// Transitions the app to `GameState::InGame` and runs two updates
 /// (first applies the transition + `OnEnter`, second applies deferred commands).
 pub fn transition_to_ingame(app: &mut App) {
@@ -103,6 +130,54 @@ pub fn tick_multiple(app: &mut App, count: usize) {
     }
 }
 
+// === World Snapshots ===
+
+/// Entity counts by key marker, captured with [`WorldSnapshot::capture`].
+///
+/// Usage: take a snapshot, run `app.update()`, take another, and compare with
+/// `before.diff(&after)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WorldSnapshot {
+    pub units: usize,
+    pub buildings: usize,
+    pub projectiles: usize,
+    pub health_bars: usize,
+}
+
+/// Signed change in entity counts between two snapshots (positive = added).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    pub units: isize,
+    pub buildings: isize,
+    pub projectiles: isize,
+    pub health_bars: isize,
+}
+
+impl WorldSnapshot {
+    /// Count units, buildings, projectiles, and health bars in `app`.
+    pub fn capture(app: &mut App) -> Self {
+        Self {
+            units: count_entities::<With<Unit>>(app),
+            buildings: count_entities::<With<Building>>(app),
+            projectiles: count_entities::<With<Projectile>>(app),
+            health_bars: count_entities::<With<HealthBarBackground>>(app),
+        }
+    }
+
+    /// Change from `self` to `later`.
+    #[must_use]
+    pub fn diff(&self, later: &Self) -> SnapshotDiff {
+        #[allow(clippy::cast_possible_wrap)] // Entity counts never approach isize::MAX
+        let delta = |before: usize, after: usize| after as isize - before as isize;
+        SnapshotDiff {
+            units: delta(self.units, later.units),
+            buildings: delta(self.buildings, later.buildings),
+            projectiles: delta(self.projectiles, later.projectiles),
+            health_bars: delta(self.health_bars, later.health_bars),
+        }
+    }
+}
+
 // === Timer Helpers ===
 
 /// Set a timer's elapsed to `duration - 1ns` so the next `tick()` with any
@@ -115,6 +190,49 @@ pub fn nearly_expire_timer(timer: &mut Timer) {
     timer.set_elapsed(duration - Duration::from_nanos(1));
 }
 
+/// Step size used by [`advance_secs`].
+const ADVANCE_STEP: Duration = Duration::from_millis(10);
+
+/// Run `n` updates, each advancing virtual time by exactly `dt`.
+///
+/// If the clock hasn't started yet (no update has run), one extra zero-length
+/// update runs first so every counted update sees the full `dt`. The app's
+/// `TimeUpdateStrategy` is restored afterwards. `dt` above `Time<Virtual>`'s
+/// max delta (250ms by default) is clamped by Bevy.
+#[allow(dead_code)]
+pub fn advance_frames(app: &mut App, dt: Duration, n: u32) {
+    use bevy::time::TimeUpdateStrategy;
+
+    let previous = app.world_mut().remove_resource::<TimeUpdateStrategy>();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(dt));
+    if app
+        .world()
+        .resource::<Time<Real>>()
+        .first_update()
+        .is_none()
+    {
+        app.update();
+    }
+    for _ in 0..n {
+        app.update();
+    }
+    app.insert_resource(previous.unwrap_or_default());
+}
+
+/// Advance virtual time by `secs` in deterministic 10ms updates (plus one
+/// shorter update for any remainder). See [`advance_frames`].
+#[allow(dead_code)]
+pub fn advance_secs(app: &mut App, secs: f32) {
+    let total = Duration::from_secs_f32(secs);
+    let steps = total.as_nanos() / ADVANCE_STEP.as_nanos();
+    let steps = u32::try_from(steps).expect("advance_secs: too many steps");
+    advance_frames(app, ADVANCE_STEP, steps);
+    let remainder = total - ADVANCE_STEP * steps;
+    if !remainder.is_zero() {
+        advance_frames(app, remainder, 1);
+    }
+}
+
 // === Resource Init Helpers ===
 
 /// Init `Assets<Mesh>` and `Assets<ColorMaterial>` — needed by any test that
@@ -125,27 +243,32 @@ pub fn init_asset_resources(app: &mut App) {
     app.init_resource::<Assets<ColorMaterial>>();
 }
 
-/// Init `Gold` and `Shop` resources — needed by building placement and
-/// production tests.
+/// Init `Gold`, `Shop` and quick-buy resources — needed by building placement
+/// and production tests.
 #[allow(dead_code)]
 pub fn init_economy_resources(app: &mut App) {
     app.init_resource::<crate::gameplay::economy::Gold>();
+    app.init_resource::<crate::gameplay::economy::RunStats>();
     app.init_resource::<crate::gameplay::economy::shop::Shop>();
+    app.init_resource::<crate::gameplay::economy::shop_ui::QuickBuyRequest>();
 }
 
-/// Init `ButtonInput<KeyCode>` and `ButtonInput<MouseButton>` — needed when
-/// `InputPlugin` is skipped to avoid `just_pressed` being cleared in `PreUpdate`.
+/// Init `ButtonInput<KeyCode>`, `ButtonInput<MouseButton>` and
+/// `AccumulatedMouseScroll` — needed when `InputPlugin` is skipped to avoid
+/// `just_pressed` being cleared in `PreUpdate`.
 #[allow(dead_code)]
 pub fn init_input_resources(app: &mut App) {
     app.init_resource::<ButtonInput<KeyCode>>();
     app.init_resource::<ButtonInput<MouseButton>>();
+    app.init_resource::<bevy::input::mouse::AccumulatedMouseScroll>();
 }
 
 // === Entity Spawn Helpers ===
 
 /// Spawn a test unit with the full Soldier archetype at `(x, y)`.
 ///
-/// Includes: Unit, UnitType::Soldier, Team, Target, TargetingState::Seeking,
+/// Includes: Unit, UnitType::Soldier, Team (plus `PlayerUnit`/`EnemyUnit`), Target,
+/// TargetingState::Seeking,
 /// Health, CombatStats, Movement, AttackTimer, Transform, GlobalTransform,
 /// Collider, LinearVelocity, NavPath.
 ///
@@ -153,7 +276,7 @@ pub fn init_input_resources(app: &mut App) {
 #[allow(dead_code)]
 pub fn spawn_test_unit(world: &mut World, team: Team, x: f32, y: f32) -> Entity {
     let stats = unit_stats(UnitType::Soldier);
-    world
+    let id = world
         .spawn((
             Unit,
             UnitType::Soldier,
@@ -184,7 +307,12 @@ pub fn spawn_test_unit(world: &mut World, team: Team, x: f32, y: f32) -> Entity
             AvoidanceAgent::default(),
             NavPath::default(),
         ))
-        .id()
+        .id();
+    match team {
+        Team::Player => world.entity_mut(id).insert(PlayerUnit),
+        Team::Enemy => world.entity_mut(id).insert(EnemyUnit),
+    };
+    id
 }
 
 /// Spawn a non-unit targetable entity at `(x, y)`.
@@ -204,3 +332,203 @@ pub fn spawn_test_target(world: &mut World, team: Team, x: f32, y: f32) -> Entit
         ))
         .id()
 }
+
+/// Spawn a bare `Building` of `building_type` at grid (`grid_col`, `grid_row`),
+/// default facing.
+///
+/// Callers add `Team`, `Transform`, `ProductionTimer` etc. via
+/// `world.entity_mut(id).insert(...)`.
+#[allow(dead_code)]
+pub fn spawn_test_building(
+    world: &mut World,
+    building_type: BuildingType,
+    grid_col: u16,
+    grid_row: u16,
+) -> Entity {
+    world
+        .spawn(Building {
+            building_type,
+            grid_col,
+            grid_row,
+            facing: Facing::default(),
+            next_lane: 0,
+        })
+        .id()
+}
+
+// === UI Helpers ===
+
+/// The button whose label starts with `prefix`, and its current label.
+///
+/// Panics if no spawned `Button` has a child `Text` starting with `prefix`.
+#[allow(dead_code)]
+pub fn find_button(app: &mut App, prefix: &str) -> (Entity, String) {
+    let mut buttons = app
+        .world_mut()
+        .query_filtered::<(Entity, &Children), With<Button>>();
+    let mut texts = app.world_mut().query::<&Text>();
+    let world = app.world();
+    buttons
+        .iter(world)
+        .find_map(|(entity, children)| {
+            children.iter().find_map(|child| {
+                texts
+                    .get(world, child)
+                    .ok()
+                    .filter(|text| text.0.starts_with(prefix))
+                    .map(|text| (entity, text.0.clone()))
+            })
+        })
+        .unwrap_or_else(|| panic!("no button labelled {prefix:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn spawn_projectile(world: &mut World) -> Entity {
+        world
+            .spawn(Projectile {
+                target: Entity::PLACEHOLDER,
+                damage: 1.0,
+                speed: 1.0,
+                splash_radius: 0.0,
+                attacker: None,
+                origin: Vec2::ZERO,
+                knockback: 0.0,
+            })
+            .id()
+    }
+
+    fn spawn_building_in_update(mut commands: Commands) {
+        commands.spawn(Building {
+            building_type: BuildingType::Farm,
+            grid_col: 0,
+            grid_row: 0,
+            facing: Facing::default(),
+            next_lane: 0,
+        });
+    }
+
+    fn despawn_projectiles_in_update(
+        mut commands: Commands,
+        projectiles: Query<Entity, With<Projectile>>,
+    ) {
+        for entity in &projectiles {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    /// One-second timer ticked by [`tick_test_timer`].
+    #[derive(Resource)]
+    struct TestTimer(Timer);
+
+    fn tick_test_timer(time: Res<Time>, mut timer: ResMut<TestTimer>) {
+        timer.0.tick(time.delta());
+    }
+
+    fn create_timer_test_app() -> App {
+        let mut app = create_test_app();
+        app.insert_resource(TestTimer(Timer::from_seconds(1.0, TimerMode::Once)));
+        app.add_systems(Update, tick_test_timer);
+        app
+    }
+
+    #[test]
+    fn advance_frames_fires_timer_after_accumulated_time() {
+        let mut app = create_timer_test_app();
+
+        advance_frames(&mut app, Duration::from_millis(100), 9);
+        assert!(!app.world().resource::<TestTimer>().0.is_finished());
+
+        advance_frames(&mut app, Duration::from_millis(100), 1);
+        assert!(app.world().resource::<TestTimer>().0.is_finished());
+        assert_eq!(
+            app.world().resource::<Time<Virtual>>().elapsed(),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn advance_secs_accumulates_exactly_including_remainder() {
+        let mut app = create_timer_test_app();
+
+        advance_secs(&mut app, 0.995);
+        assert!(!app.world().resource::<TestTimer>().0.is_finished());
+
+        advance_secs(&mut app, 0.005);
+        assert!(app.world().resource::<TestTimer>().0.is_finished());
+    }
+
+    #[test]
+    fn advance_frames_restores_time_update_strategy() {
+        use bevy::time::TimeUpdateStrategy;
+
+        let mut app = create_timer_test_app();
+        advance_frames(&mut app, Duration::from_millis(50), 2);
+
+        assert!(matches!(
+            app.world().resource::<TimeUpdateStrategy>(),
+            TimeUpdateStrategy::Automatic
+        ));
+    }
+
+    #[test]
+    fn empty_world_snapshot_is_all_zero() {
+        let mut app = create_test_app();
+        assert_eq!(WorldSnapshot::capture(&mut app), WorldSnapshot::default());
+    }
+
+    #[test]
+    fn snapshot_counts_each_marker() {
+        let mut app = create_test_app();
+        spawn_test_unit(app.world_mut(), Team::Player, 0.0, 0.0);
+        spawn_test_unit(app.world_mut(), Team::Enemy, 10.0, 0.0);
+        spawn_projectile(app.world_mut());
+        app.world_mut().spawn(HealthBarBackground);
+
+        assert_eq!(
+            WorldSnapshot::capture(&mut app),
+            WorldSnapshot {
+                units: 2,
+                buildings: 0,
+                projectiles: 1,
+                health_bars: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn diff_reports_added_and_removed_across_update() {
+        let mut app = create_test_app();
+        app.add_systems(
+            Update,
+            (spawn_building_in_update, despawn_projectiles_in_update),
+        );
+        spawn_projectile(app.world_mut());
+        spawn_projectile(app.world_mut());
+        spawn_test_unit(app.world_mut(), Team::Player, 0.0, 0.0);
+
+        let before = WorldSnapshot::capture(&mut app);
+        app.update();
+        let after = WorldSnapshot::capture(&mut app);
+
+        assert_eq!(
+            before.diff(&after),
+            SnapshotDiff {
+                buildings: 1,
+                projectiles: -2,
+                ..default()
+            }
+        );
+    }
+
+    #[test]
+    fn diff_of_identical_snapshots_is_zero() {
+        let mut app = create_test_app();
+        spawn_test_unit(app.world_mut(), Team::Player, 0.0, 0.0);
+        let snapshot = WorldSnapshot::capture(&mut app);
+        assert_eq!(snapshot.diff(&snapshot), SnapshotDiff::default());
+    }
+}
@@ -0,0 +1,151 @@
+//! Accessibility: a global multiplier for all UI font sizes.
+//!
+//! Text is spawned with the base palette sizes (`FONT_SIZE_*`). When a `TextFont`
+//! is added, its base size is remembered in [`BaseFontSize`] and the current
+//! [`Settings::font_scale`] is applied. Changing the scale rescales every existing
+//! text entity. Ctrl+= / Ctrl+- step the scale.
+
+use bevy::prelude::*;
+
+use crate::settings::Settings;
+
+/// Smallest allowed font scale.
+pub const MIN_FONT_SCALE: f32 = 1.0;
+
+/// Largest allowed font scale.
+pub const MAX_FONT_SCALE: f32 = 2.0;
+
+/// Font scale change per Ctrl+= / Ctrl+- press.
+const FONT_SCALE_STEP: f32 = 0.25;
+
+/// Unscaled font size a text entity was spawned with.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct BaseFontSize(pub f32);
+
+/// Records the spawn-time size of new text and applies the current scale.
+fn scale_new_text(
+    add: On<Add, TextFont>,
+    settings: Res<Settings>,
+    mut fonts: Query<&mut TextFont>,
+    mut commands: Commands,
+) {
+    let Ok(mut font) = fonts.get_mut(add.entity) else {
+        return;
+    };
+    let base = font.font_size;
+    font.font_size = base * settings.font_scale;
+    commands.entity(add.entity).insert(BaseFontSize(base));
+}
+
+/// Rescales all existing text when the font scale changes.
+fn rescale_text(settings: Res<Settings>, mut fonts: Query<(&BaseFontSize, &mut TextFont)>) {
+    if !settings.is_changed() {
+        return;
+    }
+    for (base, mut font) in &mut fonts {
+        font.font_size = base.0 * settings.font_scale;
+    }
+}
+
+fn adjust_font_scale(keyboard: Res<ButtonInput<KeyCode>>, mut settings: ResMut<Settings>) {
+    if !keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        return;
+    }
+    let step = if keyboard.just_pressed(KeyCode::Equal) {
+        FONT_SCALE_STEP
+    } else if keyboard.just_pressed(KeyCode::Minus) {
+        -FONT_SCALE_STEP
+    } else {
+        return;
+    };
+    settings.font_scale = (settings.font_scale + step).clamp(MIN_FONT_SCALE, MAX_FONT_SCALE);
+}
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<BaseFontSize>();
+
+    app.add_observer(scale_new_text);
+    app.add_systems(Update, (adjust_font_scale, rescale_text).chain());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::palette;
+    use pretty_assertions::assert_eq;
+
+    fn create_font_scale_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.init_resource::<Settings>();
+        app.add_plugins(plugin);
+        app
+    }
+
+    fn spawn_hud_text(app: &mut App) -> Entity {
+        app.world_mut()
+            .spawn((
+                Text::new("Gold: 200"),
+                TextFont::from_font_size(palette::FONT_SIZE_HUD),
+            ))
+            .id()
+    }
+
+    fn font_size(app: &App, entity: Entity) -> f32 {
+        app.world().get::<TextFont>(entity).unwrap().font_size
+    }
+
+    #[test]
+    fn default_scale_keeps_palette_size() {
+        let mut app = create_font_scale_test_app();
+        let text = spawn_hud_text(&mut app);
+        app.update();
+        assert_eq!(font_size(&app, text), palette::FONT_SIZE_HUD);
+    }
+
+    #[test]
+    fn text_spawned_at_scale_is_enlarged() {
+        let mut app = create_font_scale_test_app();
+        app.world_mut().resource_mut::<Settings>().font_scale = 1.5;
+        let text = spawn_hud_text(&mut app);
+        app.update();
+        assert_eq!(font_size(&app, text), palette::FONT_SIZE_HUD * 1.5);
+    }
+
+    #[test]
+    fn changing_scale_rescales_existing_text() {
+        let mut app = create_font_scale_test_app();
+        let text = spawn_hud_text(&mut app);
+        app.update();
+
+        app.world_mut().resource_mut::<Settings>().font_scale = 1.5;
+        app.update();
+        assert_eq!(font_size(&app, text), palette::FONT_SIZE_HUD * 1.5);
+
+        app.world_mut().resource_mut::<Settings>().font_scale = 1.0;
+        app.update();
+        assert_eq!(font_size(&app, text), palette::FONT_SIZE_HUD);
+    }
+
+    #[test]
+    fn ctrl_equal_steps_scale_up_within_bounds() {
+        let mut app = create_font_scale_test_app();
+        for _ in 0..10 {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.press(KeyCode::ControlLeft);
+            keyboard.press(KeyCode::Equal);
+            app.update();
+
+            // Release `=` so the next press registers as `just_pressed` again.
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.release(KeyCode::Equal);
+            keyboard.clear();
+        }
+        assert_eq!(
+            app.world().resource::<Settings>().font_scale,
+            MAX_FONT_SCALE
+        );
+    }
+}
@@ -1,8 +1,10 @@
-//! Shared UI theme: color palette, interaction feedback, and reusable widget constructors.
+//! Shared UI theme: color palette, interaction feedback, font scaling, and reusable
+//! widget constructors.
 
 use bevy::input_focus::InputDispatchPlugin;
 use bevy::input_focus::tab_navigation::TabNavigationPlugin;
 
+pub mod font_scale;
 pub mod interaction;
 pub mod palette;
 pub mod widget;
@@ -11,6 +13,7 @@ pub fn plugin(app: &mut bevy::prelude::App) {
     app.add_plugins((
         InputDispatchPlugin,
         TabNavigationPlugin,
+        font_scale::plugin,
         interaction::plugin,
         widget::plugin,
     ));
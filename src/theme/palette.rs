@@ -15,6 +15,18 @@ pub const BODY_TEXT: Color = Color::srgb(0.7, 0.7, 0.7);
 /// Gold/currency display text color (yellow-gold).
 pub const GOLD_TEXT: Color = Color::srgb(1.0, 0.85, 0.0);
 
+/// Gold display flash when gold goes up (green).
+pub const GOLD_GAIN_TEXT: Color = Color::srgb(0.3, 0.9, 0.3);
+
+/// Gold display flash when gold goes down (red).
+pub const GOLD_SPEND_TEXT: Color = Color::srgb(0.95, 0.3, 0.3);
+
+/// Breakthrough alert banner text (warning red).
+pub const BREAKTHROUGH_TEXT: Color = Color::srgb(1.0, 0.25, 0.2);
+
+/// Floating damage numbers over hit entities (soft orange).
+pub const DAMAGE_POPUP_TEXT: Color = Color::srgb(1.0, 0.6, 0.4);
+
 /// Button label text color.
 pub const BUTTON_TEXT: Color = Color::srgb(0.925, 0.925, 0.925);
 
@@ -46,6 +58,7 @@ pub const CARD_BACKGROUND: Color = Color::srgb(0.2, 0.2, 0.3);
 pub const CARD_SELECTED: Color = Color::srgb(0.3, 0.5, 0.3);
 pub const CARD_EMPTY: Color = Color::srgb(0.15, 0.15, 0.15);
 pub const CARD_HOVER: Color = Color::srgb(0.3, 0.3, 0.4);
+pub const CARD_LOCKED: Color = Color::srgb(0.45, 0.38, 0.15);
 pub const REROLL_BACKGROUND: Color = Color::srgb(0.4, 0.25, 0.1);
 
 // === Battlefield Colors ===
@@ -62,16 +75,25 @@ pub const BACKGROUND: Color = Color::srgb(0.1, 0.1, 0.12);
 
 pub const PLAYER_UNIT: Color = Color::srgb(0.2, 0.8, 0.2);
 pub const ENEMY_UNIT: Color = Color::srgb(0.8, 0.2, 0.2);
+pub const ARCHER_UNIT: Color = Color::srgb(0.2, 0.7, 0.6);
+pub const MAGE_UNIT: Color = Color::srgb(0.9, 0.5, 0.1);
 pub const PROJECTILE: Color = Color::srgb(1.0, 1.0, 0.3);
 pub const BARRACKS: Color = Color::srgb(0.15, 0.2, 0.6);
 pub const FARM: Color = Color::srgb(0.2, 0.6, 0.1);
+pub const SLOW_TOWER: Color = Color::srgb(0.4, 0.2, 0.6);
+pub const CATAPULT: Color = Color::srgb(0.55, 0.35, 0.15);
 
 // === Health/Progress Bar Colors ===
 
 pub const HEALTH_BAR_BG: Color = Color::srgb(0.8, 0.1, 0.1);
 pub const HEALTH_BAR_FILL: Color = Color::srgb(0.1, 0.9, 0.1);
+pub const HEALTH_BAR_FILL_HALF: Color = Color::srgb(0.95, 0.85, 0.1);
+pub const HEALTH_BAR_FILL_LOW: Color = Color::srgb(1.0, 0.15, 0.05);
 pub const PRODUCTION_BAR_BG: Color = Color::srgb(0.2, 0.2, 0.4);
 pub const PRODUCTION_BAR_FILL: Color = Color::srgb(0.3, 0.5, 0.9);
+pub const MINIMAP_HEAT: Color = Color::srgba(1.0, 0.45, 0.1, 0.85);
+pub const MINIMAP_PING: Color = Color::srgb(1.0, 0.1, 0.1);
+pub const MINIMAP_VIEWPORT: Color = Color::srgb(0.95, 0.95, 0.95);
 
 // === Font Size Tokens ===
 